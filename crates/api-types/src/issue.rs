@@ -30,6 +30,8 @@ pub struct Issue {
     pub start_date: Option<DateTime<Utc>>,
     pub target_date: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Set while the issue is archived. Archived issues are kept so they can be restored.
+    pub archived_at: Option<DateTime<Utc>>,
     pub sort_order: f64,
     pub parent_issue_id: Option<Uuid>,
     pub parent_issue_sort_order: Option<f64>,
@@ -46,7 +48,9 @@ pub enum IssueSortField {
     Priority,
     CreatedAt,
     UpdatedAt,
+    TargetDate,
     Title,
+    SimpleId,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
@@ -149,9 +153,50 @@ pub struct UpdateIssueRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ListIssuesQuery {
     pub project_id: Uuid,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<Uuid>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<IssuePriority>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_issue_id: Option<Uuid>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_user_id: Option<Uuid>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<Uuid>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+impl From<ListIssuesQuery> for SearchIssuesRequest {
+    fn from(query: ListIssuesQuery) -> Self {
+        Self {
+            project_id: query.project_id,
+            status_id: query.status_id,
+            priority: query.priority,
+            parent_issue_id: query.parent_issue_id,
+            search: query.search,
+            assignee_user_id: query.assignee_user_id,
+            tag_id: query.tag_id,
+            limit: query.limit,
+            offset: query.offset,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 pub struct SearchIssuesRequest {
     pub project_id: Uuid,
     #[ts(optional)]
@@ -193,6 +238,26 @@ pub struct SearchIssuesRequest {
     #[ts(optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<i32>,
+    /// Archived issues are included unless this is `false`.
+    #[ts(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_archived: Option<bool>,
+    /// Only issues created after this time.
+    #[ts(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only issues updated after this time.
+    #[ts(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_after: Option<DateTime<Utc>>,
+    /// Only issues completed after this time.
+    #[ts(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_after: Option<DateTime<Utc>>,
+    /// Only issues due before this time. Issues without a target date never match.
+    #[ts(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_date_before: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -202,3 +267,25 @@ pub struct ListIssuesResponse {
     pub limit: usize,
     pub offset: usize,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountIssuesQuery {
+    pub project_id: Uuid,
+}
+
+/// Number of a project's unarchived issues that share a status and priority.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCount {
+    pub status_id: Uuid,
+    pub priority: Option<IssuePriority>,
+    pub count: usize,
+    /// How many of these issues are linked to an open pull request.
+    pub with_open_pull_requests: usize,
+    /// How many of these issues are linked to a merged pull request.
+    pub with_merged_pull_requests: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCountsResponse {
+    pub counts: Vec<IssueCount>,
+}
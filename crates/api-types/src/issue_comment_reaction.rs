@@ -14,7 +14,7 @@ pub struct IssueCommentReaction {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct CreateIssueCommentReactionRequest {
     /// Optional client-generated ID. If not provided, server generates one.
     /// Using client-generated IDs enables stable optimistic updates.
@@ -35,7 +35,7 @@ pub struct ListIssueCommentReactionsQuery {
     pub comment_id: Uuid,
 }
 
-#[derive(Debug, Clone, Serialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ListIssueCommentReactionsResponse {
     pub issue_comment_reactions: Vec<IssueCommentReaction>,
 }
@@ -21,7 +21,8 @@ pub struct CreateIssueTagRequest {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListIssueTagsQuery {
-    pub issue_id: Uuid,
+    pub issue_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
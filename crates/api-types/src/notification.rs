@@ -80,8 +80,35 @@ pub struct NotificationPayload {
     pub emoji: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct UpdateNotificationRequest {
-    #[serde(default, deserialize_with = "some_if_present")]
+    #[serde(
+        default,
+        deserialize_with = "some_if_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub seen: Option<bool>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ListNotificationsResponse {
+    pub notifications: Vec<Notification>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateNotificationItem {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub changes: UpdateNotificationRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateNotificationsRequest {
+    pub updates: Vec<BulkUpdateNotificationItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateNotificationsResponse {
+    pub data: Vec<Notification>,
+    pub txid: i64,
+}
@@ -16,7 +16,7 @@ pub struct ProjectStatus {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct CreateProjectStatusRequest {
     /// Optional client-generated ID. If not provided, server generates one.
     /// Using client-generated IDs enables stable optimistic updates.
@@ -29,15 +29,31 @@ pub struct CreateProjectStatusRequest {
     pub hidden: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct UpdateProjectStatusRequest {
-    #[serde(default, deserialize_with = "some_if_present")]
+    #[serde(
+        default,
+        deserialize_with = "some_if_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub name: Option<String>,
-    #[serde(default, deserialize_with = "some_if_present")]
+    #[serde(
+        default,
+        deserialize_with = "some_if_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub color: Option<String>,
-    #[serde(default, deserialize_with = "some_if_present")]
+    #[serde(
+        default,
+        deserialize_with = "some_if_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sort_order: Option<i32>,
-    #[serde(default, deserialize_with = "some_if_present")]
+    #[serde(
+        default,
+        deserialize_with = "some_if_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub hidden: Option<bool>,
 }
 
@@ -46,7 +62,31 @@ pub struct ListProjectStatusesQuery {
     pub project_id: Uuid,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeleteProjectStatusQuery {
+    /// Status to move the deleted status's issues to. Must belong to the same project.
+    pub move_issues_to_status_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ListProjectStatusesResponse {
     pub project_statuses: Vec<ProjectStatus>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateProjectStatusItem {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub changes: UpdateProjectStatusRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateProjectStatusesRequest {
+    pub updates: Vec<BulkUpdateProjectStatusItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct BulkUpdateProjectStatusesResponse {
+    pub data: Vec<ProjectStatus>,
+    pub txid: i64,
+}
@@ -40,7 +40,14 @@ pub struct PullRequestIssue {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListPullRequestsQuery {
-    pub issue_id: Uuid,
+    pub issue_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListPullRequestIssuesQuery {
+    pub issue_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
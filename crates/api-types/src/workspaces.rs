@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::Workspace;
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DeleteWorkspaceRequest {
     pub local_workspace_id: Uuid,
@@ -37,3 +39,16 @@ pub struct UpdateWorkspaceRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lines_removed: Option<Option<i32>>,
 }
+
+/// Most local workspace ids accepted by one `/workspaces/by-local-ids` lookup.
+pub const MAX_LOCAL_IDS_PER_LOOKUP: usize = 500;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListWorkspacesByLocalIdsRequest {
+    pub local_workspace_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListWorkspacesByLocalIdsResponse {
+    pub workspaces: Vec<Workspace>,
+}
@@ -341,6 +341,39 @@ impl ExecutionProcess {
         .await
     }
 
+    /// Find execution processes for a workspace (across all sessions), oldest first.
+    /// Dropped processes are left out unless they are still running.
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ExecutionProcess,
+            r#"
+        SELECT
+            ep.id as "id!: Uuid",
+            ep.session_id as "session_id!: Uuid",
+            ep.run_reason as "run_reason!: ExecutionProcessRunReason",
+            ep.executor_action as "executor_action!: sqlx::types::Json<ExecutorActionField>",
+            ep.status as "status!: ExecutionProcessStatus",
+            ep.exit_code,
+            ep.dropped as "dropped!: bool",
+            ep.started_at as "started_at!: DateTime<Utc>",
+            ep.completed_at as "completed_at?: DateTime<Utc>",
+            ep.created_at as "created_at!: DateTime<Utc>",
+            ep.updated_at as "updated_at!: DateTime<Utc>"
+        FROM execution_processes ep
+        JOIN sessions s ON ep.session_id = s.id
+        WHERE s.workspace_id = ?
+          AND (ep.dropped = FALSE OR ep.status = 'running')
+        ORDER BY ep.created_at ASC
+        "#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     /// Find latest execution process by session and run reason
     /// Find latest execution process by workspace and run reason (across all sessions)
     pub async fn find_latest_by_workspace_and_run_reason(
@@ -373,6 +406,44 @@ impl ExecutionProcess {
         .await
     }
 
+    /// Find the most recent script runs for a repo and run reason, newest first.
+    /// Processes in multi-repo workspaces are tracked against every repo, so only
+    /// scripts that ran in this repo's directory are returned.
+    pub async fn find_recent_by_repo_and_run_reason(
+        pool: &SqlitePool,
+        repo: &Repo,
+        run_reason: &ExecutionProcessRunReason,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ExecutionProcess,
+            r#"SELECT
+                    ep.id as "id!: Uuid",
+                    ep.session_id as "session_id!: Uuid",
+                    ep.run_reason as "run_reason!: ExecutionProcessRunReason",
+                    ep.executor_action as "executor_action!: sqlx::types::Json<ExecutorActionField>",
+                    ep.status as "status!: ExecutionProcessStatus",
+                    ep.exit_code,
+                    ep.dropped as "dropped!: bool",
+                    ep.started_at as "started_at!: DateTime<Utc>",
+                    ep.completed_at as "completed_at?: DateTime<Utc>",
+                    ep.created_at as "created_at!: DateTime<Utc>",
+                    ep.updated_at as "updated_at!: DateTime<Utc>"
+               FROM execution_processes ep
+               JOIN execution_process_repo_states eprs ON eprs.execution_process_id = ep.id
+               WHERE eprs.repo_id = ? AND ep.run_reason = ?
+                 AND COALESCE(json_extract(ep.executor_action, '$.typ.working_dir'), ?) = ?
+               ORDER BY ep.created_at DESC LIMIT ?"#,
+            repo.id,
+            run_reason,
+            repo.name,
+            repo.name,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     /// Create a new execution process
     ///
     /// Note: We intentionally avoid using a transaction here. SQLite update
@@ -464,6 +535,14 @@ impl ExecutionProcess {
         }
     }
 
+    /// The repository directory a script process runs in, or `None` for any other process.
+    pub fn script_working_dir(&self) -> Option<&str> {
+        match self.executor_action().ok()?.typ() {
+            ExecutorActionType::ScriptRequest(request) => request.working_dir.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Soft-drop processes at and after the specified boundary (inclusive)
     pub async fn drop_at_and_after(
         pool: &SqlitePool,
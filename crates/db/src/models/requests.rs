@@ -1,9 +1,14 @@
+use chrono::{DateTime, Utc};
 use executors::profile::ExecutorConfig;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
+use utils::diff::DiffChangeKind;
 use uuid::Uuid;
 
-use super::{execution_process::ExecutionProcess, workspace::Workspace};
+use super::{
+    execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+    workspace::Workspace,
+};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ContainerQuery {
@@ -11,6 +16,15 @@ pub struct ContainerQuery {
     pub container_ref: String,
 }
 
+/// Looks up a workspace's context either by a path inside its container or directly by ID.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AttemptContextQuery {
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub container_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<Uuid>,
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 pub struct WorkspaceRepoInput {
     pub repo_id: Uuid,
@@ -55,3 +69,158 @@ pub struct UpdateWorkspace {
 pub struct UpdateSession {
     pub name: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct CreateFollowUpAttempt {
+    pub prompt: String,
+    pub executor_config: ExecutorConfig,
+    pub retry_process_id: Option<Uuid>,
+    pub force_when_dirty: Option<bool>,
+    pub perform_git_reset: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RepoScriptType {
+    Setup,
+    Cleanup,
+    DevServer,
+}
+
+impl RepoScriptType {
+    pub fn run_reason(self) -> ExecutionProcessRunReason {
+        match self {
+            Self::Setup => ExecutionProcessRunReason::SetupScript,
+            Self::Cleanup => ExecutionProcessRunReason::CleanupScript,
+            Self::DevServer => ExecutionProcessRunReason::DevServer,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepoScriptRunsQuery {
+    pub script_type: RepoScriptType,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepoScriptRun {
+    pub execution_process_id: Uuid,
+    pub session_id: Uuid,
+    pub status: ExecutionProcessStatus,
+    pub exit_code: Option<i64>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Tail of the combined stdout/stderr output
+    pub output: String,
+    pub output_truncated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidateRepoScriptRequest {
+    pub script: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidateRepoScriptResponse {
+    /// The script with Windows line endings converted to LF
+    pub script: String,
+    /// Problems that would stop the script from running at all
+    pub errors: Vec<String>,
+    /// Likely mistakes that don't stop the script from parsing
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepoBranchDetails {
+    pub name: String,
+    pub is_current: bool,
+    pub is_remote: bool,
+    pub is_default: bool,
+    pub last_commit_date: DateTime<Utc>,
+    /// Commits on this branch that are not on the default branch
+    pub ahead_of_default: Option<usize>,
+    /// Commits on the default branch that are not on this branch
+    pub behind_default: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListRepoBranchesResponse {
+    pub default_branch: Option<String>,
+    pub branches: Vec<RepoBranchDetails>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WorkspaceDiffSummaryQuery {
+    pub repo_id: Option<Uuid>,
+    pub include_patch: Option<bool>,
+    pub max_patch_bytes: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileDiffSummary {
+    pub path: String,
+    /// Previous path, for renamed and copied files
+    pub old_path: Option<String>,
+    pub change: DiffChangeKind,
+    /// Unknown for binary files and files too large to diff
+    pub additions: Option<usize>,
+    pub deletions: Option<usize>,
+    pub binary: bool,
+    pub patch: Option<String>,
+    /// True when the patch was cut short or left out to stay within the byte budget
+    pub patch_truncated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepoDiffSummary {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub target_branch: String,
+    pub files: Vec<FileDiffSummary>,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceDiffSummary {
+    pub repos: Vec<RepoDiffSummary>,
+    pub files_changed: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// True when some patches were left out to stay within the byte budget
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+    #[default]
+    Combined,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ExecutionProcessLogTailQuery {
+    pub lines: Option<usize>,
+    pub stream: Option<LogStream>,
+    /// Byte budget for the returned lines; the server also applies its own cap
+    pub max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecutionProcessLogTail {
+    /// The last lines of the selected stream, oldest first
+    pub lines: Vec<String>,
+    /// True when earlier output was left out
+    pub truncated: bool,
+    /// Size of the selected stream's whole output
+    pub total_bytes: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RunRepoScriptQuery {
+    /// Only run the script of this workspace repository
+    pub repo_id: Option<Uuid>,
+}
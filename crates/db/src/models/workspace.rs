@@ -69,11 +69,6 @@ impl std::ops::Deref for WorkspaceWithStatus {
     }
 }
 
-#[derive(Debug, Deserialize, TS)]
-pub struct CreateFollowUpAttempt {
-    pub prompt: String,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceContext {
     pub workspace: Workspace,
@@ -846,6 +846,31 @@ impl GitService {
         Ok(self.get_head_info(repo_path)?.branch)
     }
 
+    /// Ahead/behind commit counts of each branch relative to `base_branch_name`.
+    /// Branches that can't be resolved are left out.
+    pub fn get_branches_ahead_behind(
+        &self,
+        repo_path: &Path,
+        branch_names: &[&str],
+        base_branch_name: &str,
+    ) -> Result<HashMap<String, (usize, usize)>, GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let base_ref = Self::find_branch(&repo, base_branch_name)?.into_reference();
+
+        let mut counts = HashMap::new();
+        for name in branch_names {
+            let Ok(branch) = Self::find_branch(&repo, name) else {
+                continue;
+            };
+            if let Ok(status) =
+                self.get_branch_status_inner(&repo, &branch.into_reference(), &base_ref)
+            {
+                counts.insert(name.to_string(), status);
+            }
+        }
+        Ok(counts)
+    }
+
     /// Get the commit OID (as hex string) for a given branch without modifying HEAD
     pub fn get_branch_oid(
         &self,
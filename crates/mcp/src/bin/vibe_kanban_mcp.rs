@@ -1,4 +1,4 @@
-use mcp::task_server::McpServer;
+use mcp::task_server::{McpMode, McpServer, McpServerConfig};
 use rmcp::{ServiceExt, transport::stdio};
 use tracing_subscriber::{EnvFilter, prelude::*};
 use utils::{
@@ -34,10 +34,11 @@ fn main() -> anyhow::Result<()> {
             let base_url = resolve_base_url("vibe-kanban-mcp").await?;
             let LaunchConfig { mode } = launch_config;
 
-            let server = match mode {
-                McpLaunchMode::Global => McpServer::new_global(&base_url),
-                McpLaunchMode::Orchestrator => McpServer::new_orchestrator(&base_url),
+            let mode = match mode {
+                McpLaunchMode::Global => McpMode::Global,
+                McpLaunchMode::Orchestrator => McpMode::Orchestrator,
             };
+            let server = McpServer::with_config(mode, McpServerConfig::from_env(base_url));
 
             let service = server.init().await?.serve(stdio()).await.map_err(|error| {
                 tracing::error!("serving error: {:?}", error);
@@ -0,0 +1,265 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+const REQUEST_TIMEOUT_ENV: &str = "VK_MCP_TIMEOUT_MS";
+const GIT_REQUEST_TIMEOUT_ENV: &str = "VK_MCP_GIT_TIMEOUT_MS";
+const CONTEXT_PROBE_TIMEOUT_ENV: &str = "VK_MCP_CONTEXT_TIMEOUT_MS";
+const CONNECT_TIMEOUT_ENV: &str = "VK_MCP_CONNECT_TIMEOUT_MS";
+const TEXT_ONLY_OUTPUT_ENV: &str = "VK_MCP_TEXT_ONLY_OUTPUT";
+const MAX_RESPONSE_BYTES_ENV: &str = "VK_MCP_MAX_RESPONSE_BYTES";
+const WORKSPACE_ID_ENV: &str = "VK_WORKSPACE_ID";
+const WEB_BASE_URL_ENV: &str = "VK_WEB_BASE_URL";
+const STRICT_CONTEXT_ENV: &str = "VK_MCP_STRICT_CONTEXT";
+
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+// Pushing, opening a PR and rebasing run git and the provider's CLI, which can take minutes.
+const DEFAULT_GIT_REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+const DEFAULT_CONTEXT_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 64 * 1024;
+
+/// Connection settings for talking to the local VK backend.
+#[derive(Debug, Clone)]
+pub struct McpServerConfig {
+    pub base_url: String,
+    /// Upper bound for a single tool's API call.
+    pub request_timeout: Duration,
+    /// Upper bound for API calls that run git or talk to the git host, such as creating a PR
+    /// or rebasing. Replaces `request_timeout` for those calls.
+    pub git_request_timeout: Duration,
+    /// Upper bound for the best-effort context lookups (workspace and remote link).
+    pub context_probe_timeout: Duration,
+    pub connect_timeout: Duration,
+    /// Attach structured JSON content and output schemas to tool results. Disable for
+    /// clients that only understand text content.
+    pub structured_output: bool,
+    /// List responses larger than this are split into pages fetched with `fetch_more`.
+    pub max_response_bytes: usize,
+    /// Workspace to load context for, instead of detecting it from the current directory.
+    pub workspace_id: Option<Uuid>,
+    /// Where the web app is served, for `web_url` links in tool results. Defaults to
+    /// `base_url`, since the local backend serves the web app too.
+    pub web_base_url: Option<String>,
+    /// Refuse, rather than warn about, issue mutations on a project other than the one the
+    /// workspace is linked to. Tools can override this per call with `strict_context`.
+    pub strict_context: bool,
+}
+
+impl McpServerConfig {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        Self {
+            web_base_url: Some(base_url.clone()),
+            base_url,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            git_request_timeout: DEFAULT_GIT_REQUEST_TIMEOUT,
+            context_probe_timeout: DEFAULT_CONTEXT_PROBE_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            structured_output: true,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            workspace_id: None,
+            strict_context: false,
+        }
+    }
+
+    /// Defaults overridden by `VK_MCP_TIMEOUT_MS`, `VK_MCP_GIT_TIMEOUT_MS`,
+    /// `VK_MCP_CONTEXT_TIMEOUT_MS` and `VK_MCP_CONNECT_TIMEOUT_MS` when set. `VK_MCP_TEXT_ONLY_OUTPUT` turns off
+    /// structured tool output, and `VK_MCP_MAX_RESPONSE_BYTES` sets the paging threshold.
+    /// `VK_WORKSPACE_ID` pins the workspace context instead of detecting it from the
+    /// current directory, and `VK_WEB_BASE_URL` points `web_url` links elsewhere.
+    /// `VK_MCP_STRICT_CONTEXT` turns project mismatches with the workspace into errors.
+    pub fn from_env(base_url: impl Into<String>) -> Self {
+        let defaults = Self::new(base_url);
+        Self {
+            request_timeout: timeout_from_env(REQUEST_TIMEOUT_ENV, defaults.request_timeout),
+            git_request_timeout: timeout_from_env(
+                GIT_REQUEST_TIMEOUT_ENV,
+                defaults.git_request_timeout,
+            ),
+            context_probe_timeout: timeout_from_env(
+                CONTEXT_PROBE_TIMEOUT_ENV,
+                defaults.context_probe_timeout,
+            ),
+            connect_timeout: timeout_from_env(CONNECT_TIMEOUT_ENV, defaults.connect_timeout),
+            structured_output: !flag_from_env(TEXT_ONLY_OUTPUT_ENV),
+            max_response_bytes: bytes_from_env(MAX_RESPONSE_BYTES_ENV, defaults.max_response_bytes),
+            workspace_id: uuid_from_env(WORKSPACE_ID_ENV),
+            web_base_url: std::env::var(WEB_BASE_URL_ENV)
+                .ok()
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+                .or(defaults.web_base_url.clone()),
+            strict_context: flag_from_env(STRICT_CONTEXT_ENV),
+            ..defaults
+        }
+    }
+
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    pub fn with_git_request_timeout(mut self, timeout: Duration) -> Self {
+        self.git_request_timeout = timeout;
+        self
+    }
+
+    pub fn with_context_probe_timeout(mut self, timeout: Duration) -> Self {
+        self.context_probe_timeout = timeout;
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    pub fn with_structured_output(mut self, enabled: bool) -> Self {
+        self.structured_output = enabled;
+        self
+    }
+
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = max_bytes;
+        self
+    }
+
+    pub fn with_workspace_id(mut self, workspace_id: Uuid) -> Self {
+        self.workspace_id = Some(workspace_id);
+        self
+    }
+
+    pub fn with_web_base_url(mut self, web_base_url: impl Into<String>) -> Self {
+        self.web_base_url = Some(web_base_url.into());
+        self
+    }
+
+    pub fn with_strict_context(mut self, strict: bool) -> Self {
+        self.strict_context = strict;
+        self
+    }
+
+    pub(crate) fn build_client(&self) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(self.request_timeout)
+            .connect_timeout(self.connect_timeout)
+            .build()
+            .unwrap_or_else(|error| {
+                tracing::warn!("Failed to build configured HTTP client: {}", error);
+                reqwest::Client::new()
+            })
+    }
+}
+
+fn timeout_from_env(name: &str, default: Duration) -> Duration {
+    let Ok(value) = std::env::var(name) else {
+        return default;
+    };
+    match parse_timeout_ms(&value) {
+        Some(timeout) => timeout,
+        None => {
+            tracing::warn!(
+                "Ignoring invalid {}='{}', expected a positive number of milliseconds",
+                name,
+                value
+            );
+            default
+        }
+    }
+}
+
+fn bytes_from_env(name: &str, default: usize) -> usize {
+    let Ok(value) = std::env::var(name) else {
+        return default;
+    };
+    match value
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|bytes| *bytes > 0)
+    {
+        Some(bytes) => bytes,
+        None => {
+            tracing::warn!(
+                "Ignoring invalid {}='{}', expected a positive number of bytes",
+                name,
+                value
+            );
+            default
+        }
+    }
+}
+
+fn uuid_from_env(name: &str) -> Option<Uuid> {
+    let value = std::env::var(name).ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    match Uuid::parse_str(value) {
+        Ok(id) => Some(id),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid {}='{}', expected a UUID", name, value);
+            None
+        }
+    }
+}
+
+fn flag_from_env(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| parse_flag(&value))
+}
+
+fn parse_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+fn parse_timeout_ms(value: &str) -> Option<Duration> {
+    value
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis)
+}
+
+/// Formats a timeout for error messages, e.g. `10s` or `750ms`.
+pub(crate) fn format_timeout(timeout: Duration) -> String {
+    if timeout.subsec_millis() == 0 {
+        format!("{}s", timeout.as_secs())
+    } else {
+        format!("{}ms", timeout.as_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_positive_millisecond_timeouts() {
+        assert_eq!(
+            parse_timeout_ms(" 1500 "),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(parse_timeout_ms("0"), None);
+        assert_eq!(parse_timeout_ms("10s"), None);
+    }
+
+    #[test]
+    fn parses_boolean_flags() {
+        assert!(parse_flag("1"));
+        assert!(parse_flag(" TRUE "));
+        assert!(!parse_flag("0"));
+        assert!(!parse_flag(""));
+    }
+
+    #[test]
+    fn formats_timeouts_for_messages() {
+        assert_eq!(format_timeout(Duration::from_secs(10)), "10s");
+        assert_eq!(format_timeout(Duration::from_millis(750)), "750ms");
+    }
+}
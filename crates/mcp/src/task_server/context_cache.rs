@@ -0,0 +1,87 @@
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+use super::McpContext;
+
+// Workspaces are often linked to a remote issue after the MCP server starts, so re-resolve
+// the context periodically rather than trusting the startup snapshot forever.
+const CONTEXT_TTL: Duration = Duration::from_secs(30);
+// Outside a workspace every lookup misses, so a failed probe is trusted briefly instead of
+// re-running it for each tool call.
+const FAILED_PROBE_TTL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default)]
+struct ContextState {
+    context: Option<McpContext>,
+    refreshed_at: Option<Instant>,
+    failed_at: Option<Instant>,
+}
+
+/// Holds the workspace context for the current directory and tracks when it was last resolved.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ContextCache {
+    state: Arc<RwLock<ContextState>>,
+    // Serializes refreshes so concurrent tool calls don't all probe the backend.
+    refresh_lock: Arc<Mutex<()>>,
+}
+
+impl ContextCache {
+    pub(crate) fn new(context: Option<McpContext>) -> Self {
+        let cache = Self::default();
+        cache.store(context);
+        cache
+    }
+
+    pub(crate) fn read<T>(&self, f: impl FnOnce(Option<&McpContext>) -> T) -> T {
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        f(state.context.as_ref())
+    }
+
+    pub(crate) fn get(&self) -> Option<McpContext> {
+        self.read(|context| context.cloned())
+    }
+
+    pub(crate) fn is_stale(&self) -> bool {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .refreshed_at
+            .is_none_or(|at| at.elapsed() >= CONTEXT_TTL)
+    }
+
+    /// Whether the last refresh attempt failed within the past few seconds.
+    pub(crate) fn recently_failed(&self) -> bool {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .failed_at
+            .is_some_and(|at| at.elapsed() < FAILED_PROBE_TTL)
+    }
+
+    /// Records a refresh attempt. A failed lookup (`None`) keeps the previous context.
+    pub(crate) fn store(&self, context: Option<McpContext>) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        state.refreshed_at = Some(now);
+        state.failed_at = context.is_none().then_some(now);
+        if context.is_some() {
+            state.context = context;
+        }
+    }
+
+    /// Edits the cached context in place, without counting as a refresh.
+    pub(crate) fn update(&self, f: impl FnOnce(&mut McpContext)) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(context) = state.context.as_mut() {
+            f(context);
+        }
+    }
+
+    pub(crate) fn refresh_lock(&self) -> &Mutex<()> {
+        &self.refresh_lock
+    }
+}
@@ -1,13 +1,73 @@
 use rmcp::{
-    ServerHandler,
-    model::{Implementation, ProtocolVersion, ServerCapabilities, ServerInfo},
-    tool_handler,
+    ErrorData, RoleServer, ServerHandler,
+    handler::server::tool::ToolCallContext,
+    model::{
+        CallToolRequestParams, CallToolResult, Implementation, ListToolsResult,
+        PaginatedRequestParams, ProtocolVersion, ServerCapabilities, ServerInfo,
+    },
+    service::RequestContext,
 };
+use tracing::Instrument;
+use utils::http_headers::REQUEST_ID;
+use uuid::Uuid;
 
 use super::{McpMode, McpServer};
 
-#[tool_handler]
 impl ServerHandler for McpServer {
+    // Each call gets its own request ID, sent as `X-Request-Id` on every VK API request the
+    // tool makes and included in error results, so a failing call can be matched to the
+    // server's logs.
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let request_id = Uuid::new_v4().to_string();
+        let (project_id, workspace_id) = self.context.read(|ctx| {
+            (
+                ctx.and_then(|ctx| ctx.project_id),
+                ctx.map(|ctx| ctx.workspace_id),
+            )
+        });
+        let span = tracing::info_span!(
+            "mcp_tool_call",
+            tool = %request.name,
+            request_id = %request_id,
+            project_id = ?project_id,
+            workspace_id = ?workspace_id,
+        );
+
+        let tool = request.name.clone();
+        let call = self
+            .tool_router
+            .call(ToolCallContext::new(self, request, context));
+        let result = REQUEST_ID
+            .scope(request_id, call)
+            .instrument(span.clone())
+            .await;
+
+        let failed = !matches!(&result, Ok(result) if result.is_error != Some(true));
+        self.stats.record_tool_call(&tool, failed);
+        span.in_scope(|| match &result {
+            Ok(result) if result.is_error == Some(true) => tracing::warn!("Tool call failed"),
+            Ok(_) => tracing::debug!("Tool call completed"),
+            Err(error) => tracing::warn!(error = %error.message, "Tool call rejected"),
+        });
+        result
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult {
+            tools: self.tool_router.list_all(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
     fn get_info(&self) -> ServerInfo {
         let mut tool_names = self
             .tool_router
@@ -19,27 +79,28 @@ impl ServerHandler for McpServer {
 
         let preamble = match self.mode() {
             McpMode::Global => {
-                "A Vibe Kanban MCP server for task, issue, repository, workspace, and session management."
+                "A Vibe Kanban MCP server for task, issue, repository, workspace, and session management. Use 'list_project_statuses' to see a project's status columns before setting an issue's status. Use 'follow_up_workspace_session' to send another prompt to an existing workspace once 'get_workspace_session_status' reports its last run finished."
             }
             McpMode::Orchestrator => {
                 "An orchestrator-scoped Vibe Kanban MCP server with tools limited to the configured workspace and orchestrator session context."
             }
         };
-        let mut instruction = format!(
-            "{} Use list/read tools first when you need IDs or current state. TOOLS: {}.",
+        let instruction = format!(
+            "Use 'get_context' to fetch project, issue, workspace, and orchestrator-session metadata for the active MCP context when available. {} Use list/read tools first when you need IDs or current state. TOOLS: {}.",
             preamble,
             tool_names.join(", ")
         );
-        if self.context.is_some() {
-            instruction = format!(
-                "Use 'get_context' to fetch project, issue, workspace, and orchestrator-session metadata for the active MCP context when available. {}",
-                instruction
-            );
-        }
+
+        // Structured tool output was introduced in the 2025-06-18 protocol revision.
+        let protocol_version = if self.config.structured_output {
+            ProtocolVersion::V_2025_06_18
+        } else {
+            ProtocolVersion::V_2025_03_26
+        };
 
         ServerInfo::new(ServerCapabilities::builder().enable_tools().build())
             .with_server_info(Implementation::new("vibe-kanban-mcp", "1.0.0"))
-            .with_protocol_version(ProtocolVersion::V_2025_03_26)
+            .with_protocol_version(protocol_version)
             .with_instructions(instruction)
     }
 }
@@ -1,16 +1,33 @@
+mod config;
+mod context_cache;
 mod handler;
+mod name_matching;
+mod response_pages;
+mod server_stats;
+mod status_cache;
+mod tag_expansion;
 mod tools;
 
-use std::path::Path;
+use std::{borrow::Cow, fmt, time::Duration};
 
 use anyhow::Context;
-use db::models::{requests::ContainerQuery, workspace::WorkspaceContext};
+use db::models::{requests::AttemptContextQuery, workspace::WorkspaceContext};
 use rmcp::{handler::server::tool::ToolRouter, schemars};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+pub use self::config::McpServerConfig;
+use self::{
+    context_cache::ContextCache, response_pages::ResponsePageCache, server_stats::ServerStats,
+    status_cache::ProjectStatusCache,
+};
 pub(crate) use crate::ApiResponseEnvelope;
 
+// Background attempts at resolving the remote link after startup, doubling the delay
+// between them.
+const REMOTE_LINK_ATTEMPTS: u32 = 4;
+const REMOTE_LINK_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
 pub struct McpRepoContext {
     #[schemars(description = "The unique identifier of the repository")]
@@ -38,6 +55,127 @@ pub struct McpContext {
         description = "Repository info and target branches for each repo in this workspace"
     )]
     pub workspace_repos: Vec<McpRepoContext>,
+    pub remote_link_status: RemoteLinkStatus,
+}
+
+/// Whether the workspace's link to a remote project has been resolved, so a workspace that
+/// isn't linked can be told apart from one whose link couldn't be loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RemoteLinkStatus {
+    Linked,
+    /// Still being resolved in the background.
+    Pending,
+    Unlinked,
+    /// The remote API could not be reached. The last known link, if any, is kept.
+    Error(String),
+}
+
+impl fmt::Display for RemoteLinkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linked => f.write_str("linked"),
+            Self::Pending => f.write_str("pending"),
+            Self::Unlinked => f.write_str("unlinked"),
+            Self::Error(reason) => write!(f, "error: {reason}"),
+        }
+    }
+}
+
+impl Serialize for RemoteLinkStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RemoteLinkStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "linked" => Self::Linked,
+            "pending" => Self::Pending,
+            "unlinked" => Self::Unlinked,
+            other => Self::Error(other.strip_prefix("error: ").unwrap_or(other).to_string()),
+        })
+    }
+}
+
+impl schemars::JsonSchema for RemoteLinkStatus {
+    fn schema_name() -> Cow<'static, str> {
+        "RemoteLinkStatus".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "'linked', 'unlinked', 'pending' while the remote link is still being looked up, or 'error: <reason>' if the remote API could not be reached"
+        })
+    }
+}
+
+#[derive(Debug)]
+struct RemoteLink {
+    project_id: Uuid,
+    issue_id: Option<Uuid>,
+    organization_id: Result<Uuid, String>,
+}
+
+impl RemoteLink {
+    fn is_complete(link: &Result<Option<RemoteLink>, String>) -> bool {
+        !matches!(
+            link,
+            Err(_)
+                | Ok(Some(RemoteLink {
+                    organization_id: Err(_),
+                    ..
+                }))
+        )
+    }
+}
+
+impl McpContext {
+    // On failure the previous link for the same workspace is kept, so a momentarily slow
+    // remote API doesn't make project_id disappear.
+    fn apply_remote_link(
+        &mut self,
+        link: Result<Option<RemoteLink>, String>,
+        previous: Option<&McpContext>,
+    ) {
+        let previous = previous.filter(|previous| previous.workspace_id == self.workspace_id);
+        match link {
+            Ok(Some(link)) => {
+                self.project_id = Some(link.project_id);
+                self.issue_id = link.issue_id;
+                match link.organization_id {
+                    Ok(organization_id) => {
+                        self.organization_id = Some(organization_id);
+                        self.remote_link_status = RemoteLinkStatus::Linked;
+                    }
+                    Err(error) => {
+                        self.organization_id = previous
+                            .filter(|previous| previous.project_id == Some(link.project_id))
+                            .and_then(|previous| previous.organization_id);
+                        self.remote_link_status = RemoteLinkStatus::Error(format!(
+                            "could not load the project's organization: {error}"
+                        ));
+                    }
+                }
+            }
+            Ok(None) => {
+                self.project_id = None;
+                self.issue_id = None;
+                self.organization_id = None;
+                self.remote_link_status = RemoteLinkStatus::Unlinked;
+            }
+            Err(error) => {
+                if let Some(previous) = previous {
+                    self.project_id = previous.project_id;
+                    self.issue_id = previous.issue_id;
+                    self.organization_id = previous.organization_id;
+                }
+                self.remote_link_status = RemoteLinkStatus::Error(error);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -49,52 +187,85 @@ pub enum McpMode {
 #[derive(Debug, Clone)]
 pub struct McpServer {
     client: reqwest::Client,
-    base_url: String,
+    config: McpServerConfig,
     tool_router: ToolRouter<McpServer>,
-    context: Option<McpContext>,
+    context: ContextCache,
     mode: McpMode,
+    project_statuses: ProjectStatusCache,
+    response_pages: ResponsePageCache,
+    stats: ServerStats,
 }
 
 impl McpServer {
     pub fn new_global(base_url: &str) -> Self {
-        Self {
-            client: reqwest::Client::new(),
-            base_url: base_url.to_string(),
-            tool_router: Self::global_mode_router(),
-            context: None,
-            mode: McpMode::Global,
-        }
+        Self::with_config(McpMode::Global, McpServerConfig::new(base_url))
     }
 
     pub fn new_orchestrator(base_url: &str) -> Self {
+        Self::with_config(McpMode::Orchestrator, McpServerConfig::new(base_url))
+    }
+
+    pub fn with_config(mode: McpMode, config: McpServerConfig) -> Self {
+        let mut tool_router = match mode {
+            McpMode::Global => Self::global_mode_router(),
+            McpMode::Orchestrator => Self::orchestrator_mode_router(),
+        };
+        if !config.structured_output {
+            // Declaring an output schema obliges us to return structured content.
+            for route in tool_router.map.values_mut() {
+                route.attr.output_schema = None;
+            }
+        }
         Self {
-            client: reqwest::Client::new(),
-            base_url: base_url.to_string(),
-            tool_router: Self::orchestrator_mode_router(),
-            context: None,
-            mode: McpMode::Orchestrator,
+            client: config.build_client(),
+            config,
+            tool_router,
+            context: ContextCache::default(),
+            mode,
+            project_statuses: ProjectStatusCache::default(),
+            response_pages: ResponsePageCache::default(),
+            stats: ServerStats::default(),
         }
     }
 
     fn url(&self, path: &str) -> String {
         format!(
             "{}/{}",
-            self.base_url.trim_end_matches('/'),
+            self.config.base_url.trim_end_matches('/'),
             path.trim_start_matches('/')
         )
     }
 
-    pub async fn init(mut self) -> anyhow::Result<Self> {
-        let context = self.fetch_context_at_startup().await?;
+    pub async fn init(self) -> anyhow::Result<Self> {
+        // Only the local context is waited for. The remote link is resolved in the
+        // background so a slow remote API neither delays startup nor loses the link.
+        let result = self.try_fetch_local_context().await;
+        if let Err(error) = &result
+            && matches!(self.mode(), McpMode::Global)
+        {
+            // Not being inside a workspace is expected; failing to ask is a misconfiguration
+            // that would otherwise only surface at the first tool call.
+            tracing::warn!(
+                base_url = %self.config.base_url,
+                probe = %self.url("/api/containers/attempt-context"),
+                error = %format!("{error:#}"),
+                "Could not load workspace context from the Vibe Kanban API; tools will fail until it is reachable"
+            );
+        }
+        let context = self.local_context_for_mode(result)?;
 
+        let workspace_id = context.as_ref().map(|context| context.workspace_id);
         if context.is_none() {
-            self.tool_router.map.remove("get_context");
-            tracing::debug!("VK context not available, get_context tool will not be registered");
+            tracing::debug!("VK context not available yet, get_context will retry on demand");
         } else {
-            tracing::info!("VK context loaded, get_context tool available");
+            tracing::info!("VK context loaded");
         }
 
-        self.context = context;
+        self.context.store(context);
+        tokio::spawn(self.stats.clone().log_periodically());
+        if let Some(workspace_id) = workspace_id {
+            tokio::spawn(self.clone().resolve_remote_link_in_background(workspace_id));
+        }
         Ok(self)
     }
 
@@ -102,15 +273,99 @@ impl McpServer {
         &self.mode
     }
 
-    async fn fetch_context_at_startup(&self) -> anyhow::Result<Option<McpContext>> {
-        let current_dir = std::env::current_dir().context("Failed to resolve current directory")?;
-        let canonical_path = current_dir.canonicalize().unwrap_or(current_dir);
-        let normalized_path = utils::path::normalize_macos_private_alias(&canonical_path);
+    /// Returns the workspace context, re-resolving it first if the cached copy is stale.
+    async fn current_context(&self) -> Option<McpContext> {
+        if self.context.is_stale() {
+            let _guard = self.context.refresh_lock().lock().await;
+            // Another caller may have refreshed while we waited.
+            if self.context.is_stale() {
+                self.refresh_context_locked().await;
+            }
+        }
+        self.context.get()
+    }
+
+    /// Re-resolves the workspace context now, e.g. after a lookup found it missing an ID.
+    /// Skipped for a few seconds after a failed probe, so repeated misses reuse its result.
+    async fn refresh_context(&self) -> Option<McpContext> {
+        let _guard = self.context.refresh_lock().lock().await;
+        if !self.context.recently_failed() {
+            self.refresh_context_locked().await;
+        }
+        self.context.get()
+    }
+
+    async fn refresh_context_locked(&self) {
+        match self.fetch_context().await {
+            Ok(context) => self.context.store(context),
+            Err(error) => {
+                tracing::debug!("Failed to refresh VK context: {:#}", error);
+                self.context.store(None);
+            }
+        }
+    }
+
+    async fn fetch_context(&self) -> anyhow::Result<Option<McpContext>> {
+        let Some(mut context) = self.fetch_local_context().await? else {
+            return Ok(None);
+        };
+        let link = self.fetch_remote_link(context.workspace_id).await;
+        context.apply_remote_link(link, self.context.get().as_ref());
+        Ok(Some(context))
+    }
+
+    async fn resolve_remote_link_in_background(self, workspace_id: Uuid) {
+        let mut delay = REMOTE_LINK_RETRY_BASE_DELAY;
+        for attempt in 1..=REMOTE_LINK_ATTEMPTS {
+            let link = self.fetch_remote_link(workspace_id).await;
+            let complete = RemoteLink::is_complete(&link);
+            if !complete && attempt < REMOTE_LINK_ATTEMPTS {
+                tracing::debug!(
+                    "Remote link lookup attempt {} failed, retrying in {:?}",
+                    attempt,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                continue;
+            }
+
+            // A refresh may have resolved the link in the meantime; only fill it in if
+            // that hasn't happened.
+            self.context.update(|context| {
+                if context.workspace_id == workspace_id
+                    && matches!(
+                        context.remote_link_status,
+                        RemoteLinkStatus::Pending | RemoteLinkStatus::Error(_)
+                    )
+                {
+                    let previous = context.clone();
+                    context.apply_remote_link(link, Some(&previous));
+                }
+            });
+            if !complete {
+                tracing::warn!(
+                    "Could not resolve the remote link for workspace {} after {} attempts",
+                    workspace_id,
+                    REMOTE_LINK_ATTEMPTS
+                );
+            }
+            return;
+        }
+    }
 
-        match self.try_fetch_attempt_context(&normalized_path).await {
-            Ok(Some(ctx)) => Ok(Some(
-                self.build_mcp_context_from_workspace_context(&ctx).await,
-            )),
+    async fn fetch_local_context(&self) -> anyhow::Result<Option<McpContext>> {
+        let result = self.try_fetch_local_context().await;
+        self.local_context_for_mode(result)
+    }
+
+    // Global mode works without a workspace, so any failure there just means no context.
+    fn local_context_for_mode(
+        &self,
+        result: anyhow::Result<Option<McpContext>>,
+    ) -> anyhow::Result<Option<McpContext>> {
+        match result {
+            Ok(Some(context)) => Ok(Some(context)),
             Ok(None) | Err(_) if matches!(self.mode(), McpMode::Global) => Ok(None),
             Ok(None) => anyhow::bail!(
                 "Failed to load orchestrator MCP context from /api/containers/attempt-context"
@@ -119,26 +374,57 @@ impl McpServer {
         }
     }
 
+    // `Ok(None)` means the current directory is not inside a workspace; `Err` that the
+    // server could not be asked.
+    async fn try_fetch_local_context(&self) -> anyhow::Result<Option<McpContext>> {
+        let query = self.attempt_context_query()?;
+        Ok(self
+            .try_fetch_attempt_context(&query)
+            .await?
+            .map(|ctx| self.build_mcp_context_from_workspace_context(&ctx)))
+    }
+
+    fn attempt_context_query(&self) -> anyhow::Result<AttemptContextQuery> {
+        // The server matches any path inside a workspace's container, so the current
+        // directory can be a subdirectory of the worktree.
+        if let Some(workspace_id) = self.config.workspace_id {
+            return Ok(AttemptContextQuery {
+                container_ref: None,
+                workspace_id: Some(workspace_id),
+            });
+        }
+        let current_dir = std::env::current_dir().context("Failed to resolve current directory")?;
+        let canonical_path = current_dir.canonicalize().unwrap_or(current_dir);
+        let normalized_path = utils::path::normalize_macos_private_alias(&canonical_path);
+        Ok(AttemptContextQuery {
+            container_ref: Some(normalized_path.to_string_lossy().to_string()),
+            workspace_id: None,
+        })
+    }
+
     async fn try_fetch_attempt_context(
         &self,
-        path: &Path,
+        query: &AttemptContextQuery,
     ) -> anyhow::Result<Option<WorkspaceContext>> {
         let url = self.url("/api/containers/attempt-context");
-        let query = ContainerQuery {
-            container_ref: path.to_string_lossy().to_string(),
-        };
 
         let response = tokio::time::timeout(
-            std::time::Duration::from_millis(500),
-            self.client.get(&url).query(&query).send(),
+            self.config.context_probe_timeout,
+            self.client.get(&url).query(query).send(),
         )
         .await
         .context("Timed out fetching /api/containers/attempt-context")?
         .context("Failed to fetch /api/containers/attempt-context")?;
 
-        if !response.status().is_success() {
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
             return Ok(None);
         }
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "/api/containers/attempt-context returned {}",
+                response.status()
+            );
+        }
 
         let api_response: ApiResponseEnvelope<WorkspaceContext> = response
             .json()
@@ -152,7 +438,7 @@ impl McpServer {
         Ok(api_response.data)
     }
 
-    async fn build_mcp_context_from_workspace_context(&self, ctx: &WorkspaceContext) -> McpContext {
+    fn build_mcp_context_from_workspace_context(&self, ctx: &WorkspaceContext) -> McpContext {
         let workspace_repos: Vec<McpRepoContext> = ctx
             .workspace_repos
             .iter()
@@ -163,83 +449,109 @@ impl McpServer {
             })
             .collect();
 
-        let workspace_id = ctx.workspace.id;
-        let workspace_branch = ctx.workspace.branch.clone();
         let orchestrator_session_id = if matches!(self.mode(), McpMode::Orchestrator) {
             ctx.orchestrator_session_id
         } else {
             None
         };
 
-        let (project_id, issue_id, organization_id) = self
-            .fetch_remote_workspace_context(workspace_id)
-            .await
-            .unwrap_or((None, None, None));
-
         McpContext {
-            organization_id,
-            project_id,
-            issue_id,
+            organization_id: None,
+            project_id: None,
+            issue_id: None,
             orchestrator_session_id,
-            workspace_id,
-            workspace_branch,
+            workspace_id: ctx.workspace.id,
+            workspace_branch: ctx.workspace.branch.clone(),
             workspace_repos,
+            remote_link_status: RemoteLinkStatus::Pending,
         }
     }
 
-    async fn fetch_remote_workspace_context(
+    // `Ok(None)` means the workspace isn't linked; `Err` that the remote API couldn't tell.
+    async fn fetch_remote_link(
+        &self,
+        local_workspace_id: Uuid,
+    ) -> Result<Option<RemoteLink>, String> {
+        let Some(remote_ws) = self.try_fetch_remote_workspace(local_workspace_id).await? else {
+            return Ok(None);
+        };
+        let organization_id = self
+            .fetch_remote_organization_id(remote_ws.project_id)
+            .await;
+
+        Ok(Some(RemoteLink {
+            project_id: remote_ws.project_id,
+            issue_id: remote_ws.issue_id,
+            organization_id,
+        }))
+    }
+
+    // Looks up the remote workspace linked to a local workspace, if any.
+    async fn fetch_remote_workspace(
+        &self,
+        local_workspace_id: Uuid,
+    ) -> Option<api_types::Workspace> {
+        self.try_fetch_remote_workspace(local_workspace_id)
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn try_fetch_remote_workspace(
         &self,
         local_workspace_id: Uuid,
-    ) -> Option<(Option<Uuid>, Option<Uuid>, Option<Uuid>)> {
+    ) -> Result<Option<api_types::Workspace>, String> {
         let url = self.url(&format!(
             "/api/remote/workspaces/by-local-id/{}",
             local_workspace_id
         ));
+        let response = self.send_context_probe(&url).await?;
 
-        let response = tokio::time::timeout(
-            std::time::Duration::from_millis(2000),
-            self.client.get(&url).send(),
-        )
-        .await
-        .ok()?
-        .ok()?;
-
-        if !response.status().is_success() {
-            return None;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
         }
-
-        let api_response: ApiResponseEnvelope<api_types::Workspace> = response.json().await.ok()?;
-
-        if !api_response.success {
-            return None;
+        if !response.status().is_success() {
+            return Err(format!("remote API returned {}", response.status()));
         }
 
-        let remote_ws = api_response.data?;
-        let project_id = remote_ws.project_id;
-
-        // Fetch the project to get organization_id
-        let org_id = self.fetch_remote_organization_id(project_id).await;
+        let api_response: ApiResponseEnvelope<api_types::Workspace> = response
+            .json()
+            .await
+            .map_err(|e| format!("invalid remote workspace response: {e}"))?;
 
-        Some((Some(project_id), remote_ws.issue_id, org_id))
+        Ok(api_response.data.filter(|_| api_response.success))
     }
 
-    async fn fetch_remote_organization_id(&self, project_id: Uuid) -> Option<Uuid> {
+    async fn fetch_remote_organization_id(&self, project_id: Uuid) -> Result<Uuid, String> {
         let url = self.url(&format!("/api/remote/projects/{}", project_id));
-
-        let response = tokio::time::timeout(
-            std::time::Duration::from_millis(2000),
-            self.client.get(&url).send(),
-        )
-        .await
-        .ok()?
-        .ok()?;
+        let response = self.send_context_probe(&url).await?;
 
         if !response.status().is_success() {
-            return None;
+            return Err(format!("remote API returned {}", response.status()));
         }
 
-        let api_response: ApiResponseEnvelope<api_types::Project> = response.json().await.ok()?;
-        let project = api_response.data?;
-        Some(project.organization_id)
+        let api_response: ApiResponseEnvelope<api_types::Project> = response
+            .json()
+            .await
+            .map_err(|e| format!("invalid remote project response: {e}"))?;
+        api_response
+            .data
+            .map(|project| project.organization_id)
+            .ok_or_else(|| "remote project response had no data".to_string())
+    }
+
+    async fn send_context_probe(&self, url: &str) -> Result<reqwest::Response, String> {
+        tokio::time::timeout(
+            self.config.context_probe_timeout,
+            self.client.get(url).send(),
+        )
+        .await
+        .map_err(|_| {
+            format!(
+                "remote API timed out after {}",
+                config::format_timeout(self.config.context_probe_timeout)
+            )
+        })?
+        .map_err(|e| format!("could not reach the remote API: {e}"))
     }
 }
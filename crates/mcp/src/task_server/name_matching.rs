@@ -0,0 +1,131 @@
+// Loose matching for names agents type from memory, such as status and priority names.
+
+/// Lowercases `name` and drops whitespace, hyphens and underscores, so "In progress ",
+/// "in-progress" and "IN_PROGRESS" compare equal, as do "Todo" and "To Do".
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+pub(crate) fn names_match(a: &str, b: &str) -> bool {
+    normalize_name(a) == normalize_name(b)
+}
+
+/// The candidate closest to `input` by edit distance, if it is close enough to be a
+/// plausible typo rather than a different name altogether.
+pub(crate) fn closest_name<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let input = normalize_name(input);
+    if input.is_empty() {
+        return None;
+    }
+    // Roughly one typo per four characters, and always at least one.
+    let max_distance = (input.chars().count() / 4).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&input, &normalize_name(candidate)), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// " Did you mean 'X'?" when a close candidate exists, for appending to an error message.
+pub(crate) fn did_you_mean<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> String {
+    closest_name(input, candidates)
+        .map(|name| format!(" Did you mean '{name}'?"))
+        .unwrap_or_default()
+}
+
+// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [&str; 5] = ["Backlog", "To Do", "In Progress", "In Review", "Done"];
+
+    #[test]
+    fn tricky_spellings_match_their_status() {
+        let cases = [
+            ("In progress ", "In Progress"),
+            ("  in   progress", "In Progress"),
+            ("in-progress", "In Progress"),
+            ("IN_PROGRESS", "In Progress"),
+            ("Todo", "To Do"),
+            ("to-do", "To Do"),
+            ("TODO", "To Do"),
+            ("in review", "In Review"),
+            ("done\n", "Done"),
+        ];
+        for (input, expected) in cases {
+            let matched = STATUSES
+                .into_iter()
+                .filter(|status| names_match(status, input))
+                .collect::<Vec<_>>();
+            assert_eq!(matched, vec![expected], "input {input:?}");
+        }
+    }
+
+    #[test]
+    fn different_names_do_not_match() {
+        for input in ["Progress", "In", "Reviewed", "Don", ""] {
+            assert!(
+                STATUSES.iter().all(|status| !names_match(status, input)),
+                "input {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn typos_suggest_the_closest_status() {
+        let cases = [
+            ("In Progres", Some("In Progress")),
+            ("in porgress", Some("In Progress")),
+            ("Bcklog", Some("Backlog")),
+            ("In Reveiw", Some("In Review")),
+            ("Dome", Some("Done")),
+            ("Blocked", None),
+            ("Shipped", None),
+            ("", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(closest_name(input, STATUSES), expected, "input {input:?}");
+        }
+    }
+
+    #[test]
+    fn did_you_mean_is_empty_without_a_close_match() {
+        assert_eq!(
+            did_you_mean("In Progres", STATUSES),
+            " Did you mean 'In Progress'?"
+        );
+        assert_eq!(did_you_mean("Blocked", STATUSES), "");
+    }
+
+    #[test]
+    fn edit_distance_counts_single_char_edits() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+use uuid::Uuid;
+
+// Long enough for an agent to page through a result, short enough that stale listings
+// aren't served back much later.
+const RESPONSE_PAGES_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug)]
+struct CachedPages {
+    created_at: Instant,
+    // Page 0 was returned with the original response and is not kept.
+    pages: Vec<Vec<Value>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ResponsePage {
+    pub(crate) items: Vec<Value>,
+    /// 1-based page number, counting the page returned with the original response.
+    pub(crate) page: usize,
+    pub(crate) total_pages: usize,
+    pub(crate) next_cursor: Option<String>,
+}
+
+/// Holds the remaining pages of oversized tool responses so they can be fetched with
+/// `fetch_more`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResponsePageCache {
+    entries: Arc<Mutex<HashMap<Uuid, CachedPages>>>,
+}
+
+impl ResponsePageCache {
+    /// Stores every page after the first and returns the cursor for the second one.
+    pub(crate) fn store(&self, remaining_pages: Vec<Vec<Value>>) -> String {
+        let id = Uuid::new_v4();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, entry| entry.created_at.elapsed() < RESPONSE_PAGES_TTL);
+        entries.insert(
+            id,
+            CachedPages {
+                created_at: Instant::now(),
+                pages: remaining_pages,
+            },
+        );
+        cursor(id, 1)
+    }
+
+    /// Returns `None` for malformed, unknown and expired cursors alike.
+    pub(crate) fn get(&self, cursor_value: &str) -> Option<ResponsePage> {
+        let (id, index) = cursor_value.split_once(':')?;
+        let id = Uuid::parse_str(id).ok()?;
+        let index = index.parse::<usize>().ok().filter(|index| *index >= 1)?;
+
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = entries
+            .get(&id)
+            .filter(|entry| entry.created_at.elapsed() < RESPONSE_PAGES_TTL)?;
+        let items = entry.pages.get(index - 1)?.clone();
+        let total_pages = entry.pages.len() + 1;
+        Some(ResponsePage {
+            items,
+            page: index + 1,
+            total_pages,
+            next_cursor: (index + 1 < total_pages).then(|| cursor(id, index + 1)),
+        })
+    }
+}
+
+fn cursor(id: Uuid, index: usize) -> String {
+    format!("{id}:{index}")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn pages_are_served_in_order_until_exhausted() {
+        let cache = ResponsePageCache::default();
+        let first = cache.store(vec![vec![json!(2)], vec![json!(3)]]);
+
+        let page = cache.get(&first).unwrap();
+        assert_eq!(
+            (page.items, page.page, page.total_pages),
+            (vec![json!(2)], 2, 3)
+        );
+
+        let last = cache.get(&page.next_cursor.unwrap()).unwrap();
+        assert_eq!(last.items, vec![json!(3)]);
+        assert_eq!(last.next_cursor, None);
+
+        // Cursors can be re-read until they expire.
+        assert!(cache.get(&first).is_some());
+    }
+
+    #[test]
+    fn unknown_and_malformed_cursors_are_rejected() {
+        let cache = ResponsePageCache::default();
+        cache.store(vec![vec![json!(2)]]);
+
+        assert!(cache.get(&format!("{}:1", Uuid::new_v4())).is_none());
+        assert!(cache.get("not-a-cursor").is_none());
+    }
+}
@@ -0,0 +1,347 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use rmcp::schemars;
+use serde::Serialize;
+use uuid::Uuid;
+
+// Latencies are kept in a fixed ring per endpoint, so percentiles describe recent traffic
+// and memory stays bounded however long the server runs.
+const LATENCY_SAMPLES: usize = 256;
+const RECENT_ERRORS: usize = 10;
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Default)]
+struct EndpointStats {
+    calls: u64,
+    errors: u64,
+    latencies_ms: Vec<u32>,
+    // Slot the next sample overwrites once `latencies_ms` is full.
+    next_sample: usize,
+}
+
+impl EndpointStats {
+    fn record(&mut self, latency: Duration, failed: bool) {
+        self.calls += 1;
+        self.errors += u64::from(failed);
+        let latency_ms = u32::try_from(latency.as_millis()).unwrap_or(u32::MAX);
+        if self.latencies_ms.len() < LATENCY_SAMPLES {
+            self.latencies_ms.push(latency_ms);
+        } else {
+            self.latencies_ms[self.next_sample] = latency_ms;
+            self.next_sample = (self.next_sample + 1) % LATENCY_SAMPLES;
+        }
+    }
+
+    fn percentiles(&self) -> (u32, u32) {
+        let mut sorted = self.latencies_ms.clone();
+        sorted.sort_unstable();
+        (percentile(&sorted, 50), percentile(&sorted, 95))
+    }
+}
+
+#[derive(Debug, Default)]
+struct ToolStats {
+    calls: u64,
+    errors: u64,
+}
+
+#[derive(Debug, Default)]
+struct StatsState {
+    endpoints: HashMap<String, EndpointStats>,
+    tools: HashMap<String, ToolStats>,
+    recent_errors: VecDeque<RecentError>,
+}
+
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
+pub(crate) struct RecentError {
+    pub(crate) at: DateTime<Utc>,
+    #[schemars(description = "Method and path of the failing VK API request, with IDs as {id}")]
+    pub(crate) endpoint: String,
+    pub(crate) status: Option<u16>,
+    pub(crate) message: String,
+    #[schemars(description = "The `X-Request-Id` sent with the request, for matching server logs")]
+    pub(crate) request_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+pub(crate) struct EndpointSummary {
+    endpoint: String,
+    calls: u64,
+    errors: u64,
+    p50_ms: u32,
+    p95_ms: u32,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+pub(crate) struct ToolSummary {
+    tool: String,
+    calls: u64,
+    errors: u64,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+pub(crate) struct ServerStatsSnapshot {
+    pub(crate) uptime_secs: u64,
+    pub(crate) total_tool_calls: u64,
+    pub(crate) total_tool_errors: u64,
+    pub(crate) total_requests: u64,
+    pub(crate) total_request_errors: u64,
+    #[schemars(description = "Invocation counts per tool, most called first")]
+    pub(crate) tools: Vec<ToolSummary>,
+    #[schemars(
+        description = "VK API requests per endpoint, most called first. Latencies cover retries and are taken over the most recent requests."
+    )]
+    pub(crate) endpoints: Vec<EndpointSummary>,
+    #[schemars(description = "The last failed VK API requests, newest first")]
+    pub(crate) recent_errors: Vec<RecentError>,
+}
+
+/// Per-tool and per-endpoint counters for the lifetime of the MCP server.
+#[derive(Debug, Clone)]
+pub(crate) struct ServerStats {
+    started_at: Instant,
+    state: Arc<Mutex<StatsState>>,
+}
+
+impl Default for ServerStats {
+    fn default() -> Self {
+        Self {
+            started_at: Instant::now(),
+            state: Arc::default(),
+        }
+    }
+}
+
+impl ServerStats {
+    pub(crate) fn record_tool_call(&self, tool: &str, failed: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        // Look up by reference first so repeat calls don't allocate a key.
+        match state.tools.get_mut(tool) {
+            Some(stats) => {
+                stats.calls += 1;
+                stats.errors += u64::from(failed);
+            }
+            None => {
+                state.tools.insert(
+                    tool.to_string(),
+                    ToolStats {
+                        calls: 1,
+                        errors: u64::from(failed),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Records a finished VK API request. `error` is the status and message if it failed.
+    pub(crate) fn record_request(
+        &self,
+        endpoint: String,
+        latency: Duration,
+        error: Option<(Option<u16>, &str)>,
+        request_id: Option<String>,
+    ) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((status, message)) = error {
+            if state.recent_errors.len() == RECENT_ERRORS {
+                state.recent_errors.pop_back();
+            }
+            state.recent_errors.push_front(RecentError {
+                at: Utc::now(),
+                endpoint: endpoint.clone(),
+                status,
+                message: message.to_string(),
+                request_id,
+            });
+        }
+        state
+            .endpoints
+            .entry(endpoint)
+            .or_default()
+            .record(latency, error.is_some());
+    }
+
+    pub(crate) fn snapshot(&self) -> ServerStatsSnapshot {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut tools = state
+            .tools
+            .iter()
+            .map(|(tool, stats)| ToolSummary {
+                tool: tool.clone(),
+                calls: stats.calls,
+                errors: stats.errors,
+            })
+            .collect::<Vec<_>>();
+        tools.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.tool.cmp(&b.tool)));
+
+        let mut endpoints = state
+            .endpoints
+            .iter()
+            .map(|(endpoint, stats)| {
+                let (p50_ms, p95_ms) = stats.percentiles();
+                EndpointSummary {
+                    endpoint: endpoint.clone(),
+                    calls: stats.calls,
+                    errors: stats.errors,
+                    p50_ms,
+                    p95_ms,
+                }
+            })
+            .collect::<Vec<_>>();
+        endpoints.sort_by(|a, b| {
+            b.calls
+                .cmp(&a.calls)
+                .then_with(|| a.endpoint.cmp(&b.endpoint))
+        });
+
+        ServerStatsSnapshot {
+            uptime_secs: self.started_at.elapsed().as_secs(),
+            total_tool_calls: tools.iter().map(|tool| tool.calls).sum(),
+            total_tool_errors: tools.iter().map(|tool| tool.errors).sum(),
+            total_requests: endpoints.iter().map(|endpoint| endpoint.calls).sum(),
+            total_request_errors: endpoints.iter().map(|endpoint| endpoint.errors).sum(),
+            tools,
+            endpoints,
+            recent_errors: state.recent_errors.iter().cloned().collect(),
+        }
+    }
+
+    /// Logs a snapshot at debug level every `STATS_LOG_INTERVAL` for as long as the server runs.
+    pub(crate) async fn log_periodically(self) {
+        let mut interval = tokio::time::interval(STATS_LOG_INTERVAL);
+        // The first tick completes immediately, before anything has been recorded.
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            if !tracing::enabled!(tracing::Level::DEBUG) {
+                continue;
+            }
+            let snapshot = self.snapshot();
+            tracing::debug!(
+                uptime_secs = snapshot.uptime_secs,
+                tool_calls = snapshot.total_tool_calls,
+                tool_errors = snapshot.total_tool_errors,
+                requests = snapshot.total_requests,
+                request_errors = snapshot.total_request_errors,
+                "MCP server stats"
+            );
+            for endpoint in &snapshot.endpoints {
+                tracing::debug!(
+                    endpoint = %endpoint.endpoint,
+                    calls = endpoint.calls,
+                    errors = endpoint.errors,
+                    p50_ms = endpoint.p50_ms,
+                    p95_ms = endpoint.p95_ms,
+                    "MCP endpoint stats"
+                );
+            }
+            for error in &snapshot.recent_errors {
+                tracing::debug!(
+                    at = %error.at,
+                    endpoint = %error.endpoint,
+                    status = ?error.status,
+                    request_id = ?error.request_id,
+                    "MCP recent error: {}",
+                    error.message
+                );
+            }
+        }
+    }
+}
+
+/// "GET /api/remote/issues/{id}", so requests for different issues share one entry.
+pub(crate) fn endpoint_key(method: &str, path: &str) -> String {
+    let mut key = String::with_capacity(method.len() + 1 + path.len());
+    key.push_str(method);
+    key.push(' ');
+    for (index, segment) in path.split('/').enumerate() {
+        if index > 0 {
+            key.push('/');
+        }
+        let is_id = Uuid::parse_str(segment).is_ok()
+            || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()));
+        key.push_str(if is_id { "{id}" } else { segment });
+    }
+    key
+}
+
+// Nearest-rank percentile of an ascending slice.
+fn percentile(sorted: &[u32], percent: usize) -> u32 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_in_paths_share_an_endpoint() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            endpoint_key("GET", &format!("/api/remote/issues/{id}")),
+            "GET /api/remote/issues/{id}"
+        );
+        assert_eq!(
+            endpoint_key("POST", "/api/sessions/42/follow-up"),
+            "POST /api/sessions/{id}/follow-up"
+        );
+        assert_eq!(endpoint_key("GET", "/api/health"), "GET /api/health");
+    }
+
+    #[test]
+    fn percentiles_use_recent_samples() {
+        let stats = ServerStats::default();
+        for ms in 1..=100 {
+            stats.record_request(
+                endpoint_key("GET", "/api/health"),
+                Duration::from_millis(ms),
+                None,
+                None,
+            );
+        }
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.endpoints[0].p50_ms, 50);
+        assert_eq!(snapshot.endpoints[0].p95_ms, 95);
+
+        // Older samples are overwritten once the ring is full.
+        for _ in 0..LATENCY_SAMPLES {
+            stats.record_request(
+                endpoint_key("GET", "/api/health"),
+                Duration::from_millis(7),
+                None,
+                None,
+            );
+        }
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.endpoints[0].calls, 100 + LATENCY_SAMPLES as u64);
+        assert_eq!(snapshot.endpoints[0].p95_ms, 7);
+    }
+
+    #[test]
+    fn only_the_last_errors_are_kept() {
+        let stats = ServerStats::default();
+        for attempt in 0..15 {
+            stats.record_request(
+                endpoint_key("GET", "/api/health"),
+                Duration::ZERO,
+                Some((Some(502), &format!("failure {attempt}"))),
+                None,
+            );
+        }
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.total_request_errors, 15);
+        assert_eq!(snapshot.recent_errors.len(), RECENT_ERRORS);
+        assert_eq!(snapshot.recent_errors[0].message, "failure 14");
+        assert_eq!(snapshot.recent_errors[9].message, "failure 5");
+    }
+}
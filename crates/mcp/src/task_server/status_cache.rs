@@ -0,0 +1,140 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use api_types::ProjectStatus;
+use tokio::sync::{Mutex, RwLock};
+use uuid::Uuid;
+
+// Statuses rarely change, but agents may add columns mid-session, so keep entries short-lived.
+const PROJECT_STATUS_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+struct CachedStatuses {
+    fetched_at: Instant,
+    statuses: Vec<ProjectStatus>,
+    // Status IDs looked up but absent from `statuses`, so repeated misses don't refetch.
+    missing: HashSet<Uuid>,
+}
+
+/// Caches project statuses per project so repeated lookups within a burst of tool calls
+/// only hit the backend once.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProjectStatusCache {
+    entries: Arc<RwLock<HashMap<Uuid, CachedStatuses>>>,
+    // Per-project fetch guards so concurrent misses don't stampede the backend.
+    fetch_locks: Arc<Mutex<HashMap<Uuid, Arc<Mutex<()>>>>>,
+}
+
+impl ProjectStatusCache {
+    pub(crate) async fn get_or_fetch<F, Fut, E>(
+        &self,
+        project_id: Uuid,
+        fetch: F,
+    ) -> Result<Vec<ProjectStatus>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<ProjectStatus>, E>>,
+    {
+        if let Some(statuses) = self.fresh(project_id).await {
+            return Ok(statuses);
+        }
+
+        let fetch_lock = self
+            .fetch_locks
+            .lock()
+            .await
+            .entry(project_id)
+            .or_default()
+            .clone();
+        let _guard = fetch_lock.lock().await;
+
+        // Another caller may have populated the entry while we waited.
+        if let Some(statuses) = self.fresh(project_id).await {
+            return Ok(statuses);
+        }
+
+        let result = fetch().await;
+        // Waiters still hold their clone of the lock; later callers start from a fresh one.
+        let mut fetch_locks = self.fetch_locks.lock().await;
+        if fetch_locks
+            .get(&project_id)
+            .is_some_and(|lock| Arc::ptr_eq(lock, &fetch_lock))
+        {
+            fetch_locks.remove(&project_id);
+        }
+        drop(fetch_locks);
+
+        let statuses = result?;
+        self.entries.write().await.insert(
+            project_id,
+            CachedStatuses {
+                fetched_at: Instant::now(),
+                statuses: statuses.clone(),
+                missing: HashSet::new(),
+            },
+        );
+        Ok(statuses)
+    }
+
+    /// Records that `status_id` isn't among the project's cached statuses, so lookups for it
+    /// skip refetching until the entry expires or is invalidated.
+    pub(crate) async fn mark_missing(&self, project_id: Uuid, status_id: Uuid) {
+        if let Some(entry) = self.entries.write().await.get_mut(&project_id) {
+            entry.missing.insert(status_id);
+        }
+    }
+
+    pub(crate) async fn is_missing(&self, project_id: Uuid, status_id: Uuid) -> bool {
+        self.entries
+            .read()
+            .await
+            .get(&project_id)
+            .is_some_and(|entry| {
+                entry.fetched_at.elapsed() < PROJECT_STATUS_TTL
+                    && entry.missing.contains(&status_id)
+            })
+    }
+
+    pub(crate) async fn invalidate(&self, project_id: Uuid) {
+        self.entries.write().await.remove(&project_id);
+    }
+
+    async fn fresh(&self, project_id: Uuid) -> Option<Vec<ProjectStatus>> {
+        self.entries
+            .read()
+            .await
+            .get(&project_id)
+            .filter(|entry| entry.fetched_at.elapsed() < PROJECT_STATUS_TTL)
+            .map(|entry| entry.statuses.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn fetch_locks_are_released_after_each_fetch() {
+        let cache = ProjectStatusCache::default();
+        let project_id = Uuid::new_v4();
+
+        let failed = cache
+            .get_or_fetch(project_id, || async {
+                Err::<Vec<ProjectStatus>, _>("offline")
+            })
+            .await;
+        assert!(failed.is_err());
+        assert!(cache.fetch_locks.lock().await.is_empty());
+
+        let statuses = cache
+            .get_or_fetch(project_id, || async { Ok::<_, ()>(Vec::new()) })
+            .await
+            .unwrap();
+        assert!(statuses.is_empty());
+        assert!(cache.fetch_locks.lock().await.is_empty());
+    }
+}
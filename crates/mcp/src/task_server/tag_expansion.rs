@@ -0,0 +1,253 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::LazyLock,
+};
+
+use regex::{Captures, Regex};
+
+// `\@name` and `@@name` are escapes for a literal `@name`. The tag name stops at whitespace
+// or another `@`, and trailing punctuation belongs to the surrounding sentence.
+static TAG_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(\\@|@@|@)([^\s@]*[^\s@.,;:!?'")\]])"#).expect("tag pattern is valid")
+});
+
+const CODE_FENCE: &str = "```";
+// Tags may reference other tags; bound how deep that can go.
+const MAX_TAG_DEPTH: usize = 5;
+
+/// Names referenced as `@name` outside code and escapes, i.e. the tags worth looking up.
+pub(crate) fn tag_references(text: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for (is_code, segment) in split_code_segments(text) {
+        if is_code {
+            continue;
+        }
+        for caps in TAG_PATTERN.captures_iter(segment) {
+            if &caps[1] == "@" && !follows_word_char(segment, &caps) {
+                names.insert(caps[2].to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Replaces `@name` references with the content of the matching tag, expanding references
+/// inside tag content too.
+///
+/// Code spans and fenced blocks are left untouched, escaped references render as a literal
+/// `@name`, and references glued to a preceding word (e.g. email addresses) are ignored.
+/// A reference that would loop back onto a tag being expanded is replaced with a
+/// `[tag cycle: @a -> @b -> @a]` marker.
+pub(crate) fn expand_tag_references(text: &str, tags: &HashMap<String, String>) -> String {
+    expand_nested(text, tags, &mut Vec::new())
+}
+
+fn expand_nested(text: &str, tags: &HashMap<String, String>, path: &mut Vec<String>) -> String {
+    split_code_segments(text)
+        .into_iter()
+        .map(|(is_code, segment)| {
+            if is_code {
+                return segment.to_string();
+            }
+            TAG_PATTERN
+                .replace_all(segment, |caps: &Captures| {
+                    let (prefix, name) = (&caps[1], &caps[2]);
+                    if prefix != "@" {
+                        return format!("@{name}");
+                    }
+                    if follows_word_char(segment, caps) {
+                        return caps[0].to_string();
+                    }
+                    let Some(content) = tags.get(name) else {
+                        return caps[0].to_string();
+                    };
+
+                    if let Some(start) = path.iter().position(|seen| seen == name) {
+                        let cycle = path[start..]
+                            .iter()
+                            .map(String::as_str)
+                            .chain(std::iter::once(name))
+                            .map(|tag| format!("@{tag}"))
+                            .collect::<Vec<_>>()
+                            .join(" -> ");
+                        return format!("[tag cycle: {cycle}]");
+                    }
+                    if path.len() >= MAX_TAG_DEPTH {
+                        return format!(
+                            "{}[tag nesting deeper than {MAX_TAG_DEPTH} levels]",
+                            &caps[0]
+                        );
+                    }
+
+                    path.push(name.to_string());
+                    let expanded = expand_nested(content, tags, path);
+                    path.pop();
+                    expanded
+                })
+                .into_owned()
+        })
+        .collect()
+}
+
+fn follows_word_char(segment: &str, caps: &Captures) -> bool {
+    let start = caps.get(0).map_or(0, |m| m.start());
+    segment[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Splits text into `(is_code, segment)` pairs covering fenced blocks and inline code spans.
+/// An unterminated fence runs to the end of the text; an unmatched backtick is plain text.
+fn split_code_segments(text: &str) -> Vec<(bool, &str)> {
+    let mut segments = Vec::new();
+    let mut prose_start = 0;
+    let mut cursor = 0;
+
+    while let Some(offset) = text[cursor..].find('`') {
+        let start = cursor + offset;
+        let code_end = if text[start..].starts_with(CODE_FENCE) {
+            let body = start + CODE_FENCE.len();
+            Some(
+                text[body..]
+                    .find(CODE_FENCE)
+                    .map_or(text.len(), |end| body + end + CODE_FENCE.len()),
+            )
+        } else {
+            text[start + 1..].find('`').map(|end| start + 1 + end + 1)
+        };
+
+        let Some(end) = code_end else {
+            cursor = start + 1;
+            continue;
+        };
+
+        if prose_start < start {
+            segments.push((false, &text[prose_start..start]));
+        }
+        segments.push((true, &text[start..end]));
+        prose_start = end;
+        cursor = end;
+    }
+
+    if prose_start < text.len() {
+        segments.push((false, &text[prose_start..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags() -> HashMap<String, String> {
+        HashMap::from([
+            ("plan".to_string(), "Write a plan first.".to_string()),
+            ("Override".to_string(), "[override tag]".to_string()),
+            ("example.com".to_string(), "SHOULD NOT APPEAR".to_string()),
+        ])
+    }
+
+    #[test]
+    fn expands_known_tags_and_leaves_unknown_mentions() {
+        let text = "Start with @plan, then ping @alice.";
+
+        assert_eq!(
+            expand_tag_references(text, &tags()),
+            "Start with Write a plan first., then ping @alice."
+        );
+    }
+
+    #[test]
+    fn escaped_references_render_literally() {
+        let text = r"Use \@plan or @@plan literally, but expand @plan.";
+
+        assert_eq!(
+            expand_tag_references(text, &tags()),
+            "Use @plan or @plan literally, but expand Write a plan first.."
+        );
+        assert_eq!(tag_references(text), BTreeSet::from(["plan".to_string()]));
+    }
+
+    #[test]
+    fn code_spans_and_fences_are_untouched() {
+        let text = "Annotate with `@Override` as in:\n```java\n@Override\nvoid run() {}\n```\nthen @plan and @Override.";
+
+        assert_eq!(
+            expand_tag_references(text, &tags()),
+            "Annotate with `@Override` as in:\n```java\n@Override\nvoid run() {}\n```\nthen Write a plan first. and [override tag]."
+        );
+    }
+
+    #[test]
+    fn unterminated_fence_protects_the_rest_of_the_text() {
+        let text = "@plan\n```\n@plan";
+
+        assert_eq!(
+            expand_tag_references(text, &tags()),
+            "Write a plan first.\n```\n@plan"
+        );
+    }
+
+    #[test]
+    fn email_addresses_are_not_treated_as_tags() {
+        let text = "Mail dev@example.com or a `stray backtick @plan";
+
+        assert_eq!(
+            expand_tag_references(text, &tags()),
+            "Mail dev@example.com or a `stray backtick Write a plan first."
+        );
+        assert_eq!(tag_references(text), BTreeSet::from(["plan".to_string()]));
+    }
+
+    #[test]
+    fn nested_tags_are_expanded() {
+        let tags = HashMap::from([
+            (
+                "review".to_string(),
+                "Review the diff. @checklist".to_string(),
+            ),
+            ("checklist".to_string(), "Run the tests.".to_string()),
+        ]);
+
+        assert_eq!(
+            expand_tag_references("Please @review", &tags),
+            "Please Review the diff. Run the tests."
+        );
+    }
+
+    #[test]
+    fn self_referencing_tag_is_marked_as_a_cycle() {
+        let tags = HashMap::from([("loop".to_string(), "Again and @loop".to_string())]);
+
+        assert_eq!(
+            expand_tag_references("@loop", &tags),
+            "Again and [tag cycle: @loop -> @loop]"
+        );
+    }
+
+    #[test]
+    fn mutual_tag_cycle_is_marked_with_its_path() {
+        let tags = HashMap::from([
+            ("a".to_string(), "A then @b".to_string()),
+            ("b".to_string(), "B then @a".to_string()),
+        ]);
+
+        assert_eq!(
+            expand_tag_references("Start: @a", &tags),
+            "Start: A then B then [tag cycle: @a -> @b -> @a]"
+        );
+    }
+
+    #[test]
+    fn nesting_stops_at_the_depth_limit() {
+        let tags = (0..=MAX_TAG_DEPTH)
+            .map(|level| (format!("t{level}"), format!("{level} @t{}", level + 1)))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(
+            expand_tag_references("@t0", &tags),
+            "0 1 2 3 4 @t5[tag nesting deeper than 5 levels]"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::str::FromStr;
+
+use executors::{
+    executors::{AvailabilityInfo, BaseCodingAgent, CodingAgent, StandardCodingAgentExecutor},
+    profile::ExecutorConfigs,
+};
+use futures::future::join_all;
+use rmcp::{ErrorData, model::CallToolResult, schemars, tool, tool_router};
+use serde::{Deserialize, Serialize};
+use strum::VariantNames;
+
+use super::{McpServer, output_schema};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ExecutorSummary {
+    #[schemars(description = "Canonical executor name, e.g. 'CLAUDE_CODE'")]
+    name: String,
+    #[schemars(
+        description = "Other spellings accepted wherever an executor is expected, e.g. 'claude-code'"
+    )]
+    aliases: Vec<String>,
+    #[schemars(
+        description = "Configured variants that can be passed as `variant`, with 'DEFAULT' first"
+    )]
+    variants: Vec<String>,
+    #[schemars(
+        description = "Model and permission policy each variant uses unless overridden, in the same order as `variants`"
+    )]
+    variant_defaults: Vec<ExecutorVariantDefaults>,
+    #[schemars(description = "Whether any variant runs the agent under a permission policy")]
+    supports_permission_policy: bool,
+    #[schemars(
+        description = "Whether the agent is installed or logged in on this machine. Omitted when it could not be determined."
+    )]
+    available: Option<bool>,
+    #[schemars(
+        description = "How availability was determined: 'login_detected', 'installation_found' or 'not_found'"
+    )]
+    availability: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ExecutorVariantDefaults {
+    variant: String,
+    #[schemars(
+        description = "Model ID the variant uses. Omitted when the agent picks its own default."
+    )]
+    model_id: Option<String>,
+    #[schemars(description = "'AUTO', 'SUPERVISED' or 'PLAN'. Omitted when the agent has none.")]
+    permission_policy: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListExecutorsResponse {
+    executors: Vec<ExecutorSummary>,
+    count: usize,
+    available_count: usize,
+}
+
+#[derive(Deserialize)]
+struct ProfilesContent {
+    content: String,
+}
+
+#[tool_router(router = coding_agents_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "List the coding agent executors that can be passed as `executor` when starting workspaces or sessions, with their accepted aliases, configured variants with each variant's default model and permission policy, and whether each is available on this machine. Prefer an available executor.",
+        output_schema = output_schema::<McpListExecutorsResponse>()
+    )]
+    async fn list_executors(&self) -> Result<CallToolResult, ErrorData> {
+        let profiles = self.fetch_executor_profiles().await;
+        let agents = CodingAgent::VARIANTS
+            .iter()
+            .filter_map(|name| BaseCodingAgent::from_str(name).ok())
+            .collect::<Vec<_>>();
+        let availability = join_all(
+            agents
+                .iter()
+                .map(|agent| self.fetch_executor_availability(*agent)),
+        )
+        .await;
+
+        let executors = agents
+            .into_iter()
+            .zip(availability)
+            .map(|(agent, availability)| {
+                let name = agent.to_string();
+                let variant_defaults = executor_variant_defaults(&profiles, agent);
+                ExecutorSummary {
+                    aliases: executor_aliases(&name),
+                    variants: variant_defaults
+                        .iter()
+                        .map(|defaults| defaults.variant.clone())
+                        .collect(),
+                    supports_permission_policy: variant_defaults
+                        .iter()
+                        .any(|defaults| defaults.permission_policy.is_some()),
+                    variant_defaults,
+                    available: availability.as_ref().map(AvailabilityInfo::is_available),
+                    availability: availability.map(availability_label),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.success(&McpListExecutorsResponse {
+            count: executors.len(),
+            available_count: executors
+                .iter()
+                .filter(|executor| executor.available == Some(true))
+                .count(),
+            executors,
+        })
+    }
+}
+
+impl McpServer {
+    // Falls back to the built-in profiles if the server's (possibly customised) copy can't
+    // be read, so variants are still listed.
+    async fn fetch_executor_profiles(&self) -> ExecutorConfigs {
+        let url = self.url("/api/profiles");
+        match self
+            .send_json::<ProfilesContent>(self.client.get(&url))
+            .await
+        {
+            Ok(profiles) => match serde_json::from_str(&profiles.content) {
+                Ok(profiles) => return profiles,
+                Err(error) => tracing::debug!("Failed to parse executor profiles: {}", error),
+            },
+            Err(error) => tracing::debug!("Failed to fetch executor profiles: {}", error),
+        }
+        ExecutorConfigs::from_defaults()
+    }
+
+    async fn fetch_executor_availability(
+        &self,
+        agent: BaseCodingAgent,
+    ) -> Option<AvailabilityInfo> {
+        let url = self.url(&format!(
+            "/api/agents/check-availability?executor={}",
+            agent
+        ));
+        self.send_json(self.client.get(&url)).await.ok()
+    }
+}
+
+// Only spellings that `McpServer::canonical_executor_name` maps back to `name` are listed.
+fn executor_aliases(name: &str) -> Vec<String> {
+    let snake = name.to_ascii_lowercase();
+    let kebab = snake.replace('_', "-");
+    let mut aliases = vec![snake];
+    if kebab != aliases[0] {
+        aliases.push(kebab);
+    }
+    aliases.retain(|alias| McpServer::canonical_executor_name(alias) == name);
+    aliases
+}
+
+fn executor_variant_defaults(
+    profiles: &ExecutorConfigs,
+    agent: BaseCodingAgent,
+) -> Vec<ExecutorVariantDefaults> {
+    let Some(profile) = profiles.executors.get(&agent) else {
+        return Vec::new();
+    };
+    let mut variants = profile
+        .configurations
+        .iter()
+        .map(|(variant, config)| {
+            let preset = config.get_preset_options();
+            ExecutorVariantDefaults {
+                variant: variant.clone(),
+                model_id: preset.model_id,
+                permission_policy: preset
+                    .permission_policy
+                    .as_ref()
+                    .map(|policy| McpServer::permission_policy_label(policy).to_string()),
+            }
+        })
+        .collect::<Vec<_>>();
+    variants.sort_by_key(|defaults| (defaults.variant != "DEFAULT", defaults.variant.clone()));
+    variants
+}
+
+fn availability_label(availability: AvailabilityInfo) -> String {
+    match availability {
+        AvailabilityInfo::LoginDetected { .. } => "login_detected",
+        AvailabilityInfo::InstallationFound => "installation_found",
+        AvailabilityInfo::NotFound => "not_found",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_round_trip_through_executor_parsing() {
+        for name in CodingAgent::VARIANTS {
+            let agent = BaseCodingAgent::from_str(name).unwrap();
+            for alias in executor_aliases(name) {
+                assert_eq!(McpServer::parse_executor_agent(&alias).unwrap(), agent);
+            }
+        }
+    }
+
+    #[test]
+    fn default_variant_is_listed_first() {
+        let profiles = ExecutorConfigs::from_defaults();
+        let variants = executor_variant_defaults(&profiles, BaseCodingAgent::ClaudeCode)
+            .into_iter()
+            .map(|defaults| defaults.variant)
+            .collect::<Vec<_>>();
+
+        assert_eq!(variants.first().map(String::as_str), Some("DEFAULT"));
+        assert!(variants[1..].is_sorted());
+    }
+
+    #[test]
+    fn variant_defaults_report_permission_policies() {
+        let profiles = ExecutorConfigs::from_defaults();
+        let defaults = executor_variant_defaults(&profiles, BaseCodingAgent::ClaudeCode);
+
+        assert_eq!(defaults[0].variant, "DEFAULT");
+        assert_eq!(defaults[0].permission_policy.as_deref(), Some("AUTO"));
+    }
+}
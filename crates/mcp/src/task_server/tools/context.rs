@@ -1,14 +1,493 @@
-use rmcp::{ErrorData, model::CallToolResult, tool, tool_router};
+use std::time::Instant;
 
-use super::McpServer;
+use reqwest::StatusCode;
+use rmcp::{ErrorData, model::CallToolResult, schemars, tool, tool_router};
+use serde::Serialize;
+
+use super::{McpServer, output_schema};
+use crate::task_server::{
+    ApiResponseEnvelope, McpContext, config::format_timeout, server_stats::ServerStatsSnapshot,
+};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ConnectionCheck {
+    #[schemars(description = "What was checked: 'local_api', 'remote_api' or 'workspace_context'")]
+    name: String,
+    #[schemars(description = "The exact URL that was requested")]
+    url: String,
+    #[schemars(description = "'ok', 'http_error', 'unreachable' or 'timeout'")]
+    status: String,
+    #[schemars(description = "HTTP status code, if a response was received")]
+    http_status: Option<u16>,
+    latency_ms: u64,
+    #[schemars(description = "Error message or other detail about the result")]
+    detail: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpCheckConnectionResponse {
+    #[schemars(description = "The Vibe Kanban API base URL this MCP server is configured with")]
+    base_url: String,
+    #[schemars(description = "True if every check passed")]
+    ok: bool,
+    #[schemars(description = "Whether the current directory belongs to a Vibe Kanban workspace")]
+    workspace_context_detected: bool,
+    checks: Vec<ConnectionCheck>,
+}
 
 #[tool_router(router = context_tools_router, vis = "pub")]
 impl McpServer {
     #[tool(
-        description = "Return project, issue, workspace, and orchestrator-session metadata for the current MCP context."
+        description = "Return project, issue, workspace, and orchestrator-session metadata for the current MCP context. `remote_link_status` says whether the remote project link was resolved: 'linked', 'unlinked', 'pending' while it is still being looked up, or 'error: ...' if the remote API could not be reached.",
+        output_schema = output_schema::<McpContext>()
     )]
     async fn get_context(&self) -> Result<CallToolResult, ErrorData> {
-        let context = self.context.as_ref().expect("VK context should exist");
-        McpServer::success(context)
+        match self.current_context().await {
+            Some(context) => self.success(&context),
+            None => McpServer::err(
+                "No workspace context detected for current directory",
+                Some(
+                    "The MCP server is not running inside a Vibe Kanban workspace. Pass explicit IDs to tools instead.",
+                ),
+            ),
+        }
+    }
+
+    #[tool(
+        description = "Diagnose connectivity problems, e.g. when other tools fail with 'Failed to connect to VK API'. Probes the local API, the remote API and workspace context detection, and reports the status, latency and exact URL of each along with the configured base URL.",
+        output_schema = output_schema::<McpCheckConnectionResponse>()
+    )]
+    async fn check_connection(&self) -> Result<CallToolResult, ErrorData> {
+        let local = self
+            .probe_endpoint("local_api", self.client.get(self.url("/api/health")))
+            .await;
+
+        // Listing the organizations goes through the remote API without needing any IDs.
+        let remote_url = match self.context.get().and_then(|ctx| ctx.organization_id) {
+            Some(organization_id) => self.url(&format!(
+                "/api/remote/projects?organization_id={}",
+                organization_id
+            )),
+            None => self.url("/api/organizations"),
+        };
+        let remote = self
+            .probe_endpoint("remote_api", self.client.get(remote_url))
+            .await;
+
+        let mut context_request = self.client.get(self.url("/api/containers/attempt-context"));
+        match self.attempt_context_query() {
+            Ok(query) => context_request = context_request.query(&query),
+            Err(error) => tracing::debug!("Failed to build context query: {:#}", error),
+        }
+        let mut context = self
+            .probe_endpoint("workspace_context", context_request)
+            .await;
+        let workspace_context_detected = context.status == "ok";
+        if context.http_status == Some(StatusCode::NOT_FOUND.as_u16()) {
+            // The server answered; the directory just isn't part of a workspace.
+            context.status = "ok".to_string();
+            context.detail = Some("Not inside a Vibe Kanban workspace".to_string());
+        }
+
+        let checks = vec![local, remote, context];
+        self.success(&McpCheckConnectionResponse {
+            base_url: self.config.base_url.clone(),
+            ok: checks.iter().all(|check| check.status == "ok"),
+            workspace_context_detected,
+            checks,
+        })
+    }
+
+    #[tool(
+        description = "Report usage and health statistics for this MCP server since it started: uptime, call and error counts per tool, call and error counts with p50/p95 latency per VK API endpoint, and the last 10 failed VK API requests with timestamps. Use it to spot slow or failing endpoints.",
+        output_schema = output_schema::<ServerStatsSnapshot>()
+    )]
+    async fn get_server_stats(&self) -> Result<CallToolResult, ErrorData> {
+        self.success(&self.stats.snapshot())
+    }
+}
+
+impl McpServer {
+    // Sends a single request without retries, so the latency reflects one round trip.
+    async fn probe_endpoint(
+        &self,
+        name: &str,
+        request: reqwest::RequestBuilder,
+    ) -> ConnectionCheck {
+        let request = match request.build() {
+            Ok(request) => request,
+            Err(error) => {
+                return ConnectionCheck {
+                    name: name.to_string(),
+                    url: error
+                        .url()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| self.config.base_url.clone()),
+                    status: "unreachable".to_string(),
+                    http_status: None,
+                    latency_ms: 0,
+                    detail: Some(error.to_string()),
+                };
+            }
+        };
+        let url = request.url().to_string();
+
+        let started = Instant::now();
+        let result = tokio::time::timeout(
+            self.config.context_probe_timeout,
+            self.client.execute(request),
+        )
+        .await;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        let (status, http_status, detail) = match result {
+            Err(_) => (
+                "timeout",
+                None,
+                Some(format!(
+                    "No response within {}",
+                    format_timeout(self.config.context_probe_timeout)
+                )),
+            ),
+            Ok(Err(error)) => ("unreachable", None, Some(error.to_string())),
+            Ok(Ok(response)) if response.status().is_success() => {
+                ("ok", Some(response.status().as_u16()), None)
+            }
+            Ok(Ok(response)) => {
+                let http_status = response.status();
+                let message = response
+                    .json::<ApiResponseEnvelope<serde_json::Value>>()
+                    .await
+                    .ok()
+                    .and_then(|body| body.message);
+                ("http_error", Some(http_status.as_u16()), message)
+            }
+        };
+
+        ConnectionCheck {
+            name: name.to_string(),
+            url,
+            status: status.to_string(),
+            http_status,
+            latency_ms,
+            detail,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use axum::{Router, extract::RawQuery, http::StatusCode, response::IntoResponse, routing::get};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::task_server::{
+        RemoteLinkStatus,
+        tools::test_support::{MockBackend, envelope},
+    };
+
+    #[tokio::test]
+    async fn get_context_reports_missing_workspace_context() {
+        let backend = MockBackend::start(Router::new()).await;
+        let server = backend.server().init().await.unwrap();
+
+        let result = server.get_context().await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["error"],
+            "No workspace context detected for current directory"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_connection_reports_each_probe() {
+        let backend = MockBackend::start(
+            Router::new().route("/api/health", get(|| async { envelope(json!("OK")) })),
+        )
+        .await;
+        let server = backend.server();
+
+        let result = server.check_connection().await.unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["ok"], false);
+        assert_eq!(body["workspace_context_detected"], false);
+        let checks = body["checks"].as_array().unwrap();
+        assert_eq!(checks[0]["name"], "local_api");
+        assert_eq!(checks[0]["status"], "ok");
+        assert!(checks[0]["url"].as_str().unwrap().ends_with("/api/health"));
+        assert_eq!(checks[1]["name"], "remote_api");
+        assert_eq!(checks[1]["status"], "http_error");
+        assert_eq!(checks[1]["http_status"], 404);
+        // Not being inside a workspace is not a connection problem.
+        assert_eq!(checks[2]["name"], "workspace_context");
+        assert_eq!(checks[2]["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn server_stats_group_requests_by_endpoint() {
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/remote/issues/{id}",
+                    get(|| async { envelope(json!({})) }),
+                )
+                .route(
+                    "/api/organizations",
+                    get(|| async { StatusCode::FORBIDDEN }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+
+        for _ in 0..2 {
+            let url = server.url(&format!("/api/remote/issues/{}", Uuid::new_v4()));
+            server
+                .send_json::<serde_json::Value>(server.client.get(url))
+                .await
+                .unwrap();
+        }
+        let url = server.url("/api/organizations");
+        assert!(
+            server
+                .send_json::<serde_json::Value>(server.client.get(url))
+                .await
+                .is_err()
+        );
+
+        let result = server.get_server_stats().await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["total_requests"], 3);
+        assert_eq!(body["total_request_errors"], 1);
+        assert_eq!(
+            body["endpoints"][0]["endpoint"],
+            "GET /api/remote/issues/{id}"
+        );
+        assert_eq!(body["endpoints"][0]["calls"], 2);
+        assert_eq!(body["endpoints"][1]["endpoint"], "GET /api/organizations");
+        assert_eq!(body["endpoints"][1]["errors"], 1);
+        let errors = body["recent_errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0]["status"], 403);
+    }
+
+    #[tokio::test]
+    async fn project_id_is_picked_up_after_workspace_is_linked() {
+        let workspace_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let linked = Arc::new(AtomicBool::new(false));
+        let linked_route = linked.clone();
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/containers/attempt-context",
+                    get(move || async move {
+                        envelope(json!({
+                            "workspace": {
+                                "id": workspace_id,
+                                "task_id": null,
+                                "container_ref": null,
+                                "branch": "vk/linked",
+                                "setup_completed_at": null,
+                                "created_at": "2026-01-01T00:00:00Z",
+                                "updated_at": "2026-01-01T00:00:00Z",
+                                "archived": false,
+                                "pinned": false,
+                                "name": null,
+                                "worktree_deleted": false,
+                            },
+                            "workspace_repos": [],
+                            "orchestrator_session_id": null,
+                        }))
+                    }),
+                )
+                .route(
+                    "/api/remote/workspaces/by-local-id/{id}",
+                    get(move || {
+                        let linked = linked_route.clone();
+                        async move {
+                            if !linked.load(Ordering::SeqCst) {
+                                return StatusCode::NOT_FOUND.into_response();
+                            }
+                            envelope(json!({
+                                "id": Uuid::new_v4(),
+                                "project_id": project_id,
+                                "owner_user_id": Uuid::new_v4(),
+                                "issue_id": null,
+                                "local_workspace_id": workspace_id,
+                                "name": null,
+                                "archived": false,
+                                "files_changed": null,
+                                "lines_added": null,
+                                "lines_removed": null,
+                                "created_at": "2026-01-01T00:00:00Z",
+                                "updated_at": "2026-01-01T00:00:00Z",
+                            }))
+                            .into_response()
+                        }
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server().init().await.unwrap();
+        assert!(server.resolve_project_id(None).await.is_err());
+
+        linked.store(true, Ordering::SeqCst);
+
+        assert_eq!(server.resolve_project_id(None).await.unwrap(), project_id);
+    }
+
+    #[tokio::test]
+    async fn failed_context_probe_is_reused_for_repeated_misses() {
+        let backend = MockBackend::start(Router::new()).await;
+        let server = backend.server();
+
+        for _ in 0..3 {
+            assert!(server.resolve_project_id(None).await.is_err());
+        }
+
+        assert_eq!(backend.requests_to("/api/containers/attempt-context"), 1);
+    }
+
+    #[tokio::test]
+    async fn configured_workspace_id_skips_path_detection() {
+        let workspace_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/containers/attempt-context",
+            get(move |RawQuery(query): RawQuery| async move {
+                assert_eq!(query, Some(format!("workspace_id={workspace_id}")));
+                envelope(json!({
+                    "workspace": {
+                        "id": workspace_id,
+                        "task_id": null,
+                        "container_ref": null,
+                        "branch": "vk/pinned",
+                        "setup_completed_at": null,
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                        "archived": false,
+                        "pinned": false,
+                        "name": null,
+                        "worktree_deleted": false,
+                    },
+                    "workspace_repos": [],
+                    "orchestrator_session_id": null,
+                }))
+            }),
+        ))
+        .await;
+        let mut server = backend.server();
+        server.config.workspace_id = Some(workspace_id);
+        let server = server.init().await.unwrap();
+
+        let context = server.current_context().await.unwrap();
+        assert_eq!(context.workspace_id, workspace_id);
+        assert_eq!(context.workspace_branch, "vk/pinned");
+    }
+
+    #[tokio::test]
+    async fn unreachable_remote_api_keeps_last_known_link() {
+        let workspace_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let remote_up = Arc::new(AtomicBool::new(true));
+        let remote_up_route = remote_up.clone();
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/containers/attempt-context",
+                    get(move || async move {
+                        envelope(json!({
+                            "workspace": {
+                                "id": workspace_id,
+                                "task_id": null,
+                                "container_ref": null,
+                                "branch": "vk/linked",
+                                "setup_completed_at": null,
+                                "created_at": "2026-01-01T00:00:00Z",
+                                "updated_at": "2026-01-01T00:00:00Z",
+                                "archived": false,
+                                "pinned": false,
+                                "name": null,
+                                "worktree_deleted": false,
+                            },
+                            "workspace_repos": [],
+                            "orchestrator_session_id": null,
+                        }))
+                    }),
+                )
+                .route(
+                    "/api/remote/workspaces/by-local-id/{id}",
+                    get(move || {
+                        let remote_up = remote_up_route.clone();
+                        async move {
+                            if !remote_up.load(Ordering::SeqCst) {
+                                return StatusCode::BAD_GATEWAY.into_response();
+                            }
+                            envelope(json!({
+                                "id": Uuid::new_v4(),
+                                "project_id": project_id,
+                                "owner_user_id": Uuid::new_v4(),
+                                "issue_id": null,
+                                "local_workspace_id": workspace_id,
+                                "name": null,
+                                "archived": false,
+                                "files_changed": null,
+                                "lines_added": null,
+                                "lines_removed": null,
+                                "created_at": "2026-01-01T00:00:00Z",
+                                "updated_at": "2026-01-01T00:00:00Z",
+                            }))
+                            .into_response()
+                        }
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+
+        let linked = server.refresh_context().await.unwrap();
+        assert_eq!(linked.project_id, Some(project_id));
+        // The project lookup isn't routed, so the organization is missing.
+        assert!(matches!(
+            linked.remote_link_status,
+            RemoteLinkStatus::Error(_)
+        ));
+
+        remote_up.store(false, Ordering::SeqCst);
+        let context = server.refresh_context().await.unwrap();
+
+        assert_eq!(context.workspace_id, workspace_id);
+        assert_eq!(context.project_id, Some(project_id));
+        assert_eq!(
+            serde_json::to_value(&context.remote_link_status).unwrap(),
+            "error: remote API returned 502 Bad Gateway"
+        );
+    }
+
+    #[test]
+    fn remote_link_status_round_trips_through_strings() {
+        for status in [
+            RemoteLinkStatus::Linked,
+            RemoteLinkStatus::Pending,
+            RemoteLinkStatus::Unlinked,
+            RemoteLinkStatus::Error("remote API timed out after 2s".to_string()),
+        ] {
+            let value = serde_json::to_value(&status).unwrap();
+            assert_eq!(
+                serde_json::from_value::<RemoteLinkStatus>(value).unwrap(),
+                status
+            );
+        }
     }
 }
@@ -1,6 +1,4 @@
-use api_types::{
-    CreateIssueAssigneeRequest, IssueAssignee, ListIssueAssigneesResponse, MutationResponse,
-};
+use api_types::ListIssueAssigneesResponse;
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
@@ -8,7 +6,7 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, ToolError, output_schema};
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpListIssueAssigneesRequest {
@@ -40,29 +38,58 @@ struct McpAssignIssueRequest {
     #[schemars(description = "Issue ID to assign")]
     issue_id: Uuid,
     #[schemars(description = "User ID to assign to the issue")]
-    user_id: Uuid,
+    user_id: Option<Uuid>,
+    #[schemars(description = "Username of the organization member to assign")]
+    username: Option<String>,
+    #[schemars(description = "Email of the organization member to assign")]
+    email: Option<String>,
+    #[schemars(
+        description = "Optional caller-chosen UUID for the new assignment. Reuse it when retrying so the retry can't assign the user twice."
+    )]
+    client_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpAssignIssueResponse {
     issue_assignee_id: String,
+    #[schemars(description = "False if the user was already assigned to the issue")]
+    created: bool,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpUnassignIssueRequest {
-    #[schemars(description = "Issue assignee ID to remove")]
-    issue_assignee_id: Uuid,
+    #[schemars(
+        description = "Issue assignee ID to remove. Alternatively pass issue_id with one of user_id, username or email."
+    )]
+    issue_assignee_id: Option<Uuid>,
+    #[schemars(description = "Issue to remove the user from")]
+    issue_id: Option<Uuid>,
+    #[schemars(description = "User ID to remove from the issue")]
+    user_id: Option<Uuid>,
+    #[schemars(description = "Username of the organization member to remove")]
+    username: Option<String>,
+    #[schemars(description = "Email of the organization member to remove")]
+    email: Option<String>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpUnassignIssueResponse {
     success: bool,
-    issue_assignee_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_assignee_id: Option<String>,
+    #[schemars(
+        description = "Set when the user was not assigned to the issue, so nothing was removed"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
 }
 
 #[tool_router(router = issue_assignees_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "List assignees for an issue.")]
+    #[tool(
+        description = "List assignees for an issue.",
+        output_schema = output_schema::<McpListIssueAssigneesResponse>()
+    )]
     async fn list_issue_assignees(
         &self,
         Parameters(McpListIssueAssigneesRequest { issue_id }): Parameters<
@@ -90,43 +117,105 @@ impl McpServer {
             })
             .collect::<Vec<_>>();
 
-        McpServer::success(&McpListIssueAssigneesResponse {
+        self.success(&McpListIssueAssigneesResponse {
             issue_id: issue_id.to_string(),
             count: assignees.len(),
             issue_assignees: assignees,
         })
     }
 
-    #[tool(description = "Assign a user to an issue.")]
+    #[tool(
+        description = "Assign a user to an issue. Identify the user by exactly one of `user_id`, `username` or `email`; names are matched against the members of the issue's organization. Assigning a user who is already assigned returns the existing assignment with `created: false`.",
+        output_schema = output_schema::<McpAssignIssueResponse>()
+    )]
     async fn assign_issue(
         &self,
-        Parameters(McpAssignIssueRequest { issue_id, user_id }): Parameters<McpAssignIssueRequest>,
-    ) -> Result<CallToolResult, ErrorData> {
-        let payload = CreateIssueAssigneeRequest {
-            id: None,
+        Parameters(McpAssignIssueRequest {
             issue_id,
             user_id,
+            username,
+            email,
+            client_id,
+        }): Parameters<McpAssignIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let user_id = match self
+            .resolve_assignee_user_id(issue_id, user_id, username, email)
+            .await
+        {
+            Ok(user_id) => user_id,
+            Err(e) => return Ok(Self::tool_error(e)),
         };
 
-        let url = self.url("/api/remote/issue-assignees");
-        let response: MutationResponse<IssueAssignee> =
-            match self.send_json(self.client.post(&url).json(&payload)).await {
-                Ok(r) => r,
+        let (issue_assignee, created) =
+            match self.assign_issue_user(issue_id, user_id, client_id).await {
+                Ok(result) => result,
                 Err(e) => return Ok(Self::tool_error(e)),
             };
 
-        McpServer::success(&McpAssignIssueResponse {
-            issue_assignee_id: response.data.id.to_string(),
+        self.success(&McpAssignIssueResponse {
+            issue_assignee_id: issue_assignee.id.to_string(),
+            created,
         })
     }
 
-    #[tool(description = "Remove an assignee from an issue using issue_assignee_id.")]
+    #[tool(
+        description = "Remove an assignee from an issue, either by issue_assignee_id or by issue_id plus one of user_id, username or email. Removing a user who is not assigned succeeds with a `warning`.",
+        output_schema = output_schema::<McpUnassignIssueResponse>()
+    )]
     async fn unassign_issue(
         &self,
-        Parameters(McpUnassignIssueRequest { issue_assignee_id }): Parameters<
-            McpUnassignIssueRequest,
-        >,
+        Parameters(McpUnassignIssueRequest {
+            issue_assignee_id,
+            issue_id,
+            user_id,
+            username,
+            email,
+        }): Parameters<McpUnassignIssueRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let issue_assignee_id = match (issue_assignee_id, issue_id) {
+            (Some(issue_assignee_id), None) => issue_assignee_id,
+            (None, Some(issue_id)) => {
+                let user_id = match self
+                    .resolve_assignee_user_id(issue_id, user_id, username, email)
+                    .await
+                {
+                    Ok(user_id) => user_id,
+                    Err(e) => return Ok(Self::tool_error(e)),
+                };
+                let url = self.url(&format!(
+                    "/api/remote/issue-assignees?issue_id={}",
+                    issue_id
+                ));
+                let response: ListIssueAssigneesResponse =
+                    match self.send_json(self.client.get(&url)).await {
+                        Ok(r) => r,
+                        Err(e) => return Ok(Self::tool_error(e)),
+                    };
+                match response
+                    .issue_assignees
+                    .into_iter()
+                    .find(|assignee| assignee.user_id == user_id)
+                {
+                    Some(assignee) => assignee.id,
+                    None => {
+                        return self.success(&McpUnassignIssueResponse {
+                            success: true,
+                            issue_assignee_id: None,
+                            warning: Some(format!(
+                                "User {} is not assigned to issue {}; nothing was removed",
+                                user_id, issue_id
+                            )),
+                        });
+                    }
+                }
+            }
+            _ => {
+                return Ok(Self::tool_error(ToolError::message(
+                    "Pass either issue_assignee_id, or issue_id with the user to remove",
+                )));
+            }
+        };
+
         let url = self.url(&format!(
             "/api/remote/issue-assignees/{}",
             issue_assignee_id
@@ -135,9 +224,227 @@ impl McpServer {
             return Ok(Self::tool_error(e));
         }
 
-        McpServer::success(&McpUnassignIssueResponse {
+        self.success(&McpUnassignIssueResponse {
             success: true,
-            issue_assignee_id: issue_assignee_id.to_string(),
+            issue_assignee_id: Some(issue_assignee_id.to_string()),
+            warning: None,
         })
     }
 }
+
+impl McpServer {
+    // The user named by exactly one of `user_id`, `username` or `email`. Names are resolved
+    // against the members of the organization that owns the issue's project.
+    async fn resolve_assignee_user_id(
+        &self,
+        issue_id: Uuid,
+        user_id: Option<Uuid>,
+        username: Option<String>,
+        email: Option<String>,
+    ) -> Result<Uuid, ToolError> {
+        match (user_id, username, email) {
+            (Some(user_id), None, None) => Ok(user_id),
+            (None, Some(name), None) | (None, None, Some(name)) => {
+                let issue = self.fetch_issue(issue_id).await?;
+                self.resolve_member_user_id(issue.project_id, &name).await
+            }
+            _ => Err(ToolError::message(
+                "Pass exactly one of user_id, username or email",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        Json, Router,
+        routing::{delete, get, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{
+        MockBackend, envelope, issue_json, member_json, project_with_members_router,
+    };
+
+    // Routes for an issue whose organization has the given members.
+    fn issue_router(issue_id: Uuid, members: Vec<Value>) -> Router {
+        let project_id = Uuid::new_v4();
+        let mut issue = issue_json(project_id, Uuid::new_v4(), 1);
+        issue["id"] = json!(issue_id);
+        Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || async move { envelope(issue) }),
+            )
+            .merge(project_with_members_router(project_id, members))
+    }
+
+    #[tokio::test]
+    async fn assign_issue_resolves_a_username_to_its_member() {
+        let issue_id = Uuid::new_v4();
+        let (alice_id, albert_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let created = Arc::new(Mutex::new(None));
+
+        let router = issue_router(
+            issue_id,
+            vec![
+                member_json(alice_id, "alice"),
+                member_json(albert_id, "albert"),
+            ],
+        )
+        .route(
+            "/api/remote/issue-assignees",
+            post({
+                let created = created.clone();
+                move |Json(body): Json<Value>| async move {
+                    *created.lock().unwrap() = Some(body["user_id"].clone());
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "issue_id": issue_id,
+                            "user_id": body["user_id"],
+                            "assigned_at": "2026-01-01T00:00:00Z",
+                        },
+                        "txid": 1,
+                    }))
+                }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "username": "Alice",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .assign_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(
+            created.lock().unwrap().take(),
+            Some(json!(alice_id.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn assign_issue_lists_candidates_for_an_ambiguous_name() {
+        let issue_id = Uuid::new_v4();
+        let router = issue_router(
+            issue_id,
+            vec![
+                member_json(Uuid::new_v4(), "alice"),
+                member_json(Uuid::new_v4(), "albert"),
+            ],
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "username": "al",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .assign_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let text = &result.content[0].as_text().unwrap().text;
+        assert!(text.contains("alice") && text.contains("albert"), "{text}");
+        assert_eq!(backend.requests_to("/api/remote/issue-assignees"), 0);
+    }
+
+    // Adds the assignee list for `issue_id`, holding a single assignment of `user_id`.
+    fn with_assignment(router: Router, issue_id: Uuid, user_id: Uuid, assignee_id: Uuid) -> Router {
+        router
+            .route(
+                "/api/remote/issue-assignees",
+                get(move || async move {
+                    envelope(json!({ "issue_assignees": [{
+                        "id": assignee_id,
+                        "issue_id": issue_id,
+                        "user_id": user_id,
+                        "assigned_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-assignees/{issue_assignee_id}",
+                delete(|| async { envelope(json!(null)) }),
+            )
+    }
+
+    #[tokio::test]
+    async fn unassign_issue_removes_the_assignment_for_a_username() {
+        let issue_id = Uuid::new_v4();
+        let (alice_id, assignee_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let router = with_assignment(
+            issue_router(issue_id, vec![member_json(alice_id, "alice")]),
+            issue_id,
+            alice_id,
+            assignee_id,
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "username": "alice",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .unassign_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue_assignee_id"], assignee_id.to_string());
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issue-assignees/{assignee_id}")),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn unassigning_a_user_who_is_not_assigned_warns_instead_of_failing() {
+        let issue_id = Uuid::new_v4();
+        let assignee_id = Uuid::new_v4();
+        let router = with_assignment(
+            issue_router(issue_id, Vec::new()),
+            issue_id,
+            Uuid::new_v4(),
+            assignee_id,
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "user_id": Uuid::new_v4(),
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .unassign_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["success"], true);
+        assert!(body["warning"].as_str().unwrap().contains("not assigned"));
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issue-assignees/{assignee_id}")),
+            0
+        );
+    }
+}
@@ -0,0 +1,640 @@
+use std::collections::{BTreeMap, HashMap};
+
+use api_types::{
+    CreateIssueCommentReactionRequest, CreateIssueCommentRequest, IssueComment,
+    IssueCommentReaction, ListIssueCommentReactionsResponse, ListIssueCommentsResponse,
+    MutationResponse, UpdateIssueCommentRequest,
+};
+use reqwest::StatusCode;
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{McpServer, ToolError, output_schema};
+
+// The emoji offered by the web app's reaction picker.
+const REACTION_EMOJIS: [&str; 16] = [
+    "👍", "👎", "❤️", "😄", "😢", "🎉", "🚀", "👀", "🔥", "💯", "✅", "❌", "🤔", "👏", "💪", "🙌",
+];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListIssueCommentsRequest {
+    #[schemars(description = "Issue ID to list comments for")]
+    issue_id: Uuid,
+    #[schemars(description = "Maximum number of comments to return (default: 50)")]
+    limit: Option<i32>,
+    #[schemars(description = "Number of comments to skip, oldest first (default: 0)")]
+    offset: Option<i32>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct IssueCommentSummary {
+    #[schemars(description = "Comment ID")]
+    id: String,
+    #[schemars(description = "User ID of the comment author, if known")]
+    author_id: Option<String>,
+    #[schemars(description = "Parent comment ID if this is a reply")]
+    parent_id: Option<String>,
+    #[schemars(description = "Comment body")]
+    body: String,
+    #[schemars(description = "When the comment was created")]
+    created_at: String,
+    #[schemars(description = "When the comment was last edited")]
+    updated_at: String,
+    #[schemars(description = "Number of reactions per emoji, e.g. {\"👍\": 3}")]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    reactions: BTreeMap<String, usize>,
+    #[schemars(description = "Emoji the current user reacted with")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    my_reactions: Vec<String>,
+}
+
+impl IssueCommentSummary {
+    fn from_comment(comment: IssueComment) -> Self {
+        Self {
+            id: comment.id.to_string(),
+            author_id: comment.author_id.map(|id| id.to_string()),
+            parent_id: comment.parent_id.map(|id| id.to_string()),
+            body: comment.message,
+            created_at: comment.created_at.to_rfc3339(),
+            updated_at: comment.updated_at.to_rfc3339(),
+            reactions: BTreeMap::new(),
+            my_reactions: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListIssueCommentsResponse {
+    issue_id: String,
+    comments: Vec<IssueCommentSummary>,
+    count: usize,
+    total_count: usize,
+    limit: usize,
+    offset: usize,
+    #[schemars(
+        description = "Set when reactions could not be loaded. The comments themselves are complete."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpAddIssueCommentRequest {
+    #[schemars(description = "Issue ID to comment on")]
+    issue_id: Uuid,
+    #[schemars(description = "Comment body. Supports @tagname expansion.")]
+    body: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpAddIssueCommentResponse {
+    comment: IssueCommentSummary,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpUpdateIssueCommentRequest {
+    #[schemars(description = "Comment ID to edit. Only your own comments can be edited.")]
+    comment_id: Uuid,
+    #[schemars(
+        description = "New comment body, replacing the old one. Supports @tagname expansion."
+    )]
+    body: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpDeleteIssueCommentRequest {
+    #[schemars(description = "Comment ID to delete. Only your own comments can be deleted.")]
+    comment_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpDeleteIssueCommentResponse {
+    success: bool,
+    comment_id: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpAddCommentReactionRequest {
+    #[schemars(description = "Comment ID to react to")]
+    comment_id: Uuid,
+    #[schemars(description = "One of: 👍 👎 ❤️ 😄 😢 🎉 🚀 👀 🔥 💯 ✅ ❌ 🤔 👏 💪 🙌")]
+    emoji: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpAddCommentReactionResponse {
+    reaction_id: String,
+    comment_id: String,
+    emoji: String,
+    #[schemars(description = "False if you had already reacted with this emoji")]
+    created: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpRemoveCommentReactionRequest {
+    #[schemars(description = "Reaction ID to remove. Alternatively pass comment_id and emoji.")]
+    reaction_id: Option<Uuid>,
+    #[schemars(description = "Comment to remove your reaction from, together with `emoji`")]
+    comment_id: Option<Uuid>,
+    #[schemars(description = "Emoji of your reaction to remove, together with `comment_id`")]
+    emoji: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRemoveCommentReactionResponse {
+    success: bool,
+    #[schemars(description = "Removed reaction ID, if there was a reaction to remove")]
+    reaction_id: Option<String>,
+}
+
+#[tool_router(router = issue_comments_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "List comments on an issue, oldest first, with reaction counts per emoji and the emoji you reacted with. Use `limit` and `offset` to page through long threads.",
+        output_schema = output_schema::<McpListIssueCommentsResponse>()
+    )]
+    async fn list_issue_comments(
+        &self,
+        Parameters(McpListIssueCommentsRequest {
+            issue_id,
+            limit,
+            offset,
+        }): Parameters<McpListIssueCommentsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/remote/issue-comments?issue_id={}", issue_id));
+        let response: ListIssueCommentsResponse = match self.send_json(self.client.get(&url)).await
+        {
+            Ok(r) => r,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let mut issue_comments = response.issue_comments;
+        issue_comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        let total_count = issue_comments.len();
+        let offset = offset.unwrap_or(0).max(0) as usize;
+        let limit = limit.unwrap_or(50).max(0) as usize;
+        let mut comments = issue_comments
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(IssueCommentSummary::from_comment)
+            .collect::<Vec<_>>();
+
+        // Reactions are secondary, so a failure to load them doesn't fail the listing.
+        let warning = match self.fetch_issue_comment_reactions(issue_id).await {
+            Ok(reactions) if reactions.is_empty() => None,
+            Ok(reactions) => {
+                let current_user_id = self.current_user_id().await.ok();
+                add_reaction_counts(&mut comments, reactions, current_user_id);
+                None
+            }
+            Err(e) => Some(format!("Could not load reactions: {}", e.message)),
+        };
+
+        self.success(&McpListIssueCommentsResponse {
+            issue_id: issue_id.to_string(),
+            count: comments.len(),
+            total_count,
+            limit,
+            offset,
+            comments,
+            warning,
+        })
+    }
+
+    #[tool(
+        description = "Add a comment to an issue, e.g. to leave a progress note. `@tagname` references in the body are expanded.",
+        output_schema = output_schema::<McpAddIssueCommentResponse>()
+    )]
+    async fn add_issue_comment(
+        &self,
+        Parameters(McpAddIssueCommentRequest { issue_id, body }): Parameters<
+            McpAddIssueCommentRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let body = body.trim();
+        if body.is_empty() {
+            return Self::err(
+                "Comment body must not be empty",
+                Some("Provide the text of the comment in `body`."),
+            );
+        }
+
+        let payload = CreateIssueCommentRequest {
+            id: None,
+            issue_id,
+            message: self.expand_tags(body).await,
+            parent_id: None,
+        };
+
+        let url = self.url("/api/remote/issue-comments");
+        let response: MutationResponse<IssueComment> =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(r) => r,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpAddIssueCommentResponse {
+            comment: IssueCommentSummary::from_comment(response.data),
+        })
+    }
+
+    #[tool(
+        description = "Replace the body of one of your issue comments. `@tagname` references in the body are expanded.",
+        output_schema = output_schema::<McpAddIssueCommentResponse>()
+    )]
+    async fn update_issue_comment(
+        &self,
+        Parameters(McpUpdateIssueCommentRequest { comment_id, body }): Parameters<
+            McpUpdateIssueCommentRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let body = body.trim();
+        if body.is_empty() {
+            return Self::err(
+                "Comment body must not be empty",
+                Some("Use `delete_issue_comment` to remove a comment."),
+            );
+        }
+
+        let payload = UpdateIssueCommentRequest {
+            message: Some(self.expand_tags(body).await),
+            parent_id: None,
+        };
+        let url = self.url(&format!("/api/remote/issue-comments/{}", comment_id));
+        let response: MutationResponse<IssueComment> =
+            match self.send_json(self.client.patch(&url).json(&payload)).await {
+                Ok(r) => r,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpAddIssueCommentResponse {
+            comment: IssueCommentSummary::from_comment(response.data),
+        })
+    }
+
+    #[tool(
+        description = "Delete one of your issue comments.",
+        output_schema = output_schema::<McpDeleteIssueCommentResponse>()
+    )]
+    async fn delete_issue_comment(
+        &self,
+        Parameters(McpDeleteIssueCommentRequest { comment_id }): Parameters<
+            McpDeleteIssueCommentRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/remote/issue-comments/{}", comment_id));
+        if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+            return Ok(Self::tool_error(e));
+        }
+
+        self.success(&McpDeleteIssueCommentResponse {
+            success: true,
+            comment_id: comment_id.to_string(),
+        })
+    }
+
+    #[tool(
+        description = "React to an issue comment with an emoji. Reacting twice with the same emoji is not an error.",
+        output_schema = output_schema::<McpAddCommentReactionResponse>()
+    )]
+    async fn add_comment_reaction(
+        &self,
+        Parameters(McpAddCommentReactionRequest { comment_id, emoji }): Parameters<
+            McpAddCommentReactionRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let Some(emoji) = reaction_emoji(&emoji) else {
+            return Self::err(
+                format!("Unsupported reaction emoji '{}'", emoji.trim()),
+                Some(format!("Use one of: {}", REACTION_EMOJIS.join(" "))),
+            );
+        };
+
+        let payload = CreateIssueCommentReactionRequest {
+            id: None,
+            comment_id,
+            emoji: emoji.to_string(),
+        };
+        let url = self.url("/api/remote/issue-comment-reactions");
+        let (reaction, created) = match self
+            .send_json::<MutationResponse<IssueCommentReaction>>(
+                self.client.post(&url).json(&payload),
+            )
+            .await
+        {
+            Ok(response) => (response.data, true),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                match self.find_own_comment_reaction(comment_id, emoji).await {
+                    Ok(Some(reaction)) => (reaction, false),
+                    Ok(None) => return Ok(Self::tool_error(e)),
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpAddCommentReactionResponse {
+            reaction_id: reaction.id.to_string(),
+            comment_id: reaction.comment_id.to_string(),
+            emoji: reaction.emoji,
+            created,
+        })
+    }
+
+    #[tool(
+        description = "Remove one of your reactions from an issue comment. Pass `reaction_id`, or `comment_id` with `emoji`. Removing a reaction that does not exist is not an error.",
+        output_schema = output_schema::<McpRemoveCommentReactionResponse>()
+    )]
+    async fn remove_comment_reaction(
+        &self,
+        Parameters(McpRemoveCommentReactionRequest {
+            reaction_id,
+            comment_id,
+            emoji,
+        }): Parameters<McpRemoveCommentReactionRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let reaction_id = match (reaction_id, comment_id, emoji) {
+            (Some(id), _, _) => Some(id),
+            (None, Some(comment_id), Some(emoji)) => {
+                let emoji = reaction_emoji(&emoji).unwrap_or(emoji.trim());
+                match self.find_own_comment_reaction(comment_id, emoji).await {
+                    Ok(reaction) => reaction.map(|reaction| reaction.id),
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            _ => {
+                return Self::err(
+                    "Either reaction_id or comment_id with emoji is required",
+                    None,
+                );
+            }
+        };
+
+        let mut removed_id = None;
+        if let Some(id) = reaction_id {
+            let url = self.url(&format!("/api/remote/issue-comment-reactions/{}", id));
+            match self.send_empty_json(self.client.delete(&url)).await {
+                Ok(()) => removed_id = Some(id),
+                Err(e) if e.status == Some(StatusCode::NOT_FOUND.as_u16()) => {}
+                Err(e) => return Ok(Self::tool_error(e)),
+            }
+        }
+
+        self.success(&McpRemoveCommentReactionResponse {
+            success: true,
+            reaction_id: removed_id.map(|id| id.to_string()),
+        })
+    }
+}
+
+impl McpServer {
+    async fn fetch_issue_comment_reactions(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<Vec<IssueCommentReaction>, ToolError> {
+        let url = self.url(&format!(
+            "/api/remote/issue-comment-reactions?issue_id={}",
+            issue_id
+        ));
+        let response: ListIssueCommentReactionsResponse =
+            self.send_json(self.client.get(&url)).await?;
+        Ok(response.issue_comment_reactions)
+    }
+
+    // The current user's reaction with `emoji` on a comment, if any.
+    async fn find_own_comment_reaction(
+        &self,
+        comment_id: Uuid,
+        emoji: &str,
+    ) -> Result<Option<IssueCommentReaction>, ToolError> {
+        let user_id = self.current_user_id().await?;
+        let url = self.url(&format!(
+            "/api/remote/issue-comment-reactions?comment_id={}",
+            comment_id
+        ));
+        let response: ListIssueCommentReactionsResponse =
+            self.send_json(self.client.get(&url)).await?;
+        Ok(response
+            .issue_comment_reactions
+            .into_iter()
+            .find(|reaction| reaction.user_id == user_id && reaction.emoji == emoji))
+    }
+}
+
+// Maps `input` to the picker emoji it stands for. Variation selectors are ignored, so a
+// plain "❤" matches "❤️".
+fn reaction_emoji(input: &str) -> Option<&'static str> {
+    let strip = |emoji: &str| emoji.trim().replace('\u{FE0F}', "");
+    let input = strip(input);
+    REACTION_EMOJIS
+        .into_iter()
+        .find(|emoji| strip(emoji) == input)
+}
+
+fn add_reaction_counts(
+    comments: &mut [IssueCommentSummary],
+    reactions: Vec<IssueCommentReaction>,
+    current_user_id: Option<Uuid>,
+) {
+    let mut by_comment = HashMap::<String, Vec<IssueCommentReaction>>::new();
+    for reaction in reactions {
+        by_comment
+            .entry(reaction.comment_id.to_string())
+            .or_default()
+            .push(reaction);
+    }
+
+    for comment in comments {
+        for reaction in by_comment.remove(&comment.id).unwrap_or_default() {
+            if Some(reaction.user_id) == current_user_id {
+                comment.my_reactions.push(reaction.emoji.clone());
+            }
+            *comment.reactions.entry(reaction.emoji).or_default() += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, Router, response::IntoResponse, routing::get};
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope};
+
+    fn reaction_json(id: Uuid, comment_id: Uuid, user_id: Uuid, emoji: &str) -> Value {
+        json!({
+            "id": id,
+            "comment_id": comment_id,
+            "user_id": user_id,
+            "emoji": emoji,
+            "created_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    fn structured(result: &CallToolResult) -> Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[test]
+    fn reaction_emoji_ignores_variation_selectors() {
+        assert_eq!(reaction_emoji("❤"), Some("❤️"));
+        assert_eq!(reaction_emoji(" 👍 "), Some("👍"));
+        assert_eq!(reaction_emoji(":thumbsup:"), None);
+    }
+
+    #[tokio::test]
+    async fn list_issue_comments_counts_reactions_per_comment() {
+        let issue_id = Uuid::new_v4();
+        let comment_id = Uuid::new_v4();
+        let me = Uuid::new_v4();
+        let reactions = vec![
+            reaction_json(Uuid::new_v4(), comment_id, me, "👍"),
+            reaction_json(Uuid::new_v4(), comment_id, Uuid::new_v4(), "👍"),
+            reaction_json(Uuid::new_v4(), comment_id, Uuid::new_v4(), "🎉"),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issue-comments",
+                get(move || async move {
+                    envelope(json!({ "issue_comments": [{
+                        "id": comment_id,
+                        "issue_id": issue_id,
+                        "author_id": null,
+                        "parent_id": null,
+                        "message": "Looks good",
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-comment-reactions",
+                get(move || {
+                    let reactions = reactions.clone();
+                    async move { envelope(json!({ "issue_comment_reactions": reactions })) }
+                }),
+            )
+            .route(
+                "/api/auth/user",
+                get(move || async move { envelope(json!({ "user_id": me.to_string() })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "issue_id": issue_id })).unwrap();
+        let result = backend
+            .server()
+            .list_issue_comments(Parameters(request))
+            .await
+            .unwrap();
+
+        let comment = &structured(&result)["comments"][0];
+        assert_eq!(comment["reactions"], json!({ "👍": 2, "🎉": 1 }));
+        assert_eq!(comment["my_reactions"], json!(["👍"]));
+    }
+
+    #[tokio::test]
+    async fn add_comment_reaction_treats_duplicate_reaction_as_success() {
+        let comment_id = Uuid::new_v4();
+        let me = Uuid::new_v4();
+        let reaction_id = Uuid::new_v4();
+        let reactions = vec![
+            reaction_json(Uuid::new_v4(), comment_id, Uuid::new_v4(), "❤️"),
+            reaction_json(reaction_id, comment_id, me, "❤️"),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issue-comment-reactions",
+                get(move || {
+                    let reactions = reactions.clone();
+                    async move { envelope(json!({ "issue_comment_reactions": reactions })) }
+                })
+                .post(|| async {
+                    (
+                        StatusCode::CONFLICT,
+                        Json(json!({ "success": false, "message": "resource already exists" })),
+                    )
+                        .into_response()
+                }),
+            )
+            .route(
+                "/api/auth/user",
+                get(move || async move { envelope(json!({ "user_id": me.to_string() })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "comment_id": comment_id, "emoji": "❤" })).unwrap();
+        let result = backend
+            .server()
+            .add_comment_reaction(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body = structured(&result);
+        assert_eq!(body["reaction_id"], reaction_id.to_string());
+        assert_eq!(body["created"], false);
+    }
+
+    #[tokio::test]
+    async fn list_issue_comments_pages_oldest_first() {
+        let issue_id = Uuid::new_v4();
+        let comments = ["2026-01-03", "2026-01-01", "2026-01-02"]
+            .map(|day| {
+                json!({
+                    "id": Uuid::new_v4(),
+                    "issue_id": issue_id,
+                    "author_id": null,
+                    "parent_id": null,
+                    "message": format!("Written {day}"),
+                    "created_at": format!("{day}T00:00:00Z"),
+                    "updated_at": format!("{day}T00:00:00Z"),
+                })
+            })
+            .to_vec();
+        let router = Router::new()
+            .route(
+                "/api/remote/issue-comments",
+                get(move || {
+                    let comments = comments.clone();
+                    async move { envelope(json!({ "issue_comments": comments })) }
+                }),
+            )
+            .route(
+                "/api/remote/issue-comment-reactions",
+                get(|| async { envelope(json!({ "issue_comment_reactions": [] })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "limit": 2,
+            "offset": 1,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_issue_comments(Parameters(request))
+            .await
+            .unwrap();
+
+        let body = structured(&result);
+        assert_eq!(body["total_count"], 3);
+        assert_eq!(body["count"], 2);
+        assert_eq!(body["comments"][0]["body"], "Written 2026-01-02");
+        assert_eq!(body["comments"][1]["body"], "Written 2026-01-03");
+        assert_eq!(
+            body["comments"][1]["updated_at"],
+            "2026-01-03T00:00:00+00:00"
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use api_types::{
+    CreateIssueFollowerRequest, IssueFollower, ListIssueFollowersResponse, MutationResponse,
+};
+use reqwest::StatusCode;
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{McpServer, ToolError, output_schema};
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListIssueFollowersRequest {
+    #[schemars(description = "Issue ID to list followers for")]
+    issue_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct IssueFollowerSummary {
+    #[schemars(description = "Issue follower ID")]
+    id: String,
+    #[schemars(description = "Issue ID")]
+    issue_id: String,
+    #[schemars(description = "User ID")]
+    user_id: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListIssueFollowersResponse {
+    issue_id: String,
+    issue_followers: Vec<IssueFollowerSummary>,
+    count: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpFollowIssueRequest {
+    #[schemars(description = "Issue ID to follow")]
+    issue_id: Uuid,
+    #[schemars(
+        description = "User ID that should follow the issue. Defaults to the current user."
+    )]
+    user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpFollowIssueResponse {
+    issue_follower_id: String,
+    issue_id: String,
+    user_id: String,
+    #[schemars(description = "True if the user was already following the issue")]
+    already_following: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpUnfollowIssueRequest {
+    #[schemars(description = "Issue follower ID to remove. Alternatively pass issue_id.")]
+    issue_follower_id: Option<Uuid>,
+    #[schemars(
+        description = "Issue ID to stop following. Used when issue_follower_id is omitted."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "User ID that should stop following issue_id. Defaults to the current user."
+    )]
+    user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpUnfollowIssueResponse {
+    success: bool,
+    #[schemars(description = "Removed issue follower ID, if the user was following the issue")]
+    issue_follower_id: Option<String>,
+}
+
+#[tool_router(router = issue_followers_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "List followers for an issue.",
+        output_schema = output_schema::<McpListIssueFollowersResponse>()
+    )]
+    async fn list_issue_followers(
+        &self,
+        Parameters(McpListIssueFollowersRequest { issue_id }): Parameters<
+            McpListIssueFollowersRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let followers = match self.fetch_issue_followers(issue_id).await {
+            Ok(followers) => followers,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let followers = followers
+            .into_iter()
+            .map(|follower| IssueFollowerSummary {
+                id: follower.id.to_string(),
+                issue_id: follower.issue_id.to_string(),
+                user_id: follower.user_id.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        self.success(&McpListIssueFollowersResponse {
+            issue_id: issue_id.to_string(),
+            count: followers.len(),
+            issue_followers: followers,
+        })
+    }
+
+    #[tool(
+        description = "Follow an issue to receive notifications about it. `user_id` defaults to the current user. Following an issue twice is not an error.",
+        output_schema = output_schema::<McpFollowIssueResponse>()
+    )]
+    async fn follow_issue(
+        &self,
+        Parameters(McpFollowIssueRequest { issue_id, user_id }): Parameters<McpFollowIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let user_id = match user_id {
+            Some(id) => id,
+            None => match self.current_user_id().await {
+                Ok(id) => id,
+                Err(e) => return Ok(Self::tool_error(e)),
+            },
+        };
+
+        let payload = CreateIssueFollowerRequest {
+            id: None,
+            issue_id,
+            user_id,
+        };
+        let url = self.url("/api/remote/issue-followers");
+        let (follower, already_following) = match self
+            .send_json::<MutationResponse<IssueFollower>>(self.client.post(&url).json(&payload))
+            .await
+        {
+            Ok(response) => (response.data, false),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                match self.find_issue_follower(issue_id, user_id).await {
+                    Ok(Some(follower)) => (follower, true),
+                    Ok(None) => return Ok(Self::tool_error(e)),
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpFollowIssueResponse {
+            issue_follower_id: follower.id.to_string(),
+            issue_id: follower.issue_id.to_string(),
+            user_id: follower.user_id.to_string(),
+            already_following,
+        })
+    }
+
+    #[tool(
+        description = "Stop following an issue. Pass `issue_follower_id`, or `issue_id` with an optional `user_id` (defaults to the current user). Unfollowing an issue that is not followed is not an error.",
+        output_schema = output_schema::<McpUnfollowIssueResponse>()
+    )]
+    async fn unfollow_issue(
+        &self,
+        Parameters(McpUnfollowIssueRequest {
+            issue_follower_id,
+            issue_id,
+            user_id,
+        }): Parameters<McpUnfollowIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let issue_follower_id = match (issue_follower_id, issue_id) {
+            (Some(id), _) => Some(id),
+            (None, Some(issue_id)) => {
+                let user_id = match user_id {
+                    Some(id) => id,
+                    None => match self.current_user_id().await {
+                        Ok(id) => id,
+                        Err(e) => return Ok(Self::tool_error(e)),
+                    },
+                };
+                match self.find_issue_follower(issue_id, user_id).await {
+                    Ok(follower) => follower.map(|follower| follower.id),
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            (None, None) => {
+                return Self::err("Either issue_follower_id or issue_id is required", None);
+            }
+        };
+
+        if let Some(id) = issue_follower_id {
+            let url = self.url(&format!("/api/remote/issue-followers/{}", id));
+            if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+                return Ok(Self::tool_error(e));
+            }
+        }
+
+        self.success(&McpUnfollowIssueResponse {
+            success: true,
+            issue_follower_id: issue_follower_id.map(|id| id.to_string()),
+        })
+    }
+}
+
+impl McpServer {
+    async fn fetch_issue_followers(&self, issue_id: Uuid) -> Result<Vec<IssueFollower>, ToolError> {
+        let url = self.url(&format!(
+            "/api/remote/issue-followers?issue_id={}",
+            issue_id
+        ));
+        let response: ListIssueFollowersResponse = self.send_json(self.client.get(&url)).await?;
+        Ok(response.issue_followers)
+    }
+
+    async fn find_issue_follower(
+        &self,
+        issue_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<IssueFollower>, ToolError> {
+        Ok(self
+            .fetch_issue_followers(issue_id)
+            .await?
+            .into_iter()
+            .find(|follower| follower.user_id == user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, Router, response::IntoResponse, routing::get};
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope};
+
+    #[tokio::test]
+    async fn follow_issue_treats_duplicate_follow_as_success() {
+        let issue_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let follower_id = Uuid::new_v4();
+
+        let router = Router::new().route(
+            "/api/remote/issue-followers",
+            get(move || async move {
+                envelope(json!({
+                    "issue_followers": [
+                        { "id": follower_id, "issue_id": issue_id, "user_id": user_id },
+                    ],
+                }))
+            })
+            .post(|| async {
+                (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "success": false, "message": "resource already exists" })),
+                )
+                    .into_response()
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "issue_id": issue_id, "user_id": user_id })).unwrap();
+        let result = backend
+            .server()
+            .follow_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue_follower_id"], follower_id.to_string());
+        assert_eq!(body["already_following"], true);
+    }
+}
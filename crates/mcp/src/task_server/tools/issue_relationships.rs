@@ -1,14 +1,136 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
 use api_types::{
-    CreateIssueRelationshipRequest, IssueRelationship, IssueRelationshipType, MutationResponse,
+    CreateIssueRelationshipRequest, IssueRelationship, IssueRelationshipType,
+    ListIssueRelationshipsResponse, MutationResponse,
 };
+use futures::{StreamExt, stream};
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, output_schema};
+use crate::task_server::name_matching;
+
+// Upper bound on concurrent issue lookups when labelling the listed relationships.
+const ISSUE_LOOKUP_CONCURRENCY: usize = 8;
+
+const RELATIONSHIP_TYPE_VALUES: [&str; 5] = [
+    "blocking",
+    "blocked_by",
+    "related",
+    "has_duplicate",
+    "duplicate_of",
+];
+
+/// A relationship type in tool parameters. `blocked_by` and `duplicate_of` are stored as
+/// `blocking` and `has_duplicate` with the two issues swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct McpRelationshipType {
+    relationship_type: IssueRelationshipType,
+    reversed: bool,
+}
+
+impl FromStr for McpRelationshipType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (relationship_type, reversed) = match name_matching::normalize_name(value).as_str() {
+            "blocking" | "blocks" => (IssueRelationshipType::Blocking, false),
+            "blockedby" => (IssueRelationshipType::Blocking, true),
+            "related" | "relatesto" => (IssueRelationshipType::Related, false),
+            "hasduplicate" => (IssueRelationshipType::HasDuplicate, false),
+            "duplicateof" => (IssueRelationshipType::HasDuplicate, true),
+            _ => {
+                return Err(format!(
+                    "Unknown relationship type '{}'.{} Allowed values: {:?}",
+                    value,
+                    name_matching::did_you_mean(value, RELATIONSHIP_TYPE_VALUES),
+                    RELATIONSHIP_TYPE_VALUES
+                ));
+            }
+        };
+        Ok(Self {
+            relationship_type,
+            reversed,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for McpRelationshipType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl schemars::JsonSchema for McpRelationshipType {
+    fn schema_name() -> Cow<'static, str> {
+        "IssueRelationshipType".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string", "enum": RELATIONSHIP_TYPE_VALUES })
+    }
+}
+
+/// "VK-12 blocks VK-47", reading the relationship from `issue` to `related`.
+fn describe_relationship(
+    relationship_type: IssueRelationshipType,
+    issue: &str,
+    related: &str,
+) -> String {
+    match relationship_type {
+        IssueRelationshipType::Blocking => format!("{issue} blocks {related}"),
+        IssueRelationshipType::Related => format!("{issue} is related to {related}"),
+        IssueRelationshipType::HasDuplicate => format!("{issue} has duplicate {related}"),
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListIssueRelationshipsRequest {
+    #[schemars(description = "Issue ID to list relationships for")]
+    issue_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct IssueRelationshipSummary {
+    #[schemars(description = "The relationship ID (use this to delete)")]
+    id: String,
+    #[schemars(description = "The other issue in the relationship")]
+    related_issue_id: String,
+    #[schemars(description = "Relationship type: 'blocking', 'related', or 'has_duplicate'")]
+    relationship_type: IssueRelationshipType,
+    #[schemars(
+        description = "'outgoing' if the listed issue is the source (e.g. it blocks the related issue), 'incoming' if it is the target (e.g. it is blocked by the related issue)"
+    )]
+    direction: &'static str,
+    #[schemars(description = "The relationship in words, e.g. 'VK-12 blocks VK-47'")]
+    description: String,
+    #[schemars(
+        description = "The related issue's simple ID (e.g. 'PROJ-42'), if it could be resolved"
+    )]
+    related_simple_id: Option<String>,
+    #[schemars(description = "The related issue's title, if it could be resolved")]
+    related_title: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListIssueRelationshipsResponse {
+    issue_id: String,
+    #[schemars(description = "The listed issue's simple ID, if it could be resolved")]
+    simple_id: Option<String>,
+    relationships: Vec<IssueRelationshipSummary>,
+    count: usize,
+}
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpCreateIssueRelationshipRequest {
@@ -16,13 +138,17 @@ struct McpCreateIssueRelationshipRequest {
     issue_id: Uuid,
     #[schemars(description = "The related issue ID")]
     related_issue_id: Uuid,
-    #[schemars(description = "Relationship type: 'blocking', 'related', or 'has_duplicate'")]
-    relationship_type: IssueRelationshipType,
+    #[schemars(
+        description = "How issue_id relates to related_issue_id: 'blocking', 'blocked_by', 'related', 'has_duplicate', or 'duplicate_of'"
+    )]
+    relationship_type: McpRelationshipType,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpCreateIssueRelationshipResponse {
     relationship_id: String,
+    #[schemars(description = "The relationship in words, e.g. 'VK-12 blocks VK-47'")]
+    description: String,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -42,7 +168,82 @@ struct McpDeleteIssueRelationshipResponse {
 #[tool_router(router = issue_relationships_tools_router, vis = "pub")]
 impl McpServer {
     #[tool(
-        description = "Create a relationship between two issues. Types: 'blocking', 'related', 'has_duplicate'."
+        description = "List relationships for an issue, including the related issue's simple ID and title.",
+        output_schema = output_schema::<McpListIssueRelationshipsResponse>()
+    )]
+    async fn list_issue_relationships(
+        &self,
+        Parameters(McpListIssueRelationshipsRequest { issue_id }): Parameters<
+            McpListIssueRelationshipsRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!(
+            "/api/remote/issue-relationships?issue_id={}",
+            issue_id
+        ));
+        let response: ListIssueRelationshipsResponse =
+            match self.send_json(self.client.get(&url)).await {
+                Ok(r) => r,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        let other_issue_id = |relationship: &IssueRelationship| {
+            if relationship.issue_id == issue_id {
+                relationship.related_issue_id
+            } else {
+                relationship.issue_id
+            }
+        };
+
+        // An issue can appear in several relationships, so fetch each one only once.
+        let issue_ids = std::iter::once(issue_id)
+            .chain(response.issue_relationships.iter().map(other_issue_id))
+            .collect::<HashSet<_>>();
+        let issues = stream::iter(issue_ids)
+            .map(|id| async move { (id, self.fetch_issue(id).await.ok()) })
+            .buffer_unordered(ISSUE_LOOKUP_CONCURRENCY)
+            .filter_map(|(id, issue)| async move { issue.map(|issue| (id, issue)) })
+            .collect::<HashMap<_, _>>()
+            .await;
+
+        let simple_id = issues.get(&issue_id).map(|i| i.simple_id.clone());
+        let listed_label = simple_id.clone().unwrap_or_else(|| issue_id.to_string());
+
+        let mut relationships = Vec::with_capacity(response.issue_relationships.len());
+        for relationship in response.issue_relationships {
+            let outgoing = relationship.issue_id == issue_id;
+            let other_id = other_issue_id(&relationship);
+            let related_issue = issues.get(&other_id);
+            let other_label = related_issue
+                .map(|i| i.simple_id.clone())
+                .unwrap_or_else(|| other_id.to_string());
+            let description = if outgoing {
+                describe_relationship(relationship.relationship_type, &listed_label, &other_label)
+            } else {
+                describe_relationship(relationship.relationship_type, &other_label, &listed_label)
+            };
+            relationships.push(IssueRelationshipSummary {
+                id: relationship.id.to_string(),
+                related_issue_id: other_id.to_string(),
+                relationship_type: relationship.relationship_type,
+                direction: if outgoing { "outgoing" } else { "incoming" },
+                description,
+                related_simple_id: related_issue.map(|i| i.simple_id.clone()),
+                related_title: related_issue.map(|i| i.title.clone()),
+            });
+        }
+
+        self.success(&McpListIssueRelationshipsResponse {
+            issue_id: issue_id.to_string(),
+            simple_id,
+            count: relationships.len(),
+            relationships,
+        })
+    }
+
+    #[tool(
+        description = "Create a relationship between two issues, read as '<issue_id> <relationship_type> <related_issue_id>'. Types: 'blocking', 'blocked_by', 'related', 'has_duplicate', 'duplicate_of'.",
+        output_schema = output_schema::<McpCreateIssueRelationshipResponse>()
     )]
     async fn create_issue_relationship(
         &self,
@@ -52,11 +253,41 @@ impl McpServer {
             relationship_type,
         }): Parameters<McpCreateIssueRelationshipRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        if issue_id == related_issue_id {
+            return Self::err("An issue cannot be related to itself", None);
+        }
+
+        let issue = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let related_issue = match self.fetch_issue(related_issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if issue.project_id != related_issue.project_id {
+            return Self::err(
+                "Both issues must belong to the same project".to_string(),
+                Some(format!(
+                    "{} is in project {}, {} is in project {}",
+                    issue.simple_id,
+                    issue.project_id,
+                    related_issue.simple_id,
+                    related_issue.project_id
+                )),
+            );
+        }
+
+        let (source, target) = if relationship_type.reversed {
+            (related_issue, issue)
+        } else {
+            (issue, related_issue)
+        };
         let payload = CreateIssueRelationshipRequest {
             id: None,
-            issue_id,
-            related_issue_id,
-            relationship_type,
+            issue_id: source.id,
+            related_issue_id: target.id,
+            relationship_type: relationship_type.relationship_type,
         };
 
         let url = self.url("/api/remote/issue-relationships");
@@ -66,12 +297,20 @@ impl McpServer {
                 Err(e) => return Ok(Self::tool_error(e)),
             };
 
-        McpServer::success(&McpCreateIssueRelationshipResponse {
+        self.success(&McpCreateIssueRelationshipResponse {
             relationship_id: response.data.id.to_string(),
+            description: describe_relationship(
+                relationship_type.relationship_type,
+                &source.simple_id,
+                &target.simple_id,
+            ),
         })
     }
 
-    #[tool(description = "Delete a relationship between two issues.")]
+    #[tool(
+        description = "Delete a relationship between two issues.",
+        output_schema = output_schema::<McpDeleteIssueRelationshipResponse>()
+    )]
     async fn delete_issue_relationship(
         &self,
         Parameters(McpDeleteIssueRelationshipRequest { relationship_id }): Parameters<
@@ -86,9 +325,122 @@ impl McpServer {
             return Ok(Self::tool_error(e));
         }
 
-        McpServer::success(&McpDeleteIssueRelationshipResponse {
+        self.success(&McpDeleteIssueRelationshipResponse {
             success: true,
             deleted_relationship_id: relationship_id.to_string(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, extract::Path, routing::get};
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, issue_json};
+
+    #[test]
+    fn relationship_types_accept_reversed_spellings() {
+        let blocked_by: McpRelationshipType = "Blocked by".parse().unwrap();
+        assert_eq!(
+            blocked_by.relationship_type,
+            IssueRelationshipType::Blocking
+        );
+        assert!(blocked_by.reversed);
+        let blocks: McpRelationshipType = "blocks".parse().unwrap();
+        assert!(!blocks.reversed);
+        let duplicate_of: McpRelationshipType = "duplicate_of".parse().unwrap();
+        assert_eq!(
+            duplicate_of.relationship_type,
+            IssueRelationshipType::HasDuplicate
+        );
+        assert!(duplicate_of.reversed);
+
+        let error = "blokcing".parse::<McpRelationshipType>().unwrap_err();
+        assert!(error.contains("Did you mean 'blocking'?"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn list_issue_relationships_describes_both_directions() {
+        let project_id = Uuid::new_v4();
+        let (vk12, vk47, vk50) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let numbers = HashMap::from([(vk12, 12), (vk47, 47), (vk50, 50)]);
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/remote/issue-relationships",
+                    get(move || async move {
+                        envelope(json!({ "issue_relationships": [
+                            {
+                                "id": Uuid::new_v4(),
+                                "issue_id": vk12,
+                                "related_issue_id": vk47,
+                                "relationship_type": "blocking",
+                                "created_at": "2026-01-01T00:00:00Z",
+                            },
+                            {
+                                "id": Uuid::new_v4(),
+                                "issue_id": vk50,
+                                "related_issue_id": vk12,
+                                "relationship_type": "blocking",
+                                "created_at": "2026-01-01T00:00:00Z",
+                            },
+                        ] }))
+                    }),
+                )
+                .route(
+                    "/api/remote/issues/{issue_id}",
+                    get(move |Path(issue_id): Path<Uuid>| async move {
+                        let mut issue = issue_json(project_id, Uuid::new_v4(), numbers[&issue_id]);
+                        issue["id"] = json!(issue_id);
+                        envelope(issue)
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({ "issue_id": vk12 })).unwrap();
+        let result = server
+            .list_issue_relationships(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["simple_id"], "VK-12");
+        let relationships = body["relationships"].as_array().unwrap();
+        assert_eq!(relationships[0]["direction"], "outgoing");
+        assert_eq!(relationships[0]["description"], "VK-12 blocks VK-47");
+        assert_eq!(relationships[0]["related_simple_id"], "VK-47");
+        assert_eq!(relationships[1]["direction"], "incoming");
+        assert_eq!(relationships[1]["description"], "VK-50 blocks VK-12");
+        assert_eq!(relationships[1]["related_issue_id"], vk50.to_string());
+    }
+
+    #[tokio::test]
+    async fn create_issue_relationship_rejects_self_references_without_a_request() {
+        let backend = MockBackend::start(Router::new()).await;
+        let server = backend.server();
+        let issue_id = Uuid::new_v4();
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "related_issue_id": issue_id,
+            "relationship_type": "blocking",
+        }))
+        .unwrap();
+        let result = server
+            .create_issue_relationship(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issues/{issue_id}")),
+            0
+        );
+    }
+}
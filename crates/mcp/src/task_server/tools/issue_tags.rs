@@ -1,5 +1,6 @@
 use api_types::{
-    CreateIssueTagRequest, IssueTag, ListIssueTagsResponse, ListTagsResponse, MutationResponse,
+    CreateTagRequest, ListIssueTagsResponse, ListTagsResponse, MutationResponse, Tag,
+    UpdateTagRequest,
 };
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
@@ -8,7 +9,10 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, ToolError, name_matching, output_schema};
+
+// Neutral grey for tags created by `add_issue_tag` without an explicit color.
+const DEFAULT_TAG_COLOR: &str = "220 9% 46%";
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpListTagsRequest {
@@ -26,10 +30,21 @@ struct TagSummary {
     project_id: String,
     #[schemars(description = "Tag name")]
     name: String,
-    #[schemars(description = "Tag color value")]
+    #[schemars(description = "Tag color in HSL format, e.g. '355 65% 53%'")]
     color: String,
 }
 
+impl From<Tag> for TagSummary {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id.to_string(),
+            project_id: tag.project_id.to_string(),
+            name: tag.name,
+            color: tag.color,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpListTagsResponse {
     project_id: String,
@@ -37,6 +52,47 @@ struct McpListTagsResponse {
     count: usize,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpCreateTagRequest {
+    #[schemars(
+        description = "The project to create the tag in. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(description = "Tag name. Must not match an existing tag in the project.")]
+    name: String,
+    #[schemars(description = "Hex color such as '#d73a4a', or HSL such as '355 65% 53%'")]
+    color: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpUpdateTagRequest {
+    #[schemars(description = "The tag to update")]
+    tag_id: Uuid,
+    #[schemars(description = "New tag name")]
+    name: Option<String>,
+    #[schemars(description = "New hex color such as '#d73a4a', or HSL such as '355 65% 53%'")]
+    color: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpTagResponse {
+    tag: TagSummary,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpDeleteTagRequest {
+    #[schemars(
+        description = "The tag to delete. It is removed from every issue it is attached to."
+    )]
+    tag_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpDeleteTagResponse {
+    success: bool,
+    deleted_tag_id: String,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpListIssueTagsRequest {
     #[schemars(description = "Issue ID to list tags for")]
@@ -64,19 +120,45 @@ struct McpListIssueTagsResponse {
 struct McpAddIssueTagRequest {
     #[schemars(description = "Issue ID to attach the tag to")]
     issue_id: Uuid,
-    #[schemars(description = "Tag ID to attach")]
-    tag_id: Uuid,
+    #[schemars(description = "Tag ID to attach. Pass this or `tag_name`.")]
+    tag_id: Option<Uuid>,
+    #[schemars(description = "Name of the project tag to attach (case-insensitive)")]
+    tag_name: Option<String>,
+    #[schemars(
+        description = "Create the tag in the issue's project when no tag is named `tag_name`. Default: false."
+    )]
+    #[serde(default)]
+    create_if_missing: bool,
+    #[schemars(
+        description = "Color for a tag created by `create_if_missing`, as hex ('#d73a4a') or HSL ('355 65% 53%'). Defaults to grey."
+    )]
+    color: Option<String>,
+    #[schemars(
+        description = "Optional caller-chosen UUID for the new issue-tag relation. Reuse it when retrying so the retry can't attach the tag twice."
+    )]
+    client_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpAddIssueTagResponse {
     issue_tag_id: String,
+    tag_id: String,
+    #[schemars(description = "False if the tag was already attached to the issue")]
+    created: bool,
+    #[schemars(description = "True if the tag itself was created by `create_if_missing`")]
+    tag_created: bool,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpRemoveIssueTagRequest {
-    #[schemars(description = "Issue-tag relation ID to remove")]
-    issue_tag_id: Uuid,
+    #[schemars(
+        description = "Issue-tag relation ID to remove. Alternatively pass `issue_id` and `tag_name`."
+    )]
+    issue_tag_id: Option<Uuid>,
+    #[schemars(description = "Issue to remove the tag from")]
+    issue_id: Option<Uuid>,
+    #[schemars(description = "Name of the tag to remove from the issue (case-insensitive)")]
+    tag_name: Option<String>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -88,13 +170,14 @@ struct McpRemoveIssueTagResponse {
 #[tool_router(router = issue_tags_tools_router, vis = "pub")]
 impl McpServer {
     #[tool(
-        description = "List tags for a project. `project_id` is optional if running inside a workspace linked to a remote project."
+        description = "List tags for a project. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpListTagsResponse>()
     )]
     async fn list_tags(
         &self,
         Parameters(McpListTagsRequest { project_id }): Parameters<McpListTagsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let project_id = match self.resolve_project_id(project_id) {
+        let project_id = match self.resolve_project_id(project_id).await {
             Ok(id) => id,
             Err(e) => return Ok(Self::tool_error(e)),
         };
@@ -108,22 +191,139 @@ impl McpServer {
         let tags = response
             .tags
             .into_iter()
-            .map(|tag| TagSummary {
-                id: tag.id.to_string(),
-                project_id: tag.project_id.to_string(),
-                name: tag.name,
-                color: tag.color,
-            })
+            .map(TagSummary::from)
             .collect::<Vec<_>>();
 
-        McpServer::success(&McpListTagsResponse {
+        self.success(&McpListTagsResponse {
             project_id: project_id.to_string(),
             count: tags.len(),
             tags,
         })
     }
 
-    #[tool(description = "List tags attached to an issue.")]
+    #[tool(
+        description = "Create a tag in a project so it can be attached to issues. `project_id` is optional if running inside a workspace linked to a remote project. `color` accepts hex ('#d73a4a') or HSL ('355 65% 53%').",
+        output_schema = output_schema::<McpTagResponse>()
+    )]
+    async fn create_tag(
+        &self,
+        Parameters(McpCreateTagRequest {
+            project_id,
+            name,
+            color,
+        }): Parameters<McpCreateTagRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let name = match Self::parse_tag_name(&name) {
+            Ok(name) => name,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let color = match parse_color(&color) {
+            Ok(color) => color,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if let Err(e) = self
+            .ensure_tag_name_available(project_id, &name, None)
+            .await
+        {
+            return Ok(Self::tool_error(e));
+        }
+
+        let payload = CreateTagRequest {
+            id: None,
+            project_id,
+            name,
+            color,
+        };
+        let url = self.url("/api/remote/tags");
+        let response: MutationResponse<Tag> =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(response) => response,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpTagResponse {
+            tag: response.data.into(),
+        })
+    }
+
+    #[tool(
+        description = "Rename or recolor a tag. Omitted fields are left unchanged. `color` accepts hex ('#d73a4a') or HSL ('355 65% 53%').",
+        output_schema = output_schema::<McpTagResponse>()
+    )]
+    async fn update_tag(
+        &self,
+        Parameters(McpUpdateTagRequest {
+            tag_id,
+            name,
+            color,
+        }): Parameters<McpUpdateTagRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        if name.is_none() && color.is_none() {
+            return Self::err("Provide `name`, `color`, or both", None);
+        }
+        let name = match name.as_deref().map(Self::parse_tag_name).transpose() {
+            Ok(name) => name,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let color = match color.as_deref().map(parse_color).transpose() {
+            Ok(color) => color,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        if let Some(name) = &name {
+            let url = self.url(&format!("/api/remote/tags/{}", tag_id));
+            let tag: Tag = match self.send_json(self.client.get(&url)).await {
+                Ok(tag) => tag,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+            if let Err(e) = self
+                .ensure_tag_name_available(tag.project_id, name, Some(tag_id))
+                .await
+            {
+                return Ok(Self::tool_error(e));
+            }
+        }
+
+        let url = self.url(&format!("/api/remote/tags/{}", tag_id));
+        let payload = UpdateTagRequest { name, color };
+        let response: MutationResponse<Tag> =
+            match self.send_json(self.client.patch(&url).json(&payload)).await {
+                Ok(response) => response,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpTagResponse {
+            tag: response.data.into(),
+        })
+    }
+
+    #[tool(
+        description = "Delete a tag from its project. It is detached from every issue that has it.",
+        output_schema = output_schema::<McpDeleteTagResponse>()
+    )]
+    async fn delete_tag(
+        &self,
+        Parameters(McpDeleteTagRequest { tag_id }): Parameters<McpDeleteTagRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/remote/tags/{}", tag_id));
+        if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+            return Ok(Self::tool_error(e));
+        }
+
+        self.success(&McpDeleteTagResponse {
+            success: true,
+            deleted_tag_id: tag_id.to_string(),
+        })
+    }
+
+    #[tool(
+        description = "List tags attached to an issue.",
+        output_schema = output_schema::<McpListIssueTagsResponse>()
+    )]
     async fn list_issue_tags(
         &self,
         Parameters(McpListIssueTagsRequest { issue_id }): Parameters<McpListIssueTagsRequest>,
@@ -144,49 +344,511 @@ impl McpServer {
             })
             .collect::<Vec<_>>();
 
-        McpServer::success(&McpListIssueTagsResponse {
+        self.success(&McpListIssueTagsResponse {
             issue_id: issue_id.to_string(),
             count: issue_tags.len(),
             issue_tags,
         })
     }
 
-    #[tool(description = "Attach a tag to an issue.")]
+    #[tool(
+        description = "Attach a tag to an issue by `tag_id` or `tag_name`. With `create_if_missing`, a tag name that doesn't exist in the issue's project is created first. Attaching a tag that is already attached returns the existing relation with `created: false`.",
+        output_schema = output_schema::<McpAddIssueTagResponse>()
+    )]
     async fn add_issue_tag(
         &self,
-        Parameters(McpAddIssueTagRequest { issue_id, tag_id }): Parameters<McpAddIssueTagRequest>,
-    ) -> Result<CallToolResult, ErrorData> {
-        let payload = CreateIssueTagRequest {
-            id: None,
+        Parameters(McpAddIssueTagRequest {
             issue_id,
             tag_id,
+            tag_name,
+            create_if_missing,
+            color,
+            client_id,
+        }): Parameters<McpAddIssueTagRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let (tag_id, tag_created) = match (tag_id, tag_name) {
+            (Some(tag_id), None) => (tag_id, false),
+            (None, Some(tag_name)) => {
+                let issue = match self.fetch_issue(issue_id).await {
+                    Ok(issue) => issue,
+                    Err(e) => return Ok(Self::tool_error(e)),
+                };
+                match self
+                    .resolve_tag_by_name(
+                        issue.project_id,
+                        &tag_name,
+                        create_if_missing,
+                        color.as_deref(),
+                    )
+                    .await
+                {
+                    Ok(result) => result,
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            _ => return Self::err("Pass exactly one of `tag_id` or `tag_name`", None),
         };
 
-        let url = self.url("/api/remote/issue-tags");
-        let response: MutationResponse<IssueTag> =
-            match self.send_json(self.client.post(&url).json(&payload)).await {
-                Ok(r) => r,
-                Err(e) => return Ok(Self::tool_error(e)),
-            };
+        let (issue_tag, created) = match self.attach_issue_tag(issue_id, tag_id, client_id).await {
+            Ok(result) => result,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
 
-        McpServer::success(&McpAddIssueTagResponse {
-            issue_tag_id: response.data.id.to_string(),
+        self.success(&McpAddIssueTagResponse {
+            issue_tag_id: issue_tag.id.to_string(),
+            tag_id: tag_id.to_string(),
+            created,
+            tag_created,
         })
     }
 
-    #[tool(description = "Remove a tag from an issue using issue_tag_id.")]
+    #[tool(
+        description = "Remove a tag from an issue, either by issue_tag_id or by `issue_id` and `tag_name`.",
+        output_schema = output_schema::<McpRemoveIssueTagResponse>()
+    )]
     async fn remove_issue_tag(
         &self,
-        Parameters(McpRemoveIssueTagRequest { issue_tag_id }): Parameters<McpRemoveIssueTagRequest>,
+        Parameters(McpRemoveIssueTagRequest {
+            issue_tag_id,
+            issue_id,
+            tag_name,
+        }): Parameters<McpRemoveIssueTagRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let issue_tag_id = match (issue_tag_id, issue_id, tag_name) {
+            (Some(issue_tag_id), None, None) => issue_tag_id,
+            (None, Some(issue_id), Some(tag_name)) => {
+                match self.find_issue_tag_by_name(issue_id, &tag_name).await {
+                    Ok(issue_tag_id) => issue_tag_id,
+                    Err(e) => return Ok(Self::tool_error(e)),
+                }
+            }
+            _ => {
+                return Self::err(
+                    "Pass either `issue_tag_id`, or `issue_id` together with `tag_name`",
+                    None,
+                );
+            }
+        };
+
         let url = self.url(&format!("/api/remote/issue-tags/{}", issue_tag_id));
         if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
             return Ok(Self::tool_error(e));
         }
 
-        McpServer::success(&McpRemoveIssueTagResponse {
+        self.success(&McpRemoveIssueTagResponse {
             success: true,
             issue_tag_id: issue_tag_id.to_string(),
         })
     }
 }
+
+impl McpServer {
+    fn parse_tag_name(name: &str) -> Result<String, ToolError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ToolError::message("Tag name must not be empty"));
+        }
+        Ok(name.to_string())
+    }
+
+    // The remote API doesn't enforce unique tag names, but duplicates make tags impossible
+    // to pick by name, so they are refused here.
+    async fn ensure_tag_name_available(
+        &self,
+        project_id: Uuid,
+        name: &str,
+        renamed_tag_id: Option<Uuid>,
+    ) -> Result<(), ToolError> {
+        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let response: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
+        match response.tags.iter().find(|tag| {
+            Some(tag.id) != renamed_tag_id && name_matching::names_match(&tag.name, name)
+        }) {
+            Some(existing) => Err(ToolError::new(
+                format!(
+                    "A tag named '{}' already exists in this project",
+                    existing.name
+                ),
+                Some(format!(
+                    "Existing tag_id: {}. Attach it with `add_issue_tag` or change it with `update_tag`.",
+                    existing.id
+                )),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // The project tag named `name`, creating it when `create_if_missing` is set. Returns
+    // whether the tag was created.
+    async fn resolve_tag_by_name(
+        &self,
+        project_id: Uuid,
+        name: &str,
+        create_if_missing: bool,
+        color: Option<&str>,
+    ) -> Result<(Uuid, bool), ToolError> {
+        let name = Self::parse_tag_name(name)?;
+        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let response: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
+        if let Some(tag) = response
+            .tags
+            .iter()
+            .find(|tag| name_matching::names_match(&tag.name, &name))
+        {
+            return Ok((tag.id, false));
+        }
+        if !create_if_missing {
+            return Err(ToolError::new(
+                format!(
+                    "No tag named '{}' in this project.{}",
+                    name,
+                    name_matching::did_you_mean(
+                        &name,
+                        response.tags.iter().map(|tag| tag.name.as_str())
+                    )
+                ),
+                Some("Pass `create_if_missing: true` to create it."),
+            ));
+        }
+
+        let payload = CreateTagRequest {
+            id: None,
+            project_id,
+            name,
+            color: parse_color(color.unwrap_or(DEFAULT_TAG_COLOR))?,
+        };
+        let url = self.url("/api/remote/tags");
+        let response: MutationResponse<Tag> = self
+            .send_json(self.client.post(&url).json(&payload))
+            .await?;
+        Ok((response.data.id, true))
+    }
+
+    // The relation attaching the tag named `tag_name` to an issue.
+    async fn find_issue_tag_by_name(
+        &self,
+        issue_id: Uuid,
+        tag_name: &str,
+    ) -> Result<Uuid, ToolError> {
+        let issue = self.fetch_issue(issue_id).await?;
+        let url = self.url(&format!("/api/remote/tags?project_id={}", issue.project_id));
+        let tags: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
+        let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", issue_id));
+        let issue_tags: ListIssueTagsResponse = self.send_json(self.client.get(&url)).await?;
+
+        let attached = issue_tags
+            .issue_tags
+            .iter()
+            .filter_map(|issue_tag| {
+                let tag = tags.tags.iter().find(|tag| tag.id == issue_tag.tag_id)?;
+                Some((issue_tag.id, tag.name.as_str()))
+            })
+            .collect::<Vec<_>>();
+        attached
+            .iter()
+            .find(|(_, name)| name_matching::names_match(name, tag_name))
+            .map(|(id, _)| *id)
+            .ok_or_else(|| {
+                ToolError::new(
+                    format!("The issue has no tag named '{}'", tag_name.trim()),
+                    Some(format!(
+                        "Tags on the issue: [{}]",
+                        attached
+                            .iter()
+                            .map(|(_, name)| *name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                )
+            })
+    }
+}
+
+/// Converts a hex color (`#rgb` or `#rrggbb`) to the "H S% L%" format tags and projects
+/// are stored in. Colors already in that format are passed through.
+pub(super) fn parse_color(color: &str) -> Result<String, ToolError> {
+    let color = color.trim();
+    if is_hsl_color(color) {
+        return Ok(color.to_string());
+    }
+
+    let hex = color.strip_prefix('#').unwrap_or(color);
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => String::new(),
+    };
+    let Ok(rgb) = u32::from_str_radix(&expanded, 16) else {
+        return Err(ToolError::new(
+            format!("Invalid color '{color}'"),
+            Some("Use a hex color such as '#d73a4a', or HSL such as '355 65% 53%'."),
+        ));
+    };
+    let [_, r, g, b] = rgb.to_be_bytes();
+    Ok(hex_to_hsl(r, g, b))
+}
+
+fn is_hsl_color(color: &str) -> bool {
+    let mut parts = color.split(' ');
+    let (Some(h), Some(s), Some(l), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    let percent = |value: &str| {
+        value
+            .strip_suffix('%')
+            .and_then(|value| value.parse::<u8>().ok())
+            .is_some_and(|value| value <= 100)
+    };
+    h.parse::<u16>().is_ok_and(|h| h <= 360) && percent(s) && percent(l)
+}
+
+fn hex_to_hsl(r: u8, g: u8, b: u8) -> String {
+    let [r, g, b] = [r, g, b].map(|channel| f64::from(channel) / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return format!("0 0% {}%", (lightness * 100.0).round());
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    format!(
+        "{} {}% {}%",
+        hue.round() as u16 % 360,
+        (saturation * 100.0).round(),
+        (lightness * 100.0).round()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        routing::{delete, get, post},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, issue_json};
+
+    #[test]
+    fn tag_colors_accept_hex_and_hsl() {
+        assert_eq!(parse_color("#d73a4a").unwrap(), "354 66% 54%");
+        assert_eq!(parse_color("FFF").unwrap(), "0 0% 100%");
+        assert_eq!(parse_color("#0000ff").unwrap(), "240 100% 50%");
+        assert_eq!(parse_color(" 124 82% 30% ").unwrap(), "124 82% 30%");
+        for invalid in ["red", "#12345", "#gggggg", "400 50% 50%", ""] {
+            assert!(parse_color(invalid).is_err(), "color {invalid:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn create_tag_refuses_a_name_already_in_the_project() {
+        let project_id = Uuid::new_v4();
+        let existing_id = Uuid::new_v4();
+        let backend = MockBackend::start(
+            Router::new().route(
+                "/api/remote/tags",
+                get(move || async move {
+                    envelope(json!({ "tags": [{
+                        "id": existing_id,
+                        "project_id": project_id,
+                        "name": "Bug",
+                        "color": "355 65% 53%",
+                    }] }))
+                })
+                .post(|| async { envelope(json!({})) }),
+            ),
+        )
+        .await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "name": "bug ",
+            "color": "#ff0000",
+        }))
+        .unwrap();
+        let result = server.create_tag(Parameters(request)).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert!(
+            body["details"]
+                .as_str()
+                .unwrap()
+                .contains(&existing_id.to_string())
+        );
+        assert_eq!(backend.requests_to("/api/remote/tags"), 1);
+    }
+
+    #[tokio::test]
+    async fn create_tag_sends_hex_colors_as_hsl() {
+        let project_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/remote/tags",
+            get(|| async { envelope(json!({ "tags": [] })) }).post(
+                |Json(body): Json<serde_json::Value>| async move {
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "project_id": body["project_id"],
+                            "name": body["name"],
+                            "color": body["color"],
+                        },
+                        "txid": 1,
+                    }))
+                },
+            ),
+        ))
+        .await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "name": "regression",
+            "color": "#0000ff",
+        }))
+        .unwrap();
+        let result = server.create_tag(Parameters(request)).await.unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["tag"]["name"], "regression");
+        assert_eq!(body["tag"]["color"], "240 100% 50%");
+    }
+
+    // Serves `GET /api/remote/issues/{id}` for an issue in `project_id`.
+    fn issue_route(router: Router, issue_id: Uuid, project_id: Uuid) -> Router {
+        router.route(
+            "/api/remote/issues/{issue_id}",
+            get(move || async move {
+                let mut issue = issue_json(project_id, Uuid::new_v4(), 1);
+                issue["id"] = json!(issue_id);
+                envelope(issue)
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_issue_tag_creates_a_missing_tag_by_name() {
+        let (issue_id, project_id, tag_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let router = Router::new()
+            .route(
+                "/api/remote/tags",
+                get(|| async { envelope(json!({ "tags": [] })) }).post(
+                    move |Json(body): Json<serde_json::Value>| async move {
+                        assert_eq!(body["name"], "needs-triage");
+                        assert_eq!(body["color"], DEFAULT_TAG_COLOR);
+                        envelope(json!({
+                            "data": {
+                                "id": tag_id,
+                                "project_id": body["project_id"],
+                                "name": body["name"],
+                                "color": body["color"],
+                            },
+                            "txid": 1,
+                        }))
+                    },
+                ),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "issue_id": body["issue_id"],
+                            "tag_id": body["tag_id"],
+                        },
+                        "txid": 1,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(issue_route(router, issue_id, project_id)).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "tag_name": "needs-triage",
+        }))
+        .unwrap();
+        let result = server.add_issue_tag(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(backend.requests_to("/api/remote/issue-tags"), 0);
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "tag_name": "needs-triage",
+            "create_if_missing": true,
+        }))
+        .unwrap();
+        let result = server.add_issue_tag(Parameters(request)).await.unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["tag_id"], tag_id.to_string());
+        assert_eq!(body["tag_created"], true);
+        assert_eq!(body["created"], true);
+    }
+
+    #[tokio::test]
+    async fn remove_issue_tag_finds_the_relation_by_tag_name() {
+        let (issue_id, project_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (bug_id, bug_relation_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let router = Router::new()
+            .route(
+                "/api/remote/tags",
+                get(move || async move {
+                    envelope(json!({ "tags": [{
+                        "id": bug_id,
+                        "project_id": project_id,
+                        "name": "Bug",
+                        "color": "355 65% 53%",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                get(move || async move {
+                    envelope(json!({ "issue_tags": [
+                        { "id": bug_relation_id, "issue_id": issue_id, "tag_id": bug_id },
+                    ] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags/{issue_tag_id}",
+                delete(|| async { envelope(json!(null)) }),
+            );
+        let backend = MockBackend::start(issue_route(router, issue_id, project_id)).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "tag_name": "bug",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .remove_issue_tag(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issue-tags/{bug_relation_id}")),
+            1
+        );
+    }
+}
@@ -1,26 +1,61 @@
-use std::str::FromStr;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use api_types::{Issue, ListProjectStatusesResponse, ProjectStatus};
+use api_types::{
+    CreateIssueAssigneeRequest, CreateIssueTagRequest, Issue, IssueAssignee, IssueTag,
+    ListIssueAssigneesResponse, ListIssueTagsResponse, ListMembersResponse,
+    ListProjectStatusesResponse, MutationResponse, OrganizationMemberWithProfile, Project,
+    ProjectStatus, SortDirection,
+};
 use db::models::{execution_process::ExecutionProcessStatus, tag::Tag};
-use executors::executors::BaseCodingAgent;
-use regex::Regex;
+use executors::{executors::BaseCodingAgent, model_selector::PermissionPolicy};
+use rand::Rng;
+use reqwest::{
+    Method, StatusCode,
+    header::{HeaderValue, RETRY_AFTER},
+};
 use rmcp::{
     ErrorData,
-    model::{CallToolResult, Content},
+    model::{CallToolResult, Content, JsonObject},
+    schemars,
 };
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use thiserror::Error;
+use utils::http_headers::{REQUEST_ID_HEADER, current_request_id};
 use uuid::Uuid;
 
-use super::{ApiResponseEnvelope, McpMode, McpServer};
+use super::{
+    ApiResponseEnvelope, McpMode, McpServer, config::format_timeout, name_matching,
+    server_stats::endpoint_key, tag_expansion,
+};
 
 type ToolCallResult = Result<CallToolResult, ErrorData>;
 
+// Retries for transient failures (server restarting, rate limiting). GETs always qualify;
+// other methods only when the caller marks them idempotent.
+const MAX_REQUEST_RETRIES: u32 = 3;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+// Upstream error bodies can be whole HTML pages; keep enough to be useful to an agent.
+const MAX_UPSTREAM_ERROR_CHARS: usize = 500;
+
+const PERMISSION_POLICIES: [PermissionPolicy; 3] = [
+    PermissionPolicy::Auto,
+    PermissionPolicy::Supervised,
+    PermissionPolicy::Plan,
+];
+
 #[derive(Debug, Error)]
 #[error("{message}")]
 struct ToolError {
     message: String,
     details: Option<String>,
+    status: Option<u16>,
+    hint: Option<String>,
 }
 
 impl ToolError {
@@ -28,37 +63,75 @@ impl ToolError {
         Self {
             message: message.into(),
             details: details.map(Into::into),
+            status: None,
+            hint: None,
         }
     }
 
     fn message(message: impl Into<String>) -> Self {
         Self::new(message, None::<String>)
     }
+
+    /// Error for a non-2xx VK API response, carrying the upstream message when one was sent.
+    fn from_status(status: StatusCode, body: &str, attempts: u32) -> Self {
+        let hint = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Some(
+                "Authentication or permission problem. Check that you are logged in to Vibe Kanban and have access to this resource.",
+            ),
+            StatusCode::NOT_FOUND => {
+                Some("Resource not found. The ID may be stale or belong to another project.")
+            }
+            _ => None,
+        };
+
+        Self {
+            message: with_attempts(format!("VK API returned error status: {status}"), attempts),
+            details: upstream_error_message(body),
+            status: Some(status.as_u16()),
+            hint: hint.map(str::to_string),
+        }
+    }
 }
 
+mod coding_agents;
 mod context;
 mod issue_assignees;
+mod issue_comments;
+mod issue_followers;
 mod issue_relationships;
 mod issue_tags;
+mod notifications;
 mod organizations;
+mod paging;
+mod project_statuses;
+mod pull_requests;
 mod remote_issues;
 mod remote_projects;
 mod repos;
 mod sessions;
 mod task_attempts;
+#[cfg(test)]
+mod test_support;
 mod workspaces;
 
 impl McpServer {
     pub fn global_mode_router() -> rmcp::handler::server::tool::ToolRouter<Self> {
         Self::context_tools_router()
             + Self::workspaces_tools_router()
+            + Self::coding_agents_tools_router()
             + Self::organizations_tools_router()
             + Self::repos_tools_router()
             + Self::remote_projects_tools_router()
             + Self::remote_issues_tools_router()
+            + Self::project_statuses_tools_router()
             + Self::issue_assignees_tools_router()
+            + Self::issue_comments_tools_router()
+            + Self::issue_followers_tools_router()
             + Self::issue_tags_tools_router()
             + Self::issue_relationships_tools_router()
+            + Self::notifications_tools_router()
+            + Self::pull_requests_tools_router()
+            + Self::paging_tools_router()
             + Self::task_attempts_tools_router()
             + Self::session_tools_router()
     }
@@ -66,6 +139,7 @@ impl McpServer {
     pub fn orchestrator_mode_router() -> rmcp::handler::server::tool::ToolRouter<Self> {
         let mut router = Self::context_tools_router()
             + Self::workspaces_tools_router()
+            + Self::coding_agents_tools_router()
             + Self::session_tools_router();
         router.remove_route("list_workspaces");
         router.remove_route("delete_workspace");
@@ -76,25 +150,90 @@ impl McpServer {
 impl McpServer {
     fn orchestrator_session_id(&self) -> Option<Uuid> {
         self.context
-            .as_ref()
-            .and_then(|ctx| ctx.orchestrator_session_id)
+            .read(|ctx| ctx.and_then(|ctx| ctx.orchestrator_session_id))
     }
 
     fn scoped_workspace_id(&self) -> Option<Uuid> {
-        self.context.as_ref().map(|ctx| ctx.workspace_id)
+        self.context.read(|ctx| ctx.map(|ctx| ctx.workspace_id))
     }
 
-    fn success<T: Serialize>(data: &T) -> ToolCallResult {
-        Ok(CallToolResult::success(vec![Content::text(
-            serde_json::to_string_pretty(data)
+    // Pretty-printed text stays alongside the structured content for clients that ignore it.
+    fn success<T: Serialize>(&self, data: &T) -> ToolCallResult {
+        let Ok(value) = serde_json::to_value(data) else {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "Failed to serialize response",
+            )]));
+        };
+
+        let mut result = CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&value)
                 .unwrap_or_else(|_| "Failed to serialize response".to_string()),
-        )]))
+        )]);
+        if self.config.structured_output {
+            result.structured_content = Some(value);
+        }
+        Ok(result)
+    }
+
+    /// Like `success`, but when the response is larger than the configured limit only the
+    /// first page of the `items_field` array is returned, along with a `next_cursor` for
+    /// `fetch_more`. A `returned_count` field is updated to the size of that page.
+    fn success_paged<T: Serialize>(&self, data: &T, items_field: &str) -> ToolCallResult {
+        let Ok(mut value) = serde_json::to_value(data) else {
+            return self.success(data);
+        };
+        let max_bytes = self.config.max_response_bytes;
+        if json_size(&value) <= max_bytes {
+            return self.success(&value);
+        }
+        let Some(items) = value
+            .get_mut(items_field)
+            .and_then(serde_json::Value::as_array_mut)
+        else {
+            return self.success(&value);
+        };
+
+        let items = std::mem::take(items);
+        let page_bytes = max_bytes.saturating_sub(json_size(&value));
+        let mut pages = paginate_items(items, page_bytes).into_iter();
+        let first_page = pages.next().unwrap_or_default();
+        let remaining_pages = pages.collect::<Vec<_>>();
+        let total_pages = remaining_pages.len() + 1;
+
+        if value.get("returned_count").is_some() {
+            value["returned_count"] = first_page.len().into();
+        }
+        value[items_field] = serde_json::Value::Array(first_page);
+        if !remaining_pages.is_empty() {
+            value["next_cursor"] =
+                serde_json::Value::String(self.response_pages.store(remaining_pages));
+            value["total_pages"] = total_pages.into();
+        }
+        self.success(&value)
+    }
+
+    // Link into the web app, or `None` when no usable web base URL is configured, so
+    // results never carry a broken link.
+    fn web_url(&self, path: &str) -> Option<String> {
+        let base = self.config.web_base_url.as_deref()?.trim_end_matches('/');
+        (base.starts_with("http://") || base.starts_with("https://"))
+            .then(|| format!("{}/{}", base, path.trim_start_matches('/')))
+    }
+
+    fn project_web_url(&self, project_id: Uuid) -> Option<String> {
+        self.web_url(&format!("/projects/{}", project_id))
+    }
+
+    fn issue_web_url(&self, project_id: Uuid, issue_id: Uuid) -> Option<String> {
+        self.web_url(&format!("/projects/{}/issues/{}", project_id, issue_id))
     }
 
     fn err<S: Into<String>>(msg: S, details: Option<S>) -> ToolCallResult {
         Ok(Self::tool_error(ToolError::new(msg, details)))
     }
 
+    // Errors stay text-only: they don't match the tool's output schema, and clients
+    // check `is_error` before looking for structured content.
     fn tool_error(error: ToolError) -> CallToolResult {
         let mut value = serde_json::json!({
             "success": false,
@@ -103,6 +242,15 @@ impl McpServer {
         if let Some(details) = error.details {
             value["details"] = serde_json::json!(details);
         }
+        if let Some(status) = error.status {
+            value["status"] = serde_json::json!(status);
+        }
+        if let Some(hint) = error.hint {
+            value["hint"] = serde_json::json!(hint);
+        }
+        if let Some(request_id) = current_request_id() {
+            value["request_id"] = serde_json::json!(request_id);
+        }
 
         CallToolResult::error(vec![Content::text(
             serde_json::to_string_pretty(&value)
@@ -114,48 +262,200 @@ impl McpServer {
         &self,
         rb: reqwest::RequestBuilder,
     ) -> Result<T, ToolError> {
-        let resp = rb.send().await.map_err(|error| {
-            ToolError::new("Failed to connect to VK API", Some(error.to_string()))
-        })?;
+        let resp = self.send_request(rb, false).await?;
+        Self::parse_json_response(resp).await
+    }
 
-        if !resp.status().is_success() {
-            let status = resp.status();
-            return Err(ToolError::message(format!(
-                "VK API returned error status: {}",
-                status
-            )));
+    /// Like `send_json`, for endpoints that answer `data: null` when there is nothing to
+    /// return, such as the latest coding agent run of a workspace that hasn't started one.
+    async fn send_optional_json<T: DeserializeOwned>(
+        &self,
+        rb: reqwest::RequestBuilder,
+    ) -> Result<Option<T>, ToolError> {
+        let resp = self.send_request(rb, false).await?;
+        Self::parse_optional_json_response(resp).await
+    }
+
+    /// Like `send_json`, but also retries non-GET requests that are safe to repeat.
+    async fn send_idempotent_json<T: DeserializeOwned>(
+        &self,
+        rb: reqwest::RequestBuilder,
+    ) -> Result<T, ToolError> {
+        let resp = self.send_request(rb, true).await?;
+        Self::parse_json_response(resp).await
+    }
+
+    async fn send_empty_json(&self, rb: reqwest::RequestBuilder) -> Result<(), ToolError> {
+        let resp = self.send_request(rb, false).await?;
+        Self::parse_empty_json_response(resp).await
+    }
+
+    /// Like `send_json`, for endpoints that report expected failures as typed `error_data`.
+    /// Those come back as `Ok(Err(_))`; every other failure is an `Err`.
+    async fn send_json_or_error_data<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        rb: reqwest::RequestBuilder,
+    ) -> Result<Result<T, E>, ToolError> {
+        #[derive(Deserialize)]
+        struct ErrorDataApiResponse<E> {
+            success: bool,
+            data: Option<serde_json::Value>,
+            message: Option<String>,
+            error_data: Option<E>,
         }
 
+        let resp = self.send_request(rb, false).await?;
         let api_response = resp
-            .json::<ApiResponseEnvelope<T>>()
+            .json::<ErrorDataApiResponse<E>>()
             .await
             .map_err(|error| {
                 ToolError::new("Failed to parse VK API response", Some(error.to_string()))
             })?;
 
+        if let Some(error_data) = api_response.error_data {
+            return Ok(Err(error_data));
+        }
         if !api_response.success {
             let msg = api_response.message.as_deref().unwrap_or("Unknown error");
             return Err(ToolError::new("VK API returned error", Some(msg)));
         }
 
-        api_response
-            .data
-            .ok_or_else(|| ToolError::message("VK API response missing data field"))
+        // Endpoints without a payload send `data: null`, which a unit `T` accepts.
+        serde_json::from_value(api_response.data.unwrap_or_default())
+            .map(Ok)
+            .map_err(|error| {
+                ToolError::new("Failed to parse VK API response", Some(error.to_string()))
+            })
     }
 
-    async fn send_empty_json(&self, rb: reqwest::RequestBuilder) -> Result<(), ToolError> {
-        let resp = rb.send().await.map_err(|error| {
-            ToolError::new("Failed to connect to VK API", Some(error.to_string()))
+    // For endpoints that answer success with an empty body, such as `204 No Content`.
+    async fn send_no_content(&self, rb: reqwest::RequestBuilder) -> Result<(), ToolError> {
+        self.send_request(rb, false).await.map(drop)
+    }
+
+    /// Sends a request, retrying connection errors, 429 and 502/503/504 with exponential
+    /// backoff. GET requests are always retried; pass `idempotent` to opt other methods in.
+    async fn send_request(
+        &self,
+        rb: reqwest::RequestBuilder,
+        idempotent: bool,
+    ) -> Result<reqwest::Response, ToolError> {
+        let (client, request) = rb.build_split();
+        let mut request = request.map_err(|error| {
+            ToolError::new("Failed to build VK API request", Some(error.to_string()))
         })?;
+        let request_id = current_request_id();
+        if let Some(header) = request_id
+            .as_deref()
+            .and_then(|request_id| HeaderValue::from_str(request_id).ok())
+        {
+            request.headers_mut().insert(REQUEST_ID_HEADER, header);
+        }
+
+        let endpoint = endpoint_key(request.method().as_str(), request.url().path());
+        let started = Instant::now();
+        let result = self.execute_with_retries(client, request, idempotent).await;
+        self.stats.record_request(
+            endpoint,
+            started.elapsed(),
+            result
+                .as_ref()
+                .err()
+                .map(|error| (error.status, error.message.as_str())),
+            request_id,
+        );
+        result
+    }
+
+    async fn execute_with_retries(
+        &self,
+        client: reqwest::Client,
+        mut request: reqwest::Request,
+        idempotent: bool,
+    ) -> Result<reqwest::Response, ToolError> {
+        let retryable = idempotent || request.method() == Method::GET;
+        // Slow operations set their own timeout on the request instead of the client default.
+        let timeout = request
+            .timeout()
+            .copied()
+            .unwrap_or(self.config.request_timeout);
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            // Requests with streaming bodies can't be cloned, so they only get one attempt.
+            let retry = if retryable && attempts <= MAX_REQUEST_RETRIES {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let delay = match client.execute(request).await {
+                Ok(resp) if resp.status().is_success() => return Ok(resp),
+                Ok(resp) if retry.is_some() && is_retryable_status(resp.status()) => {
+                    retry_after(&resp).unwrap_or_else(|| backoff_delay(attempts))
+                }
+                Ok(resp) => {
+                    let status = resp.status();
+                    let body = resp.text().await.unwrap_or_default();
+                    return Err(ToolError::from_status(status, &body, attempts));
+                }
+                // A hung backend is unlikely to recover within the backoff window.
+                Err(error) if error.is_timeout() && !error.is_connect() => {
+                    return Err(ToolError::new(
+                        with_attempts(
+                            format!("VK API timed out after {}", format_timeout(timeout)),
+                            attempts,
+                        ),
+                        Some(
+                            "The local server did not respond in time. Raise VK_MCP_TIMEOUT_MS (or VK_MCP_GIT_TIMEOUT_MS for PRs and rebases) if this operation is expected to be slow.",
+                        ),
+                    ));
+                }
+                Err(error) if retry.is_some() && error.is_connect() => backoff_delay(attempts),
+                Err(error) => {
+                    return Err(ToolError::new(
+                        with_attempts("Failed to connect to VK API", attempts),
+                        Some(format!(
+                            "{} Run `check_connection` to see which endpoint is failing.",
+                            error
+                        )),
+                    ));
+                }
+            };
+
+            tracing::debug!(attempts, ?delay, "Retrying VK API request");
+            tokio::time::sleep(delay).await;
+            request = retry.expect("retry request is present when retrying");
+        }
+    }
+
+    async fn parse_json_response<T: DeserializeOwned>(
+        resp: reqwest::Response,
+    ) -> Result<T, ToolError> {
+        Self::parse_optional_json_response(resp)
+            .await?
+            .ok_or_else(|| ToolError::message("VK API response missing data field"))
+    }
 
-        if !resp.status().is_success() {
-            let status = resp.status();
-            return Err(ToolError::message(format!(
-                "VK API returned error status: {}",
-                status
-            )));
+    async fn parse_optional_json_response<T: DeserializeOwned>(
+        resp: reqwest::Response,
+    ) -> Result<Option<T>, ToolError> {
+        let api_response = resp
+            .json::<ApiResponseEnvelope<T>>()
+            .await
+            .map_err(|error| {
+                ToolError::new("Failed to parse VK API response", Some(error.to_string()))
+            })?;
+
+        if !api_response.success {
+            let msg = api_response.message.as_deref().unwrap_or("Unknown error");
+            return Err(ToolError::new("VK API returned error", Some(msg)));
         }
 
+        Ok(api_response.data)
+    }
+
+    async fn parse_empty_json_response(resp: reqwest::Response) -> Result<(), ToolError> {
         #[derive(Deserialize)]
         struct EmptyApiResponse {
             success: bool,
@@ -205,20 +505,9 @@ impl McpServer {
 
     // Expands @tagname references in text by replacing them with tag content.
     async fn expand_tags(&self, text: &str) -> String {
-        let tag_pattern = match Regex::new(r"@([^\s@]+)") {
-            Ok(re) => re,
-            Err(_) => return text.to_string(),
-        };
-
-        let tag_names: Vec<String> = tag_pattern
-            .captures_iter(text)
-            .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
-            .collect::<std::collections::HashSet<_>>()
-            .into_iter()
-            .collect();
-
-        if tag_names.is_empty() {
-            return text.to_string();
+        if tag_expansion::tag_references(text).is_empty() {
+            // Still resolve escapes such as `@@name`.
+            return tag_expansion::expand_tag_references(text, &HashMap::new());
         }
 
         let url = self.url("/api/tags");
@@ -232,30 +521,22 @@ impl McpServer {
             _ => return text.to_string(),
         };
 
-        let tag_map: std::collections::HashMap<&str, &str> = tags
-            .iter()
-            .map(|t| (t.tag_name.as_str(), t.content.as_str()))
-            .collect();
-
-        let result = tag_pattern.replace_all(text, |caps: &regex::Captures| {
-            let tag_name = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            match tag_map.get(tag_name) {
-                Some(content) => (*content).to_string(),
-                None => caps.get(0).map(|m| m.as_str()).unwrap_or("").to_string(),
-            }
-        });
+        let tag_map: HashMap<String, String> =
+            tags.into_iter().map(|t| (t.tag_name, t.content)).collect();
 
-        result.into_owned()
+        tag_expansion::expand_tag_references(text, &tag_map)
     }
 
-    // Resolves a project_id from an explicit parameter or falls back to context.
-    fn resolve_project_id(&self, explicit: Option<Uuid>) -> Result<Uuid, ToolError> {
+    // Resolves a project_id from an explicit parameter or falls back to context, refreshing
+    // the context once in case the workspace was linked after it was last resolved.
+    async fn resolve_project_id(&self, explicit: Option<Uuid>) -> Result<Uuid, ToolError> {
         if let Some(id) = explicit {
             return Ok(id);
         }
-        if let Some(ctx) = &self.context
-            && let Some(id) = ctx.project_id
-        {
+        if let Some(id) = self.context.read(|ctx| ctx.and_then(|ctx| ctx.project_id)) {
+            return Ok(id);
+        }
+        if let Some(id) = self.refresh_context().await.and_then(|ctx| ctx.project_id) {
             return Ok(id);
         }
         Err(ToolError::message(
@@ -263,13 +544,70 @@ impl McpServer {
         ))
     }
 
-    // Resolves an organization_id from an explicit parameter or falls back to context.
-    fn resolve_organization_id(&self, explicit: Option<Uuid>) -> Result<Uuid, ToolError> {
+    // Like `resolve_project_id`, for tools that modify the project: an explicit project_id
+    // that differs from the workspace's linked project yields a warning, or an error under
+    // strict context checking.
+    async fn resolve_project_id_checked(
+        &self,
+        explicit: Option<Uuid>,
+        strict_context: Option<bool>,
+    ) -> Result<(Uuid, Option<String>), ToolError> {
+        let project_id = self.resolve_project_id(explicit).await?;
+        let warning = self.check_project_context(project_id, strict_context)?;
+        Ok((project_id, warning))
+    }
+
+    // Agents sometimes carry IDs over from another board, which silently puts issues on the
+    // wrong project. Checks `project_id` against the workspace's linked project, if any.
+    fn check_project_context(
+        &self,
+        project_id: Uuid,
+        strict_context: Option<bool>,
+    ) -> Result<Option<String>, ToolError> {
+        let linked_project_id = self.context.read(|ctx| ctx.and_then(|ctx| ctx.project_id));
+        self.check_linked_project(project_id, linked_project_id, strict_context)
+    }
+
+    fn check_linked_project(
+        &self,
+        project_id: Uuid,
+        linked_project_id: Option<Uuid>,
+        strict_context: Option<bool>,
+    ) -> Result<Option<String>, ToolError> {
+        let Some(linked_project_id) = linked_project_id.filter(|linked| *linked != project_id)
+        else {
+            return Ok(None);
+        };
+        let message = format!(
+            "Operating on project {project_id}, which differs from your workspace's linked project {linked_project_id}"
+        );
+        if strict_context.unwrap_or(self.config.strict_context) {
+            return Err(ToolError::new(
+                message,
+                Some(
+                    "Use the workspace's project, or pass `strict_context: false` if the other project is intended.",
+                ),
+            ));
+        }
+        Ok(Some(message))
+    }
+
+    // Resolves an organization_id from an explicit parameter or falls back to context,
+    // refreshing it the same way as `resolve_project_id`.
+    async fn resolve_organization_id(&self, explicit: Option<Uuid>) -> Result<Uuid, ToolError> {
         if let Some(id) = explicit {
             return Ok(id);
         }
-        if let Some(ctx) = &self.context
-            && let Some(id) = ctx.organization_id
+        if let Some(id) = self
+            .context
+            .read(|ctx| ctx.and_then(|ctx| ctx.organization_id))
+        {
+            return Ok(id);
+        }
+        if let Some(id) = self
+            .refresh_context()
+            .await
+            .and_then(|ctx| ctx.organization_id)
         {
             return Ok(id);
         }
@@ -278,35 +616,50 @@ impl McpServer {
         ))
     }
 
-    // Fetches project statuses for a project.
+    // Fetches project statuses for a project, served from the short-lived status cache.
     async fn fetch_project_statuses(
         &self,
         project_id: Uuid,
     ) -> Result<Vec<ProjectStatus>, ToolError> {
-        let url = self.url(&format!(
-            "/api/remote/project-statuses?project_id={}",
-            project_id
-        ));
-        let response: ListProjectStatusesResponse = self.send_json(self.client.get(&url)).await?;
-        Ok(response.project_statuses)
+        self.project_statuses
+            .get_or_fetch(project_id, || async {
+                let url = self.url(&format!(
+                    "/api/remote/project-statuses?project_id={}",
+                    project_id
+                ));
+                let response: ListProjectStatusesResponse =
+                    self.send_json(self.client.get(&url)).await?;
+                Ok::<_, ToolError>(response.project_statuses)
+            })
+            .await
     }
 
-    // Resolves a status name to status_id.
+    // Resolves a status name to status_id, ignoring case, spacing, hyphens and underscores.
     async fn resolve_status_id(
         &self,
         project_id: Uuid,
         status_name: &str,
     ) -> Result<Uuid, ToolError> {
-        let statuses = self.fetch_project_statuses(project_id).await?;
+        let mut statuses = self.fetch_project_statuses(project_id).await?;
+        if !statuses
+            .iter()
+            .any(|s| name_matching::names_match(&s.name, status_name))
+        {
+            // The status may have been created after the list was cached.
+            self.project_statuses.invalidate(project_id).await;
+            statuses = self.fetch_project_statuses(project_id).await?;
+        }
         statuses
             .iter()
-            .find(|s| s.name.eq_ignore_ascii_case(status_name))
+            .find(|s| name_matching::names_match(&s.name, status_name))
             .map(|s| s.id)
             .ok_or_else(|| {
                 let available: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
                 ToolError::message(format!(
-                    "Unknown status '{}'. Available statuses: {:?}",
-                    status_name, available
+                    "Unknown status '{}'.{} Available statuses: {:?}",
+                    status_name,
+                    name_matching::did_you_mean(status_name, available.iter().copied()),
+                    available
                 ))
             })
     }
@@ -314,48 +667,326 @@ impl McpServer {
     // Gets the default status_id for a project (first non-hidden status by sort_order).
     async fn default_status_id(&self, project_id: Uuid) -> Result<Uuid, ToolError> {
         let statuses = self.fetch_project_statuses(project_id).await?;
+        Self::default_status(&statuses)
+            .map(|s| s.id)
+            .ok_or_else(|| ToolError::message("No visible statuses found for project"))
+    }
+
+    // The status new issues land in: the first non-hidden status by sort_order.
+    fn default_status(statuses: &[ProjectStatus]) -> Option<&ProjectStatus> {
         statuses
             .iter()
             .filter(|s| !s.hidden)
             .min_by_key(|s| s.sort_order)
-            .map(|s| s.id)
-            .ok_or_else(|| ToolError::message("No visible statuses found for project"))
     }
 
     // Resolves a status_id to its display name. Falls back to UUID string if lookup fails.
     async fn resolve_status_name(&self, project_id: Uuid, status_id: Uuid) -> String {
-        match self.fetch_project_statuses(project_id).await {
-            Ok(statuses) => statuses
-                .iter()
+        let find_name = |statuses: Vec<ProjectStatus>| {
+            statuses
+                .into_iter()
                 .find(|s| s.id == status_id)
-                .map(|s| s.name.clone())
-                .unwrap_or_else(|| status_id.to_string()),
+                .map(|s| s.name)
+        };
+
+        if let Ok(Some(name)) = self.fetch_project_statuses(project_id).await.map(find_name) {
+            return name;
+        }
+
+        // Unknown IDs are remembered for the cache TTL so repeated lookups don't refetch.
+        if self
+            .project_statuses
+            .is_missing(project_id, status_id)
+            .await
+        {
+            return status_id.to_string();
+        }
+
+        // The status may have been created after the list was cached.
+        self.project_statuses.invalidate(project_id).await;
+        match self.fetch_project_statuses(project_id).await.map(find_name) {
+            Ok(Some(name)) => name,
+            Ok(None) => {
+                self.project_statuses
+                    .mark_missing(project_id, status_id)
+                    .await;
+                status_id.to_string()
+            }
             Err(_) => status_id.to_string(),
         }
     }
 
+    // Parses a `sort_direction` tool parameter, falling back to `default` when omitted.
+    fn parse_sort_direction(
+        sort_direction: Option<&str>,
+        default: SortDirection,
+    ) -> Result<SortDirection, ToolError> {
+        let Some(sort_direction) = sort_direction else {
+            return Ok(default);
+        };
+        match sort_direction.trim().to_ascii_lowercase().as_str() {
+            "asc" => Ok(SortDirection::Asc),
+            "desc" => Ok(SortDirection::Desc),
+            other => Err(ToolError::message(format!(
+                "Unknown sort_direction '{}'. Allowed values: ['asc', 'desc']",
+                other
+            ))),
+        }
+    }
+
+    fn sort_direction_label(sort_direction: SortDirection) -> &'static str {
+        match sort_direction {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+
+    // Fetches a single remote issue by ID.
+    async fn fetch_issue(&self, issue_id: Uuid) -> Result<Issue, ToolError> {
+        let url = self.url(&format!("/api/remote/issues/{}", issue_id));
+        self.send_json(self.client.get(&url)).await
+    }
+
+    // Attaches an existing project tag to an issue, using `id` for the new row when given.
+    // If the tag is already attached (e.g. by an earlier attempt of a retried call), the
+    // existing row is returned with `false` instead of failing on the conflict.
+    async fn attach_issue_tag(
+        &self,
+        issue_id: Uuid,
+        tag_id: Uuid,
+        id: Option<Uuid>,
+    ) -> Result<(IssueTag, bool), ToolError> {
+        let payload = CreateIssueTagRequest {
+            id,
+            issue_id,
+            tag_id,
+        };
+        let url = self.url("/api/remote/issue-tags");
+        match self
+            .send_json::<MutationResponse<IssueTag>>(self.client.post(&url).json(&payload))
+            .await
+        {
+            Ok(response) => Ok((response.data, true)),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", issue_id));
+                let existing: ListIssueTagsResponse = self.send_json(self.client.get(&url)).await?;
+                existing
+                    .issue_tags
+                    .into_iter()
+                    .find(|issue_tag| issue_tag.tag_id == tag_id)
+                    .map(|issue_tag| (issue_tag, false))
+                    .ok_or(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // Assigns a user to an issue, with the same `id` and conflict handling as
+    // `attach_issue_tag`.
+    async fn assign_issue_user(
+        &self,
+        issue_id: Uuid,
+        user_id: Uuid,
+        id: Option<Uuid>,
+    ) -> Result<(IssueAssignee, bool), ToolError> {
+        let payload = CreateIssueAssigneeRequest {
+            id,
+            issue_id,
+            user_id,
+        };
+        let url = self.url("/api/remote/issue-assignees");
+        match self
+            .send_json::<MutationResponse<IssueAssignee>>(self.client.post(&url).json(&payload))
+            .await
+        {
+            Ok(response) => Ok((response.data, true)),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                let url = self.url(&format!(
+                    "/api/remote/issue-assignees?issue_id={}",
+                    issue_id
+                ));
+                let existing: ListIssueAssigneesResponse =
+                    self.send_json(self.client.get(&url)).await?;
+                existing
+                    .issue_assignees
+                    .into_iter()
+                    .find(|assignee| assignee.user_id == user_id)
+                    .map(|assignee| (assignee, false))
+                    .ok_or(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // The user the VK backend is logged in as, used to default `user_id` parameters.
+    async fn current_user_id(&self) -> Result<Uuid, ToolError> {
+        #[derive(Deserialize)]
+        struct CurrentUserResponse {
+            user_id: String,
+        }
+
+        let url = self.url("/api/auth/user");
+        let response: CurrentUserResponse = self.send_json(self.client.get(&url)).await?;
+        Uuid::parse_str(&response.user_id).map_err(|_| {
+            ToolError::new(
+                "Could not determine the current user",
+                Some("Pass user_id explicitly."),
+            )
+        })
+    }
+
+    // Members of the organization owning `project_id`. The organization comes from the
+    // workspace context when it is linked to that project, saving a project lookup.
+    async fn fetch_project_members(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<OrganizationMemberWithProfile>, ToolError> {
+        let context_organization_id = self.context.read(|ctx| {
+            ctx.filter(|ctx| ctx.project_id == Some(project_id))
+                .and_then(|ctx| ctx.organization_id)
+        });
+        let organization_id = match context_organization_id {
+            Some(id) => id,
+            None => {
+                let url = self.url(&format!("/api/remote/projects/{}", project_id));
+                let project: Project = self.send_json(self.client.get(&url)).await?;
+                project.organization_id
+            }
+        };
+        let url = self.url(&format!("/api/organizations/{}/members", organization_id));
+        let response: ListMembersResponse = self.send_json(self.client.get(&url)).await?;
+        Ok(response.members)
+    }
+
+    // Resolves a username or email to the user ID of a member of the project's organization.
+    // An exact case-insensitive match wins; otherwise the input must match exactly one member
+    // by prefix. Misses and ambiguous matches list the candidate usernames.
+    async fn resolve_member_user_id(
+        &self,
+        project_id: Uuid,
+        name: &str,
+    ) -> Result<Uuid, ToolError> {
+        let members = self.fetch_project_members(project_id).await?;
+        let input = name.trim();
+        let name = input.to_lowercase();
+        let identifiers = |member: &OrganizationMemberWithProfile| {
+            [member.username.as_deref(), member.email.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+        };
+
+        let exact = members
+            .iter()
+            .filter(|member| identifiers(member).contains(&name))
+            .collect::<Vec<_>>();
+        let candidates = if exact.is_empty() {
+            members
+                .iter()
+                .filter(|member| identifiers(member).iter().any(|id| id.starts_with(&name)))
+                .collect::<Vec<_>>()
+        } else {
+            exact
+        };
+
+        match candidates.as_slice() {
+            [member] => Ok(member.user_id),
+            [] => {
+                let usernames = members
+                    .iter()
+                    .filter_map(|member| member.username.as_deref())
+                    .collect::<Vec<_>>();
+                Err(ToolError::new(
+                    format!(
+                        "No organization member matches '{}'.{}",
+                        input,
+                        name_matching::did_you_mean(input, usernames.iter().copied())
+                    ),
+                    Some(format!("Members: {}", usernames.join(", "))),
+                ))
+            }
+            matches => Err(ToolError::new(
+                format!("'{}' matches more than one organization member", input),
+                Some(format!(
+                    "Use the full username or email, or user_id. Candidates: {}",
+                    matches
+                        .iter()
+                        .map(|member| {
+                            member
+                                .username
+                                .clone()
+                                .unwrap_or_else(|| member.user_id.to_string())
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            )),
+        }
+    }
+
     // Links a workspace to a remote issue by fetching issue.project_id and calling link endpoint.
     async fn link_workspace_to_issue(
         &self,
         workspace_id: Uuid,
         issue_id: Uuid,
     ) -> Result<(), ToolError> {
-        let issue_url = self.url(&format!("/api/remote/issues/{}", issue_id));
-        let issue: Issue = self.send_json(self.client.get(&issue_url)).await?;
+        let issue = self.fetch_issue(issue_id).await?;
+        self.link_workspace(workspace_id, &issue).await
+    }
 
+    async fn link_workspace(&self, workspace_id: Uuid, issue: &Issue) -> Result<(), ToolError> {
+        let issue_id = issue.id;
         let link_url = self.url(&format!("/api/workspaces/{}/links", workspace_id));
         let link_payload = serde_json::json!({
             "project_id": issue.project_id,
             "issue_id": issue_id,
         });
-        self.send_empty_json(self.client.post(&link_url).json(&link_payload))
-            .await
+        // Remote workspaces are unique per local workspace, so a retry can't create a duplicate link.
+        let resp = self
+            .send_request(self.client.post(&link_url).json(&link_payload), true)
+            .await?;
+        Self::parse_empty_json_response(resp).await
+    }
+
+    // Executors are matched ignoring case, with hyphens treated as underscores.
+    fn canonical_executor_name(executor: &str) -> String {
+        executor.replace('-', "_").to_ascii_uppercase()
     }
 
     fn parse_executor_agent(executor: &str) -> Result<BaseCodingAgent, ToolError> {
-        let normalized = executor.replace('-', "_").to_ascii_uppercase();
-        BaseCodingAgent::from_str(&normalized)
-            .map_err(|_| ToolError::message(format!("Unknown executor '{executor}'.")))
+        BaseCodingAgent::from_str(&Self::canonical_executor_name(executor)).map_err(|_| {
+            ToolError::message(format!(
+                "Unknown executor '{executor}'. Call `list_executors` to see valid executors."
+            ))
+        })
+    }
+
+    fn parse_permission_policy(value: &str) -> Result<PermissionPolicy, ToolError> {
+        let normalized = value.trim().to_ascii_uppercase();
+        PERMISSION_POLICIES
+            .iter()
+            .find(|policy| Self::permission_policy_label(policy) == normalized)
+            .cloned()
+            .ok_or_else(|| {
+                ToolError::message(format!(
+                    "Unknown permission policy '{}'. Allowed values: [{}]",
+                    value,
+                    PERMISSION_POLICIES
+                        .iter()
+                        .map(|policy| format!("'{}'", Self::permission_policy_label(policy)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })
+    }
+
+    fn permission_policy_label(policy: &PermissionPolicy) -> &'static str {
+        match policy {
+            PermissionPolicy::Auto => "AUTO",
+            PermissionPolicy::Supervised => "SUPERVISED",
+            PermissionPolicy::Plan => "PLAN",
+        }
     }
 
     fn normalize_executor_name(executor: Option<&str>) -> Result<String, ToolError> {
@@ -383,25 +1014,141 @@ impl McpServer {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::{collections::BTreeSet, sync::Once};
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
 
-    use rmcp::handler::server::tool::ToolRouter;
-    use uuid::Uuid;
+/// Delay requested by a `Retry-After` header given in seconds, capped at `RETRY_MAX_DELAY`.
+fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    let seconds = resp
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(Duration::from_secs(seconds).min(RETRY_MAX_DELAY))
+}
+
+/// Exponential backoff for the given (1-based) attempt, jittered within the upper half.
+fn backoff_delay(attempt: u32) -> Duration {
+    let ceiling = RETRY_BASE_DELAY
+        .saturating_mul(1u32 << (attempt - 1).min(16))
+        .min(RETRY_MAX_DELAY);
+    let jitter_ms = rand::thread_rng().gen_range(0..=ceiling.as_millis() as u64 / 2);
+    ceiling / 2 + Duration::from_millis(jitter_ms)
+}
 
-    use super::McpServer;
-    use crate::task_server::{McpContext, McpMode, McpRepoContext};
+/// Extracts the message from a VK (`{success, message}`) or remote (`{error}`) error body,
+/// falling back to the raw text.
+fn upstream_error_message(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return None;
+    }
 
-    static RUSTLS_PROVIDER: Once = Once::new();
+    let message = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| {
+            ["message", "error"]
+                .into_iter()
+                .find_map(|key| value.get(key)?.as_str().map(str::to_string))
+                // Typed failures (`error_data`) often carry their own message instead.
+                .or_else(|| Some(value.pointer("/error_data/message")?.as_str()?.to_string()))
+        })
+        .unwrap_or_else(|| body.to_string());
+
+    if message.chars().count() > MAX_UPSTREAM_ERROR_CHARS {
+        let truncated: String = message.chars().take(MAX_UPSTREAM_ERROR_CHARS).collect();
+        Some(format!("{truncated}…"))
+    } else {
+        Some(message)
+    }
+}
 
-    fn install_rustls_provider() {
-        RUSTLS_PROVIDER.call_once(|| {
-            rustls::crypto::aws_lc_rs::default_provider()
-                .install_default()
-                .expect("Failed to install rustls crypto provider");
-        });
+/// JSON schema for a tool's success payload, declared via `#[tool(output_schema = ...)]`.
+fn output_schema<T: schemars::JsonSchema>() -> Arc<JsonObject> {
+    match serde_json::to_value(schemars::schema_for!(T)) {
+        Ok(serde_json::Value::Object(schema)) => Arc::new(schema),
+        _ => Arc::new(JsonObject::from_iter([(
+            "type".to_string(),
+            serde_json::Value::from("object"),
+        )])),
+    }
+}
+
+fn json_size(value: &serde_json::Value) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
+// Greedily fills pages up to `page_bytes`; an item larger than that gets a page to itself.
+fn paginate_items(items: Vec<serde_json::Value>, page_bytes: usize) -> Vec<Vec<serde_json::Value>> {
+    let mut pages = Vec::new();
+    let mut page = Vec::new();
+    let mut size = 0;
+    for item in items {
+        let item_size = json_size(&item) + 1;
+        if !page.is_empty() && size + item_size > page_bytes {
+            pages.push(std::mem::take(&mut page));
+            size = 0;
+        }
+        size += item_size;
+        page.push(item);
     }
+    if !page.is_empty() || pages.is_empty() {
+        pages.push(page);
+    }
+    pages
+}
+
+// A one-line description of an error, for reports that carry several of them.
+fn describe_error(error: &ToolError) -> String {
+    match &error.details {
+        Some(details) => format!("{} ({details})", error.message),
+        None => error.message.clone(),
+    }
+}
+
+fn with_attempts(message: impl Into<String>, attempts: u32) -> String {
+    let message = message.into();
+    if attempts > 1 {
+        format!("{message} after {attempts} attempts")
+    } else {
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeSet,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use axum::{Router, http::StatusCode, response::IntoResponse, routing::get};
+    use rmcp::handler::server::tool::ToolRouter;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::{
+        McpServer,
+        test_support::{MockBackend, envelope, install_rustls_provider},
+    };
+    use crate::task_server::{
+        McpContext, McpMode, McpRepoContext, McpServerConfig, RemoteLinkStatus,
+        context_cache::ContextCache,
+    };
 
     fn tool_names(router: rmcp::handler::server::tool::ToolRouter<McpServer>) -> BTreeSet<String> {
         router
@@ -415,11 +1162,22 @@ mod tests {
     fn orchestrator_mode_exposes_only_scoped_workflow_tools() {
         let actual = tool_names(McpServer::orchestrator_mode_router());
         let expected = BTreeSet::from([
+            "archive_workspace".to_string(),
+            "check_connection".to_string(),
             "create_session".to_string(),
+            "create_workspace_pr".to_string(),
             "get_context".to_string(),
             "get_execution".to_string(),
+            "get_server_stats".to_string(),
+            "get_workspace".to_string(),
+            "get_workspace_diff_summary".to_string(),
+            "list_executors".to_string(),
             "list_sessions".to_string(),
+            "pin_workspace".to_string(),
+            "rebase_workspace".to_string(),
+            "rename_workspace".to_string(),
             "run_session_prompt".to_string(),
+            "unarchive_workspace".to_string(),
             "update_session".to_string(),
             "update_workspace".to_string(),
         ]);
@@ -436,6 +1194,43 @@ mod tests {
         assert!(!actual.contains("output_markdown"));
     }
 
+    #[test]
+    fn success_attaches_structured_content_unless_text_only() {
+        install_rustls_provider();
+        let payload = json!({ "success": true, "count": 2 });
+
+        let server = McpServer::with_config(
+            McpMode::Global,
+            McpServerConfig::new("http://127.0.0.1:3000"),
+        );
+        let result = server.success(&payload).unwrap();
+        assert_eq!(result.structured_content, Some(payload.clone()));
+        assert!(
+            server
+                .tool_router
+                .list_all()
+                .iter()
+                .any(|tool| tool.output_schema.is_some())
+        );
+
+        let server = McpServer::with_config(
+            McpMode::Global,
+            McpServerConfig::new("http://127.0.0.1:3000").with_structured_output(false),
+        );
+        let result = server.success(&payload).unwrap();
+        assert_eq!(result.structured_content, None);
+        let text: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(text, payload);
+        assert!(
+            server
+                .tool_router
+                .list_all()
+                .iter()
+                .all(|tool| tool.output_schema.is_none())
+        );
+    }
+
     #[test]
     fn orchestrator_session_id_is_resolved_from_context() {
         install_rustls_provider();
@@ -443,9 +1238,9 @@ mod tests {
         let workspace_id = Uuid::new_v4();
         let server = McpServer {
             client: reqwest::Client::new(),
-            base_url: "http://127.0.0.1:3000".to_string(),
+            config: McpServerConfig::new("http://127.0.0.1:3000"),
             tool_router: ToolRouter::default(),
-            context: Some(McpContext {
+            context: ContextCache::new(Some(McpContext {
                 organization_id: None,
                 project_id: None,
                 issue_id: None,
@@ -457,8 +1252,12 @@ mod tests {
                     repo_name: "repo".to_string(),
                     target_branch: "main".to_string(),
                 }],
-            }),
+                remote_link_status: RemoteLinkStatus::Unlinked,
+            })),
             mode: McpMode::Global,
+            project_statuses: Default::default(),
+            response_pages: Default::default(),
+            stats: Default::default(),
         };
 
         assert_eq!(server.orchestrator_session_id(), Some(session_id));
@@ -470,10 +1269,13 @@ mod tests {
         install_rustls_provider();
         let server = McpServer {
             client: reqwest::Client::new(),
-            base_url: "http://127.0.0.1:3000".to_string(),
+            config: McpServerConfig::new("http://127.0.0.1:3000"),
             tool_router: ToolRouter::default(),
-            context: None,
+            context: ContextCache::default(),
             mode: McpMode::Orchestrator,
+            project_statuses: Default::default(),
+            response_pages: Default::default(),
+            stats: Default::default(),
         };
 
         assert_eq!(server.orchestrator_session_id(), None);
@@ -481,6 +1283,41 @@ mod tests {
         assert!(server.scope_allows_workspace(Uuid::new_v4()).is_ok());
     }
 
+    #[test]
+    fn web_urls_are_omitted_without_a_usable_base_url() {
+        install_rustls_provider();
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let server_with = |config: McpServerConfig| McpServer {
+            client: reqwest::Client::new(),
+            config,
+            tool_router: ToolRouter::default(),
+            context: ContextCache::default(),
+            mode: McpMode::Global,
+            project_statuses: Default::default(),
+            response_pages: Default::default(),
+            stats: Default::default(),
+        };
+
+        let server = server_with(
+            McpServerConfig::new("http://127.0.0.1:3000")
+                .with_web_base_url("https://kanban.example.com/"),
+        );
+        assert_eq!(
+            server.issue_web_url(project_id, issue_id),
+            Some(format!(
+                "https://kanban.example.com/projects/{project_id}/issues/{issue_id}"
+            ))
+        );
+
+        let mut config = McpServerConfig::new("http://127.0.0.1:3000");
+        config.web_base_url = None;
+        assert_eq!(server_with(config).project_web_url(project_id), None);
+
+        let server = server_with(McpServerConfig::new("127.0.0.1:3000"));
+        assert_eq!(server.project_web_url(project_id), None);
+    }
+
     #[test]
     fn global_context_omits_orchestrator_session_id_from_serialized_output() {
         install_rustls_provider();
@@ -492,10 +1329,316 @@ mod tests {
             workspace_id: Uuid::new_v4(),
             workspace_branch: "main".to_string(),
             workspace_repos: vec![],
+            remote_link_status: RemoteLinkStatus::Unlinked,
         };
 
         let serialized = serde_json::to_value(&context).expect("context should serialize");
 
         assert!(serialized.get("orchestrator_session_id").is_none());
     }
+
+    #[tokio::test]
+    async fn resolve_status_name_remembers_unknown_status_ids() {
+        let project_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/remote/project-statuses",
+            get(move || async move {
+                envelope(json!({ "project_statuses": [{
+                    "id": todo_id,
+                    "project_id": project_id,
+                    "name": "To do",
+                    "color": "217 91% 60%",
+                    "sort_order": 0,
+                    "hidden": false,
+                    "created_at": "2026-01-01T00:00:00Z",
+                }] }))
+            }),
+        ))
+        .await;
+        let server = backend.server();
+
+        let unknown_id = Uuid::new_v4();
+        for _ in 0..3 {
+            assert_eq!(
+                server.resolve_status_name(project_id, unknown_id).await,
+                unknown_id.to_string()
+            );
+        }
+        // One fetch to fill the cache and one refetch in case the status is new.
+        assert_eq!(backend.requests_to("/api/remote/project-statuses"), 2);
+
+        assert_eq!(
+            server.resolve_status_name(project_id, todo_id).await,
+            "To do"
+        );
+        assert_eq!(backend.requests_to("/api/remote/project-statuses"), 2);
+    }
+
+    fn flaky_router(failures: usize, calls: Arc<AtomicUsize>) -> Router {
+        let handler = move || {
+            let calls = calls.clone();
+            async move {
+                if calls.fetch_add(1, Ordering::SeqCst) < failures {
+                    StatusCode::SERVICE_UNAVAILABLE.into_response()
+                } else {
+                    envelope(json!({ "ok": true })).into_response()
+                }
+            }
+        };
+        Router::new().route("/flaky", get(handler.clone()).post(handler))
+    }
+
+    #[tokio::test]
+    async fn get_requests_retry_transient_failures() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let backend = MockBackend::start(flaky_router(2, calls.clone())).await;
+        let server = backend.server();
+
+        let value: serde_json::Value = server
+            .send_json(server.client.get(server.url("/flaky")))
+            .await
+            .expect("GET should succeed after retrying");
+
+        assert_eq!(value["ok"], true);
+        assert_eq!(backend.requests_to("/flaky"), 3);
+    }
+
+    #[tokio::test]
+    async fn get_requests_report_attempts_when_retries_are_exhausted() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let backend = MockBackend::start(flaky_router(usize::MAX, calls)).await;
+        let server = backend.server();
+
+        let error = server
+            .send_json::<serde_json::Value>(server.client.get(server.url("/flaky")))
+            .await
+            .expect_err("GET should fail once retries are exhausted");
+
+        assert_eq!(backend.requests_to("/flaky"), 4);
+        assert!(
+            error.message.ends_with("after 4 attempts"),
+            "{}",
+            error.message
+        );
+    }
+
+    #[tokio::test]
+    async fn non_idempotent_requests_are_not_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let backend = MockBackend::start(flaky_router(1, calls.clone())).await;
+        let server = backend.server();
+
+        let error = server
+            .send_json::<serde_json::Value>(server.client.post(server.url("/flaky")))
+            .await
+            .expect_err("POST should not be retried");
+        assert_eq!(backend.requests_to("/flaky"), 1);
+        assert!(!error.message.contains("attempts"));
+    }
+
+    #[tokio::test]
+    async fn idempotent_requests_opt_in_to_retries() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let backend = MockBackend::start(flaky_router(1, calls)).await;
+        let server = backend.server();
+
+        let resp = server
+            .send_request(server.client.post(server.url("/flaky")), true)
+            .await
+            .expect("idempotent POST should succeed after retrying");
+
+        assert!(resp.status().is_success());
+        assert_eq!(backend.requests_to("/flaky"), 2);
+    }
+
+    #[tokio::test]
+    async fn timed_out_requests_report_the_configured_timeout() {
+        let backend = MockBackend::start(Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                envelope(json!({ "ok": true }))
+            }),
+        ))
+        .await;
+        let mut server = backend.server();
+        server.config = server
+            .config
+            .clone()
+            .with_request_timeout(Duration::from_millis(200));
+        server.client = server.config.build_client();
+
+        let error = server
+            .send_json::<serde_json::Value>(server.client.get(server.url("/slow")))
+            .await
+            .expect_err("request should time out");
+
+        assert_eq!(error.message, "VK API timed out after 200ms");
+        assert_eq!(backend.requests_to("/slow"), 1);
+    }
+
+    #[tokio::test]
+    async fn per_request_timeouts_override_the_client_default() {
+        let backend = MockBackend::start(Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                envelope(json!({ "ok": true }))
+            }),
+        ))
+        .await;
+        let mut server = backend.server();
+        server.config = server
+            .config
+            .clone()
+            .with_request_timeout(Duration::from_millis(200));
+        server.client = server.config.build_client();
+
+        let value: serde_json::Value = server
+            .send_json(
+                server
+                    .client
+                    .get(server.url("/slow"))
+                    .timeout(Duration::from_secs(5)),
+            )
+            .await
+            .expect("the longer per-request timeout should apply");
+        assert_eq!(value["ok"], true);
+
+        let error = server
+            .send_json::<serde_json::Value>(
+                server
+                    .client
+                    .get(server.url("/slow"))
+                    .timeout(Duration::from_millis(300)),
+            )
+            .await
+            .expect_err("request should time out");
+        assert_eq!(error.message, "VK API timed out after 300ms");
+    }
+
+    fn error_body(result: rmcp::model::CallToolResult) -> serde_json::Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn upstream_error_bodies_are_surfaced_in_tool_errors() {
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/envelope",
+                    get(|| async {
+                        (
+                            StatusCode::UNPROCESSABLE_ENTITY,
+                            axum::Json(json!({ "success": false, "message": "Title is too long" })),
+                        )
+                    }),
+                )
+                .route(
+                    "/remote",
+                    get(|| async {
+                        (
+                            StatusCode::FORBIDDEN,
+                            axum::Json(json!({ "error": "not a member of this project" })),
+                        )
+                    }),
+                )
+                .route(
+                    "/text",
+                    get(|| async { (StatusCode::NOT_FOUND, "no such issue") }),
+                )
+                .route("/empty", get(|| async { StatusCode::CONFLICT })),
+        )
+        .await;
+        let server = backend.server();
+
+        let call = |path: &'static str| {
+            let server = server.clone();
+            async move {
+                let error = server
+                    .send_json::<serde_json::Value>(server.client.get(server.url(path)))
+                    .await
+                    .expect_err("request should fail");
+                error_body(McpServer::tool_error(error))
+            }
+        };
+
+        let envelope_error = call("/envelope").await;
+        assert_eq!(envelope_error["status"], 422);
+        assert_eq!(envelope_error["details"], "Title is too long");
+        assert!(envelope_error.get("hint").is_none());
+
+        let remote_error = call("/remote").await;
+        assert_eq!(remote_error["status"], 403);
+        assert_eq!(remote_error["details"], "not a member of this project");
+        assert!(
+            remote_error["hint"]
+                .as_str()
+                .unwrap()
+                .contains("permission")
+        );
+
+        let text_error = call("/text").await;
+        assert_eq!(text_error["status"], 404);
+        assert_eq!(text_error["details"], "no such issue");
+        assert!(text_error["hint"].as_str().unwrap().contains("stale"));
+
+        let empty_error = call("/empty").await;
+        assert_eq!(empty_error["status"], 409);
+        assert!(empty_error.get("details").is_none());
+        assert!(
+            empty_error["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("VK API returned error status: 409")
+        );
+    }
+
+    #[tokio::test]
+    async fn request_id_is_forwarded_and_reported_in_errors() {
+        let backend = MockBackend::start(Router::new().route(
+            "/echo",
+            get(|headers: axum::http::HeaderMap| async move {
+                let request_id = headers[super::REQUEST_ID_HEADER]
+                    .to_str()
+                    .unwrap()
+                    .to_owned();
+                (StatusCode::BAD_REQUEST, request_id)
+            }),
+        ))
+        .await;
+        let server = backend.server();
+
+        let body = utils::http_headers::REQUEST_ID
+            .scope("req-123".to_string(), async {
+                let error = server
+                    .send_json::<serde_json::Value>(server.client.get(server.url("/echo")))
+                    .await
+                    .expect_err("request should fail");
+                error_body(McpServer::tool_error(error))
+            })
+            .await;
+
+        assert_eq!(body["details"], "req-123");
+        assert_eq!(body["request_id"], "req-123");
+    }
+
+    #[test]
+    fn long_upstream_error_messages_are_truncated() {
+        let message = super::upstream_error_message(&"x".repeat(2_000)).unwrap();
+
+        assert_eq!(message.chars().count(), super::MAX_UPSTREAM_ERROR_CHARS + 1);
+        assert!(message.ends_with('…'));
+    }
+
+    #[test]
+    fn upstream_error_messages_fall_back_to_error_data() {
+        let body = r#"{"success":false,"data":null,"message":null,"error_data":{"message":"Repository is used by 2 active workspace(s)","workspaces":["a","b"]}}"#;
+
+        assert_eq!(
+            super::upstream_error_message(body).as_deref(),
+            Some("Repository is used by 2 active workspace(s)")
+        );
+    }
 }
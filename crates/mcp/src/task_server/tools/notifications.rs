@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use api_types::{
+    BulkUpdateNotificationItem, BulkUpdateNotificationsRequest, BulkUpdateNotificationsResponse,
+    ListNotificationsResponse, MutationResponse, Notification, NotificationType,
+    UpdateNotificationRequest,
+};
+use futures::{StreamExt, stream};
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{McpServer, ToolError, output_schema};
+
+const DEFAULT_NOTIFICATIONS_LIMIT: usize = 50;
+// Notifications from older servers may lack the issue's simple ID in their payload.
+const ISSUE_LOOKUP_CONCURRENCY: usize = 8;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListNotificationsRequest {
+    #[schemars(
+        description = "The organization ID to list notifications for. Optional if running inside a workspace linked to a remote project."
+    )]
+    organization_id: Option<Uuid>,
+    #[schemars(description = "Only return unread notifications (default: false)")]
+    unread_only: Option<bool>,
+    #[schemars(description = "Maximum number of notifications to return (default: 50)")]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct NotificationSummary {
+    #[schemars(description = "Notification ID")]
+    id: String,
+    #[schemars(description = "Notification type, e.g. issue_assignee_changed")]
+    notification_type: String,
+    #[schemars(description = "Related issue ID, if any")]
+    issue_id: Option<String>,
+    #[schemars(description = "Related issue simple ID (e.g. 'VK-12'), if known")]
+    issue_simple_id: Option<String>,
+    #[schemars(description = "Related issue title, if known")]
+    issue_title: Option<String>,
+    #[schemars(description = "Whether the notification has been read")]
+    read: bool,
+    #[schemars(description = "Creation timestamp")]
+    created_at: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListNotificationsResponse {
+    organization_id: String,
+    notifications: Vec<NotificationSummary>,
+    count: usize,
+    unread_count: usize,
+    #[schemars(description = "Number of notifications matching the filters, before `limit`")]
+    total_count: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpMarkNotificationReadRequest {
+    #[schemars(description = "Notification ID to mark as read")]
+    notification_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpMarkNotificationReadResponse {
+    success: bool,
+    notification_id: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpMarkAllNotificationsReadRequest {
+    #[schemars(
+        description = "The organization ID whose notifications should be marked as read. Optional if running inside a workspace linked to a remote project."
+    )]
+    organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpMarkAllNotificationsReadResponse {
+    success: bool,
+    organization_id: String,
+    marked_count: usize,
+}
+
+#[tool_router(router = notifications_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "List your notifications in an organization, newest first. Set `unread_only` to skip notifications you have already read. `organization_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpListNotificationsResponse>()
+    )]
+    async fn list_notifications(
+        &self,
+        Parameters(McpListNotificationsRequest {
+            organization_id,
+            unread_only,
+            limit,
+        }): Parameters<McpListNotificationsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let notifications = match self.fetch_notifications(organization_id).await {
+            Ok(notifications) => notifications,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let unread_count = notifications.iter().filter(|n| !n.seen).count();
+        let mut notifications = notifications
+            .into_iter()
+            .filter(|n| !unread_only.unwrap_or(false) || !n.seen)
+            .map(Self::notification_summary)
+            .collect::<Vec<_>>();
+        let total_count = notifications.len();
+        notifications.truncate(limit.unwrap_or(DEFAULT_NOTIFICATIONS_LIMIT));
+        self.fill_issue_simple_ids(&mut notifications).await;
+
+        self.success(&McpListNotificationsResponse {
+            organization_id: organization_id.to_string(),
+            count: notifications.len(),
+            unread_count,
+            total_count,
+            notifications,
+        })
+    }
+
+    #[tool(
+        description = "Mark a single notification as read.",
+        output_schema = output_schema::<McpMarkNotificationReadResponse>()
+    )]
+    async fn mark_notification_read(
+        &self,
+        Parameters(McpMarkNotificationReadRequest { notification_id }): Parameters<
+            McpMarkNotificationReadRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/remote/notifications/{}", notification_id));
+        let payload = UpdateNotificationRequest { seen: Some(true) };
+        // Marking as read is idempotent, so it is safe to retry.
+        if let Err(e) = self
+            .send_idempotent_json::<MutationResponse<Notification>>(
+                self.client.patch(&url).json(&payload),
+            )
+            .await
+        {
+            return Ok(Self::tool_error(e));
+        }
+
+        self.success(&McpMarkNotificationReadResponse {
+            success: true,
+            notification_id: notification_id.to_string(),
+        })
+    }
+
+    #[tool(
+        description = "Mark all unread notifications in an organization as read. `organization_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpMarkAllNotificationsReadResponse>()
+    )]
+    async fn mark_all_notifications_read(
+        &self,
+        Parameters(McpMarkAllNotificationsReadRequest { organization_id }): Parameters<
+            McpMarkAllNotificationsReadRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let notifications = match self.fetch_notifications(organization_id).await {
+            Ok(notifications) => notifications,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let updates = notifications
+            .iter()
+            .filter(|n| !n.seen)
+            .map(|n| BulkUpdateNotificationItem {
+                id: n.id,
+                changes: UpdateNotificationRequest { seen: Some(true) },
+            })
+            .collect::<Vec<_>>();
+        let marked_count = updates.len();
+
+        if !updates.is_empty() {
+            let url = self.url("/api/remote/notifications/bulk");
+            let payload = BulkUpdateNotificationsRequest { updates };
+            if let Err(e) = self
+                .send_idempotent_json::<BulkUpdateNotificationsResponse>(
+                    self.client.post(&url).json(&payload),
+                )
+                .await
+            {
+                return Ok(Self::tool_error(e));
+            }
+        }
+
+        self.success(&McpMarkAllNotificationsReadResponse {
+            success: true,
+            organization_id: organization_id.to_string(),
+            marked_count,
+        })
+    }
+}
+
+impl McpServer {
+    // Notifications are listed per user across organizations, so narrow them down here.
+    // Read notifications are dismissed upstream, hence `include_dismissed`.
+    async fn fetch_notifications(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<Notification>, ToolError> {
+        let url = self.url("/api/remote/notifications?include_dismissed=true");
+        let response: ListNotificationsResponse = self.send_json(self.client.get(&url)).await?;
+        Ok(response
+            .notifications
+            .into_iter()
+            .filter(|n| n.organization_id == organization_id)
+            .collect())
+    }
+
+    // Looks up the simple ID of each related issue whose notification payload lacks one.
+    // Issues that can't be fetched, e.g. because they were deleted, are left without.
+    async fn fill_issue_simple_ids(&self, notifications: &mut [NotificationSummary]) {
+        let mut issue_ids = notifications
+            .iter()
+            .filter(|n| n.issue_simple_id.is_none())
+            .filter_map(|n| {
+                n.issue_id
+                    .as_deref()
+                    .and_then(|id| Uuid::parse_str(id).ok())
+            })
+            .collect::<Vec<_>>();
+        issue_ids.sort();
+        issue_ids.dedup();
+        if issue_ids.is_empty() {
+            return;
+        }
+
+        let simple_ids = stream::iter(issue_ids)
+            .map(|issue_id| async move {
+                let issue = self.fetch_issue(issue_id).await.ok()?;
+                Some((issue_id.to_string(), issue.simple_id))
+            })
+            .buffer_unordered(ISSUE_LOOKUP_CONCURRENCY)
+            .filter_map(|found| async move { found })
+            .collect::<HashMap<_, _>>()
+            .await;
+        for notification in notifications {
+            if notification.issue_simple_id.is_none()
+                && let Some(issue_id) = &notification.issue_id
+            {
+                notification.issue_simple_id = simple_ids.get(issue_id).cloned();
+            }
+        }
+    }
+
+    fn notification_summary(notification: Notification) -> NotificationSummary {
+        NotificationSummary {
+            id: notification.id.to_string(),
+            notification_type: notification_type_label(notification.notification_type),
+            issue_id: notification
+                .issue_id
+                .or(notification.payload.issue_id)
+                .map(|id| id.to_string()),
+            issue_simple_id: notification.payload.issue_simple_id,
+            issue_title: notification
+                .payload
+                .new_title
+                .or(notification.payload.issue_title),
+            read: notification.seen,
+            created_at: notification.created_at.to_rfc3339(),
+        }
+    }
+}
+
+fn notification_type_label(notification_type: NotificationType) -> String {
+    serde_json::to_value(notification_type)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, issue_json};
+
+    fn notification_json(organization_id: Uuid, seen: bool) -> Value {
+        json!({
+            "id": Uuid::new_v4(),
+            "organization_id": organization_id,
+            "user_id": Uuid::new_v4(),
+            "notification_type": "issue_assignee_changed",
+            "payload": { "issue_simple_id": "VK-12", "issue_title": "Fix login" },
+            "issue_id": Uuid::new_v4(),
+            "comment_id": null,
+            "seen": seen,
+            "dismissed_at": null,
+            "created_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    #[tokio::test]
+    async fn mark_all_notifications_read_only_updates_unread_in_organization() {
+        let organization_id = Uuid::new_v4();
+        let unread = notification_json(organization_id, false);
+        let unread_id = unread["id"].clone();
+        let notifications = vec![
+            unread,
+            notification_json(organization_id, true),
+            notification_json(Uuid::new_v4(), false),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/notifications",
+                get(move || {
+                    let notifications = notifications.clone();
+                    async move { envelope(json!({ "notifications": notifications })) }
+                }),
+            )
+            .route(
+                "/api/remote/notifications/bulk",
+                post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["updates"].as_array().map(Vec::len), Some(1));
+                    assert_eq!(body["updates"][0]["id"], unread_id);
+                    assert_eq!(body["updates"][0]["seen"], true);
+                    envelope(json!({ "data": [], "txid": 1 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "organization_id": organization_id })).unwrap();
+        let result = backend
+            .server()
+            .mark_all_notifications_read(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["marked_count"], 1);
+        assert_eq!(backend.requests_to("/api/remote/notifications/bulk"), 1);
+    }
+
+    #[tokio::test]
+    async fn list_notifications_limits_and_fills_missing_simple_ids() {
+        let organization_id = Uuid::new_v4();
+        let mut without_simple_id = notification_json(organization_id, false);
+        without_simple_id["payload"] = json!({});
+        let issue_id = without_simple_id["issue_id"].clone();
+        let notifications = vec![
+            without_simple_id,
+            notification_json(organization_id, false),
+            notification_json(organization_id, true),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/notifications",
+                get(move || {
+                    let notifications = notifications.clone();
+                    async move { envelope(json!({ "notifications": notifications })) }
+                }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || async move {
+                    let mut issue = issue_json(Uuid::new_v4(), Uuid::new_v4(), 7);
+                    issue["id"] = json!(issue_id);
+                    envelope(issue)
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "organization_id": organization_id,
+            "unread_only": true,
+            "limit": 1,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_notifications(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["count"], 1);
+        assert_eq!(body["total_count"], 2);
+        assert_eq!(body["notifications"][0]["issue_simple_id"], "VK-7");
+    }
+}
@@ -1,4 +1,7 @@
-use api_types::{ListMembersResponse, ListOrganizationsResponse};
+use api_types::{
+    CreateInvitationRequest, CreateInvitationResponse, Invitation, ListInvitationsResponse,
+    ListMembersResponse, ListOrganizationsResponse, MemberRole, RevokeInvitationRequest,
+};
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
@@ -6,7 +9,9 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, ToolError, output_schema};
+
+const MEMBER_ROLES: [&str; 2] = ["admin", "member"];
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct OrganizationSummary {
@@ -61,9 +66,104 @@ struct McpListOrgMembersResponse {
     count: usize,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpInviteOrgMemberRequest {
+    #[schemars(
+        description = "The organization to invite to. Optional if running inside a workspace linked to a remote organization."
+    )]
+    organization_id: Option<Uuid>,
+    #[schemars(description = "Email address to send the invitation to")]
+    email: String,
+    #[schemars(
+        description = "Role the invitee gets on joining. Allowed values: 'admin', 'member'."
+    )]
+    role: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct InvitationSummary {
+    id: String,
+    email: String,
+    #[schemars(description = "Role the invitee gets on joining: 'admin' or 'member'")]
+    role: String,
+    #[schemars(description = "PENDING, ACCEPTED, DECLINED, or EXPIRED")]
+    status: String,
+    #[schemars(description = "User who sent the invitation")]
+    invited_by_user_id: Option<String>,
+    created_at: String,
+    expires_at: String,
+}
+
+impl From<Invitation> for InvitationSummary {
+    fn from(invitation: Invitation) -> Self {
+        Self {
+            id: invitation.id.to_string(),
+            email: invitation.email,
+            role: member_role_name(invitation.role).to_string(),
+            status: format!("{:?}", invitation.status).to_uppercase(),
+            invited_by_user_id: invitation.invited_by_user_id.map(|id| id.to_string()),
+            created_at: invitation.created_at.to_rfc3339(),
+            expires_at: invitation.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpInviteOrgMemberResponse {
+    organization_id: String,
+    email: String,
+    #[schemars(description = "Whether an invitation was sent")]
+    invited: bool,
+    #[schemars(
+        description = "True when the email already belongs to a member, in which case no invitation is sent"
+    )]
+    already_member: bool,
+    #[schemars(description = "The existing member's user ID, when `already_member` is true")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    member_user_id: Option<String>,
+    #[schemars(description = "The invitation that was sent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitation: Option<InvitationSummary>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListOrgInvitationsRequest {
+    #[schemars(
+        description = "The organization to list invitations for. Optional if running inside a workspace linked to a remote organization."
+    )]
+    organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListOrgInvitationsResponse {
+    organization_id: String,
+    invitations: Vec<InvitationSummary>,
+    count: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpRevokeOrgInvitationRequest {
+    #[schemars(description = "The invitation to revoke, from `list_org_invitations`")]
+    invitation_id: Uuid,
+    #[schemars(
+        description = "The organization the invitation belongs to. Optional if running inside a workspace linked to a remote organization."
+    )]
+    organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRevokeOrgInvitationResponse {
+    organization_id: String,
+    invitation_id: String,
+    revoked: bool,
+}
+
 #[tool_router(router = organizations_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "List all the available organizations")]
+    #[tool(
+        description = "List all the available organizations",
+        output_schema = output_schema::<McpListOrganizationsResponse>()
+    )]
     async fn list_organizations(&self) -> Result<CallToolResult, ErrorData> {
         let url = self.url("/api/organizations");
         let response: ListOrganizationsResponse = match self.send_json(self.client.get(&url)).await
@@ -83,14 +183,15 @@ impl McpServer {
             })
             .collect();
 
-        McpServer::success(&McpListOrganizationsResponse {
+        self.success(&McpListOrganizationsResponse {
             count: org_summaries.len(),
             organizations: org_summaries,
         })
     }
 
     #[tool(
-        description = "List members of an organization. `organization_id` is optional if running inside a workspace linked to a remote organization."
+        description = "List members of an organization. `organization_id` is optional if running inside a workspace linked to a remote organization.",
+        output_schema = output_schema::<McpListOrgMembersResponse>()
     )]
     async fn list_org_members(
         &self,
@@ -98,7 +199,7 @@ impl McpServer {
             McpListOrgMembersRequest,
         >,
     ) -> Result<CallToolResult, ErrorData> {
-        let organization_id = match self.resolve_organization_id(organization_id) {
+        let organization_id = match self.resolve_organization_id(organization_id).await {
             Ok(id) => id,
             Err(e) => return Ok(Self::tool_error(e)),
         };
@@ -124,10 +225,276 @@ impl McpServer {
             })
             .collect();
 
-        McpServer::success(&McpListOrgMembersResponse {
+        self.success(&McpListOrgMembersResponse {
             organization_id: organization_id.to_string(),
             count: members.len(),
             members,
         })
     }
+
+    #[tool(
+        description = "Invite someone to an organization by email. Requires admin access. If the email already belongs to a member, nothing is sent and `already_member` is true. `organization_id` is optional if running inside a workspace linked to a remote organization.",
+        output_schema = output_schema::<McpInviteOrgMemberResponse>()
+    )]
+    async fn invite_org_member(
+        &self,
+        Parameters(McpInviteOrgMemberRequest {
+            organization_id,
+            email,
+            role,
+        }): Parameters<McpInviteOrgMemberRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let role = match parse_member_role(&role) {
+            Ok(role) => role,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let email = email.trim().to_string();
+        if !email.contains('@') {
+            return Self::err(format!("'{email}' is not an email address"), None);
+        }
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let already_member = |member_user_id: Option<String>| McpInviteOrgMemberResponse {
+            organization_id: organization_id.to_string(),
+            email: email.clone(),
+            invited: false,
+            already_member: true,
+            member_user_id,
+            invitation: None,
+        };
+
+        // Best effort: if the member list can't be read, the backend still refuses to
+        // invite an existing member.
+        let url = self.url(&format!("/api/organizations/{}/members", organization_id));
+        if let Ok(members) = self
+            .send_json::<ListMembersResponse>(self.client.get(&url))
+            .await
+            && let Some(member) = members.members.iter().find(|member| {
+                member
+                    .email
+                    .as_deref()
+                    .is_some_and(|member_email| member_email.eq_ignore_ascii_case(&email))
+            })
+        {
+            return self.success(&already_member(Some(member.user_id.to_string())));
+        }
+
+        let url = self.url(&format!(
+            "/api/organizations/{}/invitations",
+            organization_id
+        ));
+        let payload = CreateInvitationRequest {
+            email: email.clone(),
+            role,
+        };
+        let response: CreateInvitationResponse =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(r) => r,
+                Err(e)
+                    if e.details
+                        .as_deref()
+                        .is_some_and(|details| details.contains("already a member")) =>
+                {
+                    return self.success(&already_member(None));
+                }
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpInviteOrgMemberResponse {
+            organization_id: organization_id.to_string(),
+            email,
+            invited: true,
+            already_member: false,
+            member_user_id: None,
+            invitation: Some(response.invitation.into()),
+        })
+    }
+
+    #[tool(
+        description = "List an organization's invitations, including pending ones. Requires admin access. `organization_id` is optional if running inside a workspace linked to a remote organization.",
+        output_schema = output_schema::<McpListOrgInvitationsResponse>()
+    )]
+    async fn list_org_invitations(
+        &self,
+        Parameters(McpListOrgInvitationsRequest { organization_id }): Parameters<
+            McpListOrgInvitationsRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let url = self.url(&format!(
+            "/api/organizations/{}/invitations",
+            organization_id
+        ));
+        let response: ListInvitationsResponse = match self.send_json(self.client.get(&url)).await {
+            Ok(r) => r,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let invitations = response
+            .invitations
+            .into_iter()
+            .map(InvitationSummary::from)
+            .collect::<Vec<_>>();
+        self.success(&McpListOrgInvitationsResponse {
+            organization_id: organization_id.to_string(),
+            count: invitations.len(),
+            invitations,
+        })
+    }
+
+    #[tool(
+        description = "Revoke a pending organization invitation so it can no longer be accepted. Requires admin access. `organization_id` is optional if running inside a workspace linked to a remote organization.",
+        output_schema = output_schema::<McpRevokeOrgInvitationResponse>()
+    )]
+    async fn revoke_org_invitation(
+        &self,
+        Parameters(McpRevokeOrgInvitationRequest {
+            invitation_id,
+            organization_id,
+        }): Parameters<McpRevokeOrgInvitationRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let url = self.url(&format!(
+            "/api/organizations/{}/invitations/revoke",
+            organization_id
+        ));
+        let payload = RevokeInvitationRequest { invitation_id };
+        if let Err(e) = self
+            .send_no_content(self.client.post(&url).json(&payload))
+            .await
+        {
+            return Ok(Self::tool_error(e));
+        }
+
+        self.success(&McpRevokeOrgInvitationResponse {
+            organization_id: organization_id.to_string(),
+            invitation_id: invitation_id.to_string(),
+            revoked: true,
+        })
+    }
+}
+
+// The name `parse_member_role` accepts for a role, i.e. one of `MEMBER_ROLES`.
+fn member_role_name(role: MemberRole) -> &'static str {
+    match role {
+        MemberRole::Admin => MEMBER_ROLES[0],
+        MemberRole::Member => MEMBER_ROLES[1],
+    }
+}
+
+// Accepts the role names case-insensitively and maps them onto `MemberRole`.
+fn parse_member_role(role: &str) -> Result<MemberRole, ToolError> {
+    match role.trim().to_ascii_lowercase().as_str() {
+        "admin" => Ok(MemberRole::Admin),
+        "member" => Ok(MemberRole::Member),
+        other => Err(ToolError::message(format!(
+            "Unknown role '{}'. Allowed values: [{}]",
+            other,
+            MEMBER_ROLES
+                .iter()
+                .map(|role| format!("'{role}'"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        routing::{get, post},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, member_json};
+
+    #[tokio::test]
+    async fn inviting_an_existing_member_reports_it_without_sending_an_invitation() {
+        let organization_id = Uuid::new_v4();
+        let member_id = Uuid::new_v4();
+        let members_path = format!("/api/organizations/{organization_id}/members");
+        let invitations_path = format!("/api/organizations/{organization_id}/invitations");
+        let mut member = member_json(member_id, "bob");
+        member["email"] = json!("Bob@Example.com");
+
+        let router = Router::new()
+            .route(
+                &members_path,
+                get(move || async move { envelope(json!({ "members": [member] })) }),
+            )
+            .route(
+                &invitations_path,
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    envelope(json!({ "invitation": {
+                        "id": Uuid::new_v4(),
+                        "organization_id": organization_id,
+                        "invited_by_user_id": null,
+                        "email": body["email"],
+                        "role": body["role"],
+                        "status": "PENDING",
+                        "token": "secret",
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "expires_at": "2026-01-08T00:00:00Z",
+                    } }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let invite = |email: &str, role: &str| {
+            serde_json::from_value(json!({
+                "organization_id": organization_id,
+                "email": email,
+                "role": role,
+            }))
+            .unwrap()
+        };
+
+        let result = server
+            .invite_org_member(Parameters(invite(" bob@example.com", "member")))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["already_member"], true);
+        assert_eq!(body["invited"], false);
+        assert_eq!(body["member_user_id"], member_id.to_string());
+        assert_eq!(backend.requests_to(&invitations_path), 0);
+
+        let result = server
+            .invite_org_member(Parameters(invite("carol@example.com", "Admin")))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["invited"], true);
+        assert_eq!(body["invitation"]["role"], "admin");
+        assert!(body["invitation"].get("token").is_none());
+
+        let result = server
+            .invite_org_member(Parameters(invite("dave@example.com", "owner")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            result.content[0]
+                .as_text()
+                .unwrap()
+                .text
+                .contains("'admin', 'member'")
+        );
+        assert_eq!(backend.requests_to(&invitations_path), 1);
+    }
 }
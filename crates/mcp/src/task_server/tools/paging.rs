@@ -0,0 +1,260 @@
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{McpServer, ToolError, output_schema};
+
+pub(super) const DEFAULT_PAGE_LIMIT: usize = 50;
+pub(super) const MAX_PAGE_LIMIT: usize = 200;
+// `limit: -1` asks for every row, as long as they fit in one page.
+const ALL_ROWS_LIMIT: i32 = -1;
+
+/// A validated `limit`/`offset` pair for list tools such as `list_issues` and `list_workspaces`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PageParams {
+    /// Rows to return; `MAX_PAGE_LIMIT` when every row was asked for.
+    pub(super) limit: usize,
+    pub(super) offset: usize,
+    /// Whether the requested limit was above `MAX_PAGE_LIMIT` and was lowered to it.
+    pub(super) limit_clamped: bool,
+    all: bool,
+}
+
+impl PageParams {
+    pub(super) fn parse(limit: Option<i32>, offset: Option<i32>) -> Result<Self, ToolError> {
+        let offset = match offset.unwrap_or(0) {
+            offset if offset < 0 => {
+                return Err(ToolError::message(format!(
+                    "offset must not be negative, got {offset}"
+                )));
+            }
+            offset => offset as usize,
+        };
+        let (limit, limit_clamped, all) = match limit {
+            None => (DEFAULT_PAGE_LIMIT, false, false),
+            Some(ALL_ROWS_LIMIT) => (MAX_PAGE_LIMIT, false, true),
+            Some(limit) if limit <= 0 => {
+                return Err(ToolError::new(
+                    format!("limit must be at least 1, got {limit}"),
+                    Some(format!(
+                        "Omit `limit` for the default of {DEFAULT_PAGE_LIMIT}, or pass -1 for every row when there are at most {MAX_PAGE_LIMIT}."
+                    )),
+                ));
+            }
+            Some(limit) => {
+                let limit = limit as usize;
+                (limit.min(MAX_PAGE_LIMIT), limit > MAX_PAGE_LIMIT, false)
+            }
+        };
+
+        Ok(Self {
+            limit,
+            offset,
+            limit_clamped,
+            all,
+        })
+    }
+
+    /// Rejects `limit: -1` when more rows remain after the offset than fit in one page.
+    pub(super) fn check_total(&self, total_count: usize) -> Result<(), ToolError> {
+        let remaining = total_count.saturating_sub(self.offset);
+        if self.all && remaining > MAX_PAGE_LIMIT {
+            return Err(ToolError::new(
+                format!("limit: -1 returns at most {MAX_PAGE_LIMIT} rows, but {remaining} match"),
+                Some(format!(
+                    "Page through the results with `limit` (at most {MAX_PAGE_LIMIT}) and `offset` instead."
+                )),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Cuts the page out of rows that were filtered and sorted in memory. An offset past the
+    /// end gives an empty page.
+    pub(super) fn apply<T>(&self, rows: Vec<T>) -> Result<Vec<T>, ToolError> {
+        self.check_total(rows.len())?;
+        Ok(rows
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect())
+    }
+}
+
+/// The fields `success_paged` adds to a list response that was cut to its first page. List
+/// responses flatten this in so their output schema declares them.
+#[derive(Debug, Default, Serialize, schemars::JsonSchema)]
+pub(super) struct MorePages {
+    #[schemars(
+        description = "Cursor to pass to `fetch_more` when the list was too large to send at once. Omitted when nothing is left."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+    #[schemars(description = "Number of pages the list was split into, set with `next_cursor`")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_pages: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpFetchMoreRequest {
+    #[schemars(description = "The `next_cursor` value from a previous response")]
+    cursor: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpFetchMoreResponse {
+    #[schemars(
+        description = "The next items of the list the cursor came from, in the same shape as in the original response"
+    )]
+    items: Vec<Value>,
+    #[schemars(description = "Page number, where the original response was page 1")]
+    page: usize,
+    total_pages: usize,
+    #[schemars(description = "Cursor for the following page. Omitted on the last page.")]
+    next_cursor: Option<String>,
+}
+
+#[tool_router(router = paging_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "Fetch the next page of a large list response. Tools like `list_issues`, `list_workspaces` and `list_pull_requests` return a `next_cursor` when their result was too large to send at once; pass it here and keep following `next_cursor` until it is omitted.",
+        output_schema = output_schema::<McpFetchMoreResponse>()
+    )]
+    async fn fetch_more(
+        &self,
+        Parameters(McpFetchMoreRequest { cursor }): Parameters<McpFetchMoreRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let Some(page) = self.response_pages.get(&cursor) else {
+            return Ok(Self::tool_error(ToolError::new(
+                "Cursor is unknown or has expired",
+                Some("Re-run the original query to get fresh results."),
+            )));
+        };
+
+        self.success(&McpFetchMoreResponse {
+            items: page.items,
+            page: page.page,
+            total_pages: page.total_pages,
+            next_cursor: page.next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::tools::test_support::MockBackend;
+
+    fn structured(result: &CallToolResult) -> Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn oversized_lists_are_paged_through_fetch_more() {
+        let backend = MockBackend::start(Router::new()).await;
+        let mut server = backend.server();
+        server.config.max_response_bytes = 256;
+
+        let items = (0..20)
+            .map(|i| json!({ "id": i, "title": format!("Issue number {i}") }))
+            .collect::<Vec<_>>();
+        let first = structured(
+            &server
+                .success_paged(
+                    &json!({ "issues": items, "count": 20, "returned_count": 20 }),
+                    "issues",
+                )
+                .unwrap(),
+        );
+        assert_eq!(first["count"], 20);
+        assert!(first["total_pages"].as_u64().unwrap() > 1);
+        assert_eq!(
+            first["returned_count"].as_u64(),
+            first["issues"].as_array().map(|page| page.len() as u64)
+        );
+
+        let mut collected = first["issues"].as_array().unwrap().clone();
+        let mut cursor = first["next_cursor"].as_str().map(str::to_string);
+        while let Some(next) = cursor {
+            let request = serde_json::from_value(json!({ "cursor": next })).unwrap();
+            let page = structured(&server.fetch_more(Parameters(request)).await.unwrap());
+            collected.extend(page["items"].as_array().unwrap().iter().cloned());
+            cursor = page["next_cursor"].as_str().map(str::to_string);
+        }
+        assert_eq!(collected, items);
+    }
+
+    #[test]
+    fn page_params_reject_a_zero_limit_and_a_negative_offset() {
+        let error = PageParams::parse(Some(0), None).unwrap_err();
+        assert_eq!(error.message, "limit must be at least 1, got 0");
+        assert!(PageParams::parse(Some(-2), None).is_err());
+        assert!(PageParams::parse(None, Some(-1)).is_err());
+
+        let params = PageParams::parse(None, None).unwrap();
+        assert_eq!((params.limit, params.offset), (DEFAULT_PAGE_LIMIT, 0));
+        assert!(!params.limit_clamped);
+    }
+
+    #[test]
+    fn page_params_clamp_large_limits() {
+        let params = PageParams::parse(Some(1_000), None).unwrap();
+        assert_eq!(params.limit, MAX_PAGE_LIMIT);
+        assert!(params.limit_clamped);
+
+        let params = PageParams::parse(Some(MAX_PAGE_LIMIT as i32), None).unwrap();
+        assert!(!params.limit_clamped);
+    }
+
+    #[test]
+    fn page_params_return_all_rows_only_when_they_fit_in_one_page() {
+        let params = PageParams::parse(Some(-1), None).unwrap();
+        let rows = (0..MAX_PAGE_LIMIT).collect::<Vec<_>>();
+        assert_eq!(params.apply(rows.clone()).unwrap(), rows);
+
+        let error = params.apply((0..=MAX_PAGE_LIMIT).collect()).unwrap_err();
+        assert!(error.message.contains("201 match"));
+
+        // Rows before the offset don't count against the cap.
+        let params = PageParams::parse(Some(-1), Some(10)).unwrap();
+        assert_eq!(
+            params.apply((0..=MAX_PAGE_LIMIT).collect()).unwrap().len(),
+            191
+        );
+    }
+
+    #[test]
+    fn page_params_past_the_end_give_an_empty_page() {
+        let params = PageParams::parse(Some(10), Some(100)).unwrap();
+        assert!(
+            params
+                .apply((0..20).collect::<Vec<_>>())
+                .unwrap()
+                .is_empty()
+        );
+        assert!(params.check_total(20).is_ok());
+    }
+
+    #[tokio::test]
+    async fn unknown_cursor_asks_to_rerun_the_query() {
+        let backend = MockBackend::start(Router::new()).await;
+        let request = serde_json::from_value(json!({ "cursor": "stale" })).unwrap();
+        let result = backend
+            .server()
+            .fetch_more(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            structured(&result)["details"],
+            "Re-run the original query to get fresh results."
+        );
+    }
+}
@@ -0,0 +1,771 @@
+use api_types::{
+    BulkUpdateProjectStatusItem, BulkUpdateProjectStatusesRequest,
+    BulkUpdateProjectStatusesResponse, CreateProjectStatusRequest, ListIssuesResponse,
+    MutationResponse, ProjectStatus, SearchIssuesRequest, UpdateProjectStatusRequest,
+};
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{McpServer, ToolError, output_schema};
+use crate::task_server::name_matching;
+
+// Same grey as the default "Backlog" status.
+const DEFAULT_STATUS_COLOR: &str = "220 9% 46%";
+
+// Gap left between statuses when appending or renumbering, matching the web app's
+// "add status" button, so later inserts usually fit without touching other statuses.
+const SORT_ORDER_STEP: i32 = 1000;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpCreateProjectStatusRequest {
+    #[schemars(
+        description = "The project to add the status to. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(description = "Name of the new status column")]
+    name: String,
+    #[schemars(
+        description = "Column color in HSL format 'H S% L%', e.g. '217 91% 60%'. Defaults to grey."
+    )]
+    color: Option<String>,
+    #[schemars(
+        description = "Name of the existing status to place the new one directly after. Defaults to the end of the board."
+    )]
+    insert_after: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpUpdateProjectStatusRequest {
+    #[schemars(description = "The status ID to update")]
+    status_id: Uuid,
+    #[schemars(description = "New name for the status")]
+    name: Option<String>,
+    #[schemars(description = "New color in HSL format 'H S% L%'")]
+    color: Option<String>,
+    #[schemars(description = "Hide or show the column on the board")]
+    hidden: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpReorderProjectStatusesRequest {
+    #[schemars(
+        description = "The project whose statuses to reorder. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(
+        description = "Every status ID of the project, in the new board order from left to right"
+    )]
+    ordered_status_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpDeleteProjectStatusRequest {
+    #[schemars(
+        description = "The project the status belongs to. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(description = "The status ID to delete")]
+    status_id: Uuid,
+    #[schemars(
+        description = "Name of another status in the same project. Issues in the deleted status are moved there."
+    )]
+    move_issues_to_status: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+pub(super) struct StatusSummary {
+    #[schemars(description = "The status ID")]
+    id: String,
+    #[schemars(description = "The status name, as accepted by `status` parameters")]
+    name: String,
+    #[schemars(description = "Column color in HSL format 'H S% L%'")]
+    color: String,
+    #[schemars(description = "Position of the column on the board")]
+    sort_order: i32,
+    #[schemars(description = "Whether the column is hidden on the board")]
+    hidden: bool,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpCreateProjectStatusResponse {
+    project_id: String,
+    status: StatusSummary,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpUpdateProjectStatusResponse {
+    project_id: String,
+    status: StatusSummary,
+    #[schemars(
+        description = "Set when the status was hidden while issues are still in it. Those issues disappear from the board until moved."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpReorderProjectStatusesResponse {
+    project_id: String,
+    #[schemars(description = "The project's statuses in their new order")]
+    statuses: Vec<StatusSummary>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpDeleteProjectStatusResponse {
+    project_id: String,
+    deleted_status: StatusSummary,
+    #[schemars(description = "The status the deleted status's issues were moved to")]
+    moved_issues_to: StatusSummary,
+    #[schemars(description = "Number of issues moved, including archived ones")]
+    moved_issue_count: usize,
+}
+
+#[tool_router(router = project_statuses_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "Add a status column to a project board. Use `insert_after` with an existing status name to position it; otherwise it is added at the end. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpCreateProjectStatusResponse>()
+    )]
+    async fn create_project_status(
+        &self,
+        Parameters(McpCreateProjectStatusRequest {
+            project_id,
+            name,
+            color,
+            insert_after,
+        }): Parameters<McpCreateProjectStatusRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Self::err("Status name must not be empty", None);
+        }
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        // Work from the current statuses, not the cache, since positions are computed from them.
+        self.project_statuses.invalidate(project_id).await;
+        let mut statuses = match self.fetch_project_statuses(project_id).await {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        statuses.sort_by_key(|s| s.sort_order);
+
+        if let Some(existing) = statuses
+            .iter()
+            .find(|s| name_matching::names_match(&s.name, &name))
+        {
+            return Self::err(
+                format!("A status named '{}' already exists", existing.name),
+                Some(format!("status_id: {}", existing.id)),
+            );
+        }
+
+        let sort_order = match insert_after {
+            Some(after) => match self
+                .sort_order_after(project_id, &mut statuses, &after)
+                .await
+            {
+                Ok(sort_order) => sort_order,
+                Err(e) => return Ok(Self::tool_error(e)),
+            },
+            None => statuses
+                .last()
+                .map_or(0, |last| last.sort_order.saturating_add(SORT_ORDER_STEP)),
+        };
+
+        let url = self.url("/api/remote/project-statuses");
+        let payload = CreateProjectStatusRequest {
+            id: None,
+            project_id,
+            name,
+            color: color.unwrap_or_else(|| DEFAULT_STATUS_COLOR.to_string()),
+            sort_order,
+            hidden: false,
+        };
+        let response: MutationResponse<ProjectStatus> =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(r) => r,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+        self.project_statuses.invalidate(project_id).await;
+
+        self.success(&McpCreateProjectStatusResponse {
+            project_id: project_id.to_string(),
+            status: status_summary(response.data),
+        })
+    }
+
+    #[tool(
+        description = "Rename, recolor, hide or show a project status column. Hiding a status that still has issues succeeds but returns a `warning` with the number of affected issues.",
+        output_schema = output_schema::<McpUpdateProjectStatusResponse>()
+    )]
+    async fn update_project_status(
+        &self,
+        Parameters(McpUpdateProjectStatusRequest {
+            status_id,
+            name,
+            color,
+            hidden,
+        }): Parameters<McpUpdateProjectStatusRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let name = name.map(|name| name.trim().to_string());
+        if name.as_deref() == Some("") {
+            return Self::err("Status name must not be empty", None);
+        }
+        if name.is_none() && color.is_none() && hidden.is_none() {
+            return Self::err(
+                "Nothing to update",
+                Some("Pass at least one of `name`, `color` or `hidden`."),
+            );
+        }
+
+        let url = self.url(&format!("/api/remote/project-statuses/{}", status_id));
+        let payload = UpdateProjectStatusRequest {
+            name,
+            color,
+            sort_order: None,
+            hidden,
+        };
+        // Setting the same fields again is harmless, so it is safe to retry.
+        let response: MutationResponse<ProjectStatus> = match self
+            .send_idempotent_json(self.client.patch(&url).json(&payload))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let status = response.data;
+        self.project_statuses.invalidate(status.project_id).await;
+
+        let warning = if hidden == Some(true) {
+            match self
+                .count_issues_in_status(status.project_id, status.id)
+                .await
+            {
+                Ok(0) => None,
+                Ok(count) => Some(format!(
+                    "{} issue(s) are still in '{}' and are no longer shown on the board. Move them to a visible status to show them again.",
+                    count, status.name
+                )),
+                Err(e) => Some(format!(
+                    "Could not check for issues still in '{}': {}",
+                    status.name, e.message
+                )),
+            }
+        } else {
+            None
+        };
+
+        self.success(&McpUpdateProjectStatusResponse {
+            project_id: status.project_id.to_string(),
+            status: status_summary(status),
+            warning,
+        })
+    }
+
+    #[tool(
+        description = "Reorder the status columns of a project board. Pass every status ID of the project in the desired order; sort orders are recomputed from it. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpReorderProjectStatusesResponse>()
+    )]
+    async fn reorder_project_statuses(
+        &self,
+        Parameters(McpReorderProjectStatusesRequest {
+            project_id,
+            ordered_status_ids,
+        }): Parameters<McpReorderProjectStatusesRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.project_statuses.invalidate(project_id).await;
+        let statuses = match self.fetch_project_statuses(project_id).await {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if let Err(e) = check_complete_ordering(&statuses, &ordered_status_ids) {
+            return Ok(Self::tool_error(e));
+        }
+
+        let statuses = match self
+            .renumber_statuses(project_id, &ordered_status_ids)
+            .await
+        {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpReorderProjectStatusesResponse {
+            project_id: project_id.to_string(),
+            statuses: statuses.into_iter().map(status_summary).collect(),
+        })
+    }
+
+    #[tool(
+        description = "Delete a status column from a project board. Its issues, including archived ones, are moved to `move_issues_to_status` in the same project first, so none are left without a status. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpDeleteProjectStatusResponse>()
+    )]
+    async fn delete_project_status(
+        &self,
+        Parameters(McpDeleteProjectStatusRequest {
+            project_id,
+            status_id,
+            move_issues_to_status,
+        }): Parameters<McpDeleteProjectStatusRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.project_statuses.invalidate(project_id).await;
+        let statuses = match self.fetch_project_statuses(project_id).await {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let Some(status) = statuses.iter().find(|s| s.id == status_id) else {
+            return Self::err(
+                format!("Status {} is not in project {}", status_id, project_id),
+                Some(
+                    "Use `list_project_statuses` to find the status IDs of this project."
+                        .to_string(),
+                ),
+            );
+        };
+        let target_id = match self
+            .resolve_status_id(project_id, &move_issues_to_status)
+            .await
+        {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if target_id == status_id {
+            return Self::err(
+                "`move_issues_to_status` must be a different status than the one being deleted",
+                None,
+            );
+        }
+        let Some(target) = statuses.iter().find(|s| s.id == target_id) else {
+            return Self::err("The target status was not found", None);
+        };
+
+        let moved_issue_count = match self.count_issues_in_status(project_id, status_id).await {
+            Ok(count) => count,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let url = self.url(&format!(
+            "/api/remote/project-statuses/{}?move_issues_to_status_id={}",
+            status_id, target_id
+        ));
+        if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+            return Ok(Self::tool_error(e));
+        }
+        self.project_statuses.invalidate(project_id).await;
+
+        self.success(&McpDeleteProjectStatusResponse {
+            project_id: project_id.to_string(),
+            deleted_status: status_summary(status.clone()),
+            moved_issues_to: status_summary(target.clone()),
+            moved_issue_count,
+        })
+    }
+}
+
+impl McpServer {
+    // A sort order that places a new status directly after the one named `after`. Uses the
+    // midpoint of the gap to its successor, renumbering every status first if there is no gap.
+    async fn sort_order_after(
+        &self,
+        project_id: Uuid,
+        statuses: &mut Vec<ProjectStatus>,
+        after: &str,
+    ) -> Result<i32, ToolError> {
+        let index = statuses
+            .iter()
+            .position(|s| name_matching::names_match(&s.name, after))
+            .ok_or_else(|| {
+                let available: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
+                ToolError::message(format!(
+                    "Unknown status '{}'.{} Available statuses: {:?}",
+                    after,
+                    name_matching::did_you_mean(after, available.iter().copied()),
+                    available
+                ))
+            })?;
+
+        if let Some(sort_order) = sort_order_between(statuses, index) {
+            return Ok(sort_order);
+        }
+
+        let ordered_ids = statuses.iter().map(|s| s.id).collect::<Vec<_>>();
+        *statuses = self.renumber_statuses(project_id, &ordered_ids).await?;
+        sort_order_between(statuses, index)
+            .ok_or_else(|| ToolError::message("Could not find a position for the new status"))
+    }
+
+    // Gives the statuses evenly spaced sort orders in the given order, in one transaction.
+    async fn renumber_statuses(
+        &self,
+        project_id: Uuid,
+        ordered_status_ids: &[Uuid],
+    ) -> Result<Vec<ProjectStatus>, ToolError> {
+        let updates = ordered_status_ids
+            .iter()
+            .zip((0..).step_by(SORT_ORDER_STEP as usize))
+            .map(|(id, sort_order)| BulkUpdateProjectStatusItem {
+                id: *id,
+                changes: UpdateProjectStatusRequest {
+                    name: None,
+                    color: None,
+                    sort_order: Some(sort_order),
+                    hidden: None,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let url = self.url("/api/remote/project-statuses/bulk");
+        let payload = BulkUpdateProjectStatusesRequest { updates };
+        // Every status gets an absolute sort order, so repeating the request is harmless.
+        let response: BulkUpdateProjectStatusesResponse = self
+            .send_idempotent_json(self.client.post(&url).json(&payload))
+            .await?;
+        self.project_statuses.invalidate(project_id).await;
+
+        let mut statuses = response.data;
+        statuses.sort_by_key(|s| s.sort_order);
+        Ok(statuses)
+    }
+
+    async fn count_issues_in_status(
+        &self,
+        project_id: Uuid,
+        status_id: Uuid,
+    ) -> Result<usize, ToolError> {
+        let request = SearchIssuesRequest {
+            project_id,
+            status_id: Some(status_id),
+            limit: Some(1),
+            ..Default::default()
+        };
+        let url = self.url("/api/remote/issues/search");
+        let response: ListIssuesResponse = self
+            .send_json(self.client.post(&url).json(&request))
+            .await?;
+        Ok(response.total_count)
+    }
+}
+
+// A sort order strictly between the status at `index` and the next one, or one step past
+// it for the last status. `statuses` must be sorted by sort order.
+fn sort_order_between(statuses: &[ProjectStatus], index: usize) -> Option<i32> {
+    let before = statuses.get(index)?.sort_order;
+    match statuses.get(index + 1) {
+        Some(next) if next.sort_order - before > 1 => Some(before + (next.sort_order - before) / 2),
+        Some(_) => None,
+        None => before.checked_add(SORT_ORDER_STEP),
+    }
+}
+
+// The ordering must list each of the project's statuses exactly once.
+fn check_complete_ordering(
+    statuses: &[ProjectStatus],
+    ordered_status_ids: &[Uuid],
+) -> Result<(), ToolError> {
+    let unknown = ordered_status_ids
+        .iter()
+        .filter(|id| !statuses.iter().any(|s| s.id == **id))
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        return Err(ToolError::new(
+            "ordered_status_ids contains statuses that are not in this project",
+            Some(format!("Unknown status IDs: {}", unknown.join(", "))),
+        ));
+    }
+
+    let missing = statuses
+        .iter()
+        .filter(|s| !ordered_status_ids.contains(&s.id))
+        .map(|s| format!("'{}' ({})", s.name, s.id))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(ToolError::new(
+            "ordered_status_ids must list every status of the project",
+            Some(format!("Missing statuses: {}", missing.join(", "))),
+        ));
+    }
+
+    if ordered_status_ids.len() != statuses.len() {
+        return Err(ToolError::message(
+            "ordered_status_ids lists the same status more than once",
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn status_summary(status: ProjectStatus) -> StatusSummary {
+    StatusSummary {
+        id: status.id.to_string(),
+        name: status.name,
+        color: status.color,
+        sort_order: status.sort_order,
+        hidden: status.hidden,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope};
+
+    fn status_json(project_id: Uuid, id: Uuid, name: &str, sort_order: i32) -> Value {
+        json!({
+            "id": id,
+            "project_id": project_id,
+            "name": name,
+            "color": "217 91% 60%",
+            "sort_order": sort_order,
+            "hidden": false,
+            "created_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    fn structured(result: &CallToolResult) -> Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_project_status_inserts_between_neighbours() {
+        let project_id = Uuid::new_v4();
+        let statuses = vec![
+            status_json(project_id, Uuid::new_v4(), "To do", 0),
+            status_json(project_id, Uuid::new_v4(), "In progress", 1000),
+        ];
+
+        let router = Router::new().route(
+            "/api/remote/project-statuses",
+            get(move || {
+                let statuses = statuses.clone();
+                async move { envelope(json!({ "project_statuses": statuses })) }
+            })
+            .post(move |Json(body): Json<Value>| async move {
+                assert_eq!(body["sort_order"], 500);
+                assert_eq!(body["color"], DEFAULT_STATUS_COLOR);
+                let status = status_json(project_id, Uuid::new_v4(), "Blocked", 500);
+                envelope(json!({ "data": status, "txid": 1 }))
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "name": "Blocked",
+            "insert_after": "to-do",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .create_project_status(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(structured(&result)["status"]["sort_order"], 500);
+        assert_eq!(backend.requests_to("/api/remote/project-statuses/bulk"), 0);
+    }
+
+    #[tokio::test]
+    async fn create_project_status_renumbers_when_there_is_no_gap() {
+        let project_id = Uuid::new_v4();
+        let (todo_id, doing_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let statuses = vec![
+            status_json(project_id, todo_id, "To do", 0),
+            status_json(project_id, doing_id, "In progress", 1),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(move || {
+                    let statuses = statuses.clone();
+                    async move { envelope(json!({ "project_statuses": statuses })) }
+                })
+                .post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["sort_order"], 500);
+                    let status = status_json(project_id, Uuid::new_v4(), "Blocked", 500);
+                    envelope(json!({ "data": status, "txid": 2 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses/bulk",
+                post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["updates"][0]["id"], json!(todo_id));
+                    assert_eq!(body["updates"][1]["sort_order"], 1000);
+                    envelope(json!({
+                        "data": [
+                            status_json(project_id, todo_id, "To do", 0),
+                            status_json(project_id, doing_id, "In progress", 1000),
+                        ],
+                        "txid": 1,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "name": "Blocked",
+            "insert_after": "To do",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .create_project_status(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(backend.requests_to("/api/remote/project-statuses/bulk"), 1);
+    }
+
+    #[tokio::test]
+    async fn hiding_a_status_with_issues_warns_with_the_count() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses/{status_id}",
+                axum::routing::patch(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body, json!({ "hidden": true }));
+                    let mut status = status_json(project_id, status_id, "Blocked", 500);
+                    status["hidden"] = json!(true);
+                    envelope(json!({ "data": status, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["status_id"], json!(status_id));
+                    envelope(json!({ "issues": [], "total_count": 3, "limit": 1, "offset": 0 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "status_id": status_id, "hidden": true })).unwrap();
+        let result = backend
+            .server()
+            .update_project_status(Parameters(request))
+            .await
+            .unwrap();
+
+        let warning = structured(&result)["warning"].as_str().unwrap().to_string();
+        assert!(warning.starts_with("3 issue(s) are still in 'Blocked'"));
+    }
+
+    #[tokio::test]
+    async fn delete_project_status_moves_issues_to_the_named_status() {
+        let project_id = Uuid::new_v4();
+        let (todo_id, blocked_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let statuses = vec![
+            status_json(project_id, todo_id, "To do", 0),
+            status_json(project_id, blocked_id, "Blocked", 1000),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(move || {
+                    let statuses = statuses.clone();
+                    async move { envelope(json!({ "project_statuses": statuses })) }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses/{status_id}",
+                axum::routing::delete(
+                    move |axum::extract::RawQuery(query): axum::extract::RawQuery| async move {
+                        assert_eq!(query, Some(format!("move_issues_to_status_id={todo_id}")));
+                        envelope(json!(null))
+                    },
+                ),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move || async move {
+                    envelope(json!({ "issues": [], "total_count": 2, "limit": 1, "offset": 0 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "status_id": blocked_id,
+            "move_issues_to_status": "blocked",
+        }))
+        .unwrap();
+        let result = server
+            .delete_project_status(Parameters(request))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/project-statuses/{blocked_id}")),
+            0
+        );
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "status_id": blocked_id,
+            "move_issues_to_status": "todo",
+        }))
+        .unwrap();
+        let result = server
+            .delete_project_status(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body = structured(&result);
+        assert_eq!(body["deleted_status"]["name"], "Blocked");
+        assert_eq!(body["moved_issues_to"]["name"], "To do");
+        assert_eq!(body["moved_issue_count"], 2);
+    }
+
+    #[test]
+    fn reorder_requires_every_status_exactly_once() {
+        let project_id = Uuid::new_v4();
+        let statuses = [Uuid::new_v4(), Uuid::new_v4()]
+            .into_iter()
+            .enumerate()
+            .map(|(i, id)| {
+                serde_json::from_value::<ProjectStatus>(status_json(
+                    project_id,
+                    id,
+                    &format!("Status {i}"),
+                    i as i32,
+                ))
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let (a, b) = (statuses[0].id, statuses[1].id);
+
+        assert!(check_complete_ordering(&statuses, &[b, a]).is_ok());
+        assert!(check_complete_ordering(&statuses, &[b]).is_err());
+        assert!(check_complete_ordering(&statuses, &[b, a, a]).is_err());
+        assert!(check_complete_ordering(&statuses, &[b, a, Uuid::new_v4()]).is_err());
+    }
+}
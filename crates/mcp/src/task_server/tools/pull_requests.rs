@@ -0,0 +1,701 @@
+use std::collections::HashMap;
+
+use api_types::{
+    CreatePullRequestIssueRequest, ListIssuesResponse, ListPullRequestIssuesResponse,
+    ListPullRequestsResponse, MutationResponse, PullRequest, PullRequestIssue, PullRequestStatus,
+};
+use reqwest::StatusCode;
+use rmcp::{
+    ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
+    tool_router,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{McpServer, ToolError, output_schema, paging::MorePages};
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListPullRequestsRequest {
+    #[schemars(
+        description = "Issue ID to list pull requests for. Takes precedence over project_id."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "Project ID to list pull requests for. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(
+        description = "Only return pull requests with this status: 'open', 'merged', or 'closed'"
+    )]
+    status: Option<PullRequestStatus>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct PullRequestListItem {
+    #[schemars(description = "Pull request ID")]
+    id: String,
+    #[schemars(description = "PR number")]
+    number: i32,
+    #[schemars(description = "URL of the pull request")]
+    url: String,
+    #[schemars(description = "Status of the pull request: 'open', 'merged', or 'closed'")]
+    status: PullRequestStatus,
+    #[schemars(description = "Target branch for the PR")]
+    target_branch_name: String,
+    #[schemars(description = "When the PR was merged, if applicable")]
+    merged_at: Option<String>,
+    #[schemars(description = "Last update timestamp")]
+    updated_at: String,
+    #[schemars(description = "Owning issue ID")]
+    issue_id: String,
+    #[schemars(description = "Owning issue simple ID (e.g. 'VK-12'), if known")]
+    issue_simple_id: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, schemars::JsonSchema)]
+struct PullRequestStatusCounts {
+    open: usize,
+    merged: usize,
+    closed: usize,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListPullRequestsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_id: Option<String>,
+    pull_requests: Vec<PullRequestListItem>,
+    count: usize,
+    #[schemars(description = "Number of pull requests per status, before the status filter")]
+    status_counts: PullRequestStatusCounts,
+    #[serde(flatten)]
+    more_pages: MorePages,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetPullRequestRequest {
+    #[schemars(description = "Pull request ID to retrieve")]
+    pull_request_id: Uuid,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct PullRequestDetails {
+    #[schemars(description = "Pull request ID")]
+    id: String,
+    #[schemars(description = "PR number")]
+    number: i32,
+    #[schemars(description = "URL of the pull request")]
+    url: String,
+    #[schemars(description = "Status of the pull request: 'open', 'merged', or 'closed'")]
+    status: PullRequestStatus,
+    #[schemars(description = "Target branch for the PR")]
+    target_branch_name: String,
+    #[schemars(description = "When the PR was merged, if applicable")]
+    merged_at: Option<String>,
+    #[schemars(description = "Merge commit SHA, if merged")]
+    merge_commit_sha: Option<String>,
+    #[schemars(description = "Project ID")]
+    project_id: String,
+    #[schemars(description = "Owning issue ID")]
+    issue_id: String,
+    #[schemars(description = "Owning issue simple ID (e.g. 'VK-12'), if known")]
+    issue_simple_id: Option<String>,
+    #[schemars(description = "Remote workspace ID that opened the PR, if any")]
+    workspace_id: Option<String>,
+    #[schemars(description = "Creation timestamp")]
+    created_at: String,
+    #[schemars(description = "Last update timestamp")]
+    updated_at: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpGetPullRequestResponse {
+    pull_request: PullRequestDetails,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpLinkPullRequestRequest {
+    #[schemars(description = "Issue the pull request belongs to")]
+    issue_id: Uuid,
+    #[schemars(description = "URL of the pull request")]
+    url: String,
+    #[schemars(description = "PR number")]
+    number: i32,
+    #[schemars(description = "Branch the PR merges into, e.g. 'main'")]
+    target_branch_name: String,
+    #[schemars(
+        description = "Status of the pull request: 'open', 'merged', or 'closed'. Default: 'open'."
+    )]
+    status: Option<PullRequestStatus>,
+    #[schemars(
+        description = "Optional caller-chosen UUID for the new issue link. Reuse it when retrying."
+    )]
+    client_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpLinkPullRequestResponse {
+    #[schemars(description = "ID of the link between the pull request and the issue")]
+    pull_request_issue_id: String,
+    pull_request_id: String,
+    issue_id: String,
+    #[schemars(description = "Issue simple ID (e.g. 'VK-12')")]
+    issue_simple_id: String,
+    number: i32,
+    url: String,
+    status: PullRequestStatus,
+}
+
+#[tool_router(router = pull_requests_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "List pull requests for an issue or a whole project, open PRs first and then most recently updated. Pass `issue_id`, or `project_id` (optional if running inside a workspace linked to a remote project). Filter with `status` ('open', 'merged', 'closed'). The response includes counts per status. Very large results return only their first page plus a `next_cursor` to pass to `fetch_more`.",
+        output_schema = output_schema::<McpListPullRequestsResponse>()
+    )]
+    async fn list_pull_requests(
+        &self,
+        Parameters(McpListPullRequestsRequest {
+            issue_id,
+            project_id,
+            status,
+        }): Parameters<McpListPullRequestsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let (pull_requests, simple_ids, project_id) = if let Some(issue_id) = issue_id {
+            let issue = match self.fetch_issue(issue_id).await {
+                Ok(issue) => issue,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+            let url = self.url(&format!("/api/remote/pull-requests?issue_id={}", issue_id));
+            let response: ListPullRequestsResponse =
+                match self.send_json(self.client.get(&url)).await {
+                    Ok(response) => response,
+                    Err(e) => return Ok(Self::tool_error(e)),
+                };
+            let simple_ids = HashMap::from([(issue.id, issue.simple_id)]);
+            (response.pull_requests, simple_ids, None)
+        } else {
+            let project_id = match self.resolve_project_id(project_id).await {
+                Ok(id) => id,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+            let pull_requests = match self.fetch_project_pull_requests(project_id).await {
+                Ok(pull_requests) => pull_requests,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+            let simple_ids = self.fetch_issue_simple_ids(project_id).await;
+            (pull_requests, simple_ids, Some(project_id))
+        };
+
+        let mut status_counts = PullRequestStatusCounts::default();
+        for pr in &pull_requests {
+            match pr.status {
+                PullRequestStatus::Open => status_counts.open += 1,
+                PullRequestStatus::Merged => status_counts.merged += 1,
+                PullRequestStatus::Closed => status_counts.closed += 1,
+            }
+        }
+
+        let mut pull_requests = pull_requests
+            .into_iter()
+            .filter(|pr| status.is_none_or(|status| pr.status == status))
+            .collect::<Vec<_>>();
+        pull_requests.sort_by(|a, b| {
+            (b.status == PullRequestStatus::Open)
+                .cmp(&(a.status == PullRequestStatus::Open))
+                .then_with(|| b.updated_at.cmp(&a.updated_at))
+        });
+
+        let pull_requests = pull_requests
+            .into_iter()
+            .map(|pr| {
+                let issue_id = issue_id.unwrap_or_else(|| owning_issue_id(&pr));
+                PullRequestListItem {
+                    id: pr.id.to_string(),
+                    number: pr.number,
+                    url: pr.url,
+                    status: pr.status,
+                    target_branch_name: pr.target_branch_name,
+                    merged_at: pr.merged_at.map(|dt| dt.to_rfc3339()),
+                    updated_at: pr.updated_at.to_rfc3339(),
+                    issue_id: issue_id.to_string(),
+                    issue_simple_id: simple_ids.get(&issue_id).cloned(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.success_paged(
+            &McpListPullRequestsResponse {
+                issue_id: issue_id.map(|id| id.to_string()),
+                project_id: project_id.map(|id| id.to_string()),
+                count: pull_requests.len(),
+                status_counts,
+                pull_requests,
+                more_pages: MorePages::default(),
+            },
+            "pull_requests",
+        )
+    }
+
+    #[tool(
+        description = "Get the full record of a pull request by its ID.",
+        output_schema = output_schema::<McpGetPullRequestResponse>()
+    )]
+    async fn get_pull_request(
+        &self,
+        Parameters(McpGetPullRequestRequest { pull_request_id }): Parameters<
+            McpGetPullRequestRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/remote/pull-requests/{}", pull_request_id));
+        let pr: PullRequest = match self.send_json(self.client.get(&url)).await {
+            Ok(pr) => pr,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let issue_id = owning_issue_id(&pr);
+        let issue_simple_id = self
+            .fetch_issue(issue_id)
+            .await
+            .ok()
+            .map(|issue| issue.simple_id);
+
+        self.success(&McpGetPullRequestResponse {
+            pull_request: PullRequestDetails {
+                id: pr.id.to_string(),
+                number: pr.number,
+                url: pr.url,
+                status: pr.status,
+                target_branch_name: pr.target_branch_name,
+                merged_at: pr.merged_at.map(|dt| dt.to_rfc3339()),
+                merge_commit_sha: pr.merge_commit_sha,
+                project_id: pr.project_id.to_string(),
+                issue_id: issue_id.to_string(),
+                issue_simple_id,
+                workspace_id: pr.workspace_id.map(|id| id.to_string()),
+                created_at: pr.created_at.to_rfc3339(),
+                updated_at: pr.updated_at.to_rfc3339(),
+            },
+        })
+    }
+
+    #[tool(
+        description = "Record that an existing pull request belongs to an issue. The pull request is created in the issue's project if it isn't tracked yet. Linking a PR that is already linked to the issue, by URL or by number in the same repository, fails with a conflict.",
+        output_schema = output_schema::<McpLinkPullRequestResponse>()
+    )]
+    async fn link_pull_request(
+        &self,
+        Parameters(McpLinkPullRequestRequest {
+            issue_id,
+            url,
+            number,
+            target_branch_name,
+            status,
+            client_id,
+        }): Parameters<McpLinkPullRequestRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = url.trim().to_string();
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Self::err(format!("Invalid pull request URL '{}'", url), None);
+        }
+        if number <= 0 {
+            return Self::err("number must be a positive PR number", None);
+        }
+        let target_branch_name = target_branch_name.trim().to_string();
+        if target_branch_name.is_empty() {
+            return Self::err("target_branch_name must not be empty", None);
+        }
+
+        let issue = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let linked_url = self.url(&format!("/api/remote/pull-requests?issue_id={}", issue_id));
+        let linked: ListPullRequestsResponse =
+            match self.send_json(self.client.get(&linked_url)).await {
+                Ok(response) => response,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+        if let Some(existing) = linked
+            .pull_requests
+            .iter()
+            .find(|pr| same_pull_request(&pr.url, pr.number, &url, number))
+        {
+            return Self::err(
+                format!(
+                    "PR #{} is already linked to {}",
+                    existing.number, issue.simple_id
+                ),
+                Some(format!(
+                    "Existing pull_request_id: {} ({})",
+                    existing.id, existing.url
+                )),
+            );
+        }
+
+        let status = status.unwrap_or(PullRequestStatus::Open);
+        let payload = CreatePullRequestIssueRequest {
+            id: client_id,
+            issue_id,
+            url: url.clone(),
+            number,
+            status,
+            merged_at: None,
+            merge_commit_sha: None,
+            target_branch_name,
+        };
+        let create_url = self.url("/api/remote/pull-request-issues");
+        let response: MutationResponse<PullRequestIssue> = match self
+            .send_json(self.client.post(&create_url).json(&payload))
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpLinkPullRequestResponse {
+            pull_request_issue_id: response.data.id.to_string(),
+            pull_request_id: response.data.pull_request_id.to_string(),
+            issue_id: issue_id.to_string(),
+            issue_simple_id: issue.simple_id,
+            number,
+            url,
+            status,
+        })
+    }
+}
+
+impl McpServer {
+    async fn fetch_project_pull_requests(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<PullRequest>, ToolError> {
+        let url = self.url(&format!(
+            "/api/remote/pull-requests?project_id={}",
+            project_id
+        ));
+        let response: ListPullRequestsResponse = self.send_json(self.client.get(&url)).await?;
+        Ok(response.pull_requests)
+    }
+
+    /// Records a freshly opened PR against an issue. The server also syncs new PRs to the
+    /// remote workspace's issue in the background, so a PR that is already linked, or that the
+    /// sync links first, counts as recorded.
+    pub(super) async fn record_pull_request_on_issue(
+        &self,
+        issue_id: Uuid,
+        url: &str,
+        number: i32,
+        target_branch_name: &str,
+    ) -> Result<(), ToolError> {
+        let linked_url = self.url(&format!("/api/remote/pull-requests?issue_id={}", issue_id));
+        let linked: ListPullRequestsResponse = self.send_json(self.client.get(&linked_url)).await?;
+        if linked
+            .pull_requests
+            .iter()
+            .any(|pr| same_pull_request(&pr.url, pr.number, url, number))
+        {
+            return Ok(());
+        }
+
+        let payload = CreatePullRequestIssueRequest {
+            id: None,
+            issue_id,
+            url: url.to_string(),
+            number,
+            status: PullRequestStatus::Open,
+            merged_at: None,
+            merge_commit_sha: None,
+            target_branch_name: target_branch_name.to_string(),
+        };
+        let create_url = self.url("/api/remote/pull-request-issues");
+        match self
+            .send_json::<MutationResponse<PullRequestIssue>>(
+                self.client.post(&create_url).json(&payload),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The pull requests linked to each issue in a project, from two requests rather than one
+    /// per issue. Issues without pull requests are left out of the map.
+    pub(super) async fn fetch_pull_requests_by_issue(
+        &self,
+        project_id: Uuid,
+    ) -> Result<HashMap<Uuid, Vec<PullRequest>>, ToolError> {
+        let links_url = self.url(&format!(
+            "/api/remote/pull-request-issues?project_id={}",
+            project_id
+        ));
+        let (pull_requests, links) = tokio::join!(
+            self.fetch_project_pull_requests(project_id),
+            self.send_json::<ListPullRequestIssuesResponse>(self.client.get(&links_url)),
+        );
+        let pull_requests = pull_requests?
+            .into_iter()
+            .map(|pr| (pr.id, pr))
+            .collect::<HashMap<_, _>>();
+
+        let mut by_issue: HashMap<Uuid, Vec<PullRequest>> = HashMap::new();
+        for link in links?.pull_request_issues {
+            if let Some(pr) = pull_requests.get(&link.pull_request_id) {
+                by_issue.entry(link.issue_id).or_default().push(pr.clone());
+            }
+        }
+        Ok(by_issue)
+    }
+
+    // Simple IDs are only used to decorate the response, so a failed lookup is not fatal.
+    async fn fetch_issue_simple_ids(&self, project_id: Uuid) -> HashMap<Uuid, String> {
+        let url = self.url(&format!("/api/remote/issues?project_id={}", project_id));
+        match self
+            .send_json::<ListIssuesResponse>(self.client.get(&url))
+            .await
+        {
+            Ok(response) => response
+                .issues
+                .into_iter()
+                .map(|issue| (issue.id, issue.simple_id))
+                .collect(),
+            Err(_) => HashMap::new(),
+        }
+    }
+}
+
+// Whether two PR references name the same pull request: the same URL, or the same number
+// in the same repository.
+fn same_pull_request(a_url: &str, a_number: i32, b_url: &str, b_number: i32) -> bool {
+    let normalize = |url: &str| url.trim().trim_end_matches('/').to_ascii_lowercase();
+    let repository = |url: &str| {
+        normalize(url)
+            .rsplit_once('/')
+            .and_then(|(rest, _)| rest.rsplit_once('/'))
+            .map(|(repository, _)| repository.to_string())
+    };
+    normalize(a_url) == normalize(b_url)
+        || (a_number == b_number && repository(a_url) == repository(b_url))
+}
+
+// The issue a PR was originally opened for; later links live in `pull_request_issues`.
+#[allow(deprecated)]
+fn owning_issue_id(pr: &PullRequest) -> Uuid {
+    pr.issue_id
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        extract::Query,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, issue_json};
+
+    fn pull_request_json(
+        project_id: Uuid,
+        issue_id: Uuid,
+        number: i32,
+        status: &str,
+        updated_at: &str,
+    ) -> Value {
+        json!({
+            "id": Uuid::new_v4(),
+            "url": format!("https://github.com/acme/app/pull/{number}"),
+            "number": number,
+            "status": status,
+            "merged_at": null,
+            "merge_commit_sha": null,
+            "target_branch_name": "main",
+            "project_id": project_id,
+            "issue_id": issue_id,
+            "workspace_id": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": updated_at,
+        })
+    }
+
+    // The issue VK-7 with a fixed ID, as the PRs here link to it.
+    fn issue_7_json(project_id: Uuid, issue_id: Uuid) -> Value {
+        let mut issue = issue_json(project_id, Uuid::new_v4(), 7);
+        issue["id"] = json!(issue_id);
+        issue
+    }
+
+    #[tokio::test]
+    async fn list_pull_requests_sorts_open_first_and_counts_statuses() {
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let pull_requests = vec![
+            pull_request_json(project_id, issue_id, 1, "merged", "2026-01-05T00:00:00Z"),
+            pull_request_json(project_id, issue_id, 2, "open", "2026-01-02T00:00:00Z"),
+            pull_request_json(project_id, issue_id, 3, "merged", "2026-01-03T00:00:00Z"),
+            pull_request_json(project_id, issue_id, 4, "open", "2026-01-04T00:00:00Z"),
+        ];
+
+        let router = Router::new()
+            .route(
+                "/api/remote/pull-requests",
+                get(move |Query(query): Query<HashMap<String, String>>| {
+                    let pull_requests = pull_requests.clone();
+                    async move {
+                        assert_eq!(query.get("project_id"), Some(&project_id.to_string()));
+                        envelope(json!({ "pull_requests": pull_requests }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issues",
+                get(move || async move {
+                    envelope(json!({
+                        "issues": [issue_7_json(project_id, issue_id)],
+                        "total_count": 1,
+                        "limit": 50,
+                        "offset": 0,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .list_pull_requests(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let numbers = body["pull_requests"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|pr| pr["number"].as_i64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![4, 2, 1, 3]);
+        assert_eq!(body["status_counts"]["open"], 2);
+        assert_eq!(body["status_counts"]["merged"], 2);
+        assert_eq!(body["pull_requests"][0]["issue_simple_id"], "VK-7");
+    }
+
+    #[tokio::test]
+    async fn get_pull_request_looks_up_a_bare_id() {
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let pr = pull_request_json(project_id, issue_id, 42, "merged", "2026-01-02T00:00:00Z");
+        let pr_id: Uuid = serde_json::from_value(pr["id"].clone()).unwrap();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/pull-requests/{pull_request_id}",
+                get(move || async move { envelope(pr) }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || async move { envelope(issue_7_json(project_id, issue_id)) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "pull_request_id": pr_id })).unwrap();
+        let result = backend
+            .server()
+            .get_pull_request(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["pull_request"]["number"], 42);
+        assert_eq!(body["pull_request"]["project_id"], project_id.to_string());
+        assert_eq!(body["pull_request"]["issue_simple_id"], "VK-7");
+        assert_eq!(backend.requests_to("/api/remote/pull-requests"), 0);
+    }
+
+    #[test]
+    fn same_pull_request_matches_by_url_or_number_in_a_repository() {
+        let url = "https://github.com/acme/app/pull/42";
+        assert!(same_pull_request(
+            url,
+            42,
+            "https://github.com/Acme/app/pull/42/",
+            42
+        ));
+        assert!(!same_pull_request(
+            url,
+            42,
+            "https://github.com/acme/api/pull/42",
+            42
+        ));
+        assert!(!same_pull_request(
+            url,
+            42,
+            "https://github.com/acme/app/pull/43",
+            43
+        ));
+    }
+
+    #[tokio::test]
+    async fn link_pull_request_refuses_a_pr_already_on_the_issue() {
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let linked = pull_request_json(project_id, issue_id, 42, "open", "2026-01-02T00:00:00Z");
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || async move { envelope(issue_7_json(project_id, issue_id)) }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(move || async move { envelope(json!({ "pull_requests": [linked] })) }),
+            )
+            .route(
+                "/api/remote/pull-request-issues",
+                post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["status"], "open");
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "pull_request_id": Uuid::new_v4(),
+                            "issue_id": body["issue_id"],
+                        },
+                        "txid": 1,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "url": "https://github.com/acme/app/pull/42",
+            "number": 42,
+            "target_branch_name": "main",
+        }))
+        .unwrap();
+        let result = server.link_pull_request(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["error"], "PR #42 is already linked to VK-7");
+        assert_eq!(backend.requests_to("/api/remote/pull-request-issues"), 0);
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "url": "https://github.com/acme/app/pull/43",
+            "number": 43,
+            "target_branch_name": "main",
+        }))
+        .unwrap();
+        let result = server.link_pull_request(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue_simple_id"], "VK-7");
+        assert_eq!(body["number"], 43);
+        assert_eq!(backend.requests_to("/api/remote/pull-request-issues"), 1);
+    }
+}
@@ -1,19 +1,218 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    str::FromStr,
+};
 
 use api_types::{
     CreateIssueRequest, Issue, IssuePriority, IssueRelationshipType, IssueSortField,
-    ListIssueRelationshipsResponse, ListIssueTagsResponse, ListIssuesResponse,
-    ListPullRequestsResponse, ListTagsResponse, MutationResponse, PullRequestStatus,
-    SearchIssuesRequest, SortDirection, UpdateIssueRequest,
+    ListIssueAssigneesResponse, ListIssueRelationshipsResponse, ListIssueTagsResponse,
+    ListIssuesResponse, ListProjectsResponse, ListPullRequestsResponse, ListTagsResponse,
+    MutationResponse, Project, ProjectStatus, PullRequest, PullRequestStatus, SearchIssuesRequest,
+    SortDirection, UpdateIssueRequest,
 };
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use futures::{StreamExt, stream};
+use reqwest::StatusCode;
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
 use uuid::Uuid;
 
-use super::{McpServer, ToolError};
+use super::{
+    McpServer, ToolError, output_schema,
+    paging::{MorePages, PageParams},
+};
+use crate::task_server::name_matching;
+
+// Upper bound on concurrent per-issue pull request lookups in the `list_issues` fallback.
+const PULL_REQUEST_FETCH_CONCURRENCY: usize = 8;
+// Upper bound on concurrent per-project searches in `search_issues`.
+const PROJECT_SEARCH_CONCURRENCY: usize = 4;
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+const DEFAULT_MY_ISSUES_LIMIT: usize = 50;
+const MAX_SEARCH_LIMIT: usize = 100;
+// Upper bound on issues touched by one `bulk_update_issues` call, and on concurrent updates.
+const MAX_BULK_ISSUES: usize = 50;
+const BULK_UPDATE_CONCURRENCY: usize = 8;
+// Page size when `fetch_all_issues` walks every page of a search.
+const ISSUE_PAGE_SIZE: i32 = 100;
+// Gap between issues when `move_issue` renumbers a column, as the board's drag and drop does.
+const ISSUE_SORT_ORDER_STEP: f64 = 1.0;
+// How far up the parent chain `update_issue` looks for cycles before refusing the move.
+const MAX_PARENT_DEPTH: usize = 50;
+const DEFAULT_ISSUE_TREE_DEPTH: usize = 5;
+const MAX_ISSUE_TREE_DEPTH: usize = 20;
+
+const ISSUE_SORT_FIELDS: &[(&str, IssueSortField)] = &[
+    ("sort_order", IssueSortField::SortOrder),
+    ("updated_at", IssueSortField::UpdatedAt),
+    ("created_at", IssueSortField::CreatedAt),
+    ("priority", IssueSortField::Priority),
+    ("title", IssueSortField::Title),
+    ("simple_id", IssueSortField::SimpleId),
+    ("target_date", IssueSortField::TargetDate),
+];
+
+const PRIORITY_VALUES: [&str; 4] = ["urgent", "high", "medium", "low"];
+
+// Field descriptions for the tools that check an issue's project against the workspace's.
+const STRICT_CONTEXT_DESCRIPTION: &str = "Fail instead of warning when the issue's project differs from the workspace's linked project. Defaults to VK_MCP_STRICT_CONTEXT.";
+const CONTEXT_WARNING_DESCRIPTION: &str = "Set when the issue's project differs from the workspace's linked project. Check that this was intended.";
+
+// Spellings agents commonly reach for, mapped onto the canonical values.
+const PRIORITY_ALIASES: &[(&str, IssuePriority)] = &[
+    ("critical", IssuePriority::Urgent),
+    ("p0", IssuePriority::Urgent),
+    ("p1", IssuePriority::High),
+    ("normal", IssuePriority::Medium),
+    ("default", IssuePriority::Medium),
+    ("p2", IssuePriority::Medium),
+    ("minor", IssuePriority::Low),
+    ("p3", IssuePriority::Low),
+];
+
+/// An issue priority in tool parameters. The schema advertises the canonical values, but
+/// the aliases in `PRIORITY_ALIASES` are accepted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct McpIssuePriority(IssuePriority);
+
+impl FromStr for McpIssuePriority {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = name_matching::normalize_name(value);
+        let priority = match normalized.as_str() {
+            "urgent" => IssuePriority::Urgent,
+            "high" => IssuePriority::High,
+            "medium" => IssuePriority::Medium,
+            "low" => IssuePriority::Low,
+            alias => PRIORITY_ALIASES
+                .iter()
+                .find(|(name, _)| *name == alias)
+                .map(|(_, priority)| *priority)
+                .ok_or_else(|| unknown_priority_message(value))?,
+        };
+        Ok(Self(priority))
+    }
+}
+
+impl<'de> Deserialize<'de> for McpIssuePriority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl schemars::JsonSchema for McpIssuePriority {
+    fn schema_name() -> Cow<'static, str> {
+        "IssuePriority".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string", "enum": PRIORITY_VALUES })
+    }
+}
+
+fn unknown_priority_message(value: &str) -> String {
+    let aliases = PRIORITY_ALIASES
+        .iter()
+        .map(|(alias, priority)| {
+            format!("'{alias}' ({})", McpServer::issue_priority_label(*priority))
+        })
+        .collect::<Vec<_>>();
+    let suggestion = name_matching::did_you_mean(
+        value,
+        PRIORITY_VALUES
+            .into_iter()
+            .chain(PRIORITY_ALIASES.iter().map(|(alias, _)| *alias)),
+    );
+    format!(
+        "Unknown priority '{}'.{} Allowed values: ['urgent', 'high', 'medium', 'low']. Also accepted: {}",
+        value,
+        suggestion,
+        aliases.join(", ")
+    )
+}
+
+/// Where `move_issue` places an issue within a status column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoardPosition {
+    Top,
+    Bottom,
+    /// 0-based index from the top of the column, counting issues other than the moved one.
+    Index(usize),
+}
+
+impl FromStr for BoardPosition {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match name_matching::normalize_name(value).as_str() {
+            "top" => Ok(Self::Top),
+            "bottom" => Ok(Self::Bottom),
+            index => index.parse().map(Self::Index).map_err(|_| {
+                format!("Invalid position '{value}'. Expected 'top', 'bottom' or an index from 0.")
+            }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BoardPosition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(value) => value.parse().map_err(D::Error::custom),
+            serde_json::Value::Number(number) => number
+                .as_u64()
+                .and_then(|index| usize::try_from(index).ok())
+                .map(Self::Index)
+                .ok_or_else(|| {
+                    D::Error::custom(format!(
+                        "Invalid position {number}. Expected 'top', 'bottom' or an index from 0."
+                    ))
+                }),
+            other => Err(D::Error::custom(format!(
+                "Invalid position {other}. Expected 'top', 'bottom' or an index from 0."
+            ))),
+        }
+    }
+}
+
+impl schemars::JsonSchema for BoardPosition {
+    fn schema_name() -> Cow<'static, str> {
+        "BoardPosition".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "anyOf": [
+                { "type": "string", "enum": ["top", "bottom"] },
+                { "type": "integer", "minimum": 0 },
+            ]
+        })
+    }
+}
+
+// Omitted leaves the priority unchanged; null or 'none' clears it.
+fn deserialize_priority_update<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Option<McpIssuePriority>>, D::Error> {
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None => Ok(Some(None)),
+        Some(value) if McpServer::is_clear_sentinel(value.trim()) => Ok(Some(None)),
+        Some(value) => value.parse().map(Some).map(Some).map_err(D::Error::custom),
+    }
+}
+
+fn priority_update_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": ["string", "null"],
+        "enum": ["urgent", "high", "medium", "low", "none", null],
+    })
+}
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpCreateIssueRequest {
@@ -25,17 +224,87 @@ struct McpCreateIssueRequest {
     title: String,
     #[schemars(description = "Optional description of the issue")]
     description: Option<String>,
-    #[schemars(
-        description = "Optional priority of the issue. Allowed values: 'urgent', 'high', 'medium', 'low'."
-    )]
-    priority: Option<String>,
+    #[schemars(description = "Optional priority of the issue")]
+    priority: Option<McpIssuePriority>,
     #[schemars(description = "Optional parent issue ID to create a subissue")]
     parent_issue_id: Option<Uuid>,
+    #[schemars(
+        description = "Optional initial status name (case-insensitive). Defaults to the project's first visible status."
+    )]
+    status: Option<String>,
+    #[schemars(description = "Optional start date as RFC3339 or YYYY-MM-DD")]
+    start_date: Option<String>,
+    #[schemars(
+        description = "Optional target (due) date as RFC3339 or YYYY-MM-DD. Must not be before the start date."
+    )]
+    target_date: Option<String>,
+    #[schemars(description = "Names of existing project tags to attach (case-insensitive)")]
+    #[serde(default)]
+    tag_names: Vec<String>,
+    #[schemars(description = "User IDs to assign to the issue")]
+    #[serde(default)]
+    assignee_user_ids: Vec<Uuid>,
+    #[schemars(
+        description = "Organization members to assign to the issue, by username, email, or user ID"
+    )]
+    #[serde(default)]
+    assignees: Vec<String>,
+    #[schemars(
+        description = "Optional caller-chosen UUID to use as the issue ID. Reuse it when retrying a create that timed out: if the issue already exists it is returned with `created: false` instead of creating a duplicate."
+    )]
+    client_id: Option<Uuid>,
+    #[schemars(description = STRICT_CONTEXT_DESCRIPTION)]
+    strict_context: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpCreateIssueResponse {
     issue_id: String,
+    issue: IssueDetails,
+    #[schemars(
+        description = "False if an issue with `client_id` already existed and was returned instead"
+    )]
+    created: bool,
+    #[schemars(
+        description = "Tags or assignees that could not be attached. The issue itself was created."
+    )]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+    #[schemars(description = CONTEXT_WARNING_DESCRIPTION)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpDuplicateIssueRequest {
+    #[schemars(description = "The issue to duplicate")]
+    issue_id: Uuid,
+    #[schemars(description = "Title for the copy. Defaults to the source issue's title.")]
+    new_title: Option<String>,
+    #[schemars(description = "Copy the source issue's tags (default: true)")]
+    include_tags: Option<bool>,
+    #[schemars(description = "Copy the source issue's assignees (default: false)")]
+    include_assignees: Option<bool>,
+    #[schemars(
+        description = "Also duplicate the direct sub-issues under the copy, with the same tag and assignee options (default: false). Deeper sub-issues are not copied."
+    )]
+    include_subissues: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpDuplicateIssueResponse {
+    #[schemars(description = "The issue that was duplicated")]
+    source_issue_id: String,
+    issue_id: String,
+    issue: IssueDetails,
+    #[schemars(description = "Maps each source sub-issue ID to the ID of its copy")]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    subissue_ids: BTreeMap<String, String>,
+    #[schemars(
+        description = "Tags, assignees, or sub-issues that could not be copied. The duplicate itself was created."
+    )]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -44,16 +313,18 @@ struct McpListIssuesRequest {
         description = "The ID of the project to list issues from. Optional if running inside a workspace linked to a remote project."
     )]
     project_id: Option<Uuid>,
-    #[schemars(description = "Maximum number of issues to return (default: 50)")]
+    #[schemars(
+        description = "Maximum number of issues to return (default: 50, max: 200; larger values are lowered to 200). Pass -1 for every matching issue, which fails if more than 200 match. 0 is rejected."
+    )]
     limit: Option<i32>,
-    #[schemars(description = "Number of results to skip before returning rows (default: 0)")]
+    #[schemars(
+        description = "Number of results to skip before returning rows (default: 0). An offset past the end returns no rows."
+    )]
     offset: Option<i32>,
     #[schemars(description = "Filter by status name (case-insensitive)")]
     status: Option<String>,
-    #[schemars(
-        description = "Filter by priority. Allowed values: 'urgent', 'high', 'medium', 'low'."
-    )]
-    priority: Option<String>,
+    #[schemars(description = "Filter by priority")]
+    priority: Option<McpIssuePriority>,
     #[schemars(description = "Filter by parent issue ID (subissues of this issue)")]
     parent_issue_id: Option<Uuid>,
     #[schemars(description = "Case-insensitive substring match against title and description")]
@@ -62,16 +333,43 @@ struct McpListIssuesRequest {
     simple_id: Option<String>,
     #[schemars(description = "Filter to issues assigned to this user ID")]
     assignee_user_id: Option<Uuid>,
+    #[schemars(
+        description = "Filter to issues assigned to the organization member with this username or email. Cannot be combined with assignee_user_id."
+    )]
+    assignee: Option<String>,
     #[schemars(description = "Filter to issues having this tag ID")]
     tag_id: Option<Uuid>,
     #[schemars(description = "Filter to issues having a tag with this name (case-insensitive)")]
     tag_name: Option<String>,
     #[schemars(
-        description = "Field to sort by. Allowed values: 'sort_order', 'priority', 'created_at', 'updated_at', 'title'. Default: 'sort_order'."
+        description = "Only issues created after this RFC3339 timestamp or YYYY-MM-DD date"
+    )]
+    created_after: Option<String>,
+    #[schemars(
+        description = "Only issues updated after this RFC3339 timestamp or YYYY-MM-DD date"
+    )]
+    updated_after: Option<String>,
+    #[schemars(
+        description = "Only issues completed after this RFC3339 timestamp or YYYY-MM-DD date"
+    )]
+    completed_after: Option<String>,
+    #[schemars(
+        description = "Only issues due before this RFC3339 timestamp or YYYY-MM-DD date. Issues without a target date are left out."
+    )]
+    target_date_before: Option<String>,
+    #[schemars(
+        description = "Field to sort by before paginating. Allowed values: 'sort_order' (board order), 'updated_at', 'created_at', 'priority', 'title', 'simple_id', 'target_date'. Default: 'sort_order'. Ascending 'priority' lists urgent issues first. Issues without a priority or target date come last in either direction."
     )]
-    sort_field: Option<String>,
+    #[serde(alias = "sort_field")]
+    sort_by: Option<String>,
     #[schemars(description = "Sort direction. Allowed values: 'asc', 'desc'. Default: 'asc'.")]
     sort_direction: Option<String>,
+    #[schemars(description = "Include archived issues (default: false)")]
+    include_archived: Option<bool>,
+    #[schemars(
+        description = "Also return the assignees and tag names of each issue (default: false). Adds a few requests per call, not per issue."
+    )]
+    include_details: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -82,12 +380,20 @@ struct IssueSummary {
     title: String,
     #[schemars(description = "The human-readable issue simple ID")]
     simple_id: String,
+    #[schemars(description = "Link to the issue in the Vibe Kanban web app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
     #[schemars(description = "Current status of the issue")]
     status: String,
     #[schemars(description = "Current priority of the issue")]
     priority: Option<String>,
     #[schemars(description = "Parent issue ID if this is a subissue")]
     parent_issue_id: Option<String>,
+    #[schemars(
+        description = "When the issue was archived. Omitted for issues that are not archived."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived_at: Option<String>,
     #[schemars(description = "When the issue was created")]
     created_at: String,
     #[schemars(description = "When the issue was last updated")]
@@ -100,6 +406,28 @@ struct IssueSummary {
         description = "Status of the most recent pull request: 'open', 'merged', or 'closed'"
     )]
     latest_pr_status: Option<PullRequestStatus>,
+    #[schemars(
+        description = "Usernames of the assignees, or their email or user ID when no username is known. Only set with `include_details`."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignees: Option<Vec<String>>,
+    #[schemars(description = "Names of the issue's tags. Only set with `include_details`.")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+// Which associations `duplicate_issue` copies onto each new issue.
+#[derive(Debug, Clone, Copy)]
+struct CopiedAssociations {
+    tags: bool,
+    assignees: bool,
+}
+
+// Assignee and tag names of a project's issues, for `list_issues` with `include_details`.
+#[derive(Debug, Default)]
+struct IssueDetailsByIssue {
+    assignees: HashMap<Uuid, Vec<String>>,
+    tags: HashMap<Uuid, Vec<String>>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -130,12 +458,16 @@ struct McpTagSummary {
 struct McpRelationshipSummary {
     #[schemars(description = "The relationship ID (use this to delete)")]
     id: String,
-    #[schemars(description = "The related issue ID")]
+    #[schemars(description = "The other issue in the relationship")]
     related_issue_id: String,
     #[schemars(description = "The related issue's simple ID (e.g. 'PROJ-42')")]
     related_simple_id: String,
     #[schemars(description = "Relationship type: blocking, related, or has_duplicate")]
     relationship_type: String,
+    #[schemars(
+        description = "'outgoing' if this issue is the source (e.g. it blocks the related issue), 'incoming' if it is blocked by or a duplicate of it"
+    )]
+    direction: &'static str,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -144,6 +476,9 @@ struct McpSubIssueSummary {
     id: String,
     #[schemars(description = "Short human-readable identifier (e.g. 'PROJ-43')")]
     simple_id: String,
+    #[schemars(description = "Link to the sub-issue in the Vibe Kanban web app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
     #[schemars(description = "The sub-issue title")]
     title: String,
     #[schemars(description = "Current status of the sub-issue")]
@@ -158,6 +493,9 @@ struct IssueDetails {
     title: String,
     #[schemars(description = "The human-readable issue simple ID")]
     simple_id: String,
+    #[schemars(description = "Link to the issue in the Vibe Kanban web app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
     #[schemars(description = "Optional description of the issue")]
     description: Option<String>,
     #[schemars(description = "Current status of the issue")]
@@ -174,6 +512,8 @@ struct IssueDetails {
     target_date: Option<String>,
     #[schemars(description = "Optional completion date")]
     completed_at: Option<String>,
+    #[schemars(description = "When the issue was archived, if it is archived")]
+    archived_at: Option<String>,
     #[schemars(description = "When the issue was created")]
     created_at: String,
     #[schemars(description = "When the issue was last updated")]
@@ -195,13 +535,123 @@ struct McpListIssuesResponse {
     returned_count: usize,
     limit: usize,
     offset: usize,
+    #[schemars(description = "Whether the requested limit was above the maximum and was lowered")]
+    limit_clamped: bool,
+    project_id: String,
+    #[schemars(description = "The sort field that was applied")]
+    sort_by: String,
+    #[schemars(description = "The sort direction that was applied")]
+    sort_direction: String,
+    #[schemars(description = "The date filters that were applied, normalized to RFC3339")]
+    #[serde(skip_serializing_if = "IssueDateFilters::is_empty")]
+    date_filters: IssueDateFilters,
+    #[serde(flatten)]
+    more_pages: MorePages,
+}
+
+#[derive(Debug, Default, Serialize, schemars::JsonSchema)]
+struct IssueDateFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_date_before: Option<String>,
+}
+
+impl IssueDateFilters {
+    fn is_empty(&self) -> bool {
+        self.created_after.is_none()
+            && self.updated_after.is_none()
+            && self.completed_after.is_none()
+            && self.target_date_before.is_none()
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListMyIssuesRequest {
+    #[schemars(
+        description = "The project to list issues from. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(description = "Maximum number of issues to return (default: 50)")]
+    limit: Option<usize>,
+    #[schemars(description = "Include archived issues (default: false)")]
+    include_archived: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListMyIssuesResponse {
+    #[schemars(description = "The authenticated user the issues are assigned to")]
+    user_id: String,
+    #[schemars(description = "Username of the authenticated user, when known")]
+    username: Option<String>,
+    project_id: String,
+    #[schemars(
+        description = "Issues assigned to the user, most urgent first, then most recently updated"
+    )]
+    issues: Vec<IssueSummary>,
+    total_count: usize,
+    returned_count: usize,
+    #[serde(flatten)]
+    more_pages: MorePages,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpSearchIssuesRequest {
+    #[schemars(
+        description = "The organization to search. Optional if running inside a workspace linked to a remote project."
+    )]
+    organization_id: Option<Uuid>,
+    #[schemars(description = "Case-insensitive text to find in issue titles and descriptions")]
+    query: String,
+    #[schemars(description = "Maximum number of matches to return (default: 20, max: 100)")]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct IssueSearchMatch {
+    #[schemars(description = "The project the issue belongs to")]
     project_id: String,
+    #[schemars(description = "The name of the project the issue belongs to")]
+    project_name: String,
+    #[schemars(description = "Where the query matched: 'title' or 'description'")]
+    matched_in: &'static str,
+    #[serde(flatten)]
+    issue: IssueSummary,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpSearchIssuesResponse {
+    organization_id: String,
+    query: String,
+    #[schemars(
+        description = "Matches ranked with title matches first, then most recently updated"
+    )]
+    issues: Vec<IssueSearchMatch>,
+    returned_count: usize,
+    projects_searched: usize,
+    #[schemars(description = "Projects that could not be searched. Results may be incomplete.")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpUpdateIssueRequest {
-    #[schemars(description = "The ID of the issue to update")]
-    issue_id: Uuid,
+    #[schemars(
+        description = "The ID of the issue to update. Provide either `issue_id` or `simple_id`."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "The issue's simple ID (e.g. 'ACME-123'), as an alternative to `issue_id`"
+    )]
+    simple_id: Option<String>,
+    #[schemars(
+        description = "Project to look up `simple_id` in. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
     #[schemars(description = "New title for the issue")]
     title: Option<String>,
     #[schemars(description = "New description for the issue")]
@@ -209,94 +659,433 @@ struct McpUpdateIssueRequest {
     #[schemars(description = "New status name for the issue (must match a project status name)")]
     status: Option<String>,
     #[schemars(
-        description = "New priority for the issue. Allowed values: 'urgent', 'high', 'medium', 'low'."
+        description = "New priority for the issue. Pass null or 'none' to clear it.",
+        schema_with = "priority_update_schema"
     )]
-    priority: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_priority_update")]
+    priority: Option<Option<McpIssuePriority>>,
+    #[schemars(
+        description = "Parent issue ID to set this as a subissue. Pass null or 'none' to un-nest from parent."
+    )]
+    #[serde(default, deserialize_with = "api_types::some_if_present")]
+    parent_issue_id: Option<Option<String>>,
+    #[schemars(
+        description = "Start date as RFC3339 or YYYY-MM-DD. Pass null or 'clear' to unset."
+    )]
+    #[serde(default, deserialize_with = "api_types::some_if_present")]
+    start_date: Option<Option<String>>,
+    #[schemars(
+        description = "Target date as RFC3339 or YYYY-MM-DD. Must not be before the start date. Pass null or 'clear' to unset."
+    )]
+    #[serde(default, deserialize_with = "api_types::some_if_present")]
+    target_date: Option<Option<String>>,
     #[schemars(
-        description = "Parent issue ID to set this as a subissue. Pass null to un-nest from parent."
+        description = "Completion time as RFC3339 or YYYY-MM-DD. Pass null or 'clear' to unset."
     )]
-    parent_issue_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "api_types::some_if_present")]
+    completed_at: Option<Option<String>>,
+    #[schemars(description = STRICT_CONTEXT_DESCRIPTION)]
+    strict_context: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpUpdateIssueResponse {
     issue: IssueDetails,
+    #[schemars(description = CONTEXT_WARNING_DESCRIPTION)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpDeleteIssueRequest {
-    #[schemars(description = "The ID of the issue to delete")]
-    issue_id: Uuid,
+    #[schemars(
+        description = "The ID of the issue to delete. Provide either `issue_id` or `simple_id`."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "The issue's simple ID (e.g. 'ACME-123'), as an alternative to `issue_id`"
+    )]
+    simple_id: Option<String>,
+    #[schemars(
+        description = "Project to look up `simple_id` in. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(
+        description = "Also delete the issue's sub-issues, recursively (default: false). Without it, an issue that has sub-issues is not deleted."
+    )]
+    cascade: Option<bool>,
+    #[schemars(
+        description = "Return the issues that would be deleted without deleting anything (default: false)"
+    )]
+    dry_run: Option<bool>,
+    #[schemars(
+        description = "Delete the issue for good even though it is not archived (default: false). Prefer `archive_issue`, which can be undone."
+    )]
+    permanent: Option<bool>,
+    #[schemars(description = STRICT_CONTEXT_DESCRIPTION)]
+    strict_context: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct DeletedIssueSummary {
+    issue_id: String,
+    simple_id: String,
+    parent_issue_id: Option<String>,
+}
+
+impl From<Issue> for DeletedIssueSummary {
+    fn from(issue: Issue) -> Self {
+        Self {
+            issue_id: issue.id.to_string(),
+            simple_id: issue.simple_id,
+            parent_issue_id: issue.parent_issue_id.map(|id| id.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpDeleteIssueResponse {
+    #[schemars(description = "The requested issue, once deleted. Omitted for a dry run.")]
     deleted_issue_id: Option<String>,
+    #[schemars(
+        description = "Every issue deleted, or that would be deleted for a dry run, with sub-issues before their parents"
+    )]
+    deleted_issues: Vec<DeletedIssueSummary>,
+    dry_run: bool,
+    #[schemars(description = CONTEXT_WARNING_DESCRIPTION)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
-struct McpGetIssueRequest {
-    #[schemars(description = "The ID of the issue to retrieve")]
+struct McpArchiveIssueRequest {
+    #[schemars(description = "The ID of the issue")]
     issue_id: Uuid,
+    #[schemars(description = STRICT_CONTEXT_DESCRIPTION)]
+    strict_context: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
-struct McpGetIssueResponse {
-    issue: IssueDetails,
+struct McpArchiveIssueResponse {
+    issue_id: String,
+    simple_id: String,
+    archived: bool,
+    #[schemars(description = "When the issue was archived. Null once it is restored.")]
+    archived_at: Option<String>,
+    #[schemars(description = CONTEXT_WARNING_DESCRIPTION)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpMoveIssueRequest {
+    #[schemars(description = "The ID of the issue to move")]
+    issue_id: Uuid,
+    #[schemars(
+        description = "Target status name (case-insensitive). Provide either `status` or `direction`."
+    )]
+    status: Option<String>,
+    #[schemars(
+        description = "Move relative to the current status. Allowed values: 'next', 'previous'. Hidden statuses are skipped."
+    )]
+    direction: Option<String>,
+    #[schemars(
+        description = "Where to place the issue in the target column: 'top', 'bottom', or a 0-based index. Without `status` or `direction`, reorders the issue within its current column. Omit to leave its board position as is."
+    )]
+    position: Option<BoardPosition>,
+    #[schemars(description = STRICT_CONTEXT_DESCRIPTION)]
+    strict_context: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
-struct McpListIssuePrioritiesResponse {
-    priorities: Vec<String>,
+struct McpMoveIssueResponse {
+    issue_id: String,
+    simple_id: String,
+    #[schemars(description = "Link to the issue in the Vibe Kanban web app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
+    #[schemars(description = "Status the issue was in before the move")]
+    previous_status: String,
+    #[schemars(description = "Status the issue is in now")]
+    status: String,
+    #[schemars(
+        description = "0-based index of the issue in its column, from the top. Only set when `position` was given."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<usize>,
+    #[schemars(description = CONTEXT_WARNING_DESCRIPTION)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
 }
 
-#[tool_router(router = remote_issues_tools_router, vis = "pub")]
-impl McpServer {
-    #[tool(
-        description = "Create a new issue in a project. `project_id` is optional if running inside a workspace linked to a remote project."
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpBulkIssueFilter {
+    #[schemars(
+        description = "Project to select issues from. Optional if running inside a workspace linked to a remote project."
     )]
-    async fn create_issue(
-        &self,
-        Parameters(McpCreateIssueRequest {
-            project_id,
-            title,
-            description,
-            priority,
-            parent_issue_id,
-        }): Parameters<McpCreateIssueRequest>,
-    ) -> Result<CallToolResult, ErrorData> {
-        let project_id = match self.resolve_project_id(project_id) {
-            Ok(id) => id,
-            Err(e) => return Ok(McpServer::tool_error(e)),
-        };
+    project_id: Option<Uuid>,
+    #[schemars(description = "Only issues in this status (case-insensitive name)")]
+    status: Option<String>,
+    #[schemars(description = "Only issues with this priority")]
+    priority: Option<McpIssuePriority>,
+    #[schemars(description = "Only issues having a tag with this name (case-insensitive)")]
+    tag_name: Option<String>,
+}
 
-        let expanded_description = match description {
-            Some(desc) => Some(self.expand_tags(&desc).await),
-            None => None,
-        };
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpBulkUpdateIssuesRequest {
+    #[schemars(
+        description = "Issues to update by UUID. `issue_ids` and `simple_ids` can be combined, up to 50 issues in total; otherwise use `filter`."
+    )]
+    issue_ids: Option<Vec<Uuid>>,
+    #[schemars(description = "Issues to update by simple ID, e.g. 'VK-12'")]
+    simple_ids: Option<Vec<String>>,
+    #[schemars(
+        description = "Project the `simple_ids` belong to. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(description = "Select the issues to update with filters instead of IDs")]
+    filter: Option<McpBulkIssueFilter>,
+    #[schemars(
+        description = "Required with `filter`: the number of issues the filter matches. Guards against updating more issues than intended."
+    )]
+    confirm_count: Option<usize>,
+    #[schemars(description = "New status name for every selected issue")]
+    status: Option<String>,
+    #[schemars(description = "New priority for every selected issue")]
+    priority: Option<McpIssuePriority>,
+    #[schemars(description = "Tag names to add to every selected issue")]
+    add_tag_names: Option<Vec<String>>,
+    #[schemars(description = "Tag names to remove from every selected issue")]
+    remove_tag_names: Option<Vec<String>>,
+    #[schemars(
+        description = "Users to assign to every selected issue, by username, email, or user ID"
+    )]
+    add_assignees: Option<Vec<String>>,
+    #[schemars(
+        description = "Users to unassign from every selected issue, by username, email, or user ID"
+    )]
+    remove_assignees: Option<Vec<String>>,
+}
 
-        let status_id = match self.default_status_id(project_id).await {
-            Ok(id) => id,
-            Err(e) => return Ok(McpServer::tool_error(e)),
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct BulkIssueUpdateResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    simple_id: Option<String>,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[schemars(description = "Assignees that could not be resolved for this issue's project")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpBulkUpdateIssuesResponse {
+    updated_count: usize,
+    failed_count: usize,
+    results: Vec<BulkIssueUpdateResult>,
+}
+
+// Changes for one project, with status, tag, and assignee names already resolved to IDs.
+// Assignees that did not resolve are left out and reported in `warnings`.
+#[derive(Debug, Clone, Default)]
+struct BulkIssueChanges {
+    status_id: Option<Uuid>,
+    priority: Option<IssuePriority>,
+    add_tag_ids: Vec<Uuid>,
+    remove_tag_ids: Vec<Uuid>,
+    add_assignee_ids: Vec<Uuid>,
+    remove_assignee_ids: Vec<Uuid>,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum IssueReference {
+    Id(Uuid),
+    SimpleId(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusDirection {
+    Next,
+    Previous,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetIssueRequest {
+    #[schemars(
+        description = "The ID of the issue to retrieve. Provide either `issue_id` or `simple_id`."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "The issue's simple ID (e.g. 'ACME-123'), as an alternative to `issue_id`"
+    )]
+    simple_id: Option<String>,
+    #[schemars(
+        description = "Project to look up `simple_id` in. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpGetIssueResponse {
+    issue: IssueDetails,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetIssueTreeRequest {
+    #[schemars(
+        description = "The root issue. Provide `issue_id` or `simple_id`, or neither to use the issue linked to this workspace."
+    )]
+    issue_id: Option<Uuid>,
+    #[schemars(
+        description = "The root issue's simple ID (e.g. 'ACME-123'), as an alternative to `issue_id`"
+    )]
+    simple_id: Option<String>,
+    #[schemars(
+        description = "Project to look up `simple_id` in. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+    #[schemars(
+        description = "Levels of sub-issues to include below the root (default: 5, max: 20). Deeper issues are still counted."
+    )]
+    max_depth: Option<usize>,
+    #[schemars(description = "Include archived sub-issues (default: false)")]
+    include_archived: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct IssueTreeNode {
+    #[serde(flatten)]
+    issue: IssueSummary,
+    #[schemars(description = "Direct sub-issues, in board order")]
+    children: Vec<IssueTreeNode>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpGetIssueTreeResponse {
+    root: IssueTreeNode,
+    #[schemars(description = "Sub-issues at every level below the root, shown or not")]
+    descendant_count: usize,
+    #[schemars(
+        description = "Descendants in a done-like status (e.g. 'Done', 'Completed') or a hidden column"
+    )]
+    done_count: usize,
+    #[schemars(description = "Descendants left out of the tree because of `max_depth`")]
+    hidden_by_depth_count: usize,
+    max_depth: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpListProjectStatusesRequest {
+    #[schemars(
+        description = "The ID of the project to list statuses for. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ProjectStatusSummary {
+    #[schemars(description = "The status ID")]
+    id: String,
+    #[schemars(description = "The status name, as accepted by `status` parameters")]
+    name: String,
+    #[schemars(description = "Position of the column on the board")]
+    sort_order: i32,
+    #[schemars(description = "Whether the column is hidden on the board")]
+    hidden: bool,
+    #[schemars(description = "Whether new issues are created in this status by default")]
+    is_default: bool,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListProjectStatusesResponse {
+    project_id: String,
+    statuses: Vec<ProjectStatusSummary>,
+    #[schemars(description = "Name of the status new issues are created in")]
+    default_status: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListIssuePrioritiesResponse {
+    priorities: Vec<String>,
+    #[schemars(description = "Other accepted spellings, mapped to the priority they stand for")]
+    aliases: HashMap<String, String>,
+}
+
+#[tool_router(router = remote_issues_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "Create a new issue in a project, optionally with its initial status, dates, tags, and assignees (by username, email, or user ID). `project_id` is optional if running inside a workspace linked to a remote project. Tags or assignees that cannot be attached are reported in `warnings`. Pass a `client_id` to make retries safe.",
+        output_schema = output_schema::<McpCreateIssueResponse>()
+    )]
+    async fn create_issue(
+        &self,
+        Parameters(McpCreateIssueRequest {
+            project_id,
+            title,
+            description,
+            priority,
+            parent_issue_id,
+            status,
+            start_date,
+            target_date,
+            tag_names,
+            assignee_user_ids,
+            assignees,
+            client_id,
+            strict_context,
+        }): Parameters<McpCreateIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        // A new issue has no dates to keep, so "clear" simply leaves one unset.
+        let start_date = match Self::parse_date_update("start_date", Some(start_date)) {
+            Ok(date) => date.flatten(),
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let target_date = match Self::parse_date_update("target_date", Some(target_date)) {
+            Ok(date) => date.flatten(),
+            Err(e) => return Ok(McpServer::tool_error(e)),
         };
+        if let Err(e) = Self::validate_date_range(start_date, target_date) {
+            return Ok(McpServer::tool_error(e));
+        }
 
-        let priority = match priority {
-            Some(p) => match Self::parse_issue_priority(&p) {
-                Ok(priority) => Some(priority),
-                Err(e) => return Ok(McpServer::tool_error(e)),
-            },
+        let (project_id, warning) = match self
+            .resolve_project_id_checked(project_id, strict_context)
+            .await
+        {
+            Ok(resolved) => resolved,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let expanded_description = match description {
+            Some(desc) => Some(self.expand_tags(&desc).await),
             None => None,
         };
 
+        let status_id = match status {
+            Some(status_name) => self.resolve_status_id(project_id, &status_name).await,
+            None => self.default_status_id(project_id).await,
+        };
+        let status_id = match status_id {
+            Ok(id) => id,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let priority = priority.map(|priority| priority.0);
+
         let payload = CreateIssueRequest {
-            id: None,
+            id: client_id,
             project_id,
             status_id,
             title,
             description: expanded_description,
             priority,
-            start_date: None,
-            target_date: None,
+            start_date,
+            target_date,
             completed_at: None,
             sort_order: 0.0,
             parent_issue_id,
@@ -305,19 +1094,160 @@ impl McpServer {
         };
 
         let url = self.url("/api/remote/issues");
-        let response: MutationResponse<Issue> =
-            match self.send_json(self.client.post(&url).json(&payload)).await {
-                Ok(r) => r,
-                Err(e) => return Ok(McpServer::tool_error(e)),
-            };
+        let (issue, created) = match self
+            .send_json::<MutationResponse<Issue>>(self.client.post(&url).json(&payload))
+            .await
+        {
+            Ok(response) => (response.data, true),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                let Some(client_id) = client_id else {
+                    return Ok(McpServer::tool_error(e));
+                };
+                match self.fetch_issue(client_id).await {
+                    Ok(issue) if issue.project_id == project_id => (issue, false),
+                    Ok(_) => {
+                        return McpServer::err(
+                            format!(
+                                "client_id {client_id} is already used by an issue in another project"
+                            ),
+                            None,
+                        );
+                    }
+                    Err(e) => return Ok(McpServer::tool_error(e)),
+                }
+            }
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
 
-        McpServer::success(&McpCreateIssueResponse {
-            issue_id: response.data.id.to_string(),
+        // The issue exists from here on, so attachment failures are reported rather than
+        // failing the call and leaving the agent unsure whether to retry the create. On a
+        // retry, tags and assignees the first attempt already attached are left as they are.
+        let mut warnings = self
+            .attach_tags_by_name(project_id, issue.id, &tag_names)
+            .await;
+        let mut user_ids = assignee_user_ids;
+        for assignee in &assignees {
+            match Uuid::parse_str(assignee.trim()) {
+                Ok(user_id) => user_ids.push(user_id),
+                Err(_) => match self.resolve_member_user_id(project_id, assignee).await {
+                    Ok(user_id) => user_ids.push(user_id),
+                    Err(e) => {
+                        warnings.push(format!("Could not resolve assignee '{}': {}", assignee, e))
+                    }
+                },
+            }
+        }
+        let mut seen = HashSet::new();
+        user_ids.retain(|user_id| seen.insert(*user_id));
+        for user_id in user_ids {
+            if let Err(e) = self.assign_issue_user(issue.id, user_id, None).await {
+                warnings.push(format!("Could not assign user {}: {}", user_id, e));
+            }
+        }
+
+        let details = self
+            .issue_to_details(
+                &issue,
+                ListPullRequestsResponse {
+                    pull_requests: vec![],
+                },
+            )
+            .await;
+        self.success(&McpCreateIssueResponse {
+            issue_id: issue.id.to_string(),
+            issue: details,
+            created,
+            warnings,
+            warning,
         })
     }
 
     #[tool(
-        description = "List all the issues in a project. `project_id` is optional if running inside a workspace linked to a remote project."
+        description = "Duplicate an issue into the same project and status, copying its title, description, priority, dates, and parent. Tags are copied by default; assignees and direct sub-issues on request. Associations that cannot be copied are reported in `warnings`.",
+        output_schema = output_schema::<McpDuplicateIssueResponse>()
+    )]
+    async fn duplicate_issue(
+        &self,
+        Parameters(McpDuplicateIssueRequest {
+            issue_id,
+            new_title,
+            include_tags,
+            include_assignees,
+            include_subissues,
+        }): Parameters<McpDuplicateIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let new_title = new_title.map(|title| title.trim().to_string());
+        if new_title.as_deref() == Some("") {
+            return McpServer::err("`new_title` must not be empty", None);
+        }
+        let associations = CopiedAssociations {
+            tags: include_tags.unwrap_or(true),
+            assignees: include_assignees.unwrap_or(false),
+        };
+
+        let source = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let mut warnings = Vec::new();
+        let copy = match self
+            .copy_issue(
+                &source,
+                new_title,
+                source.parent_issue_id,
+                associations,
+                &mut warnings,
+            )
+            .await
+        {
+            Ok(copy) => copy,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let mut subissue_ids = BTreeMap::new();
+        if include_subissues.unwrap_or(false) {
+            match self.fetch_child_issues(source.project_id, source.id).await {
+                Ok(children) => {
+                    for child in children {
+                        match self
+                            .copy_issue(&child, None, Some(copy.id), associations, &mut warnings)
+                            .await
+                        {
+                            Ok(child_copy) => {
+                                subissue_ids
+                                    .insert(child.id.to_string(), child_copy.id.to_string());
+                            }
+                            Err(e) => warnings.push(format!(
+                                "Could not duplicate sub-issue {}: {}",
+                                child.simple_id, e
+                            )),
+                        }
+                    }
+                }
+                Err(e) => warnings.push(format!("Could not load sub-issues: {}", e)),
+            }
+        }
+
+        let details = self
+            .issue_to_details(
+                &copy,
+                ListPullRequestsResponse {
+                    pull_requests: vec![],
+                },
+            )
+            .await;
+        self.success(&McpDuplicateIssueResponse {
+            source_issue_id: source.id.to_string(),
+            issue_id: copy.id.to_string(),
+            issue: details,
+            subissue_ids,
+            warnings,
+        })
+    }
+
+    #[tool(
+        description = "List all the issues in a project. `project_id` is optional if running inside a workspace linked to a remote project. Very large results return only their first page plus a `next_cursor` to pass to `fetch_more`.",
+        output_schema = output_schema::<McpListIssuesResponse>()
     )]
     async fn list_issues(
         &self,
@@ -331,13 +1261,44 @@ impl McpServer {
             search,
             simple_id,
             assignee_user_id,
+            assignee,
             tag_id,
             tag_name,
-            sort_field,
+            created_after,
+            updated_after,
+            completed_after,
+            target_date_before,
+            sort_by,
             sort_direction,
+            include_archived,
+            include_details,
         }): Parameters<McpListIssuesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let project_id = match self.resolve_project_id(project_id) {
+        let page_params = match PageParams::parse(limit, offset) {
+            Ok(params) => params,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let date_filter = |field, value: Option<String>| {
+            Self::parse_date_filter(field, value.as_deref()).map_err(McpServer::tool_error)
+        };
+        let created_after = match date_filter("created_after", created_after) {
+            Ok(date) => date,
+            Err(e) => return Ok(e),
+        };
+        let updated_after = match date_filter("updated_after", updated_after) {
+            Ok(date) => date,
+            Err(e) => return Ok(e),
+        };
+        let completed_after = match date_filter("completed_after", completed_after) {
+            Ok(date) => date,
+            Err(e) => return Ok(e),
+        };
+        let target_date_before = match date_filter("target_date_before", target_date_before) {
+            Ok(date) => date,
+            Err(e) => return Ok(e),
+        };
+
+        let project_id = match self.resolve_project_id(project_id).await {
             Ok(id) => id,
             Err(e) => return Ok(McpServer::tool_error(e)),
         };
@@ -384,22 +1345,17 @@ impl McpServer {
             None => (None, None, false),
         };
 
-        let priority = match priority {
-            Some(priority) => match Self::parse_issue_priority(&priority) {
-                Ok(priority) => Some(priority),
-                Err(e) => return Ok(McpServer::tool_error(e)),
-            },
-            None => None,
-        };
+        let priority = priority.map(|priority| priority.0);
 
-        let sort_field = match Self::parse_issue_sort_field(sort_field.as_deref()) {
-            Ok(value) => Some(value),
-            Err(e) => return Ok(McpServer::tool_error(e)),
-        };
-        let sort_direction = match Self::parse_sort_direction(sort_direction.as_deref()) {
-            Ok(value) => Some(value),
+        let sort_field = match Self::parse_issue_sort_field(sort_by.as_deref()) {
+            Ok(value) => value,
             Err(e) => return Ok(McpServer::tool_error(e)),
         };
+        let sort_direction =
+            match Self::parse_sort_direction(sort_direction.as_deref(), SortDirection::Asc) {
+                Ok(value) => value,
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            };
 
         let matching_tag_ids = match tag_name.as_deref() {
             Some(tag_name) => match self.find_tag_ids_by_name(project_id, tag_name).await {
@@ -411,12 +1367,27 @@ impl McpServer {
         let (tag_id, tag_ids, missing_tag_name_match) =
             Self::resolve_tag_filters(tag_id, matching_tag_ids);
 
+        let assignee_user_id = match (assignee_user_id, assignee.as_deref()) {
+            (Some(_), Some(_)) => {
+                return Ok(McpServer::tool_error(ToolError::message(
+                    "Pass either assignee_user_id or assignee, not both",
+                )));
+            }
+            (None, Some(assignee)) => {
+                match self.resolve_member_user_id(project_id, assignee).await {
+                    Ok(user_id) => Some(user_id),
+                    Err(e) => return Ok(McpServer::tool_error(e)),
+                }
+            }
+            (assignee_user_id, None) => assignee_user_id,
+        };
+
         let response = if missing_status_name_match || missing_tag_name_match {
             ListIssuesResponse {
                 issues: Vec::new(),
                 total_count: 0,
-                limit: limit.unwrap_or(50).max(0) as usize,
-                offset: offset.unwrap_or(0).max(0) as usize,
+                limit: page_params.limit,
+                offset: page_params.offset,
             }
         } else {
             let query = SearchIssuesRequest {
@@ -430,10 +1401,15 @@ impl McpServer {
                 assignee_user_id,
                 tag_id,
                 tag_ids,
-                sort_field,
-                sort_direction,
-                limit: Some(limit.unwrap_or(50).max(0)),
-                offset: Some(offset.unwrap_or(0).max(0)),
+                sort_field: Some(sort_field),
+                sort_direction: Some(sort_direction),
+                limit: Some(page_params.limit as i32),
+                offset: Some(page_params.offset as i32),
+                include_archived: Some(include_archived.unwrap_or(false)),
+                created_after,
+                updated_after,
+                completed_after,
+                target_date_before,
             };
             let url = self.url("/api/remote/issues/search");
             match self.send_json(self.client.post(&url).json(&query)).await {
@@ -441,102 +1417,468 @@ impl McpServer {
                 Err(e) => return Ok(McpServer::tool_error(e)),
             }
         };
-
-        let mut summaries = Vec::with_capacity(response.issues.len());
-        for issue in &response.issues {
-            let pull_requests = self.fetch_pull_requests(issue.id).await;
-            summaries.push(self.issue_to_summary(
-                issue,
-                status_names_by_id.as_ref(),
-                &pull_requests,
-            ));
+        if let Err(e) = page_params.check_total(response.total_count) {
+            return Ok(McpServer::tool_error(e));
         }
 
-        McpServer::success(&McpListIssuesResponse {
-            total_count: response.total_count,
-            returned_count: summaries.len(),
-            limit: response.limit,
-            offset: response.offset,
-            issues: summaries,
-            project_id: project_id.to_string(),
-        })
+        let pull_requests = self
+            .fetch_page_pull_requests(project_id, &response.issues)
+            .await;
+        let mut details = if include_details.unwrap_or(false) && !response.issues.is_empty() {
+            match self.fetch_issue_details_by_issue(project_id).await {
+                Ok(details) => Some(details),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            }
+        } else {
+            None
+        };
+
+        let summaries = response
+            .issues
+            .iter()
+            .zip(&pull_requests)
+            .map(|(issue, pull_requests)| {
+                let mut summary =
+                    self.issue_to_summary(issue, status_names_by_id.as_ref(), pull_requests);
+                if let Some(details) = details.as_mut() {
+                    summary.assignees =
+                        Some(details.assignees.remove(&issue.id).unwrap_or_default());
+                    summary.tags = Some(details.tags.remove(&issue.id).unwrap_or_default());
+                }
+                summary
+            })
+            .collect::<Vec<_>>();
+
+        self.success_paged(
+            &McpListIssuesResponse {
+                total_count: response.total_count,
+                returned_count: summaries.len(),
+                limit: response.limit,
+                offset: response.offset,
+                limit_clamped: page_params.limit_clamped,
+                issues: summaries,
+                project_id: project_id.to_string(),
+                sort_by: Self::issue_sort_field_label(sort_field).to_string(),
+                sort_direction: Self::sort_direction_label(sort_direction).to_string(),
+                date_filters: IssueDateFilters {
+                    created_after: created_after.map(|date| date.to_rfc3339()),
+                    updated_after: updated_after.map(|date| date.to_rfc3339()),
+                    completed_after: completed_after.map(|date| date.to_rfc3339()),
+                    target_date_before: target_date_before.map(|date| date.to_rfc3339()),
+                },
+                more_pages: MorePages::default(),
+            },
+            "issues",
+        )
     }
 
     #[tool(
-        description = "Get detailed information about a specific issue. You can use `list_issues` to find issue IDs. `issue_id` is required."
+        description = "List the issues assigned to the authenticated user in a project, most urgent first and then most recently updated. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpListMyIssuesResponse>()
     )]
-    async fn get_issue(
+    async fn list_my_issues(
         &self,
-        Parameters(McpGetIssueRequest { issue_id }): Parameters<McpGetIssueRequest>,
+        Parameters(McpListMyIssuesRequest {
+            project_id,
+            limit,
+            include_archived,
+        }): Parameters<McpListMyIssuesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = self.url(&format!("/api/remote/issues/{}", issue_id));
-        let issue: Issue = match self.send_json(self.client.get(&url)).await {
-            Ok(i) => i,
+        let user_id = match self.current_user_id().await {
+            Ok(id) => id,
+            Err(e) => {
+                return Ok(McpServer::tool_error(ToolError {
+                    hint: Some(
+                        "Log in to Vibe Kanban so the MCP server can act as a remote user."
+                            .to_string(),
+                    ),
+                    ..ToolError::new("No authenticated remote user", Some(e.message))
+                }));
+            }
+        };
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
             Err(e) => return Ok(McpServer::tool_error(e)),
         };
 
-        let pull_requests = self.fetch_pull_requests(issue_id).await;
-        let details = self.issue_to_details(&issue, pull_requests).await;
-        McpServer::success(&McpGetIssueResponse { issue: details })
+        let (issues, statuses, member_names) = tokio::join!(
+            self.fetch_assigned_issues(project_id, user_id, include_archived.unwrap_or(false)),
+            self.fetch_project_statuses(project_id),
+            self.fetch_member_names(project_id),
+        );
+        let mut issues = match issues {
+            Ok(issues) => issues,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let status_names_by_id = statuses.ok().map(|statuses| {
+            statuses
+                .into_iter()
+                .map(|status| (status.id, status.name))
+                .collect::<HashMap<_, _>>()
+        });
+        let username = match member_names {
+            Ok(mut names) => names.remove(&user_id),
+            Err(e) => {
+                tracing::debug!("Failed to fetch member names: {}", e.message);
+                None
+            }
+        };
+
+        let total_count = issues.len();
+        sort_by_urgency(&mut issues);
+        issues.truncate(limit.unwrap_or(DEFAULT_MY_ISSUES_LIMIT));
+        let pull_requests = self.fetch_page_pull_requests(project_id, &issues).await;
+        let summaries = issues
+            .iter()
+            .zip(&pull_requests)
+            .map(|(issue, pull_requests)| {
+                self.issue_to_summary(issue, status_names_by_id.as_ref(), pull_requests)
+            })
+            .collect::<Vec<_>>();
+
+        self.success_paged(
+            &McpListMyIssuesResponse {
+                user_id: user_id.to_string(),
+                username,
+                project_id: project_id.to_string(),
+                total_count,
+                returned_count: summaries.len(),
+                issues: summaries,
+                more_pages: MorePages::default(),
+            },
+            "issues",
+        )
     }
 
     #[tool(
-        description = "Update an existing issue's title, description, or status. `issue_id` is required. `title`, `description`, and `status` are optional."
+        description = "Search issues across every project in an organization by text in the title or description. Use it when you don't know which project an issue lives in. `organization_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpSearchIssuesResponse>()
     )]
-    async fn update_issue(
+    async fn search_issues(
         &self,
-        Parameters(McpUpdateIssueRequest {
-            issue_id,
-            title,
-            description,
-            status,
-            priority,
-            parent_issue_id,
-        }): Parameters<McpUpdateIssueRequest>,
+        Parameters(McpSearchIssuesRequest {
+            organization_id,
+            query,
+            limit,
+        }): Parameters<McpSearchIssuesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        // First get the issue to know its project_id for status resolution
-        let get_url = self.url(&format!("/api/remote/issues/{}", issue_id));
-        let existing_issue: Issue = match self.send_json(self.client.get(&get_url)).await {
-            Ok(i) => i,
+        let query = query.trim();
+        if query.is_empty() {
+            return McpServer::err("query must not be empty", None);
+        }
+        let limit = limit
+            .unwrap_or(DEFAULT_SEARCH_LIMIT)
+            .clamp(1, MAX_SEARCH_LIMIT);
+
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
             Err(e) => return Ok(McpServer::tool_error(e)),
         };
 
-        // Resolve status name to status_id if provided
-        let status_id = if let Some(ref status_name) = status {
-            match self
-                .resolve_status_id(existing_issue.project_id, status_name)
-                .await
-            {
-                Ok(id) => Some(id),
+        let projects_url = self.url(&format!(
+            "/api/remote/projects?organization_id={}",
+            organization_id
+        ));
+        let projects: ListProjectsResponse =
+            match self.send_json(self.client.get(&projects_url)).await {
+                Ok(r) => r,
                 Err(e) => return Ok(McpServer::tool_error(e)),
-            }
-        } else {
-            None
-        };
+            };
+        let projects_searched = projects.projects.len();
 
-        // Expand @tagname references in description
-        let expanded_description = match description {
-            Some(desc) => Some(Some(self.expand_tags(&desc).await)),
-            None => None,
-        };
+        let project_results = stream::iter(
+            projects
+                .projects
+                .into_iter()
+                .map(|project| self.search_project_issues(project, query, limit)),
+        )
+        .buffer_unordered(PROJECT_SEARCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
 
-        let priority = if let Some(priority) = priority {
-            match Self::parse_issue_priority(&priority) {
-                Ok(parsed) => Some(Some(parsed)),
-                Err(e) => return Ok(McpServer::tool_error(e)),
+        let needle = query.to_lowercase();
+        let mut warnings = Vec::new();
+        let mut searched_projects = Vec::new();
+        let mut candidates = Vec::new();
+        for result in project_results {
+            match result {
+                Ok((project, issues, status_names_by_id)) => {
+                    let project_index = searched_projects.len();
+                    searched_projects.push((project, status_names_by_id));
+                    candidates.extend(issues.into_iter().map(|issue| {
+                        let title_match = issue.title.to_lowercase().contains(&needle);
+                        (title_match, issue, project_index)
+                    }));
+                }
+                Err((project, e)) => warnings.push(format!(
+                    "Could not search project '{}': {}",
+                    project.name, e
+                )),
             }
-        } else {
-            None
+        }
+
+        candidates.sort_by(|(a_title, a, _), (b_title, b, _)| {
+            b_title
+                .cmp(a_title)
+                .then_with(|| b.updated_at.cmp(&a.updated_at))
+        });
+        candidates.truncate(limit);
+
+        let pull_requests = stream::iter(
+            candidates
+                .iter()
+                .map(|(_, issue, _)| self.fetch_pull_requests(issue.id)),
+        )
+        .buffered(PULL_REQUEST_FETCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+        let issues = candidates
+            .iter()
+            .zip(&pull_requests)
+            .map(|((title_match, issue, project_index), pull_requests)| {
+                let (project, status_names_by_id) = &searched_projects[*project_index];
+                IssueSearchMatch {
+                    project_id: project.id.to_string(),
+                    project_name: project.name.clone(),
+                    matched_in: if *title_match { "title" } else { "description" },
+                    issue: self.issue_to_summary(issue, status_names_by_id.as_ref(), pull_requests),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.success(&McpSearchIssuesResponse {
+            organization_id: organization_id.to_string(),
+            query: query.to_string(),
+            returned_count: issues.len(),
+            issues,
+            projects_searched,
+            warnings,
+        })
+    }
+
+    #[tool(
+        description = "Get detailed information about a specific issue. Identify it by `issue_id` or by `simple_id` (e.g. 'ACME-123').",
+        output_schema = output_schema::<McpGetIssueResponse>()
+    )]
+    async fn get_issue(
+        &self,
+        Parameters(McpGetIssueRequest {
+            issue_id,
+            simple_id,
+            project_id,
+        }): Parameters<McpGetIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let issue = match self.resolve_issue(issue_id, simple_id, project_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let pull_requests = self.fetch_pull_requests(issue.id).await;
+        let details = self.issue_to_details(&issue, pull_requests).await;
+        self.success(&McpGetIssueResponse { issue: details })
+    }
+
+    #[tool(
+        description = "Get an issue with its sub-issues nested to `max_depth` levels, plus counts of all descendants and how many are done. Identify it by `issue_id` or `simple_id`, or pass neither inside a workspace linked to an issue.",
+        output_schema = output_schema::<McpGetIssueTreeResponse>()
+    )]
+    async fn get_issue_tree(
+        &self,
+        Parameters(McpGetIssueTreeRequest {
+            issue_id,
+            simple_id,
+            project_id,
+            max_depth,
+            include_archived,
+        }): Parameters<McpGetIssueTreeRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let issue_id = match (issue_id, simple_id.as_deref()) {
+            (None, None) => {
+                let linked = match self.context.read(|ctx| ctx.and_then(|ctx| ctx.issue_id)) {
+                    Some(id) => Some(id),
+                    None => self.refresh_context().await.and_then(|ctx| ctx.issue_id),
+                };
+                match linked {
+                    Some(id) => Some(id),
+                    None => {
+                        return McpServer::err(
+                            "Provide `issue_id` or `simple_id`; this workspace is not linked to an issue",
+                            None,
+                        );
+                    }
+                }
+            }
+            _ => issue_id,
+        };
+        let root = match self.resolve_issue(issue_id, simple_id, project_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let max_depth = max_depth
+            .unwrap_or(DEFAULT_ISSUE_TREE_DEPTH)
+            .min(MAX_ISSUE_TREE_DEPTH);
+        let include_archived = include_archived.unwrap_or(false);
+
+        let url = self.url(&format!(
+            "/api/remote/issues?project_id={}",
+            root.project_id
+        ));
+        let issues: ListIssuesResponse = match self.send_json(self.client.get(&url)).await {
+            Ok(r) => r,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        // Statuses and pull requests only decorate the tree, so failures fall back to
+        // status IDs and zero pull requests.
+        let statuses = self
+            .fetch_project_statuses(root.project_id)
+            .await
+            .unwrap_or_default();
+        let mut pull_requests = self
+            .fetch_pull_requests_by_issue(root.project_id)
+            .await
+            .unwrap_or_default();
+
+        let mut children_by_parent: HashMap<Uuid, Vec<&Issue>> = HashMap::new();
+        for issue in &issues.issues {
+            if let Some(parent_id) = issue.parent_issue_id
+                && (include_archived || issue.archived_at.is_none())
+            {
+                children_by_parent.entry(parent_id).or_default().push(issue);
+            }
+        }
+        for children in children_by_parent.values_mut() {
+            children.sort_by(|a, b| {
+                a.parent_issue_sort_order
+                    .unwrap_or(f64::MAX)
+                    .total_cmp(&b.parent_issue_sort_order.unwrap_or(f64::MAX))
+                    .then_with(|| a.issue_number.cmp(&b.issue_number))
+            });
+        }
+
+        let mut tree = IssueTree {
+            server: self,
+            children_by_parent: &children_by_parent,
+            status_names_by_id: statuses
+                .iter()
+                .map(|status| (status.id, status.name.clone()))
+                .collect(),
+            done_status_ids: statuses
+                .iter()
+                .filter(|status| status.hidden || Self::is_done_status(&status.name))
+                .map(|status| status.id)
+                .collect(),
+            pull_requests: &mut pull_requests,
+            max_depth,
+            visited: HashSet::from([root.id]),
+            descendant_count: 0,
+            done_count: 0,
+            hidden_by_depth_count: 0,
+        };
+        let root_node = tree.node(&root, 0);
+
+        self.success(&McpGetIssueTreeResponse {
+            root: root_node,
+            descendant_count: tree.descendant_count,
+            done_count: tree.done_count,
+            hidden_by_depth_count: tree.hidden_by_depth_count,
+            max_depth,
+        })
+    }
+
+    #[tool(
+        description = "Update an existing issue's title, description, status, priority, parent, or dates. Identify it by `issue_id` or `simple_id`; omitted fields are left unchanged. Dates accept RFC3339 or YYYY-MM-DD, and null or 'clear' unsets them; pass null or 'none' as `priority` to clear it.",
+        output_schema = output_schema::<McpUpdateIssueResponse>()
+    )]
+    async fn update_issue(
+        &self,
+        Parameters(McpUpdateIssueRequest {
+            issue_id,
+            simple_id,
+            project_id,
+            title,
+            description,
+            status,
+            priority,
+            parent_issue_id,
+            start_date,
+            target_date,
+            completed_at,
+            strict_context,
+        }): Parameters<McpUpdateIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let start_date = match Self::parse_date_update("start_date", start_date) {
+            Ok(date) => date,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let target_date = match Self::parse_date_update("target_date", target_date) {
+            Ok(date) => date,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let completed_at = match Self::parse_date_update("completed_at", completed_at) {
+            Ok(date) => date,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        // First get the issue to know its project_id for status resolution
+        let existing_issue = match self.resolve_issue(issue_id, simple_id, project_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let issue_id = existing_issue.id;
+        let warning = match self.check_project_context(existing_issue.project_id, strict_context) {
+            Ok(warning) => warning,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let parent_issue_id = match Self::parse_parent_update(issue_id, parent_issue_id) {
+            Ok(parent) => parent,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        if let Some(Some(parent_id)) = parent_issue_id
+            && let Err(e) = self.check_new_parent(&existing_issue, parent_id).await
+        {
+            return Ok(McpServer::tool_error(e));
+        }
+
+        // Validate against the stored dates so a partial update can't invert the range.
+        if let Err(e) = Self::validate_date_range(
+            start_date.unwrap_or(existing_issue.start_date),
+            target_date.unwrap_or(existing_issue.target_date),
+        ) {
+            return Ok(McpServer::tool_error(e));
+        }
+
+        // Resolve status name to status_id if provided
+        let status_id = if let Some(ref status_name) = status {
+            match self
+                .resolve_status_id(existing_issue.project_id, status_name)
+                .await
+            {
+                Ok(id) => Some(id),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            }
+        } else {
+            None
         };
 
+        // Expand @tagname references in description
+        let expanded_description = match description {
+            Some(desc) => Some(Some(self.expand_tags(&desc).await)),
+            None => None,
+        };
+
+        let priority = priority.map(|priority| priority.map(|priority| priority.0));
+
         let payload = UpdateIssueRequest {
             status_id,
             title,
             description: expanded_description,
             priority,
-            start_date: None,
-            target_date: None,
-            completed_at: None,
+            start_date,
+            target_date,
+            completed_at,
             sort_order: None,
             parent_issue_id,
             parent_issue_sort_order: None,
@@ -552,122 +1894,816 @@ impl McpServer {
 
         let pull_requests = self.fetch_pull_requests(issue_id).await;
         let details = self.issue_to_details(&response.data, pull_requests).await;
-        McpServer::success(&McpUpdateIssueResponse { issue: details })
-    }
-
-    #[tool(description = "List allowed issue priority values.")]
-    async fn list_issue_priorities(&self) -> Result<CallToolResult, ErrorData> {
-        McpServer::success(&McpListIssuePrioritiesResponse {
-            priorities: ["urgent", "high", "medium", "low"]
-                .iter()
-                .map(|s| s.to_string())
-                .collect(),
+        self.success(&McpUpdateIssueResponse {
+            issue: details,
+            warning,
         })
     }
 
-    #[tool(description = "Delete an issue. `issue_id` is required.")]
-    async fn delete_issue(
+    #[tool(
+        description = "Archive an issue. Archived issues are hidden from `list_issues` unless `include_archived` is true, and can be restored with `unarchive_issue`.",
+        output_schema = output_schema::<McpArchiveIssueResponse>()
+    )]
+    async fn archive_issue(
         &self,
-        Parameters(McpDeleteIssueRequest { issue_id }): Parameters<McpDeleteIssueRequest>,
+        Parameters(McpArchiveIssueRequest {
+            issue_id,
+            strict_context,
+        }): Parameters<McpArchiveIssueRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = self.url(&format!("/api/remote/issues/{}", issue_id));
-        if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
-            return Ok(McpServer::tool_error(e));
-        }
-
-        McpServer::success(&McpDeleteIssueResponse {
-            deleted_issue_id: Some(issue_id.to_string()),
-        })
-    }
-}
-
-impl McpServer {
-    fn parse_issue_sort_field(sort_field: Option<&str>) -> Result<IssueSortField, ToolError> {
-        match sort_field
-            .unwrap_or("sort_order")
-            .trim()
-            .to_ascii_lowercase()
-            .as_str()
-        {
-            "sort_order" => Ok(IssueSortField::SortOrder),
-            "priority" => Ok(IssueSortField::Priority),
-            "created_at" => Ok(IssueSortField::CreatedAt),
-            "updated_at" => Ok(IssueSortField::UpdatedAt),
-            "title" => Ok(IssueSortField::Title),
-            other => Err(ToolError::message(format!(
-                "Unknown sort_field '{}'. Allowed values: ['sort_order', 'priority', 'created_at', 'updated_at', 'title']",
-                other
-            ))),
-        }
+        self.set_issue_archived(issue_id, true, strict_context)
+            .await
     }
 
-    fn parse_sort_direction(sort_direction: Option<&str>) -> Result<SortDirection, ToolError> {
-        match sort_direction
-            .unwrap_or("asc")
-            .trim()
-            .to_ascii_lowercase()
-            .as_str()
-        {
-            "asc" => Ok(SortDirection::Asc),
-            "desc" => Ok(SortDirection::Desc),
-            other => Err(ToolError::message(format!(
-                "Unknown sort_direction '{}'. Allowed values: ['asc', 'desc']",
-                other
-            ))),
-        }
+    #[tool(
+        description = "Restore an archived issue so it shows up in `list_issues` again.",
+        output_schema = output_schema::<McpArchiveIssueResponse>()
+    )]
+    async fn unarchive_issue(
+        &self,
+        Parameters(McpArchiveIssueRequest {
+            issue_id,
+            strict_context,
+        }): Parameters<McpArchiveIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.set_issue_archived(issue_id, false, strict_context)
+            .await
     }
 
-    fn issue_to_summary(
+    #[tool(
+        description = "Move an issue to another status column without changing anything else. Pass either `status` (e.g. 'In review') or `direction` ('next' / 'previous' along the board), and optionally a `position` in the column ('top', 'bottom', or an index). `position` alone reorders the issue within its current column. `issue_id` is required.",
+        output_schema = output_schema::<McpMoveIssueResponse>()
+    )]
+    async fn move_issue(
         &self,
-        issue: &Issue,
-        status_names_by_id: Option<&HashMap<Uuid, String>>,
-        pull_requests: &ListPullRequestsResponse,
-    ) -> IssueSummary {
-        let status = status_names_by_id
-            .and_then(|status_map| status_map.get(&issue.status_id).cloned())
-            .unwrap_or_else(|| issue.status_id.to_string());
-        let latest_pr = pull_requests.pull_requests.first();
-        IssueSummary {
-            id: issue.id.to_string(),
-            title: issue.title.clone(),
-            simple_id: issue.simple_id.clone(),
+        Parameters(McpMoveIssueRequest {
+            issue_id,
             status,
-            priority: issue
-                .priority
-                .map(Self::issue_priority_label)
-                .map(str::to_string),
-            parent_issue_id: issue.parent_issue_id.map(|id| id.to_string()),
-            created_at: issue.created_at.to_rfc3339(),
-            updated_at: issue.updated_at.to_rfc3339(),
-            pull_request_count: pull_requests.pull_requests.len(),
-            latest_pr_url: latest_pr.map(|pr| pr.url.clone()),
-            latest_pr_status: latest_pr.map(|pr| pr.status),
-        }
+            direction,
+            position,
+            strict_context,
+        }): Parameters<McpMoveIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let direction = match (status.as_deref(), direction.as_deref()) {
+            (Some(_), None) => None,
+            (None, Some(direction)) => match Self::parse_status_direction(direction) {
+                Ok(direction) => Some(direction),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            },
+            (None, None) if position.is_some() => None,
+            _ => {
+                return McpServer::err(
+                    "Provide exactly one of `status` or `direction`",
+                    Some(
+                        "Use `status` for a named column, or `direction` of 'next' or 'previous'. Pass only `position` to reorder within the current column.",
+                    ),
+                );
+            }
+        };
+
+        let issue = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let warning = match self.check_project_context(issue.project_id, strict_context) {
+            Ok(warning) => warning,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let statuses = match self.fetch_project_statuses(issue.project_id).await {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let Some(current) = statuses.iter().find(|s| s.id == issue.status_id) else {
+            return McpServer::err(
+                format!(
+                    "Status {} of issue {} was not found in its project",
+                    issue.status_id, issue.simple_id
+                ),
+                None,
+            );
+        };
+
+        let (target_id, target_name) = match (direction, status.as_deref()) {
+            (Some(direction), _) => match Self::adjacent_status(&statuses, current, direction) {
+                Ok(target) => (target.id, target.name.clone()),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            },
+            (None, Some(status_name)) => {
+                match self.resolve_status_id(issue.project_id, status_name).await {
+                    Ok(id) => (id, self.resolve_status_name(issue.project_id, id).await),
+                    Err(e) => return Ok(McpServer::tool_error(e)),
+                }
+            }
+            (None, None) => (current.id, current.name.clone()),
+        };
+
+        let (sort_order, position) = match position {
+            Some(position) => match self.place_in_column(&issue, target_id, position).await {
+                Ok((sort_order, index)) => (Some(sort_order), Some(index)),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            },
+            None => (None, None),
+        };
+
+        // Keep `completed_at` in step with the board: stamp it when entering a done column
+        // and clear it when an issue is reopened.
+        let completed_at = match (
+            Self::is_done_status(&current.name),
+            Self::is_done_status(&target_name),
+        ) {
+            (false, true) => Some(Some(Utc::now())),
+            (true, false) => Some(None),
+            _ => None,
+        };
+
+        let payload = UpdateIssueRequest {
+            status_id: Some(target_id),
+            title: None,
+            description: None,
+            priority: None,
+            start_date: None,
+            target_date: None,
+            completed_at,
+            sort_order,
+            parent_issue_id: None,
+            parent_issue_sort_order: None,
+            extension_metadata: None,
+        };
+
+        let url = self.url(&format!("/api/remote/issues/{}", issue_id));
+        let response: MutationResponse<Issue> =
+            match self.send_json(self.client.patch(&url).json(&payload)).await {
+                Ok(r) => r,
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            };
+
+        self.success(&McpMoveIssueResponse {
+            issue_id: response.data.id.to_string(),
+            web_url: self.issue_web_url(response.data.project_id, response.data.id),
+            simple_id: response.data.simple_id,
+            previous_status: current.name.clone(),
+            status: target_name,
+            position,
+            warning,
+        })
     }
 
-    async fn issue_to_details(
+    #[tool(
+        description = "List the status columns of a project in board order, including hidden ones and the default status for new issues. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpListProjectStatusesResponse>()
+    )]
+    async fn list_project_statuses(
         &self,
-        issue: &Issue,
-        pull_requests: ListPullRequestsResponse,
-    ) -> IssueDetails {
-        let status = self
-            .resolve_status_name(issue.project_id, issue.status_id)
-            .await;
-
-        let tags = self
-            .fetch_issue_tags_resolved(issue.project_id, issue.id)
-            .await;
+        Parameters(McpListProjectStatusesRequest { project_id }): Parameters<
+            McpListProjectStatusesRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let mut statuses = match self.fetch_project_statuses(project_id).await {
+            Ok(statuses) => statuses,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        statuses.sort_by_key(|s| s.sort_order);
 
-        let relationships = self
-            .fetch_issue_relationships_resolved(issue.project_id, issue.id)
-            .await;
+        let default_status = Self::default_status(&statuses);
+        let default_status_id = default_status.map(|s| s.id);
+        let default_status = default_status.map(|s| s.name.clone());
 
-        let sub_issues = self.fetch_sub_issues(issue.project_id, issue.id).await;
+        self.success(&McpListProjectStatusesResponse {
+            project_id: project_id.to_string(),
+            statuses: statuses
+                .into_iter()
+                .map(|s| ProjectStatusSummary {
+                    id: s.id.to_string(),
+                    is_default: Some(s.id) == default_status_id,
+                    name: s.name,
+                    sort_order: s.sort_order,
+                    hidden: s.hidden,
+                })
+                .collect(),
+            default_status,
+        })
+    }
+
+    #[tool(
+        description = "List allowed issue priority values and the aliases accepted for them.",
+        output_schema = output_schema::<McpListIssuePrioritiesResponse>()
+    )]
+    async fn list_issue_priorities(&self) -> Result<CallToolResult, ErrorData> {
+        self.success(&McpListIssuePrioritiesResponse {
+            priorities: PRIORITY_VALUES.iter().map(|s| s.to_string()).collect(),
+            aliases: PRIORITY_ALIASES
+                .iter()
+                .map(|(alias, priority)| {
+                    (
+                        alias.to_string(),
+                        Self::issue_priority_label(*priority).to_string(),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    #[tool(
+        description = "Permanently delete an issue. Identify it by `issue_id` or `simple_id`. Only archived issues are deleted unless `permanent` is true; prefer `archive_issue` to take an issue off the board. An issue with sub-issues is only deleted with `cascade`, which deletes the sub-issues first; they must be archived too unless `permanent` is true. Use `dry_run` to see what would be deleted.",
+        output_schema = output_schema::<McpDeleteIssueResponse>()
+    )]
+    async fn delete_issue(
+        &self,
+        Parameters(McpDeleteIssueRequest {
+            issue_id,
+            simple_id,
+            project_id,
+            cascade,
+            dry_run,
+            permanent,
+            strict_context,
+        }): Parameters<McpDeleteIssueRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let issue = match Self::issue_reference(issue_id, simple_id) {
+            Ok(IssueReference::Id(issue_id)) => self.fetch_issue(issue_id).await,
+            Ok(IssueReference::SimpleId(simple_id)) => {
+                self.fetch_issue_by_simple_id(&simple_id, project_id).await
+            }
+            Err(e) => Err(e),
+        };
+        let issue = match issue {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let issue_id = issue.id;
+        let dry_run = dry_run.unwrap_or(false);
+        let warning = match self.check_project_context(issue.project_id, strict_context) {
+            Ok(warning) => warning,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+
+        let require_archived = !dry_run && !permanent.unwrap_or(false);
+        let deleted_issues = if cascade.unwrap_or(false) {
+            let tree = match self.collect_issue_tree(issue).await {
+                Ok(tree) => tree,
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            };
+            let unarchived = tree
+                .iter()
+                .filter(|issue| issue.archived_at.is_none())
+                .map(|issue| issue.simple_id.as_str())
+                .collect::<Vec<_>>();
+            if require_archived && !unarchived.is_empty() {
+                let root = tree.last().map(|issue| issue.simple_id.as_str());
+                return McpServer::err(
+                    format!(
+                        "{} issue(s) in the tree of {} are not archived, so nothing was deleted. Use `archive_issue` on them first, or pass `permanent: true` to delete them for good.",
+                        unarchived.len(),
+                        root.unwrap_or_default()
+                    ),
+                    Some(format!("Not archived: {}", unarchived.join(", "))),
+                );
+            }
+
+            match self.delete_issue_tree(tree, dry_run).await {
+                Ok(deleted) => deleted,
+                Err((deleted, e)) if deleted.is_empty() || dry_run => {
+                    return Ok(McpServer::tool_error(e));
+                }
+                Err((deleted, e)) => {
+                    let deleted = deleted
+                        .iter()
+                        .map(|issue| issue.simple_id.as_str())
+                        .collect::<Vec<_>>();
+                    return McpServer::err(
+                        format!(
+                            "Cascade delete stopped after deleting {} issue(s): {}",
+                            deleted.len(),
+                            e
+                        ),
+                        Some(format!("Already deleted: {}", deleted.join(", "))),
+                    );
+                }
+            }
+        } else {
+            let children = match self.fetch_child_issues(issue.project_id, issue.id).await {
+                Ok(children) => children,
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            };
+            if require_archived && issue.archived_at.is_none() {
+                return McpServer::err(
+                    format!(
+                        "Issue {} is not archived, so it was not deleted",
+                        issue.simple_id
+                    ),
+                    Some(
+                        "Use `archive_issue` to take it off the board reversibly, or pass `permanent: true` to delete it for good."
+                            .to_string(),
+                    ),
+                );
+            }
+            if !children.is_empty() {
+                let simple_ids = children
+                    .iter()
+                    .map(|child| child.simple_id.as_str())
+                    .collect::<Vec<_>>();
+                return McpServer::err(
+                    format!(
+                        "Issue {} has {} sub-issue(s). Pass `cascade: true` to delete them as well.",
+                        issue.simple_id,
+                        children.len()
+                    ),
+                    Some(format!("Sub-issues: {}", simple_ids.join(", "))),
+                );
+            }
+
+            if !dry_run {
+                let url = self.url(&format!("/api/remote/issues/{}", issue_id));
+                if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+                    return Ok(McpServer::tool_error(e));
+                }
+            }
+            vec![DeletedIssueSummary::from(issue)]
+        };
+
+        self.success(&McpDeleteIssueResponse {
+            deleted_issue_id: (!dry_run).then(|| issue_id.to_string()),
+            deleted_issues,
+            dry_run,
+            warning,
+        })
+    }
+
+    #[tool(
+        description = "Update the status, priority, tags, or assignees of up to 50 issues at once. Select issues with `issue_ids` and/or `simple_ids`, or with `filter` plus `confirm_count` set to the number of matching issues. Returns a result per issue; one failure does not stop the others. Assignees that cannot be resolved are reported in that issue's `warnings`.",
+        output_schema = output_schema::<McpBulkUpdateIssuesResponse>()
+    )]
+    async fn bulk_update_issues(
+        &self,
+        Parameters(McpBulkUpdateIssuesRequest {
+            issue_ids,
+            simple_ids,
+            project_id,
+            filter,
+            confirm_count,
+            status,
+            priority,
+            add_tag_names,
+            remove_tag_names,
+            add_assignees,
+            remove_assignees,
+        }): Parameters<McpBulkUpdateIssuesRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let priority = priority.map(|priority| priority.0);
+        let add_tag_names = add_tag_names.unwrap_or_default();
+        let remove_tag_names = remove_tag_names.unwrap_or_default();
+        let add_assignees = add_assignees.unwrap_or_default();
+        let remove_assignees = remove_assignees.unwrap_or_default();
+        if status.is_none()
+            && priority.is_none()
+            && add_tag_names.is_empty()
+            && remove_tag_names.is_empty()
+            && add_assignees.is_empty()
+            && remove_assignees.is_empty()
+        {
+            return McpServer::err(
+                "Nothing to change. Provide `status`, `priority`, `add_tag_names`, `remove_tag_names`, `add_assignees`, or `remove_assignees`.",
+                None,
+            );
+        }
+
+        let targets = match (issue_ids.is_some() || simple_ids.is_some(), filter) {
+            (true, None) => {
+                let references = issue_ids
+                    .into_iter()
+                    .flatten()
+                    .map(IssueReference::Id)
+                    .chain(simple_ids.into_iter().flatten().map(|simple_id| {
+                        IssueReference::SimpleId(simple_id.trim().to_ascii_uppercase())
+                    }))
+                    .collect();
+                match self.fetch_bulk_issues(references, project_id).await {
+                    Ok(targets) => targets,
+                    Err(e) => return Ok(McpServer::tool_error(e)),
+                }
+            }
+            (false, Some(filter)) => match self.find_bulk_issues(filter, confirm_count).await {
+                Ok(issues) => issues.into_iter().map(Ok).collect(),
+                Err(e) => return Ok(McpServer::tool_error(e)),
+            },
+            _ => {
+                return McpServer::err(
+                    "Provide `issue_ids` or `simple_ids`, or a `filter`, but not both.",
+                    None,
+                );
+            }
+        };
+
+        // Status, tag, and assignee names are resolved once per project, not once per issue.
+        let mut changes_by_project: HashMap<Uuid, Result<BulkIssueChanges, String>> =
+            HashMap::new();
+        for issue in targets.iter().flatten() {
+            if changes_by_project.contains_key(&issue.project_id) {
+                continue;
+            }
+            let changes = self
+                .resolve_bulk_changes(
+                    issue.project_id,
+                    status.as_deref(),
+                    priority,
+                    &add_tag_names,
+                    &remove_tag_names,
+                    &add_assignees,
+                    &remove_assignees,
+                )
+                .await
+                .map_err(|e| e.to_string());
+            changes_by_project.insert(issue.project_id, changes);
+        }
+
+        let results = stream::iter(targets.into_iter().map(|target| {
+            let changes_by_project = &changes_by_project;
+            async move {
+                let issue = match target {
+                    Ok(issue) => issue,
+                    Err((reference, e)) => {
+                        let (issue_id, simple_id) = match reference {
+                            IssueReference::Id(issue_id) => (Some(issue_id.to_string()), None),
+                            IssueReference::SimpleId(simple_id) => (None, Some(simple_id)),
+                        };
+                        return BulkIssueUpdateResult {
+                            issue_id,
+                            simple_id,
+                            ok: false,
+                            error: Some(e.to_string()),
+                            warnings: Vec::new(),
+                        };
+                    }
+                };
+                let (outcome, warnings) = match changes_by_project.get(&issue.project_id) {
+                    Some(Ok(changes)) => (
+                        self.apply_bulk_changes(issue.id, changes)
+                            .await
+                            .map_err(|e| e.to_string()),
+                        changes.warnings.clone(),
+                    ),
+                    Some(Err(e)) => (Err(e.clone()), Vec::new()),
+                    None => (
+                        Err("Changes were not resolved for this project".to_string()),
+                        Vec::new(),
+                    ),
+                };
+                BulkIssueUpdateResult {
+                    issue_id: Some(issue.id.to_string()),
+                    simple_id: Some(issue.simple_id),
+                    ok: outcome.is_ok(),
+                    error: outcome.err(),
+                    warnings,
+                }
+            }
+        }))
+        .buffered(BULK_UPDATE_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+        let updated_count = results.iter().filter(|result| result.ok).count();
+        self.success(&McpBulkUpdateIssuesResponse {
+            updated_count,
+            failed_count: results.len() - updated_count,
+            results,
+        })
+    }
+}
+
+// Urgent issues first and issues without a priority last, most recently updated first
+// within each priority.
+fn sort_by_urgency(issues: &mut [Issue]) {
+    let rank = |priority: Option<IssuePriority>| match priority {
+        Some(IssuePriority::Urgent) => 0,
+        Some(IssuePriority::High) => 1,
+        Some(IssuePriority::Medium) => 2,
+        Some(IssuePriority::Low) => 3,
+        None => 4,
+    };
+    issues.sort_by(|a, b| {
+        rank(a.priority)
+            .cmp(&rank(b.priority))
+            .then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
+}
+
+// A sort order for an issue inserted at `index` into `column`, which is in board order and
+// leaves out the issue itself. It falls between the neighbours when they leave room;
+// otherwise the column is renumbered, and the issues whose sort order changes are returned
+// alongside.
+fn board_placement(column: &[Issue], index: usize) -> (f64, Vec<(Uuid, f64)>) {
+    let index = index.min(column.len());
+    let before = index.checked_sub(1).map(|i| column[i].sort_order);
+    let after = column.get(index).map(|issue| issue.sort_order);
+    let sort_order = match (before, after) {
+        (None, None) => Some(ISSUE_SORT_ORDER_STEP),
+        (None, Some(after)) => Some(after - ISSUE_SORT_ORDER_STEP),
+        (Some(before), None) => Some(before + ISSUE_SORT_ORDER_STEP),
+        (Some(before), Some(after)) => {
+            let midpoint = before + (after - before) / 2.0;
+            (before < midpoint && midpoint < after).then_some(midpoint)
+        }
+    };
+    if let Some(sort_order) = sort_order.filter(|sort_order| sort_order.is_finite()) {
+        return (sort_order, Vec::new());
+    }
+
+    let renumbered = column
+        .iter()
+        .enumerate()
+        .map(|(i, issue)| {
+            // Leave a slot for the moved issue at `index`.
+            let slot = if i < index { i + 1 } else { i + 2 };
+            (issue, slot as f64 * ISSUE_SORT_ORDER_STEP)
+        })
+        .filter(|(issue, sort_order)| issue.sort_order != *sort_order)
+        .map(|(issue, sort_order)| (issue.id, sort_order))
+        .collect();
+    ((index + 1) as f64 * ISSUE_SORT_ORDER_STEP, renumbered)
+}
+
+// Builds `get_issue_tree` nodes from one project's issues, counting descendants as it goes.
+struct IssueTree<'a> {
+    server: &'a McpServer,
+    children_by_parent: &'a HashMap<Uuid, Vec<&'a Issue>>,
+    status_names_by_id: HashMap<Uuid, String>,
+    done_status_ids: HashSet<Uuid>,
+    pull_requests: &'a mut HashMap<Uuid, Vec<PullRequest>>,
+    max_depth: usize,
+    // Guards against a parent cycle in the data turning into endless recursion.
+    visited: HashSet<Uuid>,
+    descendant_count: usize,
+    done_count: usize,
+    hidden_by_depth_count: usize,
+}
+
+impl IssueTree<'_> {
+    fn node(&mut self, issue: &Issue, depth: usize) -> IssueTreeNode {
+        let pull_requests = ListPullRequestsResponse {
+            pull_requests: self.pull_requests.remove(&issue.id).unwrap_or_default(),
+        };
+        let summary =
+            self.server
+                .issue_to_summary(issue, Some(&self.status_names_by_id), &pull_requests);
+
+        let mut children = Vec::new();
+        let children_by_parent = self.children_by_parent;
+        for child in children_by_parent.get(&issue.id).into_iter().flatten() {
+            if !self.visited.insert(child.id) {
+                continue;
+            }
+            self.descendant_count += 1;
+            if self.done_status_ids.contains(&child.status_id) {
+                self.done_count += 1;
+            }
+            if depth < self.max_depth {
+                children.push(self.node(child, depth + 1));
+            } else {
+                self.hidden_by_depth_count += 1;
+                // Still walk the subtree so the counts cover every level.
+                self.count_hidden(child.id);
+            }
+        }
+
+        IssueTreeNode {
+            issue: summary,
+            children,
+        }
+    }
+
+    fn count_hidden(&mut self, issue_id: Uuid) {
+        let children_by_parent = self.children_by_parent;
+        for child in children_by_parent.get(&issue_id).into_iter().flatten() {
+            if !self.visited.insert(child.id) {
+                continue;
+            }
+            self.descendant_count += 1;
+            self.hidden_by_depth_count += 1;
+            if self.done_status_ids.contains(&child.status_id) {
+                self.done_count += 1;
+            }
+            self.count_hidden(child.id);
+        }
+    }
+}
+
+impl McpServer {
+    fn issue_reference(
+        issue_id: Option<Uuid>,
+        simple_id: Option<String>,
+    ) -> Result<IssueReference, ToolError> {
+        let simple_id = simple_id
+            .map(|id| id.trim().to_ascii_uppercase())
+            .filter(|id| !id.is_empty());
+        match (issue_id, simple_id) {
+            (Some(issue_id), None) => Ok(IssueReference::Id(issue_id)),
+            (None, Some(simple_id)) => {
+                if !simple_id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+                {
+                    return Err(ToolError::message(format!(
+                        "Invalid simple_id '{}'. Expected something like 'ACME-123'.",
+                        simple_id
+                    )));
+                }
+                Ok(IssueReference::SimpleId(simple_id))
+            }
+            _ => Err(ToolError::message(
+                "Provide exactly one of `issue_id` or `simple_id`",
+            )),
+        }
+    }
+
+    // Resolves an issue from either its UUID or its simple ID.
+    async fn resolve_issue(
+        &self,
+        issue_id: Option<Uuid>,
+        simple_id: Option<String>,
+        project_id: Option<Uuid>,
+    ) -> Result<Issue, ToolError> {
+        match Self::issue_reference(issue_id, simple_id)? {
+            IssueReference::Id(issue_id) => self.fetch_issue(issue_id).await,
+            IssueReference::SimpleId(simple_id) => {
+                self.fetch_issue_by_simple_id(&simple_id, project_id).await
+            }
+        }
+    }
+
+    async fn fetch_issue_by_simple_id(
+        &self,
+        simple_id: &str,
+        project_id: Option<Uuid>,
+    ) -> Result<Issue, ToolError> {
+        let project_id = self.resolve_project_id(project_id).await?;
+        let url = self.url(&format!(
+            "/api/remote/issues/by-simple-id/{}/{}",
+            project_id, simple_id
+        ));
+        self.send_json(self.client.get(&url))
+            .await
+            .map_err(|error| {
+                // Other failures keep their own message, so an outage doesn't read as a typo.
+                if error.status != Some(StatusCode::NOT_FOUND.as_u16()) {
+                    return error;
+                }
+                ToolError {
+                    hint: Some(
+                        "Simple IDs are unique per project. Pass the issue's `project_id`, or use `search_issues` to look across the organization."
+                            .to_string(),
+                    ),
+                    ..ToolError::new(
+                        format!(
+                            "Could not find issue '{}' in project {}",
+                            simple_id, project_id
+                        ),
+                        error.details,
+                    )
+                }
+            })
+    }
+
+    fn parse_issue_sort_field(sort_by: Option<&str>) -> Result<IssueSortField, ToolError> {
+        let sort_by = sort_by.unwrap_or("sort_order").trim().to_ascii_lowercase();
+        ISSUE_SORT_FIELDS
+            .iter()
+            .find(|(name, _)| *name == sort_by)
+            .map(|(_, field)| *field)
+            .ok_or_else(|| {
+                let allowed = ISSUE_SORT_FIELDS
+                    .iter()
+                    .map(|(name, _)| format!("'{name}'"))
+                    .collect::<Vec<_>>();
+                ToolError::message(format!(
+                    "Unknown sort_by '{}'. Allowed values: [{}]",
+                    sort_by,
+                    allowed.join(", ")
+                ))
+            })
+    }
+
+    fn issue_sort_field_label(sort_field: IssueSortField) -> &'static str {
+        ISSUE_SORT_FIELDS
+            .iter()
+            .find(|(_, field)| *field == sort_field)
+            .map(|(name, _)| *name)
+            .unwrap_or("sort_order")
+    }
+
+    fn parse_status_direction(direction: &str) -> Result<StatusDirection, ToolError> {
+        match direction.trim().to_ascii_lowercase().as_str() {
+            "next" => Ok(StatusDirection::Next),
+            "previous" | "prev" => Ok(StatusDirection::Previous),
+            other => Err(ToolError::message(format!(
+                "Unknown direction '{}'. Allowed values: ['next', 'previous']",
+                other
+            ))),
+        }
+    }
+
+    // Finds the visible status adjacent to `current` in board order. `current` itself may be
+    // hidden (e.g. Backlog), in which case the nearest visible column is used.
+    fn adjacent_status<'a>(
+        statuses: &'a [ProjectStatus],
+        current: &ProjectStatus,
+        direction: StatusDirection,
+    ) -> Result<&'a ProjectStatus, ToolError> {
+        let mut visible: Vec<&ProjectStatus> = statuses.iter().filter(|s| !s.hidden).collect();
+        visible.sort_by_key(|s| (s.sort_order, s.id));
+
+        let position = (current.sort_order, current.id);
+        let adjacent = match direction {
+            StatusDirection::Next => visible
+                .iter()
+                .find(|s| (s.sort_order, s.id) > position)
+                .copied(),
+            StatusDirection::Previous => visible
+                .iter()
+                .rev()
+                .find(|s| (s.sort_order, s.id) < position)
+                .copied(),
+        };
+
+        adjacent.ok_or_else(|| {
+            let (verb, boundary, position) = match direction {
+                StatusDirection::Next => ("forward", visible.last(), "last"),
+                StatusDirection::Previous => ("back", visible.first(), "first"),
+            };
+            let boundary = boundary.map_or(current.name.as_str(), |s| s.name.as_str());
+            ToolError::message(format!(
+                "Cannot move {} from '{}': '{}' is the {} status on the board",
+                verb, current.name, boundary, position
+            ))
+        })
+    }
+
+    fn is_done_status(name: &str) -> bool {
+        matches!(
+            name.trim().to_ascii_lowercase().as_str(),
+            "done" | "complete" | "completed" | "closed" | "finished" | "resolved"
+        )
+    }
+
+    fn issue_to_summary(
+        &self,
+        issue: &Issue,
+        status_names_by_id: Option<&HashMap<Uuid, String>>,
+        pull_requests: &ListPullRequestsResponse,
+    ) -> IssueSummary {
+        let status = status_names_by_id
+            .and_then(|status_map| status_map.get(&issue.status_id).cloned())
+            .unwrap_or_else(|| issue.status_id.to_string());
+        let latest_pr = pull_requests.pull_requests.first();
+        IssueSummary {
+            id: issue.id.to_string(),
+            title: issue.title.clone(),
+            simple_id: issue.simple_id.clone(),
+            web_url: self.issue_web_url(issue.project_id, issue.id),
+            status,
+            priority: issue
+                .priority
+                .map(Self::issue_priority_label)
+                .map(str::to_string),
+            parent_issue_id: issue.parent_issue_id.map(|id| id.to_string()),
+            archived_at: issue.archived_at.map(|date| date.to_rfc3339()),
+            created_at: issue.created_at.to_rfc3339(),
+            updated_at: issue.updated_at.to_rfc3339(),
+            pull_request_count: pull_requests.pull_requests.len(),
+            latest_pr_url: latest_pr.map(|pr| pr.url.clone()),
+            latest_pr_status: latest_pr.map(|pr| pr.status),
+            assignees: None,
+            tags: None,
+        }
+    }
+
+    async fn issue_to_details(
+        &self,
+        issue: &Issue,
+        pull_requests: ListPullRequestsResponse,
+    ) -> IssueDetails {
+        let status = self
+            .resolve_status_name(issue.project_id, issue.status_id)
+            .await;
+
+        let tags = self
+            .fetch_issue_tags_resolved(issue.project_id, issue.id)
+            .await;
+
+        let relationships = self
+            .fetch_issue_relationships_resolved(issue.project_id, issue.id)
+            .await;
+
+        let sub_issues = self.fetch_sub_issues(issue.project_id, issue.id).await;
 
         IssueDetails {
             id: issue.id.to_string(),
             title: issue.title.clone(),
             simple_id: issue.simple_id.clone(),
+            web_url: self.issue_web_url(issue.project_id, issue.id),
             description: issue.description.clone(),
             status,
             status_id: issue.status_id.to_string(),
@@ -679,6 +2715,7 @@ impl McpServer {
             start_date: issue.start_date.map(|date| date.to_rfc3339()),
             target_date: issue.target_date.map(|date| date.to_rfc3339()),
             completed_at: issue.completed_at.map(|date| date.to_rfc3339()),
+            archived_at: issue.archived_at.map(|date| date.to_rfc3339()),
             created_at: issue.created_at.to_rfc3339(),
             updated_at: issue.updated_at.to_rfc3339(),
             pull_requests: pull_requests
@@ -698,70 +2735,492 @@ impl McpServer {
         }
     }
 
-    async fn fetch_pull_requests(&self, issue_id: Uuid) -> ListPullRequestsResponse {
-        let url = self.url(&format!("/api/remote/pull-requests?issue_id={}", issue_id));
-        match self
-            .send_json::<ListPullRequestsResponse>(self.client.get(&url))
-            .await
-        {
-            Ok(response) => response,
-            Err(_) => ListPullRequestsResponse {
-                pull_requests: vec![],
-            },
-        }
-    }
-
-    /// Fetches tags for an issue, resolving tag_ids to names via project tags.
-    async fn fetch_issue_tags_resolved(
+    async fn set_issue_archived(
         &self,
-        project_id: Uuid,
         issue_id: Uuid,
-    ) -> Vec<McpTagSummary> {
-        let tags_url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
-        let project_tags: ListTagsResponse = match self.send_json(self.client.get(&tags_url)).await
-        {
-            Ok(r) => r,
-            Err(_) => return Vec::new(),
+        archived: bool,
+        strict_context: Option<bool>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let issue = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(McpServer::tool_error(e)),
+        };
+        let warning = match self.check_project_context(issue.project_id, strict_context) {
+            Ok(warning) => warning,
+            Err(e) => return Ok(McpServer::tool_error(e)),
         };
-        let tag_map: HashMap<Uuid, &api_types::Tag> =
-            project_tags.tags.iter().map(|t| (t.id, t)).collect();
 
-        let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", issue_id));
-        let response: ListIssueTagsResponse = match self.send_json(self.client.get(&url)).await {
+        let action = if archived { "archive" } else { "unarchive" };
+        let url = self.url(&format!("/api/remote/issues/{issue_id}/{action}"));
+        let response: MutationResponse<Issue> = match self.send_json(self.client.post(&url)).await {
             Ok(r) => r,
-            Err(_) => return Vec::new(),
+            Err(e) => return Ok(McpServer::tool_error(e)),
         };
 
-        response
-            .issue_tags
-            .iter()
-            .filter_map(|it| {
-                tag_map.get(&it.tag_id).map(|tag| McpTagSummary {
-                    id: tag.id.to_string(),
-                    name: tag.name.clone(),
-                    color: tag.color.clone(),
-                })
-            })
-            .collect()
+        self.success(&McpArchiveIssueResponse {
+            issue_id: response.data.id.to_string(),
+            simple_id: response.data.simple_id,
+            archived: response.data.archived_at.is_some(),
+            archived_at: response.data.archived_at.map(|date| date.to_rfc3339()),
+            warning,
+        })
     }
 
-    /// Fetches relationships for an issue, resolving related issue simple_ids.
-    async fn fetch_issue_relationships_resolved(
+    // Runs the server-side issue search for one project, resolving status names when possible.
+    async fn search_project_issues(
         &self,
-        project_id: Uuid,
-        issue_id: Uuid,
-    ) -> Vec<McpRelationshipSummary> {
-        let rel_url = self.url(&format!(
-            "/api/remote/issue-relationships?issue_id={}",
-            issue_id
-        ));
-        let response: ListIssueRelationshipsResponse =
-            match self.send_json(self.client.get(&rel_url)).await {
+        project: Project,
+        query: &str,
+        limit: usize,
+    ) -> Result<(Project, Vec<Issue>, Option<HashMap<Uuid, String>>), (Project, ToolError)> {
+        let request = SearchIssuesRequest {
+            project_id: project.id,
+            search: Some(query.to_string()),
+            limit: Some(limit as i32),
+            ..Default::default()
+        };
+        let url = self.url("/api/remote/issues/search");
+        let response: ListIssuesResponse =
+            match self.send_json(self.client.post(&url).json(&request)).await {
                 Ok(r) => r,
-                Err(_) => return Vec::new(),
+                Err(e) => return Err((project, e)),
             };
 
-        if response.issue_relationships.is_empty() {
+        let status_names_by_id =
+            self.fetch_project_statuses(project.id)
+                .await
+                .ok()
+                .map(|statuses| {
+                    statuses
+                        .into_iter()
+                        .map(|status| (status.id, status.name))
+                        .collect::<HashMap<_, _>>()
+                });
+
+        Ok((project, response.issues, status_names_by_id))
+    }
+
+    // Picks a sort order that puts `issue` at `position` in the target status column, and
+    // returns it with the resulting index. Renumbers the rest of the column first when the
+    // neighbours leave no room between them.
+    async fn place_in_column(
+        &self,
+        issue: &Issue,
+        status_id: Uuid,
+        position: BoardPosition,
+    ) -> Result<(f64, usize), ToolError> {
+        let column = self
+            .fetch_status_column(issue.project_id, status_id)
+            .await?
+            .into_iter()
+            .filter(|other| other.id != issue.id)
+            .collect::<Vec<_>>();
+        let index = match position {
+            BoardPosition::Top => 0,
+            BoardPosition::Bottom => column.len(),
+            BoardPosition::Index(index) => index.min(column.len()),
+        };
+
+        let (sort_order, renumbered) = board_placement(&column, index);
+        let results = stream::iter(renumbered.into_iter().map(|(issue_id, sort_order)| {
+            let url = self.url(&format!("/api/remote/issues/{}", issue_id));
+            let payload = UpdateIssueRequest {
+                status_id: None,
+                title: None,
+                description: None,
+                priority: None,
+                start_date: None,
+                target_date: None,
+                completed_at: None,
+                sort_order: Some(sort_order),
+                parent_issue_id: None,
+                parent_issue_sort_order: None,
+                extension_metadata: None,
+            };
+            async move {
+                self.send_json::<MutationResponse<Issue>>(self.client.patch(&url).json(&payload))
+                    .await
+            }
+        }))
+        .buffered(BULK_UPDATE_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+        if let Some(Err(e)) = results.into_iter().find(Result::is_err) {
+            return Err(ToolError {
+                hint: Some(
+                    "The column may be partly renumbered; retrying the move is safe.".to_string(),
+                ),
+                ..e
+            });
+        }
+
+        Ok((sort_order, index))
+    }
+
+    // Unarchived issues in one status, in board order, across all pages.
+    async fn fetch_status_column(
+        &self,
+        project_id: Uuid,
+        status_id: Uuid,
+    ) -> Result<Vec<Issue>, ToolError> {
+        self.fetch_all_issues(SearchIssuesRequest {
+            project_id,
+            status_id: Some(status_id),
+            sort_field: Some(IssueSortField::SortOrder),
+            sort_direction: Some(SortDirection::Asc),
+            include_archived: Some(false),
+            ..Default::default()
+        })
+        .await
+    }
+
+    // Every issue in a project assigned to `user_id`, across all pages.
+    async fn fetch_assigned_issues(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Issue>, ToolError> {
+        self.fetch_all_issues(SearchIssuesRequest {
+            project_id,
+            assignee_user_id: Some(user_id),
+            sort_field: Some(IssueSortField::Priority),
+            sort_direction: Some(SortDirection::Asc),
+            include_archived: Some(include_archived),
+            ..Default::default()
+        })
+        .await
+    }
+
+    // Direct sub-issues of an issue, across all pages.
+    async fn fetch_child_issues(
+        &self,
+        project_id: Uuid,
+        parent_issue_id: Uuid,
+    ) -> Result<Vec<Issue>, ToolError> {
+        self.fetch_all_issues(SearchIssuesRequest {
+            project_id,
+            parent_issue_id: Some(parent_issue_id),
+            ..Default::default()
+        })
+        .await
+    }
+
+    // Every issue matching `request`, fetched `ISSUE_PAGE_SIZE` at a time. `limit` and
+    // `offset` are overwritten for each page.
+    async fn fetch_all_issues(
+        &self,
+        mut request: SearchIssuesRequest,
+    ) -> Result<Vec<Issue>, ToolError> {
+        let url = self.url("/api/remote/issues/search");
+        let mut issues = Vec::new();
+        loop {
+            request.limit = Some(ISSUE_PAGE_SIZE);
+            request.offset = Some(issues.len() as i32);
+            let response: ListIssuesResponse = self
+                .send_json(self.client.post(&url).json(&request))
+                .await?;
+            let page_len = response.issues.len();
+            issues.extend(response.issues);
+            if page_len == 0 || issues.len() >= response.total_count {
+                return Ok(issues);
+            }
+        }
+    }
+
+    // Creates a copy of `source` under `parent_issue_id`, then copies the chosen
+    // associations. Only the create itself can fail; association failures become warnings.
+    async fn copy_issue(
+        &self,
+        source: &Issue,
+        title: Option<String>,
+        parent_issue_id: Option<Uuid>,
+        associations: CopiedAssociations,
+        warnings: &mut Vec<String>,
+    ) -> Result<Issue, ToolError> {
+        let payload = CreateIssueRequest {
+            id: None,
+            project_id: source.project_id,
+            status_id: source.status_id,
+            title: title.unwrap_or_else(|| source.title.clone()),
+            description: source.description.clone(),
+            priority: source.priority,
+            start_date: source.start_date,
+            target_date: source.target_date,
+            completed_at: None,
+            sort_order: 0.0,
+            parent_issue_id,
+            parent_issue_sort_order: source.parent_issue_sort_order,
+            extension_metadata: serde_json::json!({}),
+        };
+        let url = self.url("/api/remote/issues");
+        let copy = self
+            .send_json::<MutationResponse<Issue>>(self.client.post(&url).json(&payload))
+            .await?
+            .data;
+
+        if associations.tags {
+            let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", source.id));
+            match self
+                .send_json::<ListIssueTagsResponse>(self.client.get(&url))
+                .await
+            {
+                Ok(response) => {
+                    for issue_tag in response.issue_tags {
+                        if let Err(e) = self.attach_issue_tag(copy.id, issue_tag.tag_id, None).await
+                        {
+                            warnings.push(format!(
+                                "Could not copy tag {} to {}: {}",
+                                issue_tag.tag_id, copy.simple_id, e
+                            ));
+                        }
+                    }
+                }
+                Err(e) => warnings.push(format!(
+                    "Could not load the tags of {}: {}",
+                    source.simple_id, e
+                )),
+            }
+        }
+
+        if associations.assignees {
+            let url = self.url(&format!(
+                "/api/remote/issue-assignees?issue_id={}",
+                source.id
+            ));
+            match self
+                .send_json::<ListIssueAssigneesResponse>(self.client.get(&url))
+                .await
+            {
+                Ok(response) => {
+                    for assignee in response.issue_assignees {
+                        if let Err(e) = self
+                            .assign_issue_user(copy.id, assignee.user_id, None)
+                            .await
+                        {
+                            warnings.push(format!(
+                                "Could not assign user {} to {}: {}",
+                                assignee.user_id, copy.simple_id, e
+                            ));
+                        }
+                    }
+                }
+                Err(e) => warnings.push(format!(
+                    "Could not load the assignees of {}: {}",
+                    source.simple_id, e
+                )),
+            }
+        }
+
+        Ok(copy)
+    }
+
+    // An issue and all of its sub-issues, ordered depth-first so that every issue comes after
+    // its children and the root comes last.
+    async fn collect_issue_tree(&self, root: Issue) -> Result<Vec<Issue>, ToolError> {
+        let mut tree = Vec::new();
+        let mut stack = vec![(root, false)];
+        while let Some((issue, children_queued)) = stack.pop() {
+            if children_queued {
+                tree.push(issue);
+                continue;
+            }
+            let children = self.fetch_child_issues(issue.project_id, issue.id).await?;
+            stack.push((issue, true));
+            stack.extend(children.into_iter().map(|child| (child, false)));
+        }
+        Ok(tree)
+    }
+
+    // Deletes the issues of a tree from `collect_issue_tree` in order, so no issue is deleted
+    // before its children. On failure, returns the issues deleted so far alongside the error.
+    async fn delete_issue_tree(
+        &self,
+        tree: Vec<Issue>,
+        dry_run: bool,
+    ) -> Result<Vec<DeletedIssueSummary>, (Vec<DeletedIssueSummary>, ToolError)> {
+        let mut deleted = Vec::new();
+        for issue in tree {
+            if !dry_run {
+                let url = self.url(&format!("/api/remote/issues/{}", issue.id));
+                if let Err(e) = self.send_empty_json(self.client.delete(&url)).await {
+                    return Err((deleted, e));
+                }
+            }
+            deleted.push(DeletedIssueSummary::from(issue));
+        }
+        Ok(deleted)
+    }
+
+    // Assignee and tag names for every issue in a project, from one request per kind rather
+    // than per issue. Assignees whose profile can't be read are named by their user ID.
+    async fn fetch_issue_details_by_issue(
+        &self,
+        project_id: Uuid,
+    ) -> Result<IssueDetailsByIssue, ToolError> {
+        let assignees_url = self.url(&format!(
+            "/api/remote/issue-assignees?project_id={}",
+            project_id
+        ));
+        let issue_tags_url = self.url(&format!("/api/remote/issue-tags?project_id={}", project_id));
+        let tags_url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let (assignees, issue_tags, tags, member_names) = tokio::join!(
+            self.send_json::<ListIssueAssigneesResponse>(self.client.get(&assignees_url)),
+            self.send_json::<ListIssueTagsResponse>(self.client.get(&issue_tags_url)),
+            self.send_json::<ListTagsResponse>(self.client.get(&tags_url)),
+            self.fetch_member_names(project_id),
+        );
+        let member_names = member_names.unwrap_or_else(|e| {
+            tracing::debug!("Failed to fetch member names: {}", e.message);
+            HashMap::new()
+        });
+        let tag_names = tags?
+            .tags
+            .into_iter()
+            .map(|tag| (tag.id, tag.name))
+            .collect::<HashMap<_, _>>();
+
+        let mut details = IssueDetailsByIssue::default();
+        for assignee in assignees?.issue_assignees {
+            let name = member_names
+                .get(&assignee.user_id)
+                .cloned()
+                .unwrap_or_else(|| assignee.user_id.to_string());
+            details
+                .assignees
+                .entry(assignee.issue_id)
+                .or_default()
+                .push(name);
+        }
+        for issue_tag in issue_tags?.issue_tags {
+            if let Some(name) = tag_names.get(&issue_tag.tag_id) {
+                details
+                    .tags
+                    .entry(issue_tag.issue_id)
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+        for names in details
+            .assignees
+            .values_mut()
+            .chain(details.tags.values_mut())
+        {
+            names.sort();
+        }
+        Ok(details)
+    }
+
+    // Display names of the members of a project's organization, keyed by user ID.
+    async fn fetch_member_names(
+        &self,
+        project_id: Uuid,
+    ) -> Result<HashMap<Uuid, String>, ToolError> {
+        Ok(self
+            .fetch_project_members(project_id)
+            .await?
+            .into_iter()
+            .filter_map(|member| {
+                let name = member.username.or(member.email)?;
+                Some((member.user_id, name))
+            })
+            .collect())
+    }
+
+    // Pull requests for each issue in a page of `list_issues`, in page order. A single issue
+    // is cheaper to look up on its own than the whole project's pull requests. If the
+    // project-wide lookup fails, e.g. against an older server, each issue is fetched instead.
+    async fn fetch_page_pull_requests(
+        &self,
+        project_id: Uuid,
+        issues: &[Issue],
+    ) -> Vec<ListPullRequestsResponse> {
+        if issues.len() > 1
+            && let Ok(mut by_issue) = self.fetch_pull_requests_by_issue(project_id).await
+        {
+            return issues
+                .iter()
+                .map(|issue| ListPullRequestsResponse {
+                    pull_requests: by_issue.remove(&issue.id).unwrap_or_default(),
+                })
+                .collect();
+        }
+
+        stream::iter(
+            issues
+                .iter()
+                .map(|issue| self.fetch_pull_requests(issue.id)),
+        )
+        .buffered(PULL_REQUEST_FETCH_CONCURRENCY)
+        .collect()
+        .await
+    }
+
+    async fn fetch_pull_requests(&self, issue_id: Uuid) -> ListPullRequestsResponse {
+        let url = self.url(&format!("/api/remote/pull-requests?issue_id={}", issue_id));
+        match self
+            .send_json::<ListPullRequestsResponse>(self.client.get(&url))
+            .await
+        {
+            Ok(response) => response,
+            Err(_) => ListPullRequestsResponse {
+                pull_requests: vec![],
+            },
+        }
+    }
+
+    /// Fetches tags for an issue, resolving tag_ids to names via project tags.
+    async fn fetch_issue_tags_resolved(
+        &self,
+        project_id: Uuid,
+        issue_id: Uuid,
+    ) -> Vec<McpTagSummary> {
+        let tags_url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let project_tags: ListTagsResponse = match self.send_json(self.client.get(&tags_url)).await
+        {
+            Ok(r) => r,
+            Err(_) => return Vec::new(),
+        };
+        let tag_map: HashMap<Uuid, &api_types::Tag> =
+            project_tags.tags.iter().map(|t| (t.id, t)).collect();
+
+        let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", issue_id));
+        let response: ListIssueTagsResponse = match self.send_json(self.client.get(&url)).await {
+            Ok(r) => r,
+            Err(_) => return Vec::new(),
+        };
+
+        response
+            .issue_tags
+            .iter()
+            .filter_map(|it| {
+                tag_map.get(&it.tag_id).map(|tag| McpTagSummary {
+                    id: tag.id.to_string(),
+                    name: tag.name.clone(),
+                    color: tag.color.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Fetches relationships for an issue in both directions, resolving the other issue's
+    /// simple_id.
+    async fn fetch_issue_relationships_resolved(
+        &self,
+        project_id: Uuid,
+        issue_id: Uuid,
+    ) -> Vec<McpRelationshipSummary> {
+        let rel_url = self.url(&format!(
+            "/api/remote/issue-relationships?issue_id={}",
+            issue_id
+        ));
+        let response: ListIssueRelationshipsResponse =
+            match self.send_json(self.client.get(&rel_url)).await {
+                Ok(r) => r,
+                Err(_) => return Vec::new(),
+            };
+
+        if response.issue_relationships.is_empty() {
             return Vec::new();
         }
 
@@ -785,14 +3244,18 @@ impl McpServer {
             .issue_relationships
             .into_iter()
             .map(|r| {
-                let related_simple_id = simple_id_map
-                    .get(&r.related_issue_id)
-                    .unwrap_or(&"")
-                    .to_string();
+                let outgoing = r.issue_id == issue_id;
+                let other_id = if outgoing {
+                    r.related_issue_id
+                } else {
+                    r.issue_id
+                };
+                let related_simple_id = simple_id_map.get(&other_id).unwrap_or(&"").to_string();
                 McpRelationshipSummary {
                     id: r.id.to_string(),
-                    related_issue_id: r.related_issue_id.to_string(),
+                    related_issue_id: other_id.to_string(),
                     related_simple_id,
+                    direction: if outgoing { "outgoing" } else { "incoming" },
                     relationship_type: match r.relationship_type {
                         IssueRelationshipType::Blocking => "blocking".to_string(),
                         IssueRelationshipType::Related => "related".to_string(),
@@ -809,151 +3272,2216 @@ impl McpServer {
         project_id: Uuid,
         parent_issue_id: Uuid,
     ) -> Vec<McpSubIssueSummary> {
-        let url = self.url(&format!("/api/remote/issues?project_id={}", project_id));
-        let response: api_types::ListIssuesResponse =
-            match self.send_json(self.client.get(&url)).await {
-                Ok(r) => r,
-                Err(_) => return Vec::new(),
-            };
+        let Ok(children) = self.fetch_child_issues(project_id, parent_issue_id).await else {
+            return Vec::new();
+        };
+
+        let status_names = self
+            .fetch_project_statuses(project_id)
+            .await
+            .ok()
+            .map(|statuses| {
+                statuses
+                    .into_iter()
+                    .map(|s| (s.id, s.name))
+                    .collect::<HashMap<_, _>>()
+            });
+
+        children
+            .iter()
+            .map(|i| {
+                let status = status_names
+                    .as_ref()
+                    .and_then(|m| m.get(&i.status_id).cloned())
+                    .unwrap_or_else(|| i.status_id.to_string());
+                McpSubIssueSummary {
+                    id: i.id.to_string(),
+                    simple_id: i.simple_id.clone(),
+                    web_url: self.issue_web_url(i.project_id, i.id),
+                    title: i.title.clone(),
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    fn is_clear_sentinel(value: &str) -> bool {
+        value.eq_ignore_ascii_case("clear") || value.eq_ignore_ascii_case("none")
+    }
+
+    // Maps an optional date field to the `UpdateIssueRequest` shape: omitted leaves the
+    // value unchanged, null or "clear" unsets it.
+    fn parse_date_update(
+        field: &str,
+        value: Option<Option<String>>,
+    ) -> Result<Option<Option<DateTime<Utc>>>, ToolError> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let Some(value) = value.as_deref().map(str::trim) else {
+            return Ok(Some(None));
+        };
+        if Self::is_clear_sentinel(value) {
+            return Ok(Some(None));
+        }
+
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Ok(Some(Some(date_time.with_timezone(&Utc))));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Ok(Some(Some(date.and_time(NaiveTime::MIN).and_utc())));
+        }
+
+        Err(ToolError::new(
+            format!("Invalid {} '{}'", field, value),
+            Some("Use RFC3339 (e.g. '2026-03-01T09:00:00Z') or YYYY-MM-DD, or 'clear' to unset."),
+        ))
+    }
+
+    // Parses a list filter date. A bare date means midnight UTC at the start of that day.
+    fn parse_date_filter(
+        field: &str,
+        value: Option<&str>,
+    ) -> Result<Option<DateTime<Utc>>, ToolError> {
+        let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+            return Ok(None);
+        };
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Ok(Some(date_time.with_timezone(&Utc)));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Ok(Some(date.and_time(NaiveTime::MIN).and_utc()));
+        }
+
+        Err(ToolError::new(
+            format!("Invalid {} '{}'", field, value),
+            Some("Use RFC3339 (e.g. '2026-03-01T09:00:00Z') or YYYY-MM-DD."),
+        ))
+    }
+
+    fn validate_date_range(
+        start_date: Option<DateTime<Utc>>,
+        target_date: Option<DateTime<Utc>>,
+    ) -> Result<(), ToolError> {
+        match (start_date, target_date) {
+            (Some(start), Some(target)) if target < start => Err(ToolError::message(format!(
+                "target_date ({}) must not be before start_date ({})",
+                target.to_rfc3339(),
+                start.to_rfc3339()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_parent_update(
+        issue_id: Uuid,
+        value: Option<Option<String>>,
+    ) -> Result<Option<Option<Uuid>>, ToolError> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let Some(value) = value.as_deref().map(str::trim) else {
+            return Ok(Some(None));
+        };
+        if Self::is_clear_sentinel(value) {
+            return Ok(Some(None));
+        }
+
+        let parent_id = Uuid::parse_str(value).map_err(|_| {
+            ToolError::message(format!(
+                "Invalid parent_issue_id '{}'. Pass an issue UUID, or null / 'none' to un-nest.",
+                value
+            ))
+        })?;
+        if parent_id == issue_id {
+            return Err(ToolError::message("An issue cannot be its own parent"));
+        }
+        Ok(Some(Some(parent_id)))
+    }
+
+    // Walks up from the proposed parent so an issue can't end up nested under its own
+    // descendant, and checks the parent is in the same project.
+    async fn check_new_parent(&self, issue: &Issue, parent_id: Uuid) -> Result<(), ToolError> {
+        if parent_id == issue.id {
+            return Err(ToolError::message("An issue cannot be its own parent"));
+        }
+        let parent = self.fetch_issue(parent_id).await?;
+        if parent.project_id != issue.project_id {
+            return Err(ToolError::new(
+                "The parent issue must belong to the same project",
+                Some(format!(
+                    "{} is in project {}, {} is in project {}",
+                    issue.simple_id, issue.project_id, parent.simple_id, parent.project_id
+                )),
+            ));
+        }
+
+        let mut chain = vec![parent.simple_id.clone()];
+        let mut next = parent.parent_issue_id;
+        while let Some(ancestor_id) = next {
+            if ancestor_id == issue.id {
+                chain.reverse();
+                return Err(ToolError::new(
+                    format!(
+                        "Cannot nest {0} under {1}: {1} is already nested under {0}",
+                        issue.simple_id, parent.simple_id
+                    ),
+                    Some(format!("{} > {}", issue.simple_id, chain.join(" > "))),
+                ));
+            }
+            if chain.len() >= MAX_PARENT_DEPTH {
+                return Err(ToolError::new(
+                    "Parent chain too deep to verify",
+                    Some(format!(
+                        "Stopped after {} ancestors above {}",
+                        MAX_PARENT_DEPTH, parent.simple_id
+                    )),
+                ));
+            }
+            let ancestor = self.fetch_issue(ancestor_id).await?;
+            chain.push(ancestor.simple_id);
+            next = ancestor.parent_issue_id;
+        }
+        Ok(())
+    }
+
+    fn issue_priority_label(priority: IssuePriority) -> &'static str {
+        match priority {
+            IssuePriority::Urgent => "urgent",
+            IssuePriority::High => "high",
+            IssuePriority::Medium => "medium",
+            IssuePriority::Low => "low",
+        }
+    }
+
+    // Attaches project tags by name, returning a warning for each tag that could not be attached.
+    async fn attach_tags_by_name(
+        &self,
+        project_id: Uuid,
+        issue_id: Uuid,
+        tag_names: &[String],
+    ) -> Vec<String> {
+        if tag_names.is_empty() {
+            return Vec::new();
+        }
+
+        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let tags: ListTagsResponse = match self.send_json(self.client.get(&url)).await {
+            Ok(tags) => tags,
+            Err(e) => return vec![format!("Could not load project tags: {}", e)],
+        };
+
+        let mut warnings = Vec::new();
+        for tag_name in tag_names {
+            let Some(tag_id) = Self::matching_ids_by_name(
+                tags.tags.iter().map(|tag| (tag.id, tag.name.as_str())),
+                tag_name.trim(),
+            )
+            .first()
+            .copied() else {
+                warnings.push(format!("Tag '{}' does not exist in this project", tag_name));
+                continue;
+            };
+            if let Err(e) = self.attach_issue_tag(issue_id, tag_id, None).await {
+                warnings.push(format!("Could not attach tag '{}': {}", tag_name, e));
+            }
+        }
+        warnings
+    }
+
+    async fn find_tag_ids_by_name(
+        &self,
+        project_id: Uuid,
+        tag_name: &str,
+    ) -> Result<Vec<Uuid>, ToolError> {
+        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let tags: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
+        Ok(Self::matching_ids_by_name(
+            tags.tags.iter().map(|tag| (tag.id, tag.name.as_str())),
+            tag_name,
+        ))
+    }
+
+    fn matching_ids_by_name<'a>(
+        items: impl IntoIterator<Item = (Uuid, &'a str)>,
+        name: &str,
+    ) -> Vec<Uuid> {
+        items
+            .into_iter()
+            .filter(|(_, item_name)| name_matching::names_match(item_name, name))
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    fn resolve_tag_filters(
+        tag_id: Option<Uuid>,
+        matching_tag_ids: Option<Vec<Uuid>>,
+    ) -> (Option<Uuid>, Option<Vec<Uuid>>, bool) {
+        match (tag_id, matching_tag_ids) {
+            (Some(tag_id), Some(matching_tag_ids)) => {
+                if matching_tag_ids.contains(&tag_id) {
+                    (Some(tag_id), None, false)
+                } else {
+                    (None, None, true)
+                }
+            }
+            (None, Some(matching_tag_ids)) => {
+                let missing_tag_name_match = matching_tag_ids.is_empty();
+                (
+                    None,
+                    (!missing_tag_name_match).then_some(matching_tag_ids),
+                    missing_tag_name_match,
+                )
+            }
+            (Some(tag_id), None) => (Some(tag_id), None, false),
+            (None, None) => (None, None, false),
+        }
+    }
+}
+
+impl McpServer {
+    // Fetches the issues named by a bulk update. Simple IDs are looked up in one project, and an
+    // issue named twice, by UUID and by simple ID, is only updated once.
+    async fn fetch_bulk_issues(
+        &self,
+        references: Vec<IssueReference>,
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<Result<Issue, (IssueReference, ToolError)>>, ToolError> {
+        let mut unique_references = Vec::with_capacity(references.len());
+        for reference in references {
+            if !unique_references.contains(&reference) {
+                unique_references.push(reference);
+            }
+        }
+        if unique_references.is_empty() {
+            return Err(ToolError::message(
+                "`issue_ids` and `simple_ids` must not both be empty.",
+            ));
+        }
+        if unique_references.len() > MAX_BULK_ISSUES {
+            return Err(ToolError::message(format!(
+                "At most {MAX_BULK_ISSUES} issues can be updated at once, got {}.",
+                unique_references.len()
+            )));
+        }
+        let project_id = if unique_references
+            .iter()
+            .any(|reference| matches!(reference, IssueReference::SimpleId(_)))
+        {
+            Some(self.resolve_project_id(project_id).await?)
+        } else {
+            None
+        };
+
+        let targets: Vec<_> =
+            stream::iter(unique_references.into_iter().map(|reference| async move {
+                let issue = match &reference {
+                    IssueReference::Id(issue_id) => self.fetch_issue(*issue_id).await,
+                    IssueReference::SimpleId(simple_id) => {
+                        self.fetch_issue_by_simple_id(simple_id, project_id).await
+                    }
+                };
+                issue.map_err(|e| (reference, e))
+            }))
+            .buffered(BULK_UPDATE_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut seen = HashSet::new();
+        Ok(targets
+            .into_iter()
+            .filter(|target| match target {
+                Ok(issue) => seen.insert(issue.id),
+                Err(_) => true,
+            })
+            .collect())
+    }
+
+    // Issues matching a bulk filter. Refuses to proceed unless `confirm_count` matches, so an
+    // empty or overly broad filter can't silently touch a whole project.
+    async fn find_bulk_issues(
+        &self,
+        filter: McpBulkIssueFilter,
+        confirm_count: Option<usize>,
+    ) -> Result<Vec<Issue>, ToolError> {
+        let project_id = self.resolve_project_id(filter.project_id).await?;
+        let status_id = match filter.status.as_deref() {
+            Some(status) => Some(self.resolve_status_id(project_id, status).await?),
+            None => None,
+        };
+        let priority = filter.priority.map(|priority| priority.0);
+        let tag_ids = match filter.tag_name.as_deref() {
+            Some(tag_name) => {
+                let tag_ids = self.find_tag_ids_by_name(project_id, tag_name).await?;
+                if tag_ids.is_empty() {
+                    return Err(ToolError::message(format!(
+                        "Tag '{tag_name}' does not exist in this project."
+                    )));
+                }
+                Some(tag_ids)
+            }
+            None => None,
+        };
+
+        let query = SearchIssuesRequest {
+            project_id,
+            status_id,
+            priority,
+            tag_ids,
+            limit: Some(MAX_BULK_ISSUES as i32),
+            offset: Some(0),
+            ..Default::default()
+        };
+        let url = self.url("/api/remote/issues/search");
+        let response: ListIssuesResponse =
+            self.send_json(self.client.post(&url).json(&query)).await?;
+
+        if response.total_count > MAX_BULK_ISSUES {
+            return Err(ToolError::message(format!(
+                "The filter matches {} issues; at most {MAX_BULK_ISSUES} can be updated at once. Narrow the filter.",
+                response.total_count
+            )));
+        }
+        if confirm_count != Some(response.total_count) {
+            return Err(ToolError::new(
+                format!(
+                    "The filter matches {} issues. Pass `confirm_count: {}` to update them.",
+                    response.total_count, response.total_count
+                ),
+                Some("Nothing was changed."),
+            ));
+        }
+        Ok(response.issues)
+    }
+
+    async fn resolve_bulk_changes(
+        &self,
+        project_id: Uuid,
+        status: Option<&str>,
+        priority: Option<IssuePriority>,
+        add_tag_names: &[String],
+        remove_tag_names: &[String],
+        add_assignees: &[String],
+        remove_assignees: &[String],
+    ) -> Result<BulkIssueChanges, ToolError> {
+        let status_id = match status {
+            Some(status) => Some(self.resolve_status_id(project_id, status).await?),
+            None => None,
+        };
+
+        let mut changes = BulkIssueChanges {
+            status_id,
+            priority,
+            ..Default::default()
+        };
+        for assignee in add_assignees {
+            match self.resolve_bulk_assignee(project_id, assignee).await {
+                Ok(user_id) => changes.add_assignee_ids.push(user_id),
+                Err(warning) => changes.warnings.push(warning),
+            }
+        }
+        for assignee in remove_assignees {
+            match self.resolve_bulk_assignee(project_id, assignee).await {
+                Ok(user_id) => changes.remove_assignee_ids.push(user_id),
+                Err(warning) => changes.warnings.push(warning),
+            }
+        }
+        if add_tag_names.is_empty() && remove_tag_names.is_empty() {
+            return Ok(changes);
+        }
+
+        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
+        let tags: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
+        let tag_id = |name: &String| {
+            Self::matching_ids_by_name(
+                tags.tags.iter().map(|tag| (tag.id, tag.name.as_str())),
+                name.trim(),
+            )
+            .first()
+            .copied()
+            .ok_or_else(|| {
+                ToolError::message(format!("Tag '{}' does not exist in this project", name))
+            })
+        };
+        changes.add_tag_ids = add_tag_names.iter().map(tag_id).collect::<Result<_, _>>()?;
+        changes.remove_tag_ids = remove_tag_names
+            .iter()
+            .map(tag_id)
+            .collect::<Result<_, _>>()?;
+        Ok(changes)
+    }
+
+    // A user ID for a bulk assignee, or the warning to report when it does not resolve.
+    async fn resolve_bulk_assignee(
+        &self,
+        project_id: Uuid,
+        assignee: &str,
+    ) -> Result<Uuid, String> {
+        if let Ok(user_id) = Uuid::parse_str(assignee.trim()) {
+            return Ok(user_id);
+        }
+        self.resolve_member_user_id(project_id, assignee)
+            .await
+            .map_err(|e| format!("Could not resolve assignee '{}': {}", assignee, e))
+    }
+
+    async fn apply_bulk_changes(
+        &self,
+        issue_id: Uuid,
+        changes: &BulkIssueChanges,
+    ) -> Result<(), ToolError> {
+        if changes.status_id.is_some() || changes.priority.is_some() {
+            let payload = UpdateIssueRequest {
+                status_id: changes.status_id,
+                title: None,
+                description: None,
+                priority: changes.priority.map(Some),
+                start_date: None,
+                target_date: None,
+                completed_at: None,
+                sort_order: None,
+                parent_issue_id: None,
+                parent_issue_sort_order: None,
+                extension_metadata: None,
+            };
+            let url = self.url(&format!("/api/remote/issues/{}", issue_id));
+            let _: MutationResponse<Issue> = self
+                .send_json(self.client.patch(&url).json(&payload))
+                .await?;
+        }
+
+        if !changes.add_assignee_ids.is_empty() || !changes.remove_assignee_ids.is_empty() {
+            self.apply_bulk_assignees(issue_id, changes).await?;
+        }
+
+        if changes.add_tag_ids.is_empty() && changes.remove_tag_ids.is_empty() {
+            return Ok(());
+        }
+        let url = self.url(&format!("/api/remote/issue-tags?issue_id={}", issue_id));
+        let current: ListIssueTagsResponse = self.send_json(self.client.get(&url)).await?;
+        for tag_id in &changes.add_tag_ids {
+            if !current.issue_tags.iter().any(|tag| tag.tag_id == *tag_id) {
+                self.attach_issue_tag(issue_id, *tag_id, None).await?;
+            }
+        }
+        for issue_tag in current
+            .issue_tags
+            .iter()
+            .filter(|tag| changes.remove_tag_ids.contains(&tag.tag_id))
+        {
+            let url = self.url(&format!("/api/remote/issue-tags/{}", issue_tag.id));
+            self.send_empty_json(self.client.delete(&url)).await?;
+        }
+        Ok(())
+    }
+
+    async fn apply_bulk_assignees(
+        &self,
+        issue_id: Uuid,
+        changes: &BulkIssueChanges,
+    ) -> Result<(), ToolError> {
+        let url = self.url(&format!(
+            "/api/remote/issue-assignees?issue_id={}",
+            issue_id
+        ));
+        let current: ListIssueAssigneesResponse = self.send_json(self.client.get(&url)).await?;
+        for user_id in &changes.add_assignee_ids {
+            if !current
+                .issue_assignees
+                .iter()
+                .any(|assignee| assignee.user_id == *user_id)
+            {
+                self.assign_issue_user(issue_id, *user_id, None).await?;
+            }
+        }
+        for assignee in current
+            .issue_assignees
+            .iter()
+            .filter(|assignee| changes.remove_assignee_ids.contains(&assignee.user_id))
+        {
+            let url = self.url(&format!("/api/remote/issue-assignees/{}", assignee.id));
+            self.send_empty_json(self.client.delete(&url)).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use axum::{
+        Json, Router,
+        extract::Path,
+        http::StatusCode,
+        response::IntoResponse,
+        routing::{delete, get, post},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::{
+        McpContext, RemoteLinkStatus,
+        context_cache::ContextCache,
+        tools::test_support::{
+            MockBackend, envelope, issue_json, member_json, project_with_members_router,
+        },
+    };
+
+    #[test]
+    fn priorities_accept_aliases_and_list_them_in_errors() {
+        let parse = |value: serde_json::Value| serde_json::from_value::<McpIssuePriority>(value);
+
+        assert_eq!(parse(json!("High")).unwrap().0, IssuePriority::High);
+        assert_eq!(parse(json!("P1")).unwrap().0, IssuePriority::High);
+        assert_eq!(parse(json!("critical")).unwrap().0, IssuePriority::Urgent);
+        assert_eq!(parse(json!("normal")).unwrap().0, IssuePriority::Medium);
+        assert_eq!(parse(json!(" minor ")).unwrap().0, IssuePriority::Low);
+
+        let error = parse(json!("blocker")).unwrap_err().to_string();
+        assert!(error.contains("Unknown priority 'blocker'"), "{error}");
+        assert!(error.contains("'p0' (urgent)"), "{error}");
+    }
+
+    #[test]
+    fn priorities_ignore_spacing_and_separators() {
+        let cases = [
+            ("HIGH ", IssuePriority::High),
+            ("\tmedium", IssuePriority::Medium),
+            ("p-0", IssuePriority::Urgent),
+            ("P 2", IssuePriority::Medium),
+            ("p_3", IssuePriority::Low),
+            ("Urgent\n", IssuePriority::Urgent),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                input.parse::<McpIssuePriority>().unwrap().0,
+                expected,
+                "input {input:?}"
+            );
+        }
+
+        let error = "hgh".parse::<McpIssuePriority>().unwrap_err();
+        assert!(error.contains("Did you mean 'high'?"), "{error}");
+    }
+
+    #[test]
+    fn update_priority_distinguishes_omitted_from_cleared() {
+        let parse = |value: serde_json::Value| {
+            serde_json::from_value::<McpUpdateIssueRequest>(value)
+                .unwrap()
+                .priority
+        };
+
+        assert_eq!(parse(json!({})), None);
+        assert_eq!(parse(json!({ "priority": null })), Some(None));
+        assert_eq!(parse(json!({ "priority": "none" })), Some(None));
+        assert_eq!(
+            parse(json!({ "priority": "p0" })),
+            Some(Some(McpIssuePriority(IssuePriority::Urgent)))
+        );
+    }
+
+    #[tokio::test]
+    async fn list_issues_includes_assignee_and_tag_names_on_request() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issues = (1..=2)
+            .map(|number| issue_json(project_id, status_id, number))
+            .collect::<Vec<_>>();
+        let issue_id = issues[0]["id"].clone();
+        let (alice_id, unknown_id, tag_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/search",
+                post(move || {
+                    let issues = issues.clone();
+                    async move {
+                        envelope(json!({
+                            "issues": issues,
+                            "total_count": 2,
+                            "limit": 50,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issue-assignees",
+                get({
+                    let issue_id = issue_id.clone();
+                    move || async move {
+                        let assignee = |user_id: Uuid| {
+                            json!({
+                                "id": Uuid::new_v4(),
+                                "issue_id": issue_id,
+                                "user_id": user_id,
+                                "assigned_at": "2026-01-01T00:00:00Z",
+                            })
+                        };
+                        envelope(json!({
+                            "issue_assignees": [assignee(unknown_id), assignee(alice_id)],
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                get(move || async move {
+                    envelope(json!({ "issue_tags": [
+                        { "id": Uuid::new_v4(), "issue_id": issue_id, "tag_id": tag_id },
+                    ] }))
+                }),
+            )
+            .route(
+                "/api/remote/tags",
+                get(move || async move {
+                    envelope(json!({ "tags": [
+                        {
+                            "id": tag_id,
+                            "project_id": project_id,
+                            "name": "bug",
+                            "color": "0 84% 60%",
+                        },
+                    ] }))
+                }),
+            )
+            .merge(project_with_members_router(
+                project_id,
+                vec![member_json(alice_id, "alice")],
+            ));
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "include_details": true,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let mut expected_assignees = vec!["alice".to_string(), unknown_id.to_string()];
+        expected_assignees.sort();
+        assert_eq!(body["issues"][0]["assignees"], json!(expected_assignees));
+        assert_eq!(body["issues"][0]["tags"], json!(["bug"]));
+        assert_eq!(body["issues"][1]["assignees"], json!([]));
+        assert_eq!(body["issues"][1]["tags"], json!([]));
+        assert_eq!(backend.requests_to("/api/remote/issue-assignees"), 1);
+    }
+
+    #[tokio::test]
+    async fn list_issues_filters_by_assignee_email() {
+        let project_id = Uuid::new_v4();
+        let alice_id = Uuid::new_v4();
+        let search_body = Arc::new(std::sync::Mutex::new(None));
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post({
+                    let search_body = search_body.clone();
+                    move |Json(body): Json<serde_json::Value>| async move {
+                        *search_body.lock().unwrap() = Some(body);
+                        envelope(json!({
+                            "issues": [],
+                            "total_count": 0,
+                            "limit": 50,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .merge(project_with_members_router(
+                project_id,
+                vec![member_json(alice_id, "alice")],
+            ));
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "assignee": "Alice@Example.com",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body = search_body.lock().unwrap().take().unwrap();
+        assert_eq!(body["assignee_user_id"], alice_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn list_my_issues_sorts_by_priority_then_recent_updates() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let issue = |number: i32, priority: Option<&str>, updated_at: &str| {
+            let mut issue = issue_json(project_id, status_id, number);
+            issue["priority"] = json!(priority);
+            issue["updated_at"] = json!(updated_at);
+            issue
+        };
+        let issues = vec![
+            issue(1, Some("high"), "2026-01-02T00:00:00Z"),
+            issue(2, Some("high"), "2026-01-05T00:00:00Z"),
+            issue(3, None, "2026-01-09T00:00:00Z"),
+            issue(4, Some("urgent"), "2026-01-01T00:00:00Z"),
+        ];
+        let search_body = Arc::new(std::sync::Mutex::new(None));
+
+        let router = Router::new()
+            .route(
+                "/api/auth/user",
+                get(move || async move { envelope(json!({ "user_id": user_id })) }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post({
+                    let search_body = search_body.clone();
+                    move |Json(body): Json<serde_json::Value>| async move {
+                        *search_body.lock().unwrap() = Some(body);
+                        envelope(json!({
+                            "issues": issues,
+                            "total_count": 4,
+                            "limit": 100,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async { envelope(json!({ "pull_requests": [] })) }),
+            )
+            .route(
+                "/api/remote/pull-request-issues",
+                get(|| async { envelope(json!({ "pull_request_issues": [] })) }),
+            )
+            .merge(project_with_members_router(
+                project_id,
+                vec![member_json(user_id, "alice")],
+            ));
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .list_my_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["user_id"], user_id.to_string());
+        assert_eq!(body["username"], "alice");
+        let simple_ids = body["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| issue["simple_id"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(simple_ids, ["VK-4", "VK-2", "VK-1", "VK-3"]);
+        let search = search_body.lock().unwrap().take().unwrap();
+        assert_eq!(search["assignee_user_id"], user_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn list_my_issues_requires_an_authenticated_user() {
+        let router = Router::new().route(
+            "/api/auth/user",
+            get(|| async {
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "success": false, "message": "Unauthorized" })),
+                )
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": Uuid::new_v4() })).unwrap();
+        let result = backend
+            .server()
+            .list_my_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["error"], "No authenticated remote user");
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 0);
+    }
+
+    #[tokio::test]
+    async fn list_issues_fetches_a_page_of_pull_requests_in_one_batch() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issues = (1..=3)
+            .map(|number| issue_json(project_id, status_id, number))
+            .collect::<Vec<_>>();
+        let issue_ids = issues
+            .iter()
+            .map(|issue| issue["id"].clone())
+            .collect::<Vec<_>>();
+        let pull_request = |number: i32| {
+            json!({
+                "id": Uuid::new_v4(),
+                "url": format!("https://github.com/acme/app/pull/{number}"),
+                "number": number,
+                "status": "open",
+                "merged_at": null,
+                "merge_commit_sha": null,
+                "target_branch_name": "main",
+                "project_id": project_id,
+                "issue_id": issue_ids[0],
+                "workspace_id": null,
+                "created_at": "2026-01-01T00:00:00Z",
+                "updated_at": "2026-01-01T00:00:00Z",
+            })
+        };
+        let (first_pr, second_pr) = (pull_request(7), pull_request(8));
+        // The first PR was later linked to VK-2 as well.
+        let links = json!([
+            { "id": Uuid::new_v4(), "pull_request_id": first_pr["id"], "issue_id": issue_ids[0] },
+            { "id": Uuid::new_v4(), "pull_request_id": second_pr["id"], "issue_id": issue_ids[0] },
+            { "id": Uuid::new_v4(), "pull_request_id": first_pr["id"], "issue_id": issue_ids[1] },
+        ]);
+        let pull_requests = json!([first_pr, second_pr]);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move || {
+                    let issues = issues.clone();
+                    async move {
+                        envelope(json!({
+                            "issues": issues,
+                            "total_count": 3,
+                            "limit": 50,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(move || async move { envelope(json!({ "pull_requests": pull_requests })) }),
+            )
+            .route(
+                "/api/remote/pull-request-issues",
+                get(move || async move { envelope(json!({ "pull_request_issues": links })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let counts = body["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| issue["pull_request_count"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1, 0]);
+        assert_eq!(
+            body["issues"][1]["latest_pr_url"],
+            "https://github.com/acme/app/pull/7"
+        );
+        assert_eq!(backend.requests_to("/api/remote/pull-requests"), 1);
+        assert_eq!(backend.requests_to("/api/remote/pull-request-issues"), 1);
+    }
+
+    #[tokio::test]
+    async fn list_issues_sends_assignee_and_tag_filters_with_the_search() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let assignee_id = Uuid::new_v4();
+        let bug_tag_id = Uuid::new_v4();
+        let issues = (1..=20)
+            .map(|number| issue_json(project_id, status_id, number))
+            .collect::<Vec<_>>();
+        let search_body = Arc::new(std::sync::Mutex::new(None));
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/tags",
+                get(move || async move {
+                    envelope(json!({ "tags": [{
+                        "id": bug_tag_id,
+                        "project_id": project_id,
+                        "name": "Bug",
+                        "color": "0 0% 0%",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post({
+                    let search_body = search_body.clone();
+                    move |Json(body): Json<serde_json::Value>| {
+                        *search_body.lock().unwrap() = Some(body);
+                        let issues = issues.clone();
+                        async move {
+                            envelope(json!({
+                                "issues": issues,
+                                "total_count": 20,
+                                "limit": 50,
+                                "offset": 0,
+                            }))
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async { envelope(json!({ "pull_requests": [] })) }),
+            )
+            .route(
+                "/api/remote/pull-request-issues",
+                get(|| async { envelope(json!({ "pull_request_issues": [] })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "assignee_user_id": assignee_id,
+            "tag_name": "bug",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body = search_body.lock().unwrap().take().unwrap();
+        assert_eq!(body["assignee_user_id"], assignee_id.to_string());
+        assert_eq!(body["tag_ids"], json!([bug_tag_id]));
+        // Filtering happens in the search, so the request count doesn't grow with the page.
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 1);
+        assert_eq!(backend.requests_to("/api/remote/tags"), 1);
+        assert_eq!(backend.requests_to("/api/remote/issue-assignees"), 0);
+        assert_eq!(backend.requests_to("/api/remote/issue-tags"), 0);
+    }
+
+    #[tokio::test]
+    async fn list_issues_falls_back_to_concurrent_per_issue_pull_request_fetches() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issues = (1..=50)
+            .map(|number| issue_json(project_id, status_id, number))
+            .collect::<Vec<_>>();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move || {
+                    let issues = issues.clone();
+                    async move {
+                        envelope(json!({
+                            "issues": issues,
+                            "total_count": 50,
+                            "limit": 50,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    envelope(json!({ "pull_requests": [] }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 1);
+        // One project-wide attempt, which fails without the links endpoint, then one per issue.
+        assert_eq!(backend.requests_to("/api/remote/pull-request-issues"), 1);
+        assert_eq!(backend.requests_to("/api/remote/pull-requests"), 51);
+        assert!(backend.max_in_flight() > 1);
+        assert!(backend.max_in_flight() <= PULL_REQUEST_FETCH_CONCURRENCY);
+    }
+
+    #[tokio::test]
+    async fn issue_details_share_one_project_status_fetch() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let router = Router::new().route(
+            "/api/remote/project-statuses",
+            get(move || async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                envelope(json!({
+                    "project_statuses": [{
+                        "id": status_id,
+                        "project_id": project_id,
+                        "name": "Todo",
+                        "color": "#000000",
+                        "sort_order": 0,
+                        "hidden": false,
+                        "created_at": "2026-01-01T00:00:00Z",
+                    }],
+                }))
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let issues = (1..=20)
+            .map(|number| serde_json::from_value(issue_json(project_id, status_id, number)))
+            .collect::<Result<Vec<Issue>, _>>()
+            .unwrap();
+        let details = futures::future::join_all(issues.iter().map(|issue| {
+            server.issue_to_details(
+                issue,
+                ListPullRequestsResponse {
+                    pull_requests: vec![],
+                },
+            )
+        }))
+        .await;
+
+        assert!(details.iter().all(|detail| detail.status == "Todo"));
+        assert_eq!(backend.requests_to("/api/remote/project-statuses"), 1);
+    }
+
+    #[tokio::test]
+    async fn resolve_status_id_refetches_when_cached_statuses_miss() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = Router::new().route(
+            "/api/remote/project-statuses",
+            get(move || {
+                let calls = calls.clone();
+                async move {
+                    // The "Review" column only exists from the second fetch onwards.
+                    let statuses = if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                        json!([])
+                    } else {
+                        json!([{
+                            "id": status_id,
+                            "project_id": project_id,
+                            "name": "Review",
+                            "color": "#000000",
+                            "sort_order": 0,
+                            "hidden": false,
+                            "created_at": "2026-01-01T00:00:00Z",
+                        }])
+                    };
+                    envelope(json!({ "project_statuses": statuses }))
+                }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        server.fetch_project_statuses(project_id).await.unwrap();
+        let resolved = server
+            .resolve_status_id(project_id, "review")
+            .await
+            .unwrap();
+
+        assert_eq!(resolved, status_id);
+        assert_eq!(backend.requests_to("/api/remote/project-statuses"), 2);
+    }
+
+    #[tokio::test]
+    async fn resolve_status_id_normalizes_names_and_suggests_typos() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let router = Router::new().route(
+            "/api/remote/project-statuses",
+            get(move || async move {
+                envelope(json!({ "project_statuses": [{
+                    "id": status_id,
+                    "project_id": project_id,
+                    "name": "In Progress",
+                    "color": "#000000",
+                    "sort_order": 0,
+                    "hidden": false,
+                    "created_at": "2026-01-01T00:00:00Z",
+                }] }))
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        for input in ["In progress ", "in-progress", "IN_PROGRESS"] {
+            assert_eq!(
+                server.resolve_status_id(project_id, input).await.unwrap(),
+                status_id,
+                "input {input:?}"
+            );
+        }
+
+        let error = server
+            .resolve_status_id(project_id, "In Progres")
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .message
+                .starts_with("Unknown status 'In Progres'. Did you mean 'In Progress'?"),
+            "{}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn collects_all_matching_status_ids_case_insensitively() {
+        let first_id = Uuid::new_v4();
+        let second_id = Uuid::new_v4();
+        let statuses = [
+            (first_id, "In Progress"),
+            (second_id, "in progress"),
+            (Uuid::new_v4(), "Todo"),
+        ];
+
+        assert_eq!(
+            McpServer::matching_ids_by_name(statuses, "IN PROGRESS"),
+            vec![first_id, second_id]
+        );
+    }
+
+    #[test]
+    fn collects_all_matching_tag_ids_case_insensitively() {
+        let first_id = Uuid::new_v4();
+        let second_id = Uuid::new_v4();
+        let tags = [
+            (first_id, "bug"),
+            (second_id, "Bug"),
+            (Uuid::new_v4(), "feature"),
+        ];
+
+        assert_eq!(
+            McpServer::matching_ids_by_name(tags, "BUG"),
+            vec![first_id, second_id]
+        );
+    }
+
+    fn project_status(name: &str, sort_order: i32, hidden: bool) -> ProjectStatus {
+        ProjectStatus {
+            id: Uuid::new_v4(),
+            project_id: Uuid::nil(),
+            name: name.to_string(),
+            color: "0 0% 0%".to_string(),
+            sort_order,
+            hidden,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn adjacent_status_skips_hidden_columns() {
+        let statuses = vec![
+            project_status("Backlog", 0, true),
+            project_status("To do", 1, false),
+            project_status("In progress", 2, false),
+            project_status("Blocked", 3, true),
+            project_status("Done", 4, false),
+        ];
+
+        let next = McpServer::adjacent_status(&statuses, &statuses[2], StatusDirection::Next);
+        assert_eq!(next.unwrap().name, "Done");
+        let previous =
+            McpServer::adjacent_status(&statuses, &statuses[4], StatusDirection::Previous);
+        assert_eq!(previous.unwrap().name, "In progress");
+        // Moving forward out of a hidden column lands on the next visible one.
+        let from_backlog =
+            McpServer::adjacent_status(&statuses, &statuses[0], StatusDirection::Next);
+        assert_eq!(from_backlog.unwrap().name, "To do");
+    }
+
+    #[test]
+    fn adjacent_status_errors_past_either_end() {
+        let statuses = vec![
+            project_status("To do", 1, false),
+            project_status("Done", 4, false),
+            project_status("Cancelled", 5, true),
+        ];
+
+        let error = McpServer::adjacent_status(&statuses, &statuses[1], StatusDirection::Next)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'Done'") && error.contains("last"));
+
+        let error = McpServer::adjacent_status(&statuses, &statuses[0], StatusDirection::Previous)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'To do'") && error.contains("first"));
+    }
+
+    #[test]
+    fn board_placement_interpolates_and_renumbers_on_collisions() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let column = |sort_orders: &[f64]| {
+            sort_orders
+                .iter()
+                .enumerate()
+                .map(|(i, sort_order)| {
+                    let mut issue = issue_json(project_id, status_id, i as i32 + 1);
+                    issue["sort_order"] = json!(sort_order);
+                    serde_json::from_value::<Issue>(issue).unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(board_placement(&[], 3), (ISSUE_SORT_ORDER_STEP, vec![]));
+        let spaced = column(&[1.0, 2.0, 3.0]);
+        assert_eq!(board_placement(&spaced, 0), (0.0, vec![]));
+        assert_eq!(board_placement(&spaced, 1), (1.5, vec![]));
+        assert_eq!(board_placement(&spaced, 3), (4.0, vec![]));
+        assert_eq!(board_placement(&spaced, 10), (4.0, vec![]));
+
+        let colliding = column(&[1.0, 2.0, 2.0]);
+        let (sort_order, renumbered) = board_placement(&colliding, 2);
+        assert_eq!(sort_order, 3.0);
+        assert_eq!(renumbered, vec![(colliding[2].id, 4.0)]);
+    }
+
+    #[test]
+    fn recognises_done_like_status_names() {
+        assert!(McpServer::is_done_status("Done"));
+        assert!(McpServer::is_done_status(" completed "));
+        assert!(!McpServer::is_done_status("In review"));
+    }
+
+    #[test]
+    fn parses_update_dates_in_rfc3339_and_plain_date_forms() {
+        let rfc3339 = McpServer::parse_date_update(
+            "start_date",
+            Some(Some("2026-03-01T09:30:00+02:00".into())),
+        )
+        .unwrap();
+        assert_eq!(
+            rfc3339,
+            Some(Some(
+                "2026-03-01T07:30:00Z".parse::<DateTime<Utc>>().unwrap()
+            ))
+        );
+
+        let plain =
+            McpServer::parse_date_update("start_date", Some(Some(" 2026-03-01 ".into()))).unwrap();
+        assert_eq!(
+            plain,
+            Some(Some(
+                "2026-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn date_updates_distinguish_omitted_from_cleared() {
+        assert_eq!(
+            McpServer::parse_date_update("target_date", None).unwrap(),
+            None
+        );
+        assert_eq!(
+            McpServer::parse_date_update("target_date", Some(None)).unwrap(),
+            Some(None)
+        );
+        assert_eq!(
+            McpServer::parse_date_update("target_date", Some(Some("CLEAR".into()))).unwrap(),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_update_dates() {
+        for value in ["", "tomorrow", "2026-02-30", "2026-3-1T00:00", "01/03/2026"] {
+            let error =
+                McpServer::parse_date_update("target_date", Some(Some(value.into()))).unwrap_err();
+            assert!(error.message.starts_with("Invalid target_date"), "{value}");
+        }
+    }
+
+    #[test]
+    fn rejects_target_date_before_start_date() {
+        let start = "2026-03-10T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let target = "2026-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert!(McpServer::validate_date_range(Some(start), Some(target)).is_err());
+        assert!(McpServer::validate_date_range(Some(target), Some(start)).is_ok());
+        assert!(McpServer::validate_date_range(Some(start), Some(start)).is_ok());
+        assert!(McpServer::validate_date_range(Some(start), None).is_ok());
+    }
+
+    #[test]
+    fn update_request_deserializes_null_as_clear() {
+        let issue_id = Uuid::new_v4();
+        let request: McpUpdateIssueRequest = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "start_date": null,
+            "parent_issue_id": null,
+        }))
+        .unwrap();
+
+        assert_eq!(request.start_date, Some(None));
+        assert_eq!(request.target_date, None);
+        assert_eq!(
+            McpServer::parse_parent_update(issue_id, request.parent_issue_id).unwrap(),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn parent_update_accepts_sentinel_and_rejects_self_reference() {
+        let issue_id = Uuid::new_v4();
+        let parent_id = Uuid::new_v4();
+
+        assert_eq!(
+            McpServer::parse_parent_update(issue_id, Some(Some("none".into()))).unwrap(),
+            Some(None)
+        );
+        assert_eq!(
+            McpServer::parse_parent_update(issue_id, Some(Some(parent_id.to_string()))).unwrap(),
+            Some(Some(parent_id))
+        );
+        assert!(
+            McpServer::parse_parent_update(issue_id, Some(Some(issue_id.to_string()))).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn create_issue_reports_failed_attachments_as_warnings() {
+        let project_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let review_id = Uuid::new_v4();
+        let bug_tag_id = Uuid::new_v4();
+        let status = |id: Uuid, name: &str, sort_order: i32| {
+            json!({
+                "id": id,
+                "project_id": project_id,
+                "name": name,
+                "color": "0 0% 0%",
+                "sort_order": sort_order,
+                "hidden": false,
+                "created_at": "2026-01-01T00:00:00Z",
+            })
+        };
+        let statuses = json!([
+            status(todo_id, "To do", 0),
+            status(review_id, "In review", 1)
+        ]);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    let mut issue = issue_json(project_id, Uuid::nil(), 1);
+                    issue["status_id"] = body["status_id"].clone();
+                    envelope(json!({ "data": issue, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(json!({ "project_statuses": statuses })) }),
+            )
+            .route(
+                "/api/remote/tags",
+                get(move || async move {
+                    envelope(json!({ "tags": [{
+                        "id": bug_tag_id,
+                        "project_id": project_id,
+                        "name": "bug",
+                        "color": "0 0% 0%",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "issue_id": body["issue_id"],
+                            "tag_id": body["tag_id"],
+                        },
+                        "txid": 1,
+                    }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-assignees",
+                post(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "title": "Ship it",
+            "status": "in review",
+            "tag_names": ["Bug", "missing"],
+            "assignee_user_ids": [Uuid::new_v4()],
+        }))
+        .unwrap();
+        let result = server.create_issue(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["issue"]["status"], "In review");
+        let warnings = body["warnings"].as_array().unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].as_str().unwrap().contains("'missing'"));
+        assert!(
+            warnings[1]
+                .as_str()
+                .unwrap()
+                .starts_with("Could not assign user")
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_issue_copies_subissues_and_reports_failed_tags() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let mut source = issue_json(project_id, status_id, 80);
+        source["priority"] = json!("high");
+        let source_id: Uuid = serde_json::from_value(source["id"].clone()).unwrap();
+        let children: Vec<serde_json::Value> = (81..83)
+            .map(|number| {
+                let mut child = issue_json(project_id, status_id, number);
+                child["parent_issue_id"] = json!(source_id);
+                child
+            })
+            .collect();
+        let child_ids: Vec<String> = children
+            .iter()
+            .map(|child| child["id"].as_str().unwrap().to_string())
+            .collect();
+        let next_number = Arc::new(AtomicUsize::new(100));
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let source = source.clone();
+                    async move { envelope(source) }
+                }),
+            )
+            .route(
+                "/api/remote/issues",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let number = next_number.fetch_add(1, Ordering::SeqCst) as i32;
+                    async move {
+                        let mut issue = issue_json(project_id, status_id, number);
+                        for field in ["title", "priority", "parent_issue_id", "status_id"] {
+                            issue[field] = body[field].clone();
+                        }
+                        envelope(json!({ "data": issue, "txid": 1 }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move || {
+                    let children = children.clone();
+                    async move {
+                        envelope(json!({
+                            "issues": children,
+                            "total_count": 2,
+                            "limit": 100,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                get(|| async {
+                    envelope(json!({ "issue_tags": [{
+                        "id": Uuid::new_v4(),
+                        "issue_id": Uuid::new_v4(),
+                        "tag_id": Uuid::new_v4(),
+                    }] }))
+                })
+                .post(|| async { StatusCode::FORBIDDEN }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": source_id,
+            "new_title": "Same as VK-80 but for the other repo",
+            "include_subissues": true,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .duplicate_issue(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["source_issue_id"], source_id.to_string());
+        assert_eq!(
+            body["issue"]["title"],
+            "Same as VK-80 but for the other repo"
+        );
+        assert_eq!(body["issue"]["simple_id"], "VK-100");
+        let subissue_ids = body["subissue_ids"].as_object().unwrap();
+        assert_eq!(subissue_ids.len(), 2);
+        assert!(child_ids.iter().all(|id| subissue_ids.contains_key(id)));
+        // One tag on the copy and one on each sub-issue copy could not be attached.
+        assert_eq!(body["warnings"].as_array().unwrap().len(), 3);
+        assert_eq!(backend.requests_to("/api/remote/issues"), 3);
+    }
+
+    #[tokio::test]
+    async fn create_issue_attaches_known_tags_and_assignees_by_username() {
+        let project_id = Uuid::new_v4();
+        let alice_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let tag = |name: &str| {
+            json!({
+                "id": Uuid::new_v4(),
+                "project_id": project_id,
+                "name": name,
+                "color": "0 0% 0%",
+            })
+        };
+        let tags = json!([tag("bug"), tag("frontend")]);
+        let attached = Arc::new(AtomicUsize::new(0));
+        let attached_in_route = attached.clone();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues",
+                post(move || async move {
+                    let issue = issue_json(project_id, todo_id, 1);
+                    envelope(json!({ "data": issue, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move {
+                    envelope(json!({ "project_statuses": [{
+                        "id": todo_id,
+                        "project_id": project_id,
+                        "name": "To do",
+                        "color": "0 0% 0%",
+                        "sort_order": 0,
+                        "hidden": false,
+                        "created_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/tags",
+                get(move || {
+                    let tags = tags.clone();
+                    async move { envelope(json!({ "tags": tags })) }
+                }),
+            )
+            .route(
+                "/api/remote/issue-tags",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    attached_in_route.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        envelope(json!({
+                            "data": {
+                                "id": Uuid::new_v4(),
+                                "issue_id": body["issue_id"],
+                                "tag_id": body["tag_id"],
+                            },
+                            "txid": 1,
+                        }))
+                    }
+                }),
+            )
+            .merge(project_with_members_router(
+                project_id,
+                vec![member_json(alice_id, "alice")],
+            ))
+            .route(
+                "/api/remote/issue-assignees",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "issue_id": body["issue_id"],
+                            "user_id": body["user_id"],
+                            "assigned_at": "2026-01-01T00:00:00Z",
+                        },
+                        "txid": 1,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "title": "Ship it",
+            "tag_names": ["Bug", "wontfix", "frontend"],
+            "assignees": ["Alice", alice_id.to_string()],
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .create_issue(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(attached.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            body["warnings"],
+            json!(["Tag 'wontfix' does not exist in this project"])
+        );
+        assert_eq!(backend.requests_to("/api/remote/issue-assignees"), 1);
+    }
+
+    #[tokio::test]
+    async fn create_issue_falls_back_to_the_default_status() {
+        let project_id = Uuid::new_v4();
+        let hidden_id = Uuid::new_v4();
+        let backlog_id = Uuid::new_v4();
+        let review_id = Uuid::new_v4();
+        let status = |id: Uuid, name: &str, sort_order: i32, hidden: bool| {
+            json!({
+                "id": id,
+                "project_id": project_id,
+                "name": name,
+                "color": "0 0% 0%",
+                "sort_order": sort_order,
+                "hidden": hidden,
+                "created_at": "2026-01-01T00:00:00Z",
+            })
+        };
+        let statuses = json!([
+            status(review_id, "In Review", 2, false),
+            status(hidden_id, "Triage", 0, true),
+            status(backlog_id, "Backlog", 1, false),
+        ]);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    let mut issue = issue_json(project_id, Uuid::nil(), 1);
+                    issue["status_id"] = body["status_id"].clone();
+                    envelope(json!({ "data": issue, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(json!({ "project_statuses": statuses })) }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let create = |status: Option<&str>| {
+            let mut request = json!({ "project_id": project_id, "title": "Ship it" });
+            if let Some(status) = status {
+                request["status"] = json!(status);
+            }
+            serde_json::from_value(request).unwrap()
+        };
+
+        let result = server.create_issue(Parameters(create(None))).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue"]["status"], "Backlog");
+
+        let result = server
+            .create_issue(Parameters(create(Some("IN-REVIEW"))))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue"]["status"], "In Review");
+
+        let result = server
+            .create_issue(Parameters(create(Some("Shipped"))))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let error = body["error"].as_str().unwrap();
+        assert!(error.contains("Unknown status 'Shipped'"), "{error}");
+        assert!(error.contains("\"Backlog\""), "{error}");
+        // The unknown status is rejected before anything is created.
+        assert_eq!(backend.requests_to("/api/remote/issues"), 2);
+    }
+
+    #[tokio::test]
+    async fn create_issue_sets_dates_and_rejects_inverted_ranges() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/remote/issues",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    let mut issue = issue_json(project_id, status_id, 1);
+                    issue["start_date"] = body["start_date"].clone();
+                    issue["target_date"] = body["target_date"].clone();
+                    envelope(json!({ "data": issue, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move {
+                    envelope(json!({ "project_statuses": [{
+                        "id": status_id,
+                        "project_id": project_id,
+                        "name": "To do",
+                        "color": "0 0% 0%",
+                        "sort_order": 0,
+                        "hidden": false,
+                        "created_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let create = |start_date: &str, target_date: &str| {
+            serde_json::from_value(json!({
+                "project_id": project_id,
+                "title": "Ship it",
+                "start_date": start_date,
+                "target_date": target_date,
+            }))
+            .unwrap()
+        };
+
+        let result = server
+            .create_issue(Parameters(create("2026-03-01", "2026-03-15T17:00:00Z")))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issue"]["start_date"], "2026-03-01T00:00:00+00:00");
+        assert_eq!(body["issue"]["target_date"], "2026-03-15T17:00:00+00:00");
+
+        let result = server
+            .create_issue(Parameters(create("2026-03-15", "2026-03-01")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let result = server
+            .create_issue(Parameters(create("next week", "2026-03-01")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(backend.requests_to("/api/remote/issues"), 1);
+    }
+
+    #[tokio::test]
+    async fn update_issue_refuses_to_nest_an_issue_under_its_descendant() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let root = issue_json(project_id, status_id, 1);
+        let mut child = issue_json(project_id, status_id, 2);
+        child["parent_issue_id"] = root["id"].clone();
+        let mut grandchild = issue_json(project_id, status_id, 3);
+        grandchild["parent_issue_id"] = child["id"].clone();
+        let root_id = root["id"].clone();
+        let grandchild_id = grandchild["id"].clone();
+        let issues = [root.clone(), child, grandchild];
+        let patches = Arc::new(AtomicUsize::new(0));
+
+        let router = Router::new().route(
+            "/api/remote/issues/{issue_id}",
+            get(move |Path(issue_id): Path<String>| {
+                let issue = issues
+                    .iter()
+                    .find(|issue| issue["id"] == issue_id)
+                    .cloned()
+                    .unwrap();
+                async move { envelope(issue) }
+            })
+            .patch({
+                let patches = patches.clone();
+                move || {
+                    patches.fetch_add(1, Ordering::SeqCst);
+                    async { StatusCode::INTERNAL_SERVER_ERROR }
+                }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
 
-        let status_names = self
-            .fetch_project_statuses(project_id)
+        let request = serde_json::from_value(json!({
+            "issue_id": root_id,
+            "parent_issue_id": grandchild_id,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .update_issue(Parameters(request))
             .await
-            .ok()
-            .map(|statuses| {
-                statuses
-                    .into_iter()
-                    .map(|s| (s.id, s.name))
-                    .collect::<HashMap<_, _>>()
-            });
+            .unwrap();
 
-        response
-            .issues
-            .iter()
-            .filter(|i| i.parent_issue_id == Some(parent_issue_id))
-            .map(|i| {
-                let status = status_names
-                    .as_ref()
-                    .and_then(|m| m.get(&i.status_id).cloned())
-                    .unwrap_or_else(|| i.status_id.to_string());
-                McpSubIssueSummary {
-                    id: i.id.to_string(),
-                    simple_id: i.simple_id.clone(),
-                    title: i.title.clone(),
-                    status,
-                }
-            })
-            .collect()
-    }
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["error"],
+            "Cannot nest VK-1 under VK-3: VK-3 is already nested under VK-1"
+        );
+        assert_eq!(body["details"], "VK-1 > VK-2 > VK-3");
+        assert_eq!(patches.load(Ordering::SeqCst), 0);
 
-    fn parse_issue_priority(priority: &str) -> Result<IssuePriority, ToolError> {
-        match priority.trim().to_ascii_lowercase().as_str() {
-            "urgent" => Ok(IssuePriority::Urgent),
-            "high" => Ok(IssuePriority::High),
-            "medium" => Ok(IssuePriority::Medium),
-            "low" => Ok(IssuePriority::Low),
-            _ => Err(ToolError::message(format!(
-                "Unknown priority '{}'. Allowed values: ['urgent', 'high', 'medium', 'low']",
-                priority
-            ))),
-        }
+        let root: Issue = serde_json::from_value(root).unwrap();
+        let error = backend
+            .server()
+            .check_new_parent(&root, root.id)
+            .await
+            .unwrap_err();
+        assert_eq!(error.message, "An issue cannot be its own parent");
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issues/{}", root.id)),
+            1
+        );
     }
 
-    fn issue_priority_label(priority: IssuePriority) -> &'static str {
-        match priority {
-            IssuePriority::Urgent => "urgent",
-            IssuePriority::High => "high",
-            IssuePriority::Medium => "medium",
-            IssuePriority::Low => "low",
+    #[tokio::test]
+    async fn check_new_parent_refuses_chains_too_deep_to_verify() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let mut chain: Vec<serde_json::Value> = Vec::new();
+        for number in 1..=(MAX_PARENT_DEPTH as i32 + 2) {
+            let mut issue = issue_json(project_id, status_id, number);
+            if let Some(parent) = chain.last() {
+                issue["parent_issue_id"] = parent["id"].clone();
+            }
+            chain.push(issue);
         }
-    }
+        let deepest: Uuid = serde_json::from_value(chain.last().unwrap()["id"].clone()).unwrap();
+        let moved: Issue = serde_json::from_value(issue_json(project_id, status_id, 999)).unwrap();
 
-    async fn find_tag_ids_by_name(
-        &self,
-        project_id: Uuid,
-        tag_name: &str,
-    ) -> Result<Vec<Uuid>, ToolError> {
-        let url = self.url(&format!("/api/remote/tags?project_id={}", project_id));
-        let tags: ListTagsResponse = self.send_json(self.client.get(&url)).await?;
-        Ok(Self::matching_ids_by_name(
-            tags.tags.iter().map(|tag| (tag.id, tag.name.as_str())),
-            tag_name,
-        ))
-    }
+        let router = Router::new().route(
+            "/api/remote/issues/{issue_id}",
+            get(move |Path(issue_id): Path<String>| {
+                let issue = chain
+                    .iter()
+                    .find(|issue| issue["id"] == issue_id)
+                    .cloned()
+                    .unwrap();
+                async move { envelope(issue) }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
 
-    fn matching_ids_by_name<'a>(
-        items: impl IntoIterator<Item = (Uuid, &'a str)>,
-        name: &str,
-    ) -> Vec<Uuid> {
-        items
-            .into_iter()
-            .filter(|(_, item_name)| item_name.eq_ignore_ascii_case(name))
-            .map(|(id, _)| id)
-            .collect()
+        let error = backend
+            .server()
+            .check_new_parent(&moved, deepest)
+            .await
+            .unwrap_err();
+        assert_eq!(error.message, "Parent chain too deep to verify");
     }
 
-    fn resolve_tag_filters(
-        tag_id: Option<Uuid>,
-        matching_tag_ids: Option<Vec<Uuid>>,
-    ) -> (Option<Uuid>, Option<Vec<Uuid>>, bool) {
-        match (tag_id, matching_tag_ids) {
-            (Some(tag_id), Some(matching_tag_ids)) => {
-                if matching_tag_ids.contains(&tag_id) {
-                    (Some(tag_id), None, false)
-                } else {
-                    (None, None, true)
-                }
-            }
-            (None, Some(matching_tag_ids)) => {
-                let missing_tag_name_match = matching_tag_ids.is_empty();
-                (
-                    None,
-                    (!missing_tag_name_match).then_some(matching_tag_ids),
-                    missing_tag_name_match,
-                )
+    #[tokio::test]
+    async fn get_issue_tree_nests_sub_issues_and_counts_every_level() {
+        let project_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let done_id = Uuid::new_v4();
+        let issue = |number: i32, status_id: Uuid, parent: Option<&serde_json::Value>| {
+            let mut issue = issue_json(project_id, status_id, number);
+            if let Some(parent) = parent {
+                issue["parent_issue_id"] = parent["id"].clone();
+                issue["parent_issue_sort_order"] = json!(-number);
             }
-            (Some(tag_id), None) => (Some(tag_id), None, false),
-            (None, None) => (None, None, false),
-        }
+            issue
+        };
+        let root = issue(1, todo_id, None);
+        let done_child = issue(2, done_id, Some(&root));
+        let open_child = issue(3, todo_id, Some(&root));
+        let grandchild = issue(4, done_id, Some(&open_child));
+        let great_grandchild = issue(5, todo_id, Some(&grandchild));
+        let mut archived_child = issue(6, todo_id, Some(&root));
+        archived_child["archived_at"] = json!("2026-02-01T00:00:00Z");
+        let root_id = root["id"].clone();
+        let issues = vec![
+            root.clone(),
+            done_child,
+            open_child,
+            grandchild,
+            great_grandchild,
+            archived_child,
+        ];
+        let statuses = json!([
+            {
+                "id": todo_id,
+                "project_id": project_id,
+                "name": "To do",
+                "color": "0 0% 0%",
+                "sort_order": 0,
+                "hidden": false,
+                "created_at": "2026-01-01T00:00:00Z",
+            },
+            {
+                "id": done_id,
+                "project_id": project_id,
+                "name": "Done",
+                "color": "0 0% 0%",
+                "sort_order": 1,
+                "hidden": false,
+                "created_at": "2026-01-01T00:00:00Z",
+            },
+        ]);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let root = root.clone();
+                    async move { envelope(root) }
+                }),
+            )
+            .route(
+                "/api/remote/issues",
+                get(move || {
+                    let issues = issues.clone();
+                    async move {
+                        envelope(json!({
+                            "issues": issues,
+                            "total_count": 6,
+                            "limit": 6,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(json!({ "project_statuses": statuses })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "issue_id": root_id, "max_depth": 1 })).unwrap();
+        let result = backend
+            .server()
+            .get_issue_tree(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let root = &body["root"];
+        assert_eq!(root["simple_id"], "VK-1");
+        let children = root["children"].as_array().unwrap();
+        // Ordered by position under the parent, with the archived sub-issue left out.
+        assert_eq!(children[0]["simple_id"], "VK-3");
+        assert_eq!(children[1]["simple_id"], "VK-2");
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[1]["status"], "Done");
+        assert_eq!(children[0]["children"], json!([]));
+        assert_eq!(body["descendant_count"], 4);
+        assert_eq!(body["done_count"], 2);
+        assert_eq!(body["hidden_by_depth_count"], 2);
+        assert_eq!(backend.requests_to("/api/remote/issues"), 1);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn create_issue_retry_with_client_id_returns_existing_issue() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let client_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let mut existing = issue_json(project_id, status_id, 7);
+        existing["id"] = json!(client_id);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["id"], json!(client_id));
+                    (
+                        StatusCode::CONFLICT,
+                        Json(json!({ "success": false, "message": "resource already exists" })),
+                    )
+                        .into_response()
+                }),
+            )
+            .route(
+                "/api/remote/issues/{id}",
+                get(move || {
+                    let existing = existing.clone();
+                    async move { envelope(existing) }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move {
+                    envelope(json!({ "project_statuses": [{
+                        "id": status_id,
+                        "project_id": project_id,
+                        "name": "To do",
+                        "color": "0 0% 0%",
+                        "sort_order": 0,
+                        "hidden": false,
+                        "created_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                }),
+            )
+            .route(
+                "/api/remote/issue-assignees",
+                get(move || async move {
+                    envelope(json!({ "issue_assignees": [{
+                        "id": Uuid::new_v4(),
+                        "issue_id": client_id,
+                        "user_id": user_id,
+                        "assigned_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                })
+                .post(|| async {
+                    (
+                        StatusCode::CONFLICT,
+                        Json(json!({ "success": false, "message": "resource already exists" })),
+                    )
+                        .into_response()
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "title": "Issue 7",
+            "assignee_user_ids": [user_id],
+            "client_id": client_id,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .create_issue(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["issue_id"], client_id.to_string());
+        assert_eq!(body["created"], false);
+        assert!(body.get("warnings").is_none());
+    }
 
     #[test]
-    fn collects_all_matching_status_ids_case_insensitively() {
-        let first_id = Uuid::new_v4();
-        let second_id = Uuid::new_v4();
-        let statuses = [
-            (first_id, "In Progress"),
-            (second_id, "in progress"),
-            (Uuid::new_v4(), "Todo"),
-        ];
+    fn issue_reference_requires_exactly_one_identifier() {
+        let issue_id = Uuid::new_v4();
 
         assert_eq!(
-            McpServer::matching_ids_by_name(statuses, "IN PROGRESS"),
-            vec![first_id, second_id]
+            McpServer::issue_reference(Some(issue_id), None).unwrap(),
+            IssueReference::Id(issue_id)
+        );
+        assert_eq!(
+            McpServer::issue_reference(None, Some(" acme-123 ".into())).unwrap(),
+            IssueReference::SimpleId("ACME-123".into())
         );
+        assert!(McpServer::issue_reference(None, None).is_err());
+        assert!(McpServer::issue_reference(None, Some("  ".into())).is_err());
+        assert!(McpServer::issue_reference(Some(issue_id), Some("ACME-123".into())).is_err());
+        assert!(McpServer::issue_reference(None, Some("ACME/123".into())).is_err());
     }
 
-    #[test]
-    fn collects_all_matching_tag_ids_case_insensitively() {
-        let first_id = Uuid::new_v4();
-        let second_id = Uuid::new_v4();
-        let tags = [
-            (first_id, "bug"),
-            (second_id, "Bug"),
-            (Uuid::new_v4(), "feature"),
-        ];
+    #[tokio::test]
+    async fn unknown_simple_id_reports_the_searched_project() {
+        let project_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new()).await;
+        let server = backend.server();
+
+        let error = server
+            .resolve_issue(None, Some("ACME-404".into()), Some(project_id))
+            .await
+            .unwrap_err();
 
+        assert!(error.message.contains("ACME-404"));
+        assert!(error.message.contains(&project_id.to_string()));
+        assert!(error.hint.unwrap().contains("search_issues"));
         assert_eq!(
-            McpServer::matching_ids_by_name(tags, "BUG"),
-            vec![first_id, second_id]
+            backend.requests_to(&format!(
+                "/api/remote/issues/by-simple-id/{}/ACME-404",
+                project_id
+            )),
+            1
         );
     }
 
+    #[tokio::test]
+    async fn simple_id_lookup_failures_are_not_reported_as_missing() {
+        let project_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/remote/issues/by-simple-id/{project_id}/{simple_id}",
+            get(|| async { StatusCode::FORBIDDEN }),
+        ))
+        .await;
+        let server = backend.server();
+
+        let error = server
+            .resolve_issue(None, Some("acme-7".into()), Some(project_id))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.status, Some(403));
+        assert!(!error.message.contains("Could not find"));
+    }
+
     #[test]
     fn resolve_tag_filters_requires_explicit_tag_id_to_match_tag_name() {
         let tag_id = Uuid::new_v4();
@@ -975,4 +5503,944 @@ mod tests {
             (Some(tag_id), None, false)
         );
     }
+
+    #[tokio::test]
+    async fn search_issues_ranks_title_matches_across_projects_with_bounded_concurrency() {
+        let organization_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let projects = (0..12)
+            .map(|index| {
+                json!({
+                    "id": Uuid::new_v4(),
+                    "organization_id": organization_id,
+                    "name": format!("Project {index}"),
+                    "color": "#000000",
+                    "sort_order": index,
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "updated_at": "2026-01-01T00:00:00Z",
+                })
+            })
+            .collect::<Vec<_>>();
+        let description_project: Uuid = serde_json::from_value(projects[0]["id"].clone()).unwrap();
+        let title_project: Uuid = serde_json::from_value(projects[1]["id"].clone()).unwrap();
+
+        let mut description_match = issue_json(description_project, status_id, 1);
+        description_match["title"] = json!("Session cleanup");
+        description_match["description"] = json!("Happens right after LOGIN");
+        description_match["updated_at"] = json!("2026-03-01T00:00:00Z");
+        let mut title_match = issue_json(title_project, status_id, 2);
+        title_match["title"] = json!("Fix login bug");
+
+        let router = Router::new()
+            .route(
+                "/api/remote/projects",
+                get(move || {
+                    let projects = projects.clone();
+                    async move { envelope(json!({ "projects": projects })) }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let (description_match, title_match) =
+                        (description_match.clone(), title_match.clone());
+                    async move {
+                        tokio::time::sleep(Duration::from_millis(20)).await;
+                        assert_eq!(body["search"], "login");
+                        let project_id: Uuid =
+                            serde_json::from_value(body["project_id"].clone()).unwrap();
+                        let issues = if project_id == description_project {
+                            vec![description_match]
+                        } else if project_id == title_project {
+                            vec![title_match]
+                        } else {
+                            vec![]
+                        };
+                        envelope(json!({
+                            "total_count": issues.len(),
+                            "issues": issues,
+                            "limit": 20,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async { envelope(json!({ "pull_requests": [] })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "organization_id": organization_id,
+            "query": " login ",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .search_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["projects_searched"], 12);
+        assert_eq!(body["returned_count"], 2);
+        assert_eq!(body["issues"][0]["title"], "Fix login bug");
+        assert_eq!(body["issues"][0]["matched_in"], "title");
+        assert_eq!(body["issues"][0]["project_name"], "Project 1");
+        assert_eq!(body["issues"][1]["matched_in"], "description");
+        assert_eq!(
+            body["issues"][1]["project_id"],
+            description_project.to_string()
+        );
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 12);
+        assert!(backend.max_in_flight() <= PROJECT_SEARCH_CONCURRENCY);
+    }
+
+    #[tokio::test]
+    async fn move_issue_places_the_issue_at_the_requested_position() {
+        let project_id = Uuid::new_v4();
+        let (todo_id, doing_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let issue = issue_json(project_id, todo_id, 9);
+        let issue_id: Uuid = serde_json::from_value(issue["id"].clone()).unwrap();
+        let column = (1..=3)
+            .map(|number| issue_json(project_id, doing_id, number))
+            .collect::<Vec<_>>();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let issue = issue.clone();
+                    async move { envelope(issue) }
+                })
+                .patch(move |Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["status_id"], json!(doing_id));
+                    assert_eq!(body["sort_order"], 1.5);
+                    let mut moved = issue_json(project_id, doing_id, 9);
+                    moved["sort_order"] = json!(1.5);
+                    envelope(json!({ "data": moved, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let column = column.clone();
+                    async move {
+                        assert_eq!(body["status_id"], json!(doing_id));
+                        assert_eq!(body["include_archived"], false);
+                        envelope(json!({
+                            "issues": column,
+                            "total_count": 3,
+                            "limit": 100,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move {
+                    let status = |id: Uuid, name: &str, sort_order: i32| {
+                        json!({
+                            "id": id,
+                            "project_id": project_id,
+                            "name": name,
+                            "color": "#000000",
+                            "sort_order": sort_order,
+                            "hidden": false,
+                            "created_at": "2026-01-01T00:00:00Z",
+                        })
+                    };
+                    envelope(json!({
+                        "project_statuses": [
+                            status(todo_id, "To do", 0),
+                            status(doing_id, "In progress", 1),
+                        ],
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_id": issue_id,
+            "status": "in progress",
+            "position": 1,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .move_issue(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["status"], "In progress");
+        assert_eq!(body["position"], 1);
+    }
+
+    #[tokio::test]
+    async fn bulk_update_reports_per_issue_results_on_partial_failure() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issue = issue_json(project_id, status_id, 1);
+        let existing_id: Uuid = serde_json::from_value(issue["id"].clone()).unwrap();
+        let missing_id = Uuid::new_v4();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move |Path(issue_id): Path<Uuid>| {
+                    let issue = issue.clone();
+                    async move {
+                        if issue_id == existing_id {
+                            envelope(issue).into_response()
+                        } else {
+                            StatusCode::NOT_FOUND.into_response()
+                        }
+                    }
+                })
+                .patch(move |Json(body): Json<serde_json::Value>| {
+                    let updated = issue_json(project_id, status_id, 1);
+                    async move {
+                        assert_eq!(body["status_id"], json!(status_id));
+                        envelope(json!({ "data": updated, "txid": 1 }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move {
+                    envelope(json!({
+                        "project_statuses": [{
+                            "id": status_id,
+                            "project_id": project_id,
+                            "name": "Backlog",
+                            "color": "#000000",
+                            "sort_order": 0,
+                            "hidden": false,
+                            "created_at": "2026-01-01T00:00:00Z",
+                        }],
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_ids": [existing_id, missing_id],
+            "status": "backlog",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .bulk_update_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["updated_count"], 1);
+        assert_eq!(body["failed_count"], 1);
+        assert_eq!(body["results"][0]["issue_id"], existing_id.to_string());
+        assert_eq!(body["results"][0]["ok"], true);
+        assert_eq!(body["results"][1]["issue_id"], missing_id.to_string());
+        assert_eq!(body["results"][1]["ok"], false);
+    }
+
+    #[tokio::test]
+    async fn bulk_update_accepts_simple_ids_and_updates_each_issue_once() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issue = issue_json(project_id, status_id, 1);
+        let issue_id: Uuid = serde_json::from_value(issue["id"].clone()).unwrap();
+        let patches = Arc::new(AtomicUsize::new(0));
+        let patch_counter = patches.clone();
+        let by_id = issue.clone();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/by-simple-id/{project_id}/{simple_id}",
+                get(move |Path((_, simple_id)): Path<(Uuid, String)>| {
+                    let issue = issue.clone();
+                    async move {
+                        if simple_id == "VK-1" {
+                            envelope(issue).into_response()
+                        } else {
+                            StatusCode::NOT_FOUND.into_response()
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let issue = by_id.clone();
+                    async move { envelope(issue) }
+                })
+                .patch(move || {
+                    patch_counter.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        envelope(json!({ "data": issue_json(project_id, status_id, 1), "txid": 1 }))
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_ids": [issue_id],
+            "simple_ids": ["vk-1", "VK-99"],
+            "project_id": project_id,
+            "priority": "high",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .bulk_update_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["updated_count"], 1);
+        assert_eq!(body["failed_count"], 1);
+        assert_eq!(patches.load(Ordering::SeqCst), 1);
+        assert_eq!(body["results"][1]["simple_id"], "VK-99");
+        assert!(body["results"][1].get("issue_id").is_none());
+        assert!(
+            body["results"][1]["error"]
+                .as_str()
+                .unwrap()
+                .contains("Could not find issue 'VK-99'")
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_update_adds_and_removes_assignees_by_name() {
+        let project_id = Uuid::new_v4();
+        let issue = issue_json(project_id, Uuid::new_v4(), 1);
+        let issue_id: Uuid = serde_json::from_value(issue["id"].clone()).unwrap();
+        let (alice_id, bob_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let bob_assignment_id = Uuid::new_v4();
+        let assigned = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || async move { envelope(issue) }),
+            )
+            .merge(project_with_members_router(
+                project_id,
+                vec![member_json(alice_id, "alice"), member_json(bob_id, "bob")],
+            ))
+            .route(
+                "/api/remote/issue-assignees",
+                get(move || async move {
+                    envelope(json!({ "issue_assignees": [{
+                        "id": bob_assignment_id,
+                        "issue_id": issue_id,
+                        "user_id": bob_id,
+                        "assigned_at": "2026-01-01T00:00:00Z",
+                    }] }))
+                })
+                .post({
+                    let assigned = assigned.clone();
+                    move |Json(body): Json<serde_json::Value>| async move {
+                        assigned.lock().unwrap().push(body["user_id"].clone());
+                        envelope(json!({
+                            "data": {
+                                "id": Uuid::new_v4(),
+                                "issue_id": body["issue_id"],
+                                "user_id": body["user_id"],
+                                "assigned_at": "2026-01-01T00:00:00Z",
+                            },
+                            "txid": 1,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issue-assignees/{issue_assignee_id}",
+                delete(|| async { envelope(json!({ "txid": 1 })) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "issue_ids": [issue_id],
+            "add_assignees": ["Alice", "nobody"],
+            "remove_assignees": ["bob@example.com"],
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .bulk_update_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["updated_count"], 1);
+        assert_eq!(body["results"][0]["ok"], true);
+        let warnings = body["results"][0]["warnings"].as_array().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0]
+                .as_str()
+                .unwrap()
+                .contains("Could not resolve assignee 'nobody'")
+        );
+        assert_eq!(*assigned.lock().unwrap(), vec![json!(alice_id)]);
+        assert_eq!(
+            backend.requests_to(&format!("/api/remote/issue-assignees/{bob_assignment_id}")),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_update_by_filter_requires_matching_confirm_count() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let issues = (1..=3)
+            .map(|number| issue_json(project_id, status_id, number))
+            .collect::<Vec<_>>();
+
+        let router = Router::new().route(
+            "/api/remote/issues/search",
+            post(move || {
+                let issues = issues.clone();
+                async move {
+                    envelope(json!({
+                        "issues": issues,
+                        "total_count": 3,
+                        "limit": 50,
+                        "offset": 0,
+                    }))
+                }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "filter": { "project_id": project_id, "priority": "low" },
+            "priority": "medium",
+            "confirm_count": 2,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .bulk_update_issues(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert!(body["error"].as_str().unwrap().contains("confirm_count: 3"));
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 1);
+    }
+
+    #[tokio::test]
+    async fn delete_issue_cascades_to_sub_issues_depth_first() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let parent = issue_json(project_id, status_id, 1);
+        let mut child = issue_json(project_id, status_id, 2);
+        let mut grandchild = issue_json(project_id, status_id, 3);
+        child["parent_issue_id"] = parent["id"].clone();
+        grandchild["parent_issue_id"] = child["id"].clone();
+        let parent_id = parent["id"].clone();
+
+        let issues = vec![parent.clone(), child, grandchild];
+        let deleted = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let children = issues
+                        .iter()
+                        .filter(|issue| issue["parent_issue_id"] == body["parent_issue_id"])
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    async move {
+                        envelope(json!({
+                            "total_count": children.len(),
+                            "issues": children,
+                            "limit": 100,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let parent = parent.clone();
+                    async move { envelope(parent) }
+                })
+                .delete({
+                    let deleted = deleted.clone();
+                    move |Path(issue_id): Path<String>| {
+                        deleted.lock().unwrap().push(issue_id);
+                        async { Json(json!({ "success": true })) }
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request =
+            serde_json::from_value(json!({ "issue_id": parent_id, "permanent": true })).unwrap();
+        let refused = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_eq!(refused.is_error, Some(true));
+        assert!(refused.content[0].as_text().unwrap().text.contains("VK-2"));
+
+        let request = serde_json::from_value(json!({
+            "issue_id": parent_id,
+            "cascade": true,
+            "dry_run": true,
+        }))
+        .unwrap();
+        let plan = server.delete_issue(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&plan.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["deleted_issues"].as_array().map(Vec::len), Some(3));
+        assert!(deleted.lock().unwrap().is_empty());
+
+        let request = serde_json::from_value(json!({
+            "issue_id": parent_id,
+            "cascade": true,
+            "permanent": true,
+        }))
+        .unwrap();
+        let result = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let simple_ids =
+            serde_json::from_str::<serde_json::Value>(&result.content[0].as_text().unwrap().text)
+                .unwrap()["deleted_issues"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|issue| issue["simple_id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>();
+        assert_eq!(simple_ids, ["VK-3", "VK-2", "VK-1"]);
+        assert_eq!(
+            deleted.lock().unwrap().last(),
+            Some(&parent_id.as_str().unwrap().to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn cascade_delete_requires_every_sub_issue_to_be_archived() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let mut parent = issue_json(project_id, status_id, 1);
+        let mut child = issue_json(project_id, status_id, 2);
+        let mut grandchild = issue_json(project_id, status_id, 3);
+        parent["archived_at"] = json!("2026-02-01T00:00:00Z");
+        grandchild["archived_at"] = json!("2026-02-01T00:00:00Z");
+        child["parent_issue_id"] = parent["id"].clone();
+        grandchild["parent_issue_id"] = child["id"].clone();
+        let parent_id = parent["id"].clone();
+
+        let issues = vec![parent.clone(), child, grandchild];
+        let deleted = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let children = issues
+                        .iter()
+                        .filter(|issue| issue["parent_issue_id"] == body["parent_issue_id"])
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    async move {
+                        envelope(json!({
+                            "total_count": children.len(),
+                            "issues": children,
+                            "limit": 100,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let parent = parent.clone();
+                    async move { envelope(parent) }
+                })
+                .delete({
+                    let deleted = deleted.clone();
+                    move |Path(issue_id): Path<String>| {
+                        deleted.lock().unwrap().push(issue_id);
+                        async { Json(json!({ "success": true })) }
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request =
+            serde_json::from_value(json!({ "issue_id": parent_id, "cascade": true })).unwrap();
+        let result = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["details"], "Not archived: VK-2");
+        assert!(deleted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn archived_issues_are_hidden_and_only_deleted_when_archived_or_permanent() {
+        let project_id = Uuid::new_v4();
+        let status_id = Uuid::new_v4();
+        let active = issue_json(project_id, status_id, 1);
+        let mut archived = issue_json(project_id, status_id, 2);
+        archived["archived_at"] = json!("2026-02-01T00:00:00Z");
+        let active_id = active["id"].clone();
+        let archived_id = archived["id"].clone();
+
+        let issues = vec![active.clone(), archived.clone()];
+        let deleted = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(move |Json(body): Json<serde_json::Value>| {
+                    let issues = issues
+                        .iter()
+                        .filter(|issue| issue["parent_issue_id"] == body["parent_issue_id"])
+                        .filter(|issue| {
+                            body["include_archived"] != false || issue["archived_at"].is_null()
+                        })
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    async move {
+                        envelope(json!({
+                            "total_count": issues.len(),
+                            "issues": issues,
+                            "limit": 50,
+                            "offset": 0,
+                        }))
+                    }
+                }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async { envelope(json!({ "pull_requests": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move |Path(issue_id): Path<String>| {
+                    let issue = if active["id"] == issue_id {
+                        active.clone()
+                    } else {
+                        archived.clone()
+                    };
+                    async move { envelope(issue) }
+                })
+                .delete({
+                    let deleted = deleted.clone();
+                    move |Path(issue_id): Path<String>| {
+                        deleted.lock().unwrap().push(issue_id);
+                        async { Json(json!({ "success": true })) }
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let list = |include_archived: bool| {
+            serde_json::from_value(json!({
+                "project_id": project_id,
+                "include_archived": include_archived,
+            }))
+            .unwrap()
+        };
+
+        let result = server.list_issues(Parameters(list(false))).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issues"].as_array().map(Vec::len), Some(1));
+        assert_eq!(body["issues"][0]["simple_id"], "VK-1");
+        assert_eq!(body["total_count"], 1);
+
+        let result = server.list_issues(Parameters(list(true))).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["issues"].as_array().map(Vec::len), Some(2));
+        assert_eq!(body["total_count"], 2);
+        assert_eq!(
+            body["issues"][1]["archived_at"],
+            "2026-02-01T00:00:00+00:00"
+        );
+
+        let request = serde_json::from_value(json!({ "issue_id": active_id })).unwrap();
+        let refused = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_eq!(refused.is_error, Some(true));
+        assert!(
+            refused.content[0]
+                .as_text()
+                .unwrap()
+                .text
+                .contains("archive_issue")
+        );
+
+        let request = serde_json::from_value(json!({ "issue_id": archived_id })).unwrap();
+        let result = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+
+        let request =
+            serde_json::from_value(json!({ "issue_id": active_id, "permanent": true })).unwrap();
+        let result = server.delete_issue(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(
+            *deleted.lock().unwrap(),
+            [
+                archived_id.as_str().unwrap().to_string(),
+                active_id.as_str().unwrap().to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_issues_passes_the_sort_to_the_search_and_echoes_it() {
+        let project_id = Uuid::new_v4();
+        let search_bodies = Arc::new(std::sync::Mutex::new(Vec::<serde_json::Value>::new()));
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post({
+                    let search_bodies = search_bodies.clone();
+                    move |Json(body): Json<serde_json::Value>| {
+                        search_bodies.lock().unwrap().push(body);
+                        async {
+                            envelope(json!({
+                                "issues": [],
+                                "total_count": 0,
+                                "limit": 50,
+                                "offset": 0,
+                            }))
+                        }
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "sort_by": "Simple_ID",
+            "sort_direction": "desc",
+        }))
+        .unwrap();
+        let result = server.list_issues(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["sort_by"], "simple_id");
+        assert_eq!(body["sort_direction"], "desc");
+        assert_eq!(search_bodies.lock().unwrap()[0]["sort_field"], "simple_id");
+
+        let request =
+            serde_json::from_value(json!({ "project_id": project_id, "sort_by": "status" }))
+                .unwrap();
+        let result = server.list_issues(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(result.content[0].as_text().unwrap().text.contains(
+            "'sort_order', 'updated_at', 'created_at', 'priority', 'title', 'simple_id'"
+        ));
+        assert_eq!(search_bodies.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn list_issues_sorts_by_target_date() {
+        let project_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["sort_field"], "target_date");
+                    assert_eq!(body["sort_direction"], "asc");
+                    envelope(json!({ "issues": [], "total_count": 0, "limit": 50, "offset": 0 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "sort_by": "target_date",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .list_issues(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["sort_by"], "target_date");
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 1);
+    }
+
+    #[tokio::test]
+    async fn list_issues_applies_and_echoes_date_filters() {
+        let project_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(|| async { envelope(json!({ "project_statuses": [] })) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["updated_after"], "2026-03-02T00:00:00Z");
+                    assert_eq!(body["target_date_before"], "2026-03-06T17:00:00Z");
+                    assert!(body.get("created_after").is_none());
+                    envelope(json!({ "issues": [], "total_count": 0, "limit": 50, "offset": 0 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "updated_after": "2026-03-02",
+            "target_date_before": "2026-03-06T18:00:00+01:00",
+        }))
+        .unwrap();
+        let result = server.list_issues(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(
+            body["date_filters"],
+            json!({
+                "updated_after": "2026-03-02T00:00:00+00:00",
+                "target_date_before": "2026-03-06T17:00:00+00:00",
+            })
+        );
+
+        let request = serde_json::from_value(json!({
+            "project_id": project_id,
+            "completed_after": "last week",
+        }))
+        .unwrap();
+        let result = server.list_issues(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            result.content[0]
+                .as_text()
+                .unwrap()
+                .text
+                .contains("Invalid completed_after 'last week'")
+        );
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 1);
+    }
+
+    #[tokio::test]
+    async fn mutations_outside_the_linked_project_warn_or_fail_under_strict_context() {
+        let linked_project_id = Uuid::new_v4();
+        let issue = issue_json(Uuid::new_v4(), Uuid::new_v4(), 1);
+        let issue_id = issue["id"].clone();
+        let router = Router::new()
+            .route(
+                "/api/remote/issues/{issue_id}",
+                get(move || {
+                    let issue = issue.clone();
+                    async move { envelope(issue) }
+                }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(|| async {
+                    envelope(json!({
+                        "total_count": 0,
+                        "issues": [],
+                        "limit": 50,
+                        "offset": 0,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let mut server = backend.server();
+        server.context = ContextCache::new(Some(McpContext {
+            organization_id: None,
+            project_id: Some(linked_project_id),
+            issue_id: None,
+            orchestrator_session_id: None,
+            workspace_id: Uuid::new_v4(),
+            workspace_branch: "vk/linked".to_string(),
+            workspace_repos: vec![],
+            remote_link_status: RemoteLinkStatus::Linked,
+        }));
+        let dry_run_delete = |strict_context: Option<bool>| {
+            serde_json::from_value(json!({
+                "issue_id": issue_id,
+                "dry_run": true,
+                "strict_context": strict_context,
+            }))
+            .unwrap()
+        };
+
+        let result = server
+            .delete_issue(Parameters(dry_run_delete(None)))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert!(
+            body["warning"]
+                .as_str()
+                .unwrap()
+                .contains(&linked_project_id.to_string())
+        );
+
+        let result = server
+            .delete_issue(Parameters(dry_run_delete(Some(true))))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+
+        // The per-call parameter overrides VK_MCP_STRICT_CONTEXT.
+        server.config.strict_context = true;
+        let result = server
+            .delete_issue(Parameters(dry_run_delete(None)))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let result = server
+            .delete_issue(Parameters(dry_run_delete(Some(false))))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+
+        // Archiving goes through the same check, before anything is changed.
+        let request = serde_json::from_value(json!({ "issue_id": issue_id })).unwrap();
+        let result = server.archive_issue(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let archive_path = format!("/api/remote/issues/{}/archive", issue_id.as_str().unwrap());
+        assert_eq!(backend.requests_to(&archive_path), 0);
+    }
 }
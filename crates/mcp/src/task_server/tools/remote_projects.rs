@@ -1,4 +1,9 @@
-use api_types::ListProjectsResponse;
+use std::collections::HashMap;
+
+use api_types::{
+    CreateProjectRequest, IssueCountsResponse, IssuePriority, ListIssuesResponse,
+    ListProjectsResponse, MutationResponse, Project, SearchIssuesRequest, UpdateProjectRequest,
+};
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
@@ -6,7 +11,15 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{
+    McpServer, ToolError,
+    issue_tags::parse_color,
+    output_schema,
+    project_statuses::{StatusSummary, status_summary},
+};
+
+// Used when `create_project` is called without a color.
+const DEFAULT_PROJECT_COLOR: &str = "217 91% 60%";
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpListProjectsRequest {
@@ -20,6 +33,9 @@ struct ProjectSummary {
     id: String,
     #[schemars(description = "The name of the project")]
     name: String,
+    #[schemars(description = "Link to the project board in the Vibe Kanban web app")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
     #[schemars(description = "When the project was created")]
     created_at: String,
     #[schemars(description = "When the project was last updated")]
@@ -27,10 +43,11 @@ struct ProjectSummary {
 }
 
 impl ProjectSummary {
-    fn from_remote_project(project: api_types::Project) -> Self {
+    fn from_remote_project(project: api_types::Project, web_url: Option<String>) -> Self {
         Self {
             id: project.id.to_string(),
             name: project.name,
+            web_url,
             created_at: project.created_at.to_rfc3339(),
             updated_at: project.updated_at.to_rfc3339(),
         }
@@ -43,9 +60,102 @@ struct McpListProjectsResponse {
     count: usize,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpCreateProjectRequest {
+    #[schemars(
+        description = "The organization to create the project in. Optional if running inside a workspace linked to a remote project."
+    )]
+    organization_id: Option<Uuid>,
+    #[schemars(description = "Project name")]
+    name: String,
+    #[schemars(
+        description = "Hex color such as '#3b82f6', or HSL such as '217 91% 60%'. Defaults to blue."
+    )]
+    color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpUpdateProjectRequest {
+    #[schemars(description = "The project to update")]
+    project_id: Uuid,
+    #[schemars(description = "New project name")]
+    name: Option<String>,
+    #[schemars(description = "New hex color such as '#3b82f6', or HSL such as '217 91% 60%'")]
+    color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetProjectRequest {
+    #[schemars(
+        description = "The project to get. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpProjectResponse {
+    project: ProjectSummary,
+    #[schemars(description = "The organization the project belongs to")]
+    organization_id: String,
+    #[schemars(description = "Project color in HSL format 'H S% L%'")]
+    color: String,
+    #[schemars(description = "The project's status columns in board order")]
+    statuses: Vec<StatusSummary>,
+    #[schemars(
+        description = "Number of issues in the project, leaving out archived ones. Only set by `get_project`."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetProjectStatsRequest {
+    #[schemars(
+        description = "The project to summarize. Optional if running inside a workspace linked to a remote project."
+    )]
+    project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct StatusCount {
+    #[schemars(description = "The status name")]
+    status: String,
+    #[schemars(description = "Number of issues in the status")]
+    count: usize,
+    #[schemars(description = "Whether the column is hidden on the board")]
+    hidden: bool,
+}
+
+#[derive(Debug, Default, Serialize, schemars::JsonSchema)]
+struct PriorityCounts {
+    urgent: usize,
+    high: usize,
+    medium: usize,
+    low: usize,
+    #[schemars(description = "Issues without a priority")]
+    none: usize,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpProjectStatsResponse {
+    project_id: String,
+    #[schemars(description = "Number of issues in the project, leaving out archived ones")]
+    total_issues: usize,
+    #[schemars(description = "Issue count per status, in board order. Includes hidden statuses.")]
+    by_status: Vec<StatusCount>,
+    by_priority: PriorityCounts,
+    #[schemars(description = "Issues linked to at least one open pull request")]
+    issues_with_open_pull_requests: usize,
+    #[schemars(description = "Issues linked to at least one merged pull request")]
+    issues_with_merged_pull_requests: usize,
+}
+
 #[tool_router(router = remote_projects_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "List all the available projects")]
+    #[tool(
+        description = "List all the available projects",
+        output_schema = output_schema::<McpListProjectsResponse>()
+    )]
     async fn list_projects(
         &self,
         Parameters(McpListProjectsRequest { organization_id }): Parameters<McpListProjectsRequest>,
@@ -62,12 +172,491 @@ impl McpServer {
         let project_summaries: Vec<ProjectSummary> = response
             .projects
             .into_iter()
-            .map(ProjectSummary::from_remote_project)
+            .map(|project| {
+                let web_url = self.project_web_url(project.id);
+                ProjectSummary::from_remote_project(project, web_url)
+            })
             .collect();
 
-        McpServer::success(&McpListProjectsResponse {
+        self.success(&McpListProjectsResponse {
             count: project_summaries.len(),
             projects: project_summaries,
         })
     }
+
+    #[tool(
+        description = "Create a project in an organization. It starts with the default status columns, which are returned so issues can be filed right away. `organization_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpProjectResponse>()
+    )]
+    async fn create_project(
+        &self,
+        Parameters(McpCreateProjectRequest {
+            organization_id,
+            name,
+            color,
+        }): Parameters<McpCreateProjectRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Self::err("Project name must not be empty", None);
+        }
+        let color = match parse_color(color.as_deref().unwrap_or(DEFAULT_PROJECT_COLOR)) {
+            Ok(color) => color,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let organization_id = match self.resolve_organization_id(organization_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let payload = CreateProjectRequest {
+            id: None,
+            organization_id,
+            name,
+            color,
+        };
+        let url = self.url("/api/remote/projects");
+        let response: MutationResponse<Project> =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(response) => response,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        match self.project_response(response.data, false).await {
+            Ok(response) => self.success(&response),
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "Rename or recolor a project. Omitted fields are left unchanged.",
+        output_schema = output_schema::<McpProjectResponse>()
+    )]
+    async fn update_project(
+        &self,
+        Parameters(McpUpdateProjectRequest {
+            project_id,
+            name,
+            color,
+        }): Parameters<McpUpdateProjectRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        if name.is_none() && color.is_none() {
+            return Self::err("Provide `name`, `color`, or both", None);
+        }
+        let name = name.map(|name| name.trim().to_string());
+        if name.as_deref() == Some("") {
+            return Self::err("Project name must not be empty", None);
+        }
+        let color = match color.as_deref().map(parse_color).transpose() {
+            Ok(color) => color,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let payload = UpdateProjectRequest {
+            name,
+            color,
+            sort_order: None,
+        };
+        let url = self.url(&format!("/api/remote/projects/{}", project_id));
+        let response: MutationResponse<Project> =
+            match self.send_json(self.client.patch(&url).json(&payload)).await {
+                Ok(response) => response,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        match self.project_response(response.data, false).await {
+            Ok(response) => self.success(&response),
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "Get a project with its status columns and issue count. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpProjectResponse>()
+    )]
+    async fn get_project(
+        &self,
+        Parameters(McpGetProjectRequest { project_id }): Parameters<McpGetProjectRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let url = self.url(&format!("/api/remote/projects/{}", project_id));
+        let project: Project = match self.send_json(self.client.get(&url)).await {
+            Ok(project) => project,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        match self.project_response(project, true).await {
+            Ok(response) => self.success(&response),
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "Count a project's issues by status, by priority, and by linked pull request state, leaving out archived issues. Cheaper than paging through `list_issues` for a status report. `project_id` is optional if running inside a workspace linked to a remote project.",
+        output_schema = output_schema::<McpProjectStatsResponse>()
+    )]
+    async fn get_project_stats(
+        &self,
+        Parameters(McpGetProjectStatsRequest { project_id }): Parameters<McpGetProjectStatsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let project_id = match self.resolve_project_id(project_id).await {
+            Ok(id) => id,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let counts_url = self.url(&format!(
+            "/api/remote/issues/counts?project_id={}",
+            project_id
+        ));
+        let (statuses, counts) = tokio::join!(
+            self.fetch_project_statuses(project_id),
+            self.send_json::<IssueCountsResponse>(self.client.get(&counts_url)),
+        );
+        let (mut statuses, counts) = match (statuses, counts) {
+            (Ok(statuses), Ok(counts)) => (statuses, counts.counts),
+            (Err(e), _) | (_, Err(e)) => return Ok(Self::tool_error(e)),
+        };
+
+        let mut status_counts: HashMap<Uuid, usize> = HashMap::new();
+        let mut by_priority = PriorityCounts::default();
+        for count in &counts {
+            *status_counts.entry(count.status_id).or_default() += count.count;
+            match count.priority {
+                Some(IssuePriority::Urgent) => by_priority.urgent += count.count,
+                Some(IssuePriority::High) => by_priority.high += count.count,
+                Some(IssuePriority::Medium) => by_priority.medium += count.count,
+                Some(IssuePriority::Low) => by_priority.low += count.count,
+                None => by_priority.none += count.count,
+            }
+        }
+
+        statuses.sort_by_key(|status| status.sort_order);
+        let by_status = statuses
+            .into_iter()
+            .map(|status| StatusCount {
+                count: status_counts.get(&status.id).copied().unwrap_or(0),
+                status: status.name,
+                hidden: status.hidden,
+            })
+            .collect();
+
+        self.success(&McpProjectStatsResponse {
+            project_id: project_id.to_string(),
+            total_issues: counts.iter().map(|count| count.count).sum(),
+            by_status,
+            by_priority,
+            issues_with_open_pull_requests: counts
+                .iter()
+                .map(|count| count.with_open_pull_requests)
+                .sum(),
+            issues_with_merged_pull_requests: counts
+                .iter()
+                .map(|count| count.with_merged_pull_requests)
+                .sum(),
+        })
+    }
+}
+
+impl McpServer {
+    async fn project_response(
+        &self,
+        project: Project,
+        with_issue_count: bool,
+    ) -> Result<McpProjectResponse, ToolError> {
+        let statuses = self.fetch_project_statuses(project.id).await?;
+        let issue_count = if with_issue_count {
+            Some(self.count_project_issues(project.id).await?)
+        } else {
+            None
+        };
+
+        let web_url = self.project_web_url(project.id);
+        Ok(McpProjectResponse {
+            organization_id: project.organization_id.to_string(),
+            color: project.color.clone(),
+            statuses: statuses.into_iter().map(status_summary).collect(),
+            issue_count,
+            project: ProjectSummary::from_remote_project(project, web_url),
+        })
+    }
+
+    async fn count_project_issues(&self, project_id: Uuid) -> Result<usize, ToolError> {
+        let request = SearchIssuesRequest {
+            project_id,
+            limit: Some(1),
+            include_archived: Some(false),
+            ..Default::default()
+        };
+        let url = self.url("/api/remote/issues/search");
+        let response: ListIssuesResponse = self
+            .send_json(self.client.post(&url).json(&request))
+            .await?;
+        Ok(response.total_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        extract::RawQuery,
+        routing::{get, patch, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope};
+
+    fn project_json(id: Uuid, organization_id: Uuid, name: &str, color: &str) -> Value {
+        json!({
+            "id": id,
+            "organization_id": organization_id,
+            "name": name,
+            "color": color,
+            "sort_order": 0,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    fn statuses_json(project_id: Uuid) -> Value {
+        let statuses: Vec<Value> = ["To do", "In progress", "Done"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                json!({
+                    "id": Uuid::new_v4(),
+                    "project_id": project_id,
+                    "name": name,
+                    "color": "217 91% 60%",
+                    "sort_order": i * 1000,
+                    "hidden": false,
+                    "created_at": "2026-01-01T00:00:00Z",
+                })
+            })
+            .collect();
+        json!({ "project_statuses": statuses })
+    }
+
+    fn structured(result: &CallToolResult) -> Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_project_returns_default_statuses() {
+        let project_id = Uuid::new_v4();
+        let organization_id = Uuid::new_v4();
+
+        let router = Router::new()
+            .route(
+                "/api/remote/projects",
+                post(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["name"], "Roadmap");
+                    assert_eq!(body["color"], DEFAULT_PROJECT_COLOR);
+                    let project = project_json(
+                        project_id,
+                        organization_id,
+                        "Roadmap",
+                        DEFAULT_PROJECT_COLOR,
+                    );
+                    envelope(json!({ "data": project, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(statuses_json(project_id)) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "organization_id": organization_id,
+            "name": "  Roadmap ",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .create_project(Parameters(request))
+            .await
+            .unwrap();
+
+        let body = structured(&result);
+        assert_eq!(body["project"]["id"], project_id.to_string());
+        assert_eq!(body["statuses"].as_array().unwrap().len(), 3);
+        assert_eq!(body["statuses"][0]["name"], "To do");
+        assert!(body.get("issue_count").is_none());
+    }
+
+    #[tokio::test]
+    async fn update_project_converts_hex_colors_and_rejects_invalid_ones() {
+        let project_id = Uuid::new_v4();
+        let organization_id = Uuid::new_v4();
+        let project_path = format!("/api/remote/projects/{}", project_id);
+
+        let router = Router::new()
+            .route(
+                &project_path,
+                patch(move |Json(body): Json<Value>| async move {
+                    assert_eq!(body["color"], "217 91% 60%");
+                    let project =
+                        project_json(project_id, organization_id, "Roadmap", "217 91% 60%");
+                    envelope(json!({ "data": project, "txid": 1 }))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(statuses_json(project_id)) }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request =
+            serde_json::from_value(json!({ "project_id": project_id, "color": "blue" })).unwrap();
+        let result = server.update_project(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(structured(&result)["error"], "Invalid color 'blue'");
+        assert_eq!(backend.requests_to(&project_path), 0);
+
+        let request =
+            serde_json::from_value(json!({ "project_id": project_id, "color": "#3b82f6" }))
+                .unwrap();
+        let result = server.update_project(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(structured(&result)["project"]["color"], "217 91% 60%");
+        assert_eq!(backend.requests_to(&project_path), 1);
+    }
+
+    #[tokio::test]
+    async fn get_project_counts_issues() {
+        let project_id = Uuid::new_v4();
+        let organization_id = Uuid::new_v4();
+
+        let router = Router::new()
+            .route(
+                &format!("/api/remote/projects/{}", project_id),
+                get(move || async move {
+                    envelope(project_json(
+                        project_id,
+                        organization_id,
+                        "Roadmap",
+                        "0 0% 50%",
+                    ))
+                }),
+            )
+            .route(
+                "/api/remote/project-statuses",
+                get(move || async move { envelope(statuses_json(project_id)) }),
+            )
+            .route(
+                "/api/remote/issues/search",
+                post(|Json(body): Json<Value>| async move {
+                    assert_eq!(body["limit"], 1);
+                    envelope(json!({ "issues": [], "total_count": 12, "limit": 1, "offset": 0 }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .get_project(Parameters(request))
+            .await
+            .unwrap();
+
+        let body = structured(&result);
+        assert_eq!(body["organization_id"], organization_id.to_string());
+        assert_eq!(body["color"], "0 0% 50%");
+        assert_eq!(body["issue_count"], 12);
+    }
+
+    #[tokio::test]
+    async fn get_project_stats_counts_by_status_priority_and_pull_request() {
+        let project_id = Uuid::new_v4();
+        let (todo_id, done_id, hidden_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let status = |id: Uuid, name: &str, sort_order: i32, hidden: bool| {
+            json!({
+                "id": id,
+                "project_id": project_id,
+                "name": name,
+                "color": "217 91% 60%",
+                "sort_order": sort_order,
+                "hidden": hidden,
+                "created_at": "2026-01-01T00:00:00Z",
+            })
+        };
+        let statuses = json!([
+            status(done_id, "Done", 2000, false),
+            status(hidden_id, "Icebox", 3000, true),
+            status(todo_id, "To do", 0, false),
+        ]);
+        let counts = json!([
+            {
+                "status_id": todo_id,
+                "priority": "high",
+                "count": 1,
+                "with_open_pull_requests": 1,
+                "with_merged_pull_requests": 0,
+            },
+            {
+                "status_id": todo_id,
+                "priority": null,
+                "count": 1,
+                "with_open_pull_requests": 0,
+                "with_merged_pull_requests": 1,
+            },
+            {
+                "status_id": hidden_id,
+                "priority": "urgent",
+                "count": 1,
+                "with_open_pull_requests": 0,
+                "with_merged_pull_requests": 0,
+            },
+        ]);
+
+        let router = Router::new()
+            .route(
+                "/api/remote/project-statuses",
+                get(move || {
+                    let statuses = statuses.clone();
+                    async move { envelope(json!({ "project_statuses": statuses })) }
+                }),
+            )
+            .route(
+                "/api/remote/issues/counts",
+                get(move |RawQuery(query): RawQuery| {
+                    let counts = counts.clone();
+                    async move {
+                        assert_eq!(query, Some(format!("project_id={project_id}")));
+                        envelope(json!({ "counts": counts }))
+                    }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "project_id": project_id })).unwrap();
+        let result = backend
+            .server()
+            .get_project_stats(Parameters(request))
+            .await
+            .unwrap();
+
+        let body = structured(&result);
+        assert_eq!(body["total_issues"], 3);
+        assert_eq!(
+            body["by_status"],
+            json!([
+                { "status": "To do", "count": 2, "hidden": false },
+                { "status": "Done", "count": 0, "hidden": false },
+                { "status": "Icebox", "count": 1, "hidden": true },
+            ])
+        );
+        assert_eq!(
+            body["by_priority"],
+            json!({ "urgent": 1, "high": 1, "medium": 0, "low": 0, "none": 1 })
+        );
+        assert_eq!(body["issues_with_open_pull_requests"], 1);
+        assert_eq!(body["issues_with_merged_pull_requests"], 1);
+        assert_eq!(backend.requests_to("/api/remote/issues/search"), 0);
+    }
 }
@@ -1,4 +1,12 @@
-use db::models::repo::Repo;
+use db::models::{
+    execution_process::ExecutionProcessStatus,
+    repo::Repo,
+    requests::{
+        ListRepoBranchesResponse, RepoScriptRun, RepoScriptRunsQuery, RepoScriptType,
+        ValidateRepoScriptRequest, ValidateRepoScriptResponse,
+    },
+};
+use reqwest::StatusCode;
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
@@ -6,7 +14,10 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, ToolError, output_schema};
+
+const DEFAULT_BRANCH_LIMIT: usize = 100;
+const MAX_BRANCH_LIMIT: usize = 100;
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpRepoSummary {
@@ -30,6 +41,10 @@ struct RepoDetails {
     name: String,
     #[schemars(description = "The display name of the repository")]
     display_name: String,
+    #[schemars(description = "The local path of the repository")]
+    path: String,
+    #[schemars(description = "The branch new workspaces target by default, if set")]
+    default_target_branch: Option<String>,
     #[schemars(description = "The setup script that runs when initializing a workspace")]
     setup_script: Option<String>,
     #[schemars(description = "The cleanup script that runs when tearing down a workspace")]
@@ -38,6 +53,110 @@ struct RepoDetails {
     dev_server_script: Option<String>,
 }
 
+impl From<Repo> for RepoDetails {
+    fn from(repo: Repo) -> Self {
+        Self {
+            id: repo.id.to_string(),
+            name: repo.name,
+            display_name: repo.display_name,
+            path: repo.path.to_string_lossy().into_owned(),
+            default_target_branch: repo.default_target_branch,
+            setup_script: repo.setup_script,
+            cleanup_script: repo.cleanup_script,
+            dev_server_script: repo.dev_server_script,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct AddRepoRequest {
+    #[schemars(
+        description = "Absolute path to an existing git repository on this machine; `~` is expanded"
+    )]
+    path: String,
+    #[schemars(description = "Name to show for the repository (default: the folder name)")]
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct UpdateRepoRequest {
+    #[schemars(description = "The ID of the repository to update")]
+    repo_id: Uuid,
+    #[schemars(description = "New display name for the repository")]
+    display_name: Option<String>,
+    #[schemars(
+        description = "Branch new workspaces should target by default (use empty string to clear)"
+    )]
+    default_target_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct RemoveRepoRequest {
+    #[schemars(description = "The ID of the repository to remove")]
+    repo_id: Uuid,
+    #[schemars(
+        description = "Remove the repository even if active workspaces use it; they lose the repository (default: false)"
+    )]
+    force: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct RemoveRepoResponse {
+    #[schemars(description = "Whether the repository was removed")]
+    success: bool,
+    #[schemars(description = "The repository ID that was removed")]
+    repo_id: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct ListRepoBranchesRequest {
+    #[schemars(description = "The ID of the repository")]
+    repo_id: Uuid,
+    #[schemars(
+        description = "Only return branches whose name contains this text (case-insensitive)"
+    )]
+    #[serde(alias = "filter")]
+    search: Option<String>,
+    #[schemars(description = "Maximum number of branches to return (default and max: 100)")]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRepoBranch {
+    #[schemars(
+        description = "Branch name; remote-tracking branches include the remote, e.g. 'origin/main'"
+    )]
+    name: String,
+    #[schemars(description = "Whether the branch is checked out in the repository")]
+    is_current: bool,
+    is_remote: bool,
+    #[schemars(description = "Whether this is the repository's default branch")]
+    is_default: bool,
+    last_commit_date: String,
+    #[schemars(
+        description = "Commits on this branch that are not on the default branch, if computed"
+    )]
+    ahead_of_default: Option<usize>,
+    #[schemars(
+        description = "Commits on the default branch that are not on this branch, if computed"
+    )]
+    behind_default: Option<usize>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpListRepoBranchesResponse {
+    repo_id: String,
+    default_branch: Option<String>,
+    branches: Vec<McpRepoBranch>,
+    count: usize,
+    #[schemars(
+        description = "Number of branches matching the search before the limit was applied"
+    )]
+    total_count: usize,
+    #[schemars(description = "Whether some matching branches were left out by the limit")]
+    truncated: bool,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct UpdateSetupScriptRequest {
     #[schemars(description = "The ID of the repository to update")]
@@ -70,6 +189,65 @@ struct UpdateRepoScriptResponse {
     repo_id: String,
     #[schemars(description = "The script field that was updated")]
     field: String,
+    #[schemars(
+        description = "The script content before this update, so an accidental overwrite can be reverted"
+    )]
+    previous_script: Option<String>,
+    #[schemars(description = "Likely mistakes found in the saved script")]
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum McpRepoScriptType {
+    Setup,
+    Cleanup,
+    DevServer,
+}
+
+impl From<McpRepoScriptType> for RepoScriptType {
+    fn from(script_type: McpRepoScriptType) -> Self {
+        match script_type {
+            McpRepoScriptType::Setup => Self::Setup,
+            McpRepoScriptType::Cleanup => Self::Cleanup,
+            McpRepoScriptType::DevServer => Self::DevServer,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct GetRepoScriptRunsRequest {
+    #[schemars(description = "The ID of the repository")]
+    repo_id: Uuid,
+    #[schemars(description = "Which script to show runs for: 'setup', 'cleanup' or 'dev_server'")]
+    script_type: McpRepoScriptType,
+    #[schemars(
+        description = "Maximum number of runs to return, newest first (default: 5, max: 50)"
+    )]
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct RepoScriptRunSummary {
+    #[schemars(description = "The execution process that ran the script")]
+    execution_process_id: String,
+    #[schemars(description = "Run status: 'running', 'completed', 'failed' or 'killed'")]
+    status: String,
+    #[schemars(description = "Exit code, if the script has finished")]
+    exit_code: Option<i64>,
+    started_at: String,
+    finished_at: Option<String>,
+    #[schemars(description = "The last few KB of combined stdout and stderr")]
+    output: String,
+    #[schemars(description = "Whether earlier output was cut off")]
+    output_truncated: bool,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct GetRepoScriptRunsResponse {
+    repo_id: String,
+    runs: Vec<RepoScriptRunSummary>,
+    count: usize,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -80,7 +258,10 @@ struct ListReposResponse {
 
 #[tool_router(router = repos_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "List all repositories.")]
+    #[tool(
+        description = "List all repositories.",
+        output_schema = output_schema::<ListReposResponse>()
+    )]
     async fn list_repos(&self) -> Result<CallToolResult, ErrorData> {
         let url = self.url("/api/repos");
         let repos: Vec<Repo> = match self.send_json(self.client.get(&url)).await {
@@ -101,11 +282,12 @@ impl McpServer {
             repos: repo_summaries,
         };
 
-        McpServer::success(&response)
+        self.success(&response)
     }
 
     #[tool(
-        description = "Get detailed information about a repository including its scripts. Use `list_repos` to find available repo IDs."
+        description = "Get detailed information about a repository including its scripts. Use `list_repos` to find available repo IDs.",
+        output_schema = output_schema::<RepoDetails>()
     )]
     async fn get_repo(
         &self,
@@ -116,47 +298,226 @@ impl McpServer {
             Ok(r) => r,
             Err(e) => return Ok(Self::tool_error(e)),
         };
-        McpServer::success(&RepoDetails {
-            id: repo.id.to_string(),
-            name: repo.name,
-            display_name: repo.display_name,
-            setup_script: repo.setup_script,
-            cleanup_script: repo.cleanup_script,
-            dev_server_script: repo.dev_server_script,
-        })
+        self.success(&RepoDetails::from(repo))
     }
 
     #[tool(
-        description = "Update a repository's setup script. The setup script runs when initializing a workspace."
+        description = "Register a local git repository so workspaces can use it. The server checks that the path is a git repository; registering an already known path returns the existing repository.",
+        output_schema = output_schema::<RepoDetails>()
     )]
-    async fn update_setup_script(
+    async fn add_repo(
         &self,
-        Parameters(UpdateSetupScriptRequest { repo_id, script }): Parameters<
-            UpdateSetupScriptRequest,
-        >,
+        Parameters(AddRepoRequest { path, display_name }): Parameters<AddRepoRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = path.trim();
+        if path.is_empty() {
+            return Self::err("path must not be empty", None);
+        }
+        let display_name = display_name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+
+        let url = self.url("/api/repos");
+        let payload = serde_json::json!({ "path": path, "display_name": display_name });
+        match self
+            .send_json::<Repo>(self.client.post(&url).json(&payload))
+            .await
+        {
+            Ok(repo) => self.success(&RepoDetails::from(repo)),
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "Update a repository's display name and/or default target branch. Fields that are omitted are left unchanged. Use the `update_*_script` tools to change scripts.",
+        output_schema = output_schema::<RepoDetails>()
+    )]
+    async fn update_repo(
+        &self,
+        Parameters(UpdateRepoRequest {
+            repo_id,
+            display_name,
+            default_target_branch,
+        }): Parameters<UpdateRepoRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let mut payload = serde_json::Map::new();
+        if let Some(display_name) = display_name {
+            let display_name = display_name.trim();
+            if display_name.is_empty() {
+                return Self::err("display_name must not be empty", None);
+            }
+            payload.insert("display_name".to_string(), display_name.into());
+        }
+        if let Some(branch) = default_target_branch {
+            let branch = branch.trim();
+            let branch = (!branch.is_empty()).then_some(branch);
+            payload.insert("default_target_branch".to_string(), branch.into());
+        }
+        if payload.is_empty() {
+            return Self::err(
+                "Nothing to update. Provide display_name and/or default_target_branch.",
+                None,
+            );
+        }
+
         let url = self.url(&format!("/api/repos/{}", repo_id));
-        let script_value = if script.is_empty() {
-            None
-        } else {
-            Some(script)
-        };
-        let payload = serde_json::json!({
-            "setup_script": script_value
-        });
-        let _repo: Repo = match self.send_json(self.client.put(&url).json(&payload)).await {
-            Ok(r) => r,
+        match self
+            .send_json::<Repo>(self.client.put(&url).json(&payload))
+            .await
+        {
+            Ok(repo) => self.success(&RepoDetails::from(repo)),
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "Remove a repository from Vibe Kanban. Files on disk are not touched. Fails with the number of active workspaces still using the repository unless `force` is set.",
+        output_schema = output_schema::<RemoveRepoResponse>()
+    )]
+    async fn remove_repo(
+        &self,
+        Parameters(RemoveRepoRequest { repo_id, force }): Parameters<RemoveRepoRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/repos/{}", repo_id));
+        let force = force.unwrap_or(false);
+        match self
+            .send_empty_json(self.client.delete(&url).query(&[("force", force)]))
+            .await
+        {
+            Ok(()) => self.success(&RemoveRepoResponse {
+                success: true,
+                repo_id: repo_id.to_string(),
+            }),
+            Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                Ok(Self::tool_error(ToolError {
+                    hint: Some(
+                        "Archive or delete those workspaces first, or pass `force: true` to remove the repository from them."
+                            .to_string(),
+                    ),
+                    ..e
+                }))
+            }
+            Err(e) => Ok(Self::tool_error(e)),
+        }
+    }
+
+    #[tool(
+        description = "List local and remote-tracking branches of a repository, default branch first and then by most recent commit, with ahead/behind counts relative to the default branch. Use this to pick a base branch for `start_workspace`.",
+        output_schema = output_schema::<McpListRepoBranchesResponse>()
+    )]
+    async fn list_repo_branches(
+        &self,
+        Parameters(ListRepoBranchesRequest {
+            repo_id,
+            search,
+            limit,
+        }): Parameters<ListRepoBranchesRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/repos/{}/branches/details", repo_id));
+        let response: ListRepoBranchesResponse = match self.send_json(self.client.get(&url)).await {
+            Ok(response) => response,
             Err(e) => return Ok(Self::tool_error(e)),
         };
-        McpServer::success(&UpdateRepoScriptResponse {
-            success: true,
+
+        let search = search
+            .map(|search| search.trim().to_lowercase())
+            .filter(|search| !search.is_empty());
+        let mut branches = response
+            .branches
+            .into_iter()
+            .filter(|branch| {
+                search
+                    .as_ref()
+                    .is_none_or(|search| branch.name.to_lowercase().contains(search))
+            })
+            .collect::<Vec<_>>();
+        // The server lists the current branch first; the default branch is what callers want.
+        branches.sort_by_key(|branch| !branch.is_default);
+        let total_count = branches.len();
+        let branches = branches
+            .into_iter()
+            .take(limit.unwrap_or(DEFAULT_BRANCH_LIMIT).min(MAX_BRANCH_LIMIT))
+            .map(|branch| McpRepoBranch {
+                name: branch.name,
+                is_current: branch.is_current,
+                is_remote: branch.is_remote,
+                is_default: branch.is_default,
+                last_commit_date: branch.last_commit_date.to_rfc3339(),
+                ahead_of_default: branch.ahead_of_default,
+                behind_default: branch.behind_default,
+            })
+            .collect::<Vec<_>>();
+
+        self.success(&McpListRepoBranchesResponse {
             repo_id: repo_id.to_string(),
-            field: "setup_script".to_string(),
+            default_branch: response.default_branch,
+            count: branches.len(),
+            truncated: branches.len() < total_count,
+            total_count,
+            branches,
         })
     }
 
     #[tool(
-        description = "Update a repository's cleanup script. The cleanup script runs when tearing down a workspace."
+        description = "Show recent runs of a repository's setup, cleanup or dev server script, newest first, with exit codes and the tail of their output. Use this to check whether a script change worked.",
+        output_schema = output_schema::<GetRepoScriptRunsResponse>()
+    )]
+    async fn get_repo_script_runs(
+        &self,
+        Parameters(GetRepoScriptRunsRequest {
+            repo_id,
+            script_type,
+            limit,
+        }): Parameters<GetRepoScriptRunsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let url = self.url(&format!("/api/repos/{}/script-runs", repo_id));
+        let query = RepoScriptRunsQuery {
+            script_type: script_type.into(),
+            limit,
+        };
+        let runs: Vec<RepoScriptRun> =
+            match self.send_json(self.client.get(&url).query(&query)).await {
+                Ok(runs) => runs,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        let runs = runs
+            .into_iter()
+            .map(|run| RepoScriptRunSummary {
+                execution_process_id: run.execution_process_id.to_string(),
+                status: script_run_status_label(&run.status).to_string(),
+                exit_code: run.exit_code,
+                started_at: run.started_at.to_rfc3339(),
+                finished_at: run.completed_at.map(|at| at.to_rfc3339()),
+                output: run.output,
+                output_truncated: run.output_truncated,
+            })
+            .collect::<Vec<_>>();
+
+        self.success(&GetRepoScriptRunsResponse {
+            repo_id: repo_id.to_string(),
+            count: runs.len(),
+            runs,
+        })
+    }
+
+    #[tool(
+        description = "Update a repository's setup script. The setup script runs when initializing a workspace. The script is checked for syntax errors, which block saving. Likely mistakes, such as a `cd` that isn't followed by `&&`, are returned as warnings. Returns the previous script so it can be restored.",
+        output_schema = output_schema::<UpdateRepoScriptResponse>()
+    )]
+    async fn update_setup_script(
+        &self,
+        Parameters(UpdateSetupScriptRequest { repo_id, script }): Parameters<
+            UpdateSetupScriptRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.update_repo_script(repo_id, "setup_script", script, |repo| repo.setup_script)
+            .await
+    }
+
+    #[tool(
+        description = "Update a repository's cleanup script. The cleanup script runs when tearing down a workspace. The script is checked for syntax errors, which block saving. Likely mistakes, such as a `cd` that isn't followed by `&&`, are returned as warnings. Returns the previous script so it can be restored.",
+        output_schema = output_schema::<UpdateRepoScriptResponse>()
     )]
     async fn update_cleanup_script(
         &self,
@@ -164,52 +525,352 @@ impl McpServer {
             UpdateCleanupScriptRequest,
         >,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = self.url(&format!("/api/repos/{}", repo_id));
-        let script_value = if script.is_empty() {
-            None
-        } else {
-            Some(script)
-        };
-        let payload = serde_json::json!({
-            "cleanup_script": script_value
-        });
-        let _repo: Repo = match self.send_json(self.client.put(&url).json(&payload)).await {
-            Ok(r) => r,
-            Err(e) => return Ok(Self::tool_error(e)),
-        };
-        McpServer::success(&UpdateRepoScriptResponse {
-            success: true,
-            repo_id: repo_id.to_string(),
-            field: "cleanup_script".to_string(),
+        self.update_repo_script(repo_id, "cleanup_script", script, |repo| {
+            repo.cleanup_script
         })
+        .await
     }
 
     #[tool(
-        description = "Update a repository's dev server script. The dev server script starts the development server for the repository."
+        description = "Update a repository's dev server script. The dev server script starts the development server for the repository. The script is checked for syntax errors, which block saving. Likely mistakes, such as a `cd` that isn't followed by `&&`, are returned as warnings. Returns the previous script so it can be restored.",
+        output_schema = output_schema::<UpdateRepoScriptResponse>()
     )]
     async fn update_dev_server_script(
         &self,
         Parameters(UpdateDevServerScriptRequest { repo_id, script }): Parameters<
             UpdateDevServerScriptRequest,
         >,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.update_repo_script(repo_id, "dev_server_script", script, |repo| {
+            repo.dev_server_script
+        })
+        .await
+    }
+}
+
+impl McpServer {
+    async fn update_repo_script(
+        &self,
+        repo_id: Uuid,
+        field: &'static str,
+        script: String,
+        current_script: fn(Repo) -> Option<String>,
     ) -> Result<CallToolResult, ErrorData> {
         let url = self.url(&format!("/api/repos/{}", repo_id));
-        let script_value = if script.is_empty() {
-            None
-        } else {
-            Some(script)
-        };
-        let payload = serde_json::json!({
-            "dev_server_script": script_value
-        });
-        let _repo: Repo = match self.send_json(self.client.put(&url).json(&payload)).await {
-            Ok(r) => r,
+        // Read the current script first so the response can hand it back.
+        let previous_script = match self.send_json(self.client.get(&url)).await {
+            Ok(repo) => current_script(repo),
             Err(e) => return Ok(Self::tool_error(e)),
         };
-        McpServer::success(&UpdateRepoScriptResponse {
+
+        let (script_value, warnings) = if script.is_empty() {
+            (None, Vec::new())
+        } else {
+            let validation = match self.validate_repo_script(repo_id, script).await {
+                Ok(validation) => validation,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+            if !validation.errors.is_empty() {
+                return Self::err(
+                    "Script has errors and was not saved".to_string(),
+                    Some(validation.errors.join("\n")),
+                );
+            }
+            (Some(validation.script), validation.warnings)
+        };
+        let payload = serde_json::json!({ field: script_value });
+        if let Err(e) = self
+            .send_json::<Repo>(self.client.put(&url).json(&payload))
+            .await
+        {
+            return Ok(Self::tool_error(e));
+        }
+        self.success(&UpdateRepoScriptResponse {
             success: true,
             repo_id: repo_id.to_string(),
-            field: "dev_server_script".to_string(),
+            field: field.to_string(),
+            previous_script,
+            warnings,
         })
     }
+
+    // Line endings are normalised and the script is parsed by the server, since the MCP
+    // process may not have a shell of its own.
+    async fn validate_repo_script(
+        &self,
+        repo_id: Uuid,
+        script: String,
+    ) -> Result<ValidateRepoScriptResponse, ToolError> {
+        let url = self.url(&format!("/api/repos/{}/validate-script", repo_id));
+        self.send_json(
+            self.client
+                .post(&url)
+                .json(&ValidateRepoScriptRequest { script }),
+        )
+        .await
+    }
+}
+
+fn script_run_status_label(status: &ExecutionProcessStatus) -> &'static str {
+    match status {
+        ExecutionProcessStatus::Running => "running",
+        ExecutionProcessStatus::Completed => "completed",
+        ExecutionProcessStatus::Failed => "failed",
+        ExecutionProcessStatus::Killed => "killed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        extract::RawQuery,
+        http::StatusCode,
+        routing::{delete, get, post},
+    };
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope};
+
+    fn repo_json(repo_id: Uuid, setup_script: Option<&str>) -> Value {
+        json!({
+            "id": repo_id,
+            "path": "/tmp/repo",
+            "name": "repo",
+            "display_name": "repo",
+            "setup_script": setup_script,
+            "cleanup_script": null,
+            "archive_script": null,
+            "copy_files": null,
+            "parallel_setup_script": false,
+            "dev_server_script": null,
+            "default_target_branch": null,
+            "default_working_dir": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    fn router(repo_id: Uuid) -> Router {
+        Router::new()
+            .route(
+                "/api/repos/{repo_id}",
+                get(move || async move { envelope(repo_json(repo_id, Some("npm install"))) }).put(
+                    move |Json(body): Json<Value>| async move {
+                        assert_eq!(body, json!({ "setup_script": "pnpm install\n" }));
+                        envelope(repo_json(repo_id, Some("pnpm install\n")))
+                    },
+                ),
+            )
+            .route(
+                "/api/repos/{repo_id}/validate-script",
+                post(|Json(body): Json<Value>| async move {
+                    let script = body["script"].as_str().unwrap().replace("\r\n", "\n");
+                    envelope(json!({
+                        "script": script,
+                        "errors": [],
+                        "warnings": ["Line 1: `cd web` is not followed by `&&`"],
+                    }))
+                }),
+            )
+    }
+
+    #[tokio::test]
+    async fn update_setup_script_returns_previous_script() {
+        let repo_id = Uuid::new_v4();
+        let backend = MockBackend::start(router(repo_id)).await;
+
+        let request =
+            serde_json::from_value(json!({ "repo_id": repo_id, "script": "pnpm install\r\n" }))
+                .unwrap();
+        let result = backend
+            .server()
+            .update_setup_script(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["previous_script"], "npm install");
+        assert_eq!(
+            body["warnings"][0],
+            "Line 1: `cd web` is not followed by `&&`"
+        );
+        assert_eq!(backend.requests_to(&format!("/api/repos/{}", repo_id)), 2);
+    }
+
+    #[tokio::test]
+    async fn update_setup_script_refuses_syntax_errors() {
+        let repo_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/repos/{repo_id}",
+                get(move || async move { envelope(repo_json(repo_id, Some("npm install"))) }),
+            )
+            .route(
+                "/api/repos/{repo_id}/validate-script",
+                post(|Json(body): Json<Value>| async move {
+                    envelope(json!({
+                        "script": body["script"],
+                        "errors": ["line 1: unexpected EOF while looking for matching `\"'"],
+                        "warnings": [],
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "repo_id": repo_id, "script": "echo \"oops" })).unwrap();
+        let result = backend
+            .server()
+            .update_setup_script(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            result.content[0]
+                .as_text()
+                .unwrap()
+                .text
+                .contains("unexpected EOF")
+        );
+        // Only the initial read; nothing was saved.
+        assert_eq!(backend.requests_to(&format!("/api/repos/{}", repo_id)), 1);
+    }
+
+    #[tokio::test]
+    async fn remove_repo_reports_dependent_workspaces_unless_forced() {
+        let repo_id = Uuid::new_v4();
+        let router = Router::new().route(
+            "/api/repos/{repo_id}",
+            delete(|RawQuery(query): RawQuery| async move {
+                if query.as_deref() == Some("force=true") {
+                    return (StatusCode::OK, envelope(Value::Null));
+                }
+                let conflict = json!({
+                    "success": false,
+                    "data": null,
+                    "message": null,
+                    "error_data": {
+                        "message": "Repository is used by 2 active workspace(s)",
+                        "workspaces": ["Login page", "Fix typo"],
+                    },
+                });
+                (StatusCode::CONFLICT, Json(conflict))
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({ "repo_id": repo_id })).unwrap();
+        let result = server.remove_repo(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["details"],
+            "Repository is used by 2 active workspace(s)"
+        );
+        assert!(body["hint"].as_str().unwrap().contains("force: true"));
+
+        let request = serde_json::from_value(json!({ "repo_id": repo_id, "force": true })).unwrap();
+        let result = server.remove_repo(Parameters(request)).await.unwrap();
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["success"], true);
+        assert_eq!(body["repo_id"], repo_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn list_repo_branches_searches_case_insensitively_and_limits() {
+        let repo_id = Uuid::new_v4();
+        let branch = |name: &str, is_default: bool| {
+            json!({
+                "name": name,
+                "is_current": is_default,
+                "is_remote": name.starts_with("origin/"),
+                "is_default": is_default,
+                "last_commit_date": "2026-01-01T00:00:00Z",
+                "ahead_of_default": if is_default { Some(0) } else { None },
+                "behind_default": if is_default { Some(0) } else { None },
+            })
+        };
+        let branches = vec![
+            branch("main", true),
+            branch("Feature/Login", false),
+            branch("origin/feature/login", false),
+            branch("fix/typo", false),
+        ];
+
+        let router = Router::new().route(
+            "/api/repos/{repo_id}/branches/details",
+            get(move || {
+                let branches = branches.clone();
+                async move { envelope(json!({ "default_branch": "main", "branches": branches })) }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request =
+            serde_json::from_value(json!({ "repo_id": repo_id, "search": "FEATURE/", "limit": 1 }))
+                .unwrap();
+        let result = backend
+            .server()
+            .list_repo_branches(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["default_branch"], "main");
+        assert_eq!(body["total_count"], 2);
+        assert_eq!(body["count"], 1);
+        assert_eq!(body["truncated"], true);
+        assert_eq!(body["branches"][0]["name"], "Feature/Login");
+    }
+
+    #[tokio::test]
+    async fn list_repo_branches_puts_the_default_branch_first() {
+        let repo_id = Uuid::new_v4();
+        let branch = |name: &str, is_current: bool, is_default: bool| {
+            json!({
+                "name": name,
+                "is_current": is_current,
+                "is_remote": false,
+                "is_default": is_default,
+                "last_commit_date": "2026-01-01T00:00:00Z",
+                "ahead_of_default": null,
+                "behind_default": null,
+            })
+        };
+        let branches = vec![
+            branch("feature/login", true, false),
+            branch("fix/typo", false, false),
+            branch("master", false, true),
+        ];
+
+        let router = Router::new().route(
+            "/api/repos/{repo_id}/branches/details",
+            get(move || {
+                let branches = branches.clone();
+                async move { envelope(json!({ "default_branch": "master", "branches": branches })) }
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "repo_id": repo_id })).unwrap();
+        let result = backend
+            .server()
+            .list_repo_branches(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let names = body["branches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|branch| branch["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["master", "feature/login", "fix/typo"]);
+        assert_eq!(body["truncated"], false);
+    }
 }
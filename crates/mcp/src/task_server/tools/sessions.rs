@@ -9,7 +9,7 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, output_schema};
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct CreateSessionRequest {
@@ -142,7 +142,10 @@ struct GetExecutionResponse {
 
 #[tool_router(router = session_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "Create a new session in a workspace.")]
+    #[tool(
+        description = "Create a new session in a workspace.",
+        output_schema = output_schema::<CreateSessionResponse>()
+    )]
     async fn create_session(
         &self,
         Parameters(CreateSessionRequest {
@@ -185,12 +188,15 @@ impl McpServer {
             Err(error_result) => return Ok(Self::tool_error(error_result)),
         };
 
-        Self::success(&CreateSessionResponse {
+        self.success(&CreateSessionResponse {
             session: self.session_summary(session),
         })
     }
 
-    #[tool(description = "List all sessions for a workspace.")]
+    #[tool(
+        description = "List all sessions for a workspace.",
+        output_schema = output_schema::<ListSessionsResponse>()
+    )]
     async fn list_sessions(
         &self,
         Parameters(ListSessionsRequest { workspace_id }): Parameters<ListSessionsRequest>,
@@ -214,14 +220,17 @@ impl McpServer {
             .map(|session| self.session_summary(session))
             .collect::<Vec<_>>();
 
-        Self::success(&ListSessionsResponse {
+        self.success(&ListSessionsResponse {
             workspace_id: workspace_id.to_string(),
             total_count: sessions.len(),
             sessions,
         })
     }
 
-    #[tool(description = "Update a session's name. `session_id` is required.")]
+    #[tool(
+        description = "Update a session's name. `session_id` is required.",
+        output_schema = output_schema::<UpdateSessionResponse>()
+    )]
     async fn update_session(
         &self,
         Parameters(UpdateSessionRequest { session_id, name }): Parameters<UpdateSessionRequest>,
@@ -245,7 +254,7 @@ impl McpServer {
             Err(error_result) => return Ok(Self::tool_error(error_result)),
         };
 
-        Self::success(&UpdateSessionResponse {
+        self.success(&UpdateSessionResponse {
             success: true,
             session_id: updated.id.to_string(),
             name: updated.name,
@@ -253,7 +262,8 @@ impl McpServer {
     }
 
     #[tool(
-        description = "Run a coding agent turn in an existing session and return immediately with the execution process."
+        description = "Run a coding agent turn in an existing session and return immediately with the execution process.",
+        output_schema = output_schema::<RunCodingAgentInSessionResponse>()
     )]
     async fn run_session_prompt(
         &self,
@@ -310,14 +320,17 @@ impl McpServer {
             Err(error_result) => return Ok(Self::tool_error(error_result)),
         };
 
-        Self::success(&RunCodingAgentInSessionResponse {
+        self.success(&RunCodingAgentInSessionResponse {
             session_id: session_id.to_string(),
             execution_id,
             execution,
         })
     }
 
-    #[tool(description = "Get status for an execution.")]
+    #[tool(
+        description = "Get status for an execution.",
+        output_schema = output_schema::<GetExecutionResponse>()
+    )]
     async fn get_execution(
         &self,
         Parameters(GetExecutionRequest { execution_id }): Parameters<GetExecutionRequest>,
@@ -345,7 +358,7 @@ impl McpServer {
             Err(error_result) => return Ok(Self::tool_error(error_result)),
         };
 
-        Self::success(&GetExecutionResponse {
+        self.success(&GetExecutionResponse {
             execution_id: execution_process.id.to_string(),
             session_id: execution_process.session_id.to_string(),
             status: Self::execution_process_status_label(&execution_process.status).to_string(),
@@ -383,6 +396,32 @@ impl McpServer {
         }
     }
 
+    /// The workspace's most recently updated session other than the orchestrator's own, or a
+    /// new one when there is none, for running scripts that need a session to belong to.
+    pub(super) async fn latest_or_new_session(
+        &self,
+        workspace_id: Uuid,
+    ) -> Result<Session, super::ToolError> {
+        let url = self.url(&format!("/api/sessions?workspace_id={workspace_id}"));
+        let sessions: Vec<Session> = self.send_json(self.client.get(&url)).await?;
+        let orchestrator_session_id = self.orchestrator_session_id();
+        if let Some(session) = sessions
+            .into_iter()
+            .filter(|session| Some(session.id) != orchestrator_session_id)
+            .max_by_key(|session| session.updated_at)
+        {
+            return Ok(session);
+        }
+
+        let payload = CreateSessionPayload {
+            workspace_id,
+            executor: None,
+            name: None,
+        };
+        self.send_json(self.client.post(self.url("/api/sessions")).json(&payload))
+            .await
+    }
+
     fn serialize_execution_process(
         execution_process: &ExecutionProcess,
     ) -> Result<serde_json::Value, super::ToolError> {
@@ -1,8 +1,19 @@
-use db::models::requests::{
-    CreateAndStartWorkspaceRequest, CreateAndStartWorkspaceResponse, LinkedIssueInfo,
-    WorkspaceRepoInput,
+use std::time::{Duration, Instant};
+
+use db::models::{
+    execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+    repo::Repo,
+    requests::{
+        CreateAndStartWorkspaceRequest, CreateAndStartWorkspaceResponse, CreateFollowUpAttempt,
+        ExecutionProcessLogTail, ExecutionProcessLogTailQuery, LinkedIssueInfo, LogStream,
+        RunRepoScriptQuery, WorkspaceRepoInput,
+    },
+    session::Session,
+    workspace::Workspace,
+    workspace_repo::RepoWithTargetBranch,
 };
-use executors::profile::ExecutorConfig;
+use executors::{executors::BaseCodingAgent, profile::ExecutorConfig};
+use reqwest::StatusCode;
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
@@ -10,14 +21,37 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{McpServer, ToolError, describe_error, output_schema};
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpWorkspaceRepoInput {
-    #[schemars(description = "The repository ID")]
-    repo_id: Uuid,
-    #[schemars(description = "The branch for this repository")]
-    branch: String,
+    #[schemars(description = "The repository ID. Either `repo_id` or `repo_name` is required.")]
+    repo_id: Option<Uuid>,
+    #[schemars(description = "The repository name, matched case-insensitively")]
+    repo_name: Option<String>,
+    #[schemars(
+        description = "The base branch for this repository. Defaults to the repository's default branch."
+    )]
+    branch: Option<String>,
+}
+
+const PROMPT_PREVIEW_CHARS: usize = 500;
+const DEFAULT_START_WAIT_SECS: u64 = 120;
+const MAX_START_WAIT_SECS: u64 = 600;
+const START_POLL_INITIAL_DELAY: Duration = Duration::from_millis(500);
+const START_POLL_MAX_DELAY: Duration = Duration::from_secs(5);
+const SETUP_OUTPUT_TAIL_LINES: usize = 40;
+const DEFAULT_PROCESS_LOG_LINES: usize = 200;
+const MAX_PROCESS_LOG_LINES: usize = 1000;
+// Keeps a single log response well within what MCP clients handle comfortably.
+const MAX_PROCESS_LOG_BYTES: usize = 64 * 1024;
+
+// Subset of the branch info returned by `/api/repos/{id}/branches`.
+#[derive(Debug, Deserialize)]
+struct RepoBranch {
+    name: String,
+    is_current: bool,
+    is_remote: bool,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -29,22 +63,124 @@ struct StartWorkspaceRequest {
     )]
     prompt: Option<String>,
     #[schemars(
-        description = "The coding agent executor to run ('CLAUDE_CODE', 'AMP', 'GEMINI', 'CODEX', 'OPENCODE', 'CURSOR_AGENT', 'QWEN_CODE', 'COPILOT', 'DROID')"
+        description = "The coding agent executor to run ('CLAUDE_CODE', 'AMP', 'GEMINI', 'CODEX', 'OPENCODE', 'CURSOR_AGENT', 'QWEN_CODE', 'COPILOT', 'DROID'). Use 'list_executors' to see which are available."
     )]
     executor: String,
     #[schemars(description = "Optional executor variant, if needed")]
     variant: Option<String>,
-    #[schemars(description = "Repository selection for the workspace")]
-    repositories: Vec<McpWorkspaceRepoInput>,
+    #[schemars(
+        description = "Model ID to run instead of the variant's default, e.g. a cheaper model for a sub-task"
+    )]
+    model_id: Option<String>,
+    #[schemars(
+        description = "Reasoning effort to use instead of the variant's default, e.g. 'low' or 'high'"
+    )]
+    reasoning: Option<String>,
+    #[schemars(
+        description = "Permission policy to run the agent under instead of the variant's default. Allowed values: 'AUTO', 'SUPERVISED', 'PLAN'."
+    )]
+    permission_policy: Option<String>,
+    #[schemars(
+        description = "Repository selection for the workspace. Defaults to the current workspace's repositories and target branches when running inside a workspace."
+    )]
+    repositories: Option<Vec<McpWorkspaceRepoInput>>,
     #[schemars(
         description = "Optional issue ID to link the workspace to. When provided, the workspace will be associated with this remote issue."
     )]
     issue_id: Option<Uuid>,
+    #[schemars(
+        description = "Validate the request without creating anything. Returns every problem found and what would be started."
+    )]
+    dry_run: Option<bool>,
+    #[schemars(
+        description = "Wait for the setup script to finish before returning (default: false). The outcome is reported in `start_state`."
+    )]
+    wait_for_start: Option<bool>,
+    #[schemars(
+        description = "How long to wait when `wait_for_start` is set, in seconds (default: 120, max: 600)"
+    )]
+    wait_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct StartWorkspaceResponse {
     workspace_id: String,
+    #[schemars(description = "ID of the initial execution process, for polling its status")]
+    execution_process_id: String,
+    #[schemars(description = "Repositories and base branches the workspace was created with")]
+    repositories: Vec<StartedWorkspaceRepo>,
+    #[schemars(
+        description = "True when `repositories` was omitted and the current workspace's repositories and target branches were used"
+    )]
+    repositories_from_context: bool,
+    #[schemars(description = "Setup outcome, present when `wait_for_start` was set")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_state: Option<WorkspaceStartState>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct WorkspaceStartState {
+    #[schemars(
+        description = "'setup_completed', 'setup_failed', 'no_setup' (the coding agent started directly), 'timed_out', or 'unknown' if the status could not be read"
+    )]
+    status: String,
+    #[schemars(description = "Status of the initial execution process when waiting stopped")]
+    process_status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i64>,
+    #[schemars(description = "Last lines of the setup output, when setup failed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_tail: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    waited_ms: u64,
+}
+
+impl WorkspaceStartState {
+    fn new(status: &str, process: &ExecutionProcess, started: Instant) -> Self {
+        Self {
+            status: status.to_string(),
+            process_status: McpServer::execution_process_status_label(&process.status).to_string(),
+            exit_code: process.exit_code,
+            output_tail: None,
+            error: None,
+            waited_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct StartedWorkspaceRepo {
+    repo_id: String,
+    branch: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct DryRunRepoCheck {
+    #[schemars(description = "The repository as requested, when it could not be resolved")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested: Option<String>,
+    repo_id: Option<String>,
+    repo_name: Option<String>,
+    #[schemars(description = "Base branch the workspace would be created from")]
+    branch: Option<String>,
+    branch_exists: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct StartWorkspaceDryRunResponse {
+    dry_run: bool,
+    #[schemars(description = "Whether start_workspace would go ahead with these parameters")]
+    would_create: bool,
+    name: String,
+    executor: Option<String>,
+    issue_id: Option<String>,
+    #[schemars(description = "Start of the prompt the first session would receive")]
+    prompt_preview: Option<String>,
+    repositories: Vec<DryRunRepoCheck>,
+    errors: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -53,6 +189,10 @@ struct LinkWorkspaceIssueRequest {
     workspace_id: Uuid,
     #[schemars(description = "The issue ID to link the workspace to")]
     issue_id: Uuid,
+    #[schemars(
+        description = "Fail instead of warning when the issue belongs to a different project than the one the workspace is linked to. Defaults to VK_MCP_STRICT_CONTEXT."
+    )]
+    strict_context: Option<bool>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -63,6 +203,316 @@ struct LinkWorkspaceIssueResponse {
     workspace_id: String,
     #[schemars(description = "The issue ID it was linked to")]
     issue_id: String,
+    #[schemars(
+        description = "Set when the workspace was previously linked to a different project. Relinking across projects is usually a mistake."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct FollowUpWorkspaceSessionRequest {
+    #[schemars(
+        description = "Workspace ID to continue. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(
+        description = "Follow-up prompt for the coding agent. Supports @tagname expansion."
+    )]
+    prompt: String,
+    #[schemars(
+        description = "Optional executor override. Defaults to the executor of the workspace's latest session."
+    )]
+    executor: Option<String>,
+    #[schemars(description = "Optional executor variant, if needed")]
+    variant: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct FollowUpWorkspaceSessionResponse {
+    workspace_id: String,
+    #[schemars(description = "Session the follow-up was run in")]
+    session_id: String,
+    #[schemars(description = "ID of the execution process started for the follow-up")]
+    execution_process_id: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct GetWorkspaceSessionStatusRequest {
+    #[schemars(
+        description = "Workspace ID to inspect. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct GetWorkspaceSessionStatusResponse {
+    workspace_id: String,
+    #[schemars(
+        description = "State of the latest coding agent run: 'running', 'completed', 'failed', 'killed', or 'not_started'"
+    )]
+    status: String,
+    is_finished: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution_process_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finished_at: Option<String>,
+    #[schemars(description = "Executor used for the run, if known")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    executor: Option<String>,
+}
+
+impl GetWorkspaceSessionStatusResponse {
+    fn not_started(workspace_id: Uuid) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            status: "not_started".to_string(),
+            is_finished: false,
+            execution_process_id: None,
+            session_id: None,
+            exit_code: None,
+            started_at: None,
+            finished_at: None,
+            executor: None,
+        }
+    }
+
+    fn from_process(workspace_id: Uuid, process: &ExecutionProcess) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            status: McpServer::execution_process_status_label(&process.status).to_string(),
+            is_finished: process.status != ExecutionProcessStatus::Running,
+            execution_process_id: Some(process.id.to_string()),
+            session_id: Some(process.session_id.to_string()),
+            exit_code: process.exit_code,
+            started_at: Some(process.started_at.to_rfc3339()),
+            finished_at: process.completed_at.map(|at| at.to_rfc3339()),
+            executor: process
+                .executor_action()
+                .ok()
+                .and_then(|action| action.base_executor())
+                .map(|executor| executor.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct ListExecutionProcessesRequest {
+    #[schemars(
+        description = "Workspace ID to inspect. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(description = "Only return processes that are still running. Default: false.")]
+    running_only: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ExecutionProcessSummary {
+    execution_process_id: String,
+    session_id: String,
+    #[schemars(
+        description = "Why the process ran: 'setup_script', 'cleanup_script', 'archive_script', 'coding_agent', or 'dev_server'"
+    )]
+    run_reason: String,
+    #[schemars(description = "'running', 'completed', 'failed', or 'killed'")]
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i64>,
+    started_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finished_at: Option<String>,
+}
+
+impl ExecutionProcessSummary {
+    fn from_process(process: &ExecutionProcess) -> Self {
+        Self {
+            execution_process_id: process.id.to_string(),
+            session_id: process.session_id.to_string(),
+            run_reason: run_reason_label(&process.run_reason).to_string(),
+            status: McpServer::execution_process_status_label(&process.status).to_string(),
+            exit_code: process.exit_code,
+            started_at: process.started_at.to_rfc3339(),
+            finished_at: process.completed_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ListExecutionProcessesResponse {
+    workspace_id: String,
+    #[schemars(description = "Execution processes across the workspace's sessions, oldest first")]
+    execution_processes: Vec<ExecutionProcessSummary>,
+    count: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct GetProcessLogsRequest {
+    #[schemars(
+        description = "Execution process to read. Use `list_execution_processes` to find IDs."
+    )]
+    execution_process_id: Uuid,
+    #[schemars(description = "Number of lines to return from the end (default: 200, max: 1000)")]
+    tail_lines: Option<usize>,
+    #[schemars(description = "'stdout', 'stderr', or 'combined' (default: 'combined')")]
+    stream: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct GetProcessLogsResponse {
+    execution_process_id: String,
+    run_reason: String,
+    #[schemars(description = "'running', 'completed', 'failed', or 'killed'")]
+    status: String,
+    stream: String,
+    #[schemars(description = "The last lines of output, oldest first")]
+    lines: Vec<String>,
+    #[schemars(
+        description = "True when earlier output was left out, by `tail_lines` or the 64KB size cap"
+    )]
+    truncated: bool,
+    #[schemars(description = "Size in bytes of the stream's whole output")]
+    total_bytes: usize,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct StopWorkspaceSessionRequest {
+    #[schemars(
+        description = "Workspace ID whose execution to stop. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(
+        description = "Stop only this execution process. By default every running process except dev servers is stopped."
+    )]
+    execution_process_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct StoppedExecutionProcess {
+    execution_process_id: String,
+    run_reason: String,
+    #[schemars(description = "Status before the stop was requested")]
+    previous_status: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct StopWorkspaceSessionResponse {
+    workspace_id: String,
+    #[schemars(description = "Processes that were signalled. Empty if nothing was running.")]
+    stopped: Vec<StoppedExecutionProcess>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct RunRepoScriptRequest {
+    #[schemars(
+        description = "Workspace to run the script in. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(description = "Repository of the workspace whose script to run")]
+    repo_id: Uuid,
+}
+
+// Mirrors the server's `RunScriptError`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RunScriptFailure {
+    NoScriptConfigured,
+    ProcessAlreadyRunning,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct RunRepoScriptResponse {
+    workspace_id: String,
+    repo_id: String,
+    execution_process: ExecutionProcessSummary,
+    #[schemars(
+        description = "True when the repository's dev server was already running and that process is returned instead of a new one"
+    )]
+    already_running: bool,
+}
+
+fn run_reason_label(run_reason: &ExecutionProcessRunReason) -> &'static str {
+    match run_reason {
+        ExecutionProcessRunReason::SetupScript => "setup_script",
+        ExecutionProcessRunReason::CleanupScript => "cleanup_script",
+        ExecutionProcessRunReason::ArchiveScript => "archive_script",
+        ExecutionProcessRunReason::CodingAgent => "coding_agent",
+        ExecutionProcessRunReason::DevServer => "dev_server",
+    }
+}
+
+const LOG_STREAMS: &[(&str, LogStream)] = &[
+    ("stdout", LogStream::Stdout),
+    ("stderr", LogStream::Stderr),
+    ("combined", LogStream::Combined),
+];
+
+fn parse_log_stream(stream: Option<&str>) -> Result<LogStream, ToolError> {
+    let stream = stream.unwrap_or("combined").trim().to_ascii_lowercase();
+    LOG_STREAMS
+        .iter()
+        .find(|(name, _)| *name == stream)
+        .map(|(_, stream)| *stream)
+        .ok_or_else(|| {
+            let allowed = LOG_STREAMS
+                .iter()
+                .map(|(name, _)| format!("'{name}'"))
+                .collect::<Vec<_>>();
+            ToolError::message(format!(
+                "Unknown stream '{}'. Allowed values: [{}]",
+                stream,
+                allowed.join(", ")
+            ))
+        })
+}
+
+fn log_stream_label(stream: LogStream) -> &'static str {
+    LOG_STREAMS
+        .iter()
+        .find(|(_, candidate)| *candidate == stream)
+        .map(|(name, _)| *name)
+        .unwrap_or("combined")
+}
+
+fn missing_script_error(repo: &Repo, script: &str, update_tool: &str) -> ToolError {
+    ToolError {
+        hint: Some(format!("Set one with `{update_tool}`.")),
+        ..ToolError::message(format!("Repository '{}' has no {script} script", repo.name))
+    }
+}
+
+// Blank overrides count as omitted, so the variant's own defaults apply.
+fn start_executor_config(
+    executor: BaseCodingAgent,
+    variant: Option<String>,
+    model_id: Option<String>,
+    reasoning: Option<String>,
+    permission_policy: Option<&str>,
+) -> Result<ExecutorConfig, ToolError> {
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let permission_policy = permission_policy
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(McpServer::parse_permission_policy)
+        .transpose()?;
+
+    Ok(ExecutorConfig {
+        executor,
+        variant: non_empty(variant),
+        model_id: non_empty(model_id),
+        agent_id: None,
+        reasoning_id: non_empty(reasoning),
+        permission_policy,
+    })
 }
 
 fn build_workspace_prompt_from_issue(issue: &api_types::Issue) -> Option<String> {
@@ -89,105 +539,560 @@ fn build_workspace_prompt_from_issue(issue: &api_types::Issue) -> Option<String>
     Some(format!("{title}\n\n{description}"))
 }
 
-#[tool_router(router = task_attempts_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "Create a new workspace and start its first session.")]
-    async fn start_workspace(
+    // Fills in repositories and base branches the caller left out, so agents don't have to
+    // guess branch names.
+    async fn resolve_workspace_repos(
         &self,
-        Parameters(StartWorkspaceRequest {
-            name,
-            prompt,
-            executor,
-            variant,
-            repositories,
-            issue_id,
-        }): Parameters<StartWorkspaceRequest>,
-    ) -> Result<CallToolResult, ErrorData> {
+        repositories: Option<Vec<McpWorkspaceRepoInput>>,
+    ) -> Result<Vec<WorkspaceRepoInput>, ToolError> {
+        let repositories = repositories.unwrap_or_default();
         if repositories.is_empty() {
-            return Self::err("At least one repository must be specified.", None::<&str>);
+            let context_repos = self
+                .current_context()
+                .await
+                .map(|ctx| ctx.workspace_repos)
+                .unwrap_or_default();
+            if context_repos.is_empty() {
+                return Err(ToolError::new(
+                    "At least one repository must be specified.",
+                    Some("Run `list_repos` to find repository IDs or names."),
+                ));
+            }
+            tracing::info!(
+                repos = ?context_repos
+                    .iter()
+                    .map(|repo| format!("{}@{}", repo.repo_name, repo.target_branch))
+                    .collect::<Vec<_>>(),
+                "Defaulting workspace repositories to the current workspace context"
+            );
+            return Ok(context_repos
+                .into_iter()
+                .map(|repo| WorkspaceRepoInput {
+                    repo_id: repo.repo_id,
+                    target_branch: repo.target_branch,
+                })
+                .collect());
         }
 
-        let executor_trimmed = executor.trim();
-        if executor_trimmed.is_empty() {
-            return Self::err("Executor must not be empty.", None::<&str>);
+        let mut known_repos = None;
+        let mut workspace_repos = Vec::with_capacity(repositories.len());
+        for input in repositories {
+            workspace_repos.push(self.resolve_workspace_repo(input, &mut known_repos).await?);
         }
+        Ok(workspace_repos)
+    }
 
-        let prompt = prompt.and_then(|prompt| {
-            let trimmed = prompt.trim();
-            if trimmed.is_empty() {
-                None
-            } else {
-                Some(trimmed.to_string())
-            }
-        });
+    // Resolves one repository selection. `/api/repos` is fetched at most once and only when
+    // a name or default branch has to be looked up.
+    async fn resolve_workspace_repo(
+        &self,
+        input: McpWorkspaceRepoInput,
+        known_repos: &mut Option<Vec<Repo>>,
+    ) -> Result<WorkspaceRepoInput, ToolError> {
+        let branch = input
+            .branch
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty());
+        if let (Some(repo_id), Some(target_branch)) = (input.repo_id, branch.clone()) {
+            return Ok(WorkspaceRepoInput {
+                repo_id,
+                target_branch,
+            });
+        }
 
-        let base_executor = match Self::parse_executor_agent(executor_trimmed) {
-            Ok(exec) => exec,
-            Err(_) => {
-                return Self::err(
-                    format!("Unknown executor '{executor_trimmed}'."),
-                    None::<String>,
-                );
-            }
+        if known_repos.is_none() {
+            let url = self.url("/api/repos");
+            *known_repos = Some(self.send_json(self.client.get(&url)).await?);
+        }
+        let repos = known_repos.as_deref().unwrap_or_default();
+        let repo = Self::find_repo(repos, input.repo_id, input.repo_name.as_deref())?;
+        let target_branch = match branch {
+            Some(branch) => branch,
+            None => self.default_branch(repo).await?,
         };
+        Ok(WorkspaceRepoInput {
+            repo_id: repo.id,
+            target_branch,
+        })
+    }
 
-        let variant = variant.and_then(|v| {
-            let trimmed = v.trim();
-            if trimmed.is_empty() {
-                None
-            } else {
-                Some(trimmed.to_string())
-            }
+    fn find_repo<'a>(
+        repos: &'a [Repo],
+        repo_id: Option<Uuid>,
+        repo_name: Option<&str>,
+    ) -> Result<&'a Repo, ToolError> {
+        if let Some(repo_id) = repo_id {
+            return repos
+                .iter()
+                .find(|repo| repo.id == repo_id)
+                .ok_or_else(|| ToolError::message(format!("Repository {repo_id} not found.")));
+        }
+
+        let Some(name) = repo_name.map(str::trim).filter(|name| !name.is_empty()) else {
+            return Err(ToolError::message(
+                "Each repository needs a `repo_id` or `repo_name`.",
+            ));
+        };
+        let mut matches = repos.iter().filter(|repo| {
+            repo.name.eq_ignore_ascii_case(name) || repo.display_name.eq_ignore_ascii_case(name)
         });
+        match (matches.next(), matches.next()) {
+            (Some(repo), None) => Ok(repo),
+            (Some(_), Some(_)) => Err(ToolError::new(
+                format!("Repository name '{name}' is ambiguous."),
+                Some("Pass `repo_id` instead; run `list_repos` to find it."),
+            )),
+            (None, _) => {
+                let available = repos
+                    .iter()
+                    .map(|repo| repo.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(ToolError::new(
+                    format!("No repository named '{name}'."),
+                    Some(format!("Available repositories: {available}")),
+                ))
+            }
+        }
+    }
+
+    // The repository's configured default target branch, falling back to its checked-out branch.
+    async fn default_branch(&self, repo: &Repo) -> Result<String, ToolError> {
+        if let Some(branch) = repo
+            .default_target_branch
+            .as_deref()
+            .map(str::trim)
+            .filter(|branch| !branch.is_empty())
+        {
+            return Ok(branch.to_string());
+        }
 
-        let workspace_repos: Vec<WorkspaceRepoInput> = repositories
+        let url = self.url(&format!("/api/repos/{}/branches", repo.id));
+        let branches: Vec<RepoBranch> = self.send_json(self.client.get(&url)).await?;
+        branches
             .into_iter()
-            .map(|r| WorkspaceRepoInput {
-                repo_id: r.repo_id,
-                target_branch: r.branch,
+            .find(|branch| branch.is_current && !branch.is_remote)
+            .map(|branch| branch.name)
+            .ok_or_else(|| {
+                ToolError::message(format!(
+                    "Could not determine the default branch for '{}'. Pass `branch` explicitly.",
+                    repo.name
+                ))
             })
-            .collect();
+    }
+}
 
-        let (linked_issue, issue_prompt) = if let Some(issue_id) = issue_id {
-            let issue_url = self.url(&format!("/api/remote/issues/{issue_id}"));
-            let issue: api_types::Issue = match self.send_json(self.client.get(&issue_url)).await {
-                Ok(issue) => issue,
-                Err(e) => return Ok(Self::tool_error(e)),
-            };
+impl McpServer {
+    fn session_running_error(workspace_id: Uuid, process_id: Option<Uuid>) -> ToolError {
+        ToolError {
+            status: Some(StatusCode::CONFLICT.as_u16()),
+            hint: Some(
+                "Poll `get_workspace_session_status` until `is_finished` is true, then send the follow-up again, or cancel the run with `stop_workspace_session`."
+                    .to_string(),
+            ),
+            ..ToolError::new(
+                format!("Workspace {workspace_id} already has a coding agent running"),
+                process_id.map(|id| format!("Execution process {id} is still running")),
+            )
+        }
+    }
 
-            (
-                Some(LinkedIssueInfo {
-                    remote_project_id: issue.project_id,
-                    issue_id,
-                }),
-                build_workspace_prompt_from_issue(&issue),
+    async fn fetch_workspace_repo(
+        &self,
+        workspace_id: Uuid,
+        repo_id: Uuid,
+    ) -> Result<Repo, ToolError> {
+        let url = self.url(&format!("/api/workspaces/{workspace_id}/repos"));
+        let repos: Vec<RepoWithTargetBranch> = self.send_json(self.client.get(&url)).await?;
+        if let Some(repo) = repos.iter().find(|repo| repo.repo.id == repo_id) {
+            return Ok(repo.repo.clone());
+        }
+        let available = repos
+            .iter()
+            .map(|repo| format!("{} ({})", repo.repo.name, repo.repo.id))
+            .collect::<Vec<_>>();
+        Err(ToolError {
+            status: Some(StatusCode::NOT_FOUND.as_u16()),
+            ..ToolError::new(
+                format!("Repository {repo_id} is not part of workspace {workspace_id}"),
+                Some(format!("Workspace repositories: {}", available.join(", "))),
             )
-        } else {
-            (None, None)
-        };
+        })
+    }
 
-        let workspace_prompt = match prompt.or(issue_prompt) {
-            Some(prompt) => prompt,
-            None => {
-                return Self::err(
-                    "Provide `prompt`, or `issue_id` that has a non-empty title/description.",
-                    None::<&str>,
-                );
+    async fn fetch_workspace_execution_processes(
+        &self,
+        workspace_id: Uuid,
+    ) -> Result<Vec<ExecutionProcess>, ToolError> {
+        let url = self.url(&format!(
+            "/api/workspaces/{workspace_id}/execution/processes"
+        ));
+        self.send_json(self.client.get(&url)).await
+    }
+
+    // Polls the workspace's first process with exponential backoff until its setup script
+    // finishes. The workspace already exists, so failures are reported instead of returned.
+    async fn wait_for_workspace_start(
+        &self,
+        process: &ExecutionProcess,
+        timeout: Duration,
+    ) -> WorkspaceStartState {
+        let started = Instant::now();
+        if process.run_reason != ExecutionProcessRunReason::SetupScript {
+            return WorkspaceStartState::new("no_setup", process, started);
+        }
+
+        let url = self.url(&format!("/api/execution-processes/{}", process.id));
+        let mut delay = START_POLL_INITIAL_DELAY;
+        loop {
+            let current: ExecutionProcess = match self.send_json(self.client.get(&url)).await {
+                Ok(current) => current,
+                Err(e) => {
+                    return WorkspaceStartState {
+                        error: Some(e.to_string()),
+                        ..WorkspaceStartState::new("unknown", process, started)
+                    };
+                }
+            };
+            match current.status {
+                ExecutionProcessStatus::Running => {}
+                ExecutionProcessStatus::Completed => {
+                    return WorkspaceStartState::new("setup_completed", &current, started);
+                }
+                ExecutionProcessStatus::Failed | ExecutionProcessStatus::Killed => {
+                    return WorkspaceStartState {
+                        output_tail: self.fetch_log_tail(current.id).await,
+                        ..WorkspaceStartState::new("setup_failed", &current, started)
+                    };
+                }
             }
+
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                return WorkspaceStartState::new("timed_out", &current, started);
+            }
+            tokio::time::sleep(delay.min(remaining)).await;
+            delay = (delay * 2).min(START_POLL_MAX_DELAY);
+        }
+    }
+
+    // Best-effort: a missing log shouldn't hide the setup failure itself.
+    async fn fetch_log_tail(&self, execution_process_id: Uuid) -> Option<Vec<String>> {
+        let url = self.url(&format!(
+            "/api/execution-processes/{execution_process_id}/raw-logs/tail"
+        ));
+        let query = ExecutionProcessLogTailQuery {
+            lines: Some(SETUP_OUTPUT_TAIL_LINES),
+            ..Default::default()
         };
+        self.send_json::<ExecutionProcessLogTail>(self.client.get(&url).query(&query))
+            .await
+            .ok()
+            .map(|tail| tail.lines)
+    }
 
-        let create_and_start_payload = CreateAndStartWorkspaceRequest {
+    // Runs every start_workspace check that has no side effects and reports all failures.
+    async fn dry_run_start_workspace(
+        &self,
+        name: String,
+        prompt: Option<String>,
+        executor: &str,
+        permission_policy: Option<&str>,
+        repositories: Option<Vec<McpWorkspaceRepoInput>>,
+        issue_id: Option<Uuid>,
+    ) -> StartWorkspaceDryRunResponse {
+        let mut errors = Vec::new();
+
+        let executor = if executor.is_empty() {
+            errors.push("Executor must not be empty.".to_string());
+            None
+        } else {
+            match Self::parse_executor_agent(executor) {
+                Ok(agent) => Some(agent.to_string()),
+                Err(e) => {
+                    errors.push(describe_error(&e));
+                    None
+                }
+            }
+        };
+        if let Some(Err(e)) = permission_policy
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(Self::parse_permission_policy)
+        {
+            errors.push(describe_error(&e));
+        }
+
+        let mut repo_checks = Vec::new();
+        let inputs = repositories.unwrap_or_default();
+        let resolved = if inputs.is_empty() {
+            match self.resolve_workspace_repos(None).await {
+                Ok(repos) => repos.into_iter().map(|repo| (None, Ok(repo))).collect(),
+                Err(e) => {
+                    errors.push(describe_error(&e));
+                    Vec::new()
+                }
+            }
+        } else {
+            let mut known_repos = None;
+            let mut resolved = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                let requested = input
+                    .repo_name
+                    .clone()
+                    .or_else(|| input.repo_id.map(|id| id.to_string()));
+                let result = self.resolve_workspace_repo(input, &mut known_repos).await;
+                resolved.push((requested, result));
+            }
+            resolved
+        };
+
+        for (requested, result) in resolved {
+            let check = match result {
+                Ok(repo) => self.check_workspace_repo(repo).await,
+                Err(e) => DryRunRepoCheck {
+                    requested,
+                    repo_id: None,
+                    repo_name: None,
+                    branch: None,
+                    branch_exists: false,
+                    error: Some(describe_error(&e)),
+                },
+            };
+            if let Some(error) = &check.error {
+                errors.push(error.clone());
+            }
+            repo_checks.push(check);
+        }
+
+        let issue_prompt = match issue_id {
+            Some(issue_id) => match self.fetch_issue(issue_id).await {
+                Ok(issue) => build_workspace_prompt_from_issue(&issue),
+                Err(e) => {
+                    errors.push(format!("Issue {issue_id}: {}", describe_error(&e)));
+                    None
+                }
+            },
+            None => None,
+        };
+        let prompt = prompt.or(issue_prompt);
+        if prompt.is_none() {
+            errors.push(
+                "Provide `prompt`, or `issue_id` that has a non-empty title/description."
+                    .to_string(),
+            );
+        }
+
+        StartWorkspaceDryRunResponse {
+            dry_run: true,
+            would_create: errors.is_empty(),
+            name,
+            executor,
+            issue_id: issue_id.map(|id| id.to_string()),
+            prompt_preview: prompt.map(|prompt| prompt_preview(&prompt)),
+            repositories: repo_checks,
+            errors,
+        }
+    }
+
+    // Confirms the repository exists and has the target branch, locally or on a remote.
+    async fn check_workspace_repo(&self, repo: WorkspaceRepoInput) -> DryRunRepoCheck {
+        let mut check = DryRunRepoCheck {
+            requested: None,
+            repo_id: Some(repo.repo_id.to_string()),
+            repo_name: None,
+            branch: Some(repo.target_branch.clone()),
+            branch_exists: false,
+            error: None,
+        };
+
+        let url = self.url(&format!("/api/repos/{}", repo.repo_id));
+        let details: Repo = match self.send_json(self.client.get(&url)).await {
+            Ok(details) => details,
+            Err(e) => {
+                check.error = Some(format!(
+                    "Repository {}: {}",
+                    repo.repo_id,
+                    describe_error(&e)
+                ));
+                return check;
+            }
+        };
+        check.repo_name = Some(details.name.clone());
+
+        let url = self.url(&format!("/api/repos/{}/branches", repo.repo_id));
+        let branches: Vec<RepoBranch> = match self.send_json(self.client.get(&url)).await {
+            Ok(branches) => branches,
+            Err(e) => {
+                check.error = Some(format!(
+                    "Repository '{}': {}",
+                    details.name,
+                    describe_error(&e)
+                ));
+                return check;
+            }
+        };
+        check.branch_exists = branches.iter().any(|branch| {
+            branch.name == repo.target_branch
+                || (branch.is_remote
+                    && branch
+                        .name
+                        .split_once('/')
+                        .is_some_and(|(_, name)| name == repo.target_branch))
+        });
+        if !check.branch_exists {
+            check.error = Some(format!(
+                "Branch '{}' not found in repository '{}'. Use `list_repo_branches` to see its branches.",
+                repo.target_branch, details.name
+            ));
+        }
+        check
+    }
+}
+
+// The server names the branch when it rejects one, as a 400 or a 404 depending on where
+// the lookup failed.
+fn unknown_branch_hint(error: ToolError) -> ToolError {
+    let rejected = error.status == Some(StatusCode::BAD_REQUEST.as_u16())
+        || error.status == Some(StatusCode::NOT_FOUND.as_u16());
+    let unknown_branch = rejected
+        && error
+            .details
+            .as_deref()
+            .is_some_and(|details| details.starts_with("Branch '"));
+    if !unknown_branch {
+        return error;
+    }
+    ToolError {
+        hint: Some(
+            "Use `list_repo_branches` to see which branches each repository has.".to_string(),
+        ),
+        ..error
+    }
+}
+
+fn prompt_preview(prompt: &str) -> String {
+    if prompt.chars().count() > PROMPT_PREVIEW_CHARS {
+        let truncated: String = prompt.chars().take(PROMPT_PREVIEW_CHARS).collect();
+        format!("{truncated}…")
+    } else {
+        prompt.to_string()
+    }
+}
+
+#[tool_router(router = task_attempts_tools_router, vis = "pub")]
+impl McpServer {
+    #[tool(
+        description = "Create a new workspace and start its first session. Repositories can be given by ID or name; base branches default to each repository's default branch, and `repositories` defaults to the current workspace's repositories. Returns once the workspace is created; set `wait_for_start` to wait for its setup script to finish first."
+    )]
+    async fn start_workspace(
+        &self,
+        Parameters(StartWorkspaceRequest {
+            name,
+            prompt,
+            executor,
+            variant,
+            model_id,
+            reasoning,
+            permission_policy,
+            repositories,
+            issue_id,
+            dry_run,
+            wait_for_start,
+            wait_timeout_secs,
+        }): Parameters<StartWorkspaceRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let executor_trimmed = executor.trim();
+        let prompt = prompt.and_then(|prompt| {
+            let trimmed = prompt.trim();
+            if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed.to_string())
+            }
+        });
+
+        if dry_run.unwrap_or(false) {
+            let report = self
+                .dry_run_start_workspace(
+                    name,
+                    prompt,
+                    executor_trimmed,
+                    permission_policy.as_deref(),
+                    repositories,
+                    issue_id,
+                )
+                .await;
+            return self.success(&report);
+        }
+
+        if executor_trimmed.is_empty() {
+            return Self::err("Executor must not be empty.", None::<&str>);
+        }
+
+        let base_executor = match Self::parse_executor_agent(executor_trimmed) {
+            Ok(exec) => exec,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let executor_config = match start_executor_config(
+            base_executor,
+            variant,
+            model_id,
+            reasoning,
+            permission_policy.as_deref(),
+        ) {
+            Ok(config) => config,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let repositories_from_context = repositories.as_ref().is_none_or(Vec::is_empty);
+        let workspace_repos = match self.resolve_workspace_repos(repositories).await {
+            Ok(repos) => repos,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let started_repos = workspace_repos
+            .iter()
+            .map(|repo| StartedWorkspaceRepo {
+                repo_id: repo.repo_id.to_string(),
+                branch: repo.target_branch.clone(),
+            })
+            .collect();
+
+        let (linked_issue, issue_prompt) = if let Some(issue_id) = issue_id {
+            let issue_url = self.url(&format!("/api/remote/issues/{issue_id}"));
+            let issue: api_types::Issue = match self.send_json(self.client.get(&issue_url)).await {
+                Ok(issue) => issue,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+            (
+                Some(LinkedIssueInfo {
+                    remote_project_id: issue.project_id,
+                    issue_id,
+                }),
+                build_workspace_prompt_from_issue(&issue),
+            )
+        } else {
+            (None, None)
+        };
+
+        let workspace_prompt = match prompt.or(issue_prompt) {
+            Some(prompt) => prompt,
+            None => {
+                return Self::err(
+                    "Provide `prompt`, or `issue_id` that has a non-empty title/description.",
+                    None::<&str>,
+                );
+            }
+        };
+
+        let create_and_start_payload = CreateAndStartWorkspaceRequest {
             name: Some(name.clone()),
             repos: workspace_repos,
             linked_issue,
-            executor_config: ExecutorConfig {
-                executor: base_executor,
-                variant,
-                model_id: None,
-                agent_id: None,
-                reasoning_id: None,
-                permission_policy: None,
-            },
+            executor_config,
             prompt: workspace_prompt,
             attachment_ids: None,
         };
@@ -202,7 +1107,7 @@ impl McpServer {
             .await
         {
             Ok(response) => response,
-            Err(e) => return Ok(Self::tool_error(e)),
+            Err(e) => return Ok(Self::tool_error(unknown_branch_hint(e))),
         };
 
         // Link workspace to remote issue if issue_id is provided
@@ -214,31 +1119,1531 @@ impl McpServer {
             return Ok(Self::tool_error(e));
         }
 
+        let start_state = if wait_for_start.unwrap_or(false) {
+            let timeout = Duration::from_secs(
+                wait_timeout_secs
+                    .unwrap_or(DEFAULT_START_WAIT_SECS)
+                    .min(MAX_START_WAIT_SECS),
+            );
+            Some(
+                self.wait_for_workspace_start(
+                    &create_and_start_response.execution_process,
+                    timeout,
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+
         let response = StartWorkspaceResponse {
             workspace_id: create_and_start_response.workspace.id.to_string(),
+            execution_process_id: create_and_start_response.execution_process.id.to_string(),
+            repositories: started_repos,
+            repositories_from_context,
+            start_state,
+        };
+
+        self.success(&response)
+    }
+
+    #[tool(
+        description = "Send a follow-up prompt to an existing workspace, continuing its latest session. Fails while the workspace's coding agent is still running. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<FollowUpWorkspaceSessionResponse>()
+    )]
+    async fn follow_up_workspace_session(
+        &self,
+        Parameters(FollowUpWorkspaceSessionRequest {
+            workspace_id,
+            prompt,
+            executor,
+            variant,
+        }): Parameters<FollowUpWorkspaceSessionRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let prompt = prompt.trim();
+        if prompt.is_empty() {
+            return Self::err("prompt must not be empty", None);
+        }
+
+        let executor_override = match executor
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(Self::parse_executor_agent)
+            .transpose()
+        {
+            Ok(executor) => executor,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let variant = variant.and_then(|v| {
+            let trimmed = v.trim();
+            if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed.to_string())
+            }
+        });
+
+        let workspace_url = self.url(&format!("/api/workspaces/{workspace_id}"));
+        let workspace: Workspace = match self.send_json(self.client.get(&workspace_url)).await {
+            Ok(workspace) => workspace,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if workspace.archived {
+            return Self::err(
+                format!("Workspace {workspace_id} is archived"),
+                Some(
+                    "Unarchive it first with `update_workspace` (archived: false), then retry."
+                        .to_string(),
+                ),
+            );
+        }
+
+        let latest_url = self.url(&format!(
+            "/api/workspaces/{workspace_id}/execution/latest-coding-agent"
+        ));
+        match self
+            .send_optional_json::<ExecutionProcess>(self.client.get(&latest_url))
+            .await
+        {
+            Ok(Some(process)) if process.status == ExecutionProcessStatus::Running => {
+                return Ok(Self::tool_error(Self::session_running_error(
+                    workspace_id,
+                    Some(process.id),
+                )));
+            }
+            Ok(_) => {}
+            Err(e) => return Ok(Self::tool_error(e)),
+        }
+
+        let sessions_url = self.url(&format!("/api/sessions?workspace_id={workspace_id}"));
+        let sessions: Vec<Session> = match self.send_json(self.client.get(&sessions_url)).await {
+            Ok(sessions) => sessions,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let orchestrator_session_id = self.orchestrator_session_id();
+        let Some(session) = sessions
+            .into_iter()
+            .filter(|session| Some(session.id) != orchestrator_session_id)
+            .max_by_key(|session| session.updated_at)
+        else {
+            return Self::err(
+                format!("Workspace {workspace_id} has no session to follow up on"),
+                Some("Use `create_session` and `run_session_prompt` instead.".to_string()),
+            );
+        };
+
+        let executor = match executor_override {
+            Some(executor) => executor,
+            None => match Self::normalize_executor_name(session.executor.as_deref())
+                .and_then(|name| Self::parse_executor_agent(&name))
+            {
+                Ok(executor) => executor,
+                Err(e) => return Ok(Self::tool_error(e)),
+            },
+        };
+
+        let payload = CreateFollowUpAttempt {
+            prompt: self.expand_tags(prompt).await,
+            executor_config: ExecutorConfig {
+                executor,
+                variant,
+                model_id: None,
+                agent_id: None,
+                reasoning_id: None,
+                permission_policy: None,
+            },
+            retry_process_id: None,
+            force_when_dirty: None,
+            perform_git_reset: None,
+        };
+
+        let url = self.url(&format!("/api/sessions/{}/follow-up", session.id));
+        let execution_process: ExecutionProcess =
+            match self.send_json(self.client.post(&url).json(&payload)).await {
+                Ok(process) => process,
+                // A run started between the check above and this request.
+                Err(e) if e.status == Some(StatusCode::CONFLICT.as_u16()) => {
+                    return Ok(Self::tool_error(Self::session_running_error(
+                        workspace_id,
+                        None,
+                    )));
+                }
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&FollowUpWorkspaceSessionResponse {
+            workspace_id: workspace_id.to_string(),
+            session_id: session.id.to_string(),
+            execution_process_id: execution_process.id.to_string(),
+        })
+    }
+
+    #[tool(
+        description = "Get the state of a workspace's latest coding agent run (running, completed, failed, killed), with exit code and timestamps. Use it to poll whether a session has finished. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<GetWorkspaceSessionStatusResponse>()
+    )]
+    async fn get_workspace_session_status(
+        &self,
+        Parameters(GetWorkspaceSessionStatusRequest { workspace_id }): Parameters<
+            GetWorkspaceSessionStatusRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
         };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
 
-        McpServer::success(&response)
+        let url = self.url(&format!(
+            "/api/workspaces/{workspace_id}/execution/latest-coding-agent"
+        ));
+        let latest: Option<ExecutionProcess> =
+            match self.send_optional_json(self.client.get(&url)).await {
+                Ok(process) => process,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        let response = match latest {
+            Some(process) => {
+                GetWorkspaceSessionStatusResponse::from_process(workspace_id, &process)
+            }
+            None => GetWorkspaceSessionStatusResponse::not_started(workspace_id),
+        };
+        self.success(&response)
     }
 
     #[tool(
-        description = "Link an existing workspace to a remote issue. This associates the workspace with the issue for tracking."
+        description = "List a workspace's execution processes (setup scripts, coding agent runs, dev servers, ...) with their status. Use it to find the `execution_process_id` to pass to `get_process_logs` or `stop_workspace_session`. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<ListExecutionProcessesResponse>()
+    )]
+    async fn list_execution_processes(
+        &self,
+        Parameters(ListExecutionProcessesRequest {
+            workspace_id,
+            running_only,
+        }): Parameters<ListExecutionProcessesRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let processes = match self.fetch_workspace_execution_processes(workspace_id).await {
+            Ok(processes) => processes,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let execution_processes = processes
+            .iter()
+            .filter(|process| {
+                !running_only.unwrap_or(false) || process.status == ExecutionProcessStatus::Running
+            })
+            .map(ExecutionProcessSummary::from_process)
+            .collect::<Vec<_>>();
+
+        self.success(&ListExecutionProcessesResponse {
+            workspace_id: workspace_id.to_string(),
+            count: execution_processes.len(),
+            execution_processes,
+        })
+    }
+
+    #[tool(
+        description = "Read the output of an execution process, e.g. to see why a setup script or coding agent run failed. Returns the last `tail_lines` lines (default 200) of stdout, stderr, or both, capped at 64KB, with a `truncated` flag and the total output size. Find process IDs with `list_execution_processes`.",
+        output_schema = output_schema::<GetProcessLogsResponse>()
+    )]
+    async fn get_process_logs(
+        &self,
+        Parameters(GetProcessLogsRequest {
+            execution_process_id,
+            tail_lines,
+            stream,
+        }): Parameters<GetProcessLogsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let stream = match parse_log_stream(stream.as_deref()) {
+            Ok(stream) => stream,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let process_url = self.url(&format!("/api/execution-processes/{execution_process_id}"));
+        let process: ExecutionProcess = match self.send_json(self.client.get(&process_url)).await {
+            Ok(process) => process,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let url = self.url(&format!(
+            "/api/execution-processes/{execution_process_id}/raw-logs/tail"
+        ));
+        let query = ExecutionProcessLogTailQuery {
+            lines: Some(
+                tail_lines
+                    .unwrap_or(DEFAULT_PROCESS_LOG_LINES)
+                    .min(MAX_PROCESS_LOG_LINES),
+            ),
+            stream: Some(stream),
+            max_bytes: Some(MAX_PROCESS_LOG_BYTES),
+        };
+        let tail: ExecutionProcessLogTail =
+            match self.send_json(self.client.get(&url).query(&query)).await {
+                Ok(tail) => tail,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&GetProcessLogsResponse {
+            execution_process_id: process.id.to_string(),
+            run_reason: run_reason_label(&process.run_reason).to_string(),
+            status: Self::execution_process_status_label(&process.status).to_string(),
+            stream: log_stream_label(stream).to_string(),
+            lines: tail.lines,
+            truncated: tail.truncated,
+            total_bytes: tail.total_bytes,
+        })
+    }
+
+    #[tool(
+        description = "Stop a workspace's running execution, e.g. a runaway coding agent. Stops every running process except dev servers, or only `execution_process_id` when given. Succeeds with an empty `stopped` list when nothing is running, so it is safe to call defensively. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<StopWorkspaceSessionResponse>()
+    )]
+    async fn stop_workspace_session(
+        &self,
+        Parameters(StopWorkspaceSessionRequest {
+            workspace_id,
+            execution_process_id,
+        }): Parameters<StopWorkspaceSessionRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let processes = match self.fetch_workspace_execution_processes(workspace_id).await {
+            Ok(processes) => processes,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        // Mirrors the server's workspace stop, which leaves dev servers running.
+        let targets = match execution_process_id {
+            Some(process_id) => {
+                let Some(process) = processes.iter().find(|process| process.id == process_id)
+                else {
+                    return Ok(Self::tool_error(ToolError {
+                        status: Some(StatusCode::NOT_FOUND.as_u16()),
+                        hint: Some(
+                            "Call `list_execution_processes` to see this workspace's processes."
+                                .to_string(),
+                        ),
+                        ..ToolError::message(format!(
+                            "Execution process {process_id} does not belong to workspace {workspace_id}"
+                        ))
+                    }));
+                };
+                vec![process]
+            }
+            None => processes
+                .iter()
+                .filter(|process| process.run_reason != ExecutionProcessRunReason::DevServer)
+                .collect(),
+        };
+        let stopped = targets
+            .into_iter()
+            .filter(|process| process.status == ExecutionProcessStatus::Running)
+            .map(|process| StoppedExecutionProcess {
+                execution_process_id: process.id.to_string(),
+                run_reason: run_reason_label(&process.run_reason).to_string(),
+                previous_status: Self::execution_process_status_label(&process.status).to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        if !stopped.is_empty() {
+            let url = match execution_process_id {
+                Some(process_id) => {
+                    self.url(&format!("/api/execution-processes/{process_id}/stop"))
+                }
+                None => self.url(&format!("/api/workspaces/{workspace_id}/execution/stop")),
+            };
+            if let Err(e) = self.send_empty_json(self.client.post(&url)).await {
+                return Ok(Self::tool_error(e));
+            }
+        }
+
+        self.success(&StopWorkspaceSessionResponse {
+            workspace_id: workspace_id.to_string(),
+            stopped,
+        })
+    }
+
+    #[tool(
+        description = "Run a workspace repository's setup script, e.g. to check that a script change works. Returns the new execution process; follow it with `list_execution_processes` and read its output with `get_repo_script_runs`. Fails while a coding agent or other script is running in the workspace. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<RunRepoScriptResponse>()
+    )]
+    async fn run_setup_script(
+        &self,
+        Parameters(RunRepoScriptRequest {
+            workspace_id,
+            repo_id,
+        }): Parameters<RunRepoScriptRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let repo = match self.fetch_workspace_repo(workspace_id, repo_id).await {
+            Ok(repo) => repo,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if repo
+            .setup_script
+            .as_deref()
+            .is_none_or(|script| script.trim().is_empty())
+        {
+            return Ok(Self::tool_error(missing_script_error(
+                &repo,
+                "setup",
+                "update_setup_script",
+            )));
+        }
+
+        let session = match self.latest_or_new_session(workspace_id).await {
+            Ok(session) => session,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let url = self.url(&format!("/api/sessions/{}/setup", session.id));
+        let query = RunRepoScriptQuery {
+            repo_id: Some(repo_id),
+        };
+        let process = match self
+            .send_json_or_error_data::<ExecutionProcess, RunScriptFailure>(
+                self.client.post(&url).query(&query),
+            )
+            .await
+        {
+            Ok(Ok(process)) => process,
+            Ok(Err(RunScriptFailure::NoScriptConfigured)) => {
+                return Ok(Self::tool_error(missing_script_error(
+                    &repo,
+                    "setup",
+                    "update_setup_script",
+                )));
+            }
+            Ok(Err(RunScriptFailure::ProcessAlreadyRunning)) => {
+                return Ok(Self::tool_error(ToolError {
+                    status: Some(StatusCode::CONFLICT.as_u16()),
+                    hint: Some(
+                        "Wait for the running process to finish, or stop it with `stop_workspace_session`, then try again."
+                            .to_string(),
+                    ),
+                    ..ToolError::message(format!(
+                        "Workspace {workspace_id} already has a process running"
+                    ))
+                }));
+            }
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&RunRepoScriptResponse {
+            workspace_id: workspace_id.to_string(),
+            repo_id: repo_id.to_string(),
+            execution_process: ExecutionProcessSummary::from_process(&process),
+            already_running: false,
+        })
+    }
+
+    #[tool(
+        description = "Start a workspace repository's dev server. If it is already running, the running process is returned with `already_running: true` instead of starting another. Returns the execution process; read its output with `get_repo_script_runs`. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<RunRepoScriptResponse>()
+    )]
+    async fn run_dev_server(
+        &self,
+        Parameters(RunRepoScriptRequest {
+            workspace_id,
+            repo_id,
+        }): Parameters<RunRepoScriptRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let repo = match self.fetch_workspace_repo(workspace_id, repo_id).await {
+            Ok(repo) => repo,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if repo
+            .dev_server_script
+            .as_deref()
+            .is_none_or(|script| script.trim().is_empty())
+        {
+            return Ok(Self::tool_error(missing_script_error(
+                &repo,
+                "dev server",
+                "update_dev_server_script",
+            )));
+        }
+
+        // The server restarts a running dev server, so look for one first.
+        let processes = match self.fetch_workspace_execution_processes(workspace_id).await {
+            Ok(processes) => processes,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if let Some(running) = processes.iter().rev().find(|process| {
+            process.run_reason == ExecutionProcessRunReason::DevServer
+                && process.status == ExecutionProcessStatus::Running
+                && process.script_working_dir() == Some(repo.name.as_str())
+        }) {
+            return self.success(&RunRepoScriptResponse {
+                workspace_id: workspace_id.to_string(),
+                repo_id: repo_id.to_string(),
+                execution_process: ExecutionProcessSummary::from_process(running),
+                already_running: true,
+            });
+        }
+
+        let url = self.url(&format!(
+            "/api/workspaces/{workspace_id}/execution/dev-server/start"
+        ));
+        let query = RunRepoScriptQuery {
+            repo_id: Some(repo_id),
+        };
+        let started: Vec<ExecutionProcess> =
+            match self.send_json(self.client.post(&url).query(&query)).await {
+                Ok(started) => started,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+        let Some(process) = started.first() else {
+            return Self::err("The server did not start a dev server process", None);
+        };
+
+        self.success(&RunRepoScriptResponse {
+            workspace_id: workspace_id.to_string(),
+            repo_id: repo_id.to_string(),
+            execution_process: ExecutionProcessSummary::from_process(process),
+            already_running: false,
+        })
+    }
+
+    #[tool(
+        description = "Link an existing workspace to a remote issue. This associates the workspace with the issue for tracking.",
+        output_schema = output_schema::<LinkWorkspaceIssueResponse>()
     )]
     async fn link_workspace_issue(
         &self,
         Parameters(LinkWorkspaceIssueRequest {
             workspace_id,
             issue_id,
+            strict_context,
         }): Parameters<LinkWorkspaceIssueRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        if let Err(e) = self.link_workspace_to_issue(workspace_id, issue_id).await {
+        let issue = match self.fetch_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let linked_project_id = self
+            .fetch_remote_workspace(workspace_id)
+            .await
+            .map(|workspace| workspace.project_id);
+        let warning =
+            match self.check_linked_project(issue.project_id, linked_project_id, strict_context) {
+                Ok(warning) => warning,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+        if let Err(e) = self.link_workspace(workspace_id, &issue).await {
             return Ok(Self::tool_error(e));
         }
 
-        McpServer::success(&LinkWorkspaceIssueResponse {
+        self.success(&LinkWorkspaceIssueResponse {
             success: true,
             workspace_id: workspace_id.to_string(),
             issue_id: issue_id.to_string(),
+            warning,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        extract::RawQuery,
+        routing::{get, post},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::{
+        McpContext, McpRepoContext, RemoteLinkStatus,
+        context_cache::ContextCache,
+        tools::test_support::{MockBackend, envelope, execution_process_json},
+    };
+
+    #[tokio::test]
+    async fn follow_up_rejects_archived_workspace() {
+        let workspace_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/{id}",
+            get(move || async move {
+                envelope(json!({
+                    "id": workspace_id,
+                    "task_id": null,
+                    "container_ref": null,
+                    "branch": "vk/archived",
+                    "setup_completed_at": null,
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "updated_at": "2026-01-01T00:00:00Z",
+                    "archived": true,
+                    "pinned": false,
+                    "name": null,
+                    "worktree_deleted": false,
+                }))
+            }),
+        ))
+        .await;
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "prompt": "Keep going",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .follow_up_workspace_session(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert!(body["error"].as_str().unwrap().contains("archived"));
+        assert!(body["details"].as_str().unwrap().contains("Unarchive"));
+        assert_eq!(backend.requests_to("/api/sessions"), 0);
+    }
+
+    #[tokio::test]
+    async fn follow_up_is_sent_when_no_coding_agent_has_run() {
+        let workspace_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let process = execution_process_json("codingagent", "running");
+        let process_id = process["id"].clone();
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}",
+                get(move || async move {
+                    envelope(json!({
+                        "id": workspace_id,
+                        "task_id": null,
+                        "container_ref": null,
+                        "branch": "vk/fresh",
+                        "setup_completed_at": null,
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                        "archived": false,
+                        "pinned": false,
+                        "name": null,
+                        "worktree_deleted": false,
+                    }))
+                }),
+            )
+            .route(
+                "/api/workspaces/{id}/execution/latest-coding-agent",
+                get(|| async { envelope(json!(null)) }),
+            )
+            .route(
+                "/api/sessions",
+                get(move || async move {
+                    envelope(json!([{
+                        "id": session_id,
+                        "workspace_id": workspace_id,
+                        "name": null,
+                        "executor": "CLAUDE_CODE",
+                        "agent_working_dir": null,
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                    }]))
+                }),
+            )
+            .route(
+                "/api/sessions/{id}/follow-up",
+                post(move || {
+                    let process = process.clone();
+                    async move { envelope(process) }
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "prompt": "Start with the tests",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .follow_up_workspace_session(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["session_id"], session_id.to_string());
+        assert_eq!(body["execution_process_id"], process_id);
+    }
+
+    #[tokio::test]
+    async fn follow_up_reports_a_running_session() {
+        let workspace_id = Uuid::new_v4();
+        let process_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}",
+                get(move || async move {
+                    envelope(json!({
+                        "id": workspace_id,
+                        "task_id": null,
+                        "container_ref": null,
+                        "branch": "vk/busy",
+                        "setup_completed_at": null,
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                        "archived": false,
+                        "pinned": false,
+                        "name": null,
+                        "worktree_deleted": false,
+                    }))
+                }),
+            )
+            .route(
+                "/api/workspaces/{id}/execution/latest-coding-agent",
+                get(move || async move {
+                    let mut process = execution_process_json("codingagent", "running");
+                    process["id"] = json!(process_id);
+                    envelope(process)
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "prompt": "Also update the docs",
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .follow_up_workspace_session(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .contains("already has a coding agent running")
+        );
+        assert!(
+            body["details"]
+                .as_str()
+                .unwrap()
+                .contains(&process_id.to_string())
+        );
+        assert_eq!(body["status"], 409);
+        assert_eq!(backend.requests_to("/api/sessions"), 0);
+    }
+
+    #[tokio::test]
+    async fn session_status_reports_latest_coding_agent_run() {
+        let workspace_id = Uuid::new_v4();
+        let process_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let mut process = execution_process_json("codingagent", "failed");
+        process["id"] = json!(process_id);
+        process["session_id"] = json!(session_id);
+        process["exit_code"] = json!(1);
+        process["completed_at"] = json!("2026-01-01T00:05:00Z");
+        process["updated_at"] = json!("2026-01-01T00:05:00Z");
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/{id}/execution/latest-coding-agent",
+            get(move || async move { envelope(process) }),
+        ))
+        .await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .get_workspace_session_status(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["status"], "failed");
+        assert_eq!(body["is_finished"], true);
+        assert_eq!(body["exit_code"], 1);
+        assert_eq!(body["execution_process_id"], process_id.to_string());
+        assert!(body["finished_at"].as_str().is_some());
+    }
+
+    #[tokio::test]
+    async fn session_status_is_not_started_without_a_coding_agent_run() {
+        let workspace_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/{id}/execution/latest-coding-agent",
+            get(|| async { envelope(json!(null)) }),
+        ))
+        .await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .get_workspace_session_status(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["status"], "not_started");
+        assert_eq!(body["is_finished"], false);
+    }
+
+    #[tokio::test]
+    async fn stop_workspace_session_signals_running_processes_except_dev_servers() {
+        let workspace_id = Uuid::new_v4();
+        let agent = execution_process_json("codingagent", "running");
+        let processes = json!([
+            execution_process_json("setupscript", "completed"),
+            agent.clone(),
+            execution_process_json("devserver", "running"),
+        ]);
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/workspaces/{id}/execution/processes",
+                    get(move || {
+                        let processes = processes.clone();
+                        async move { envelope(processes) }
+                    }),
+                )
+                .route(
+                    "/api/workspaces/{id}/execution/stop",
+                    post(|| async { envelope(json!(null)) }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = server
+            .stop_workspace_session(Parameters(request))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["stopped"],
+            json!([{
+                "execution_process_id": agent["id"],
+                "run_reason": "coding_agent",
+                "previous_status": "running",
+            }])
+        );
+        let stop_path = format!("/api/workspaces/{workspace_id}/execution/stop");
+        assert_eq!(backend.requests_to(&stop_path), 1);
+    }
+
+    #[tokio::test]
+    async fn get_process_logs_caps_the_size_and_selects_the_stream() {
+        let process = execution_process_json("setupscript", "failed");
+        let process_id = process["id"].as_str().unwrap().to_string();
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/execution-processes/{id}",
+                    get(move || async move { envelope(process) }),
+                )
+                .route(
+                    "/api/execution-processes/{id}/raw-logs/tail",
+                    get(|RawQuery(query): RawQuery| async move {
+                        assert_eq!(
+                            query.as_deref(),
+                            Some("lines=1000&stream=stderr&max_bytes=65536")
+                        );
+                        envelope(json!({
+                            "lines": ["error: linker failed"],
+                            "truncated": true,
+                            "total_bytes": 200000,
+                        }))
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "execution_process_id": process_id,
+            "tail_lines": 5000,
+            "stream": "STDERR",
+        }))
+        .unwrap();
+        let result = server.get_process_logs(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["run_reason"], "setup_script");
+        assert_eq!(body["status"], "failed");
+        assert_eq!(body["stream"], "stderr");
+        assert_eq!(body["lines"], json!(["error: linker failed"]));
+        assert_eq!(body["truncated"], true);
+        assert_eq!(body["total_bytes"], 200000);
+
+        let request = serde_json::from_value(json!({
+            "execution_process_id": process_id,
+            "stream": "both",
+        }))
+        .unwrap();
+        let result = server.get_process_logs(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["error"],
+            "Unknown stream 'both'. Allowed values: ['stdout', 'stderr', 'combined']"
+        );
+    }
+
+    #[tokio::test]
+    async fn stop_workspace_session_succeeds_when_nothing_is_running() {
+        let workspace_id = Uuid::new_v4();
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/workspaces/{id}/execution/processes",
+                    get(|| async {
+                        envelope(json!([execution_process_json("codingagent", "completed")]))
+                    }),
+                )
+                .route(
+                    "/api/workspaces/{id}/execution/stop",
+                    post(|| async { envelope(json!(null)) }),
+                ),
+        )
+        .await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .stop_workspace_session(Parameters(request))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["stopped"], json!([]));
+        let stop_path = format!("/api/workspaces/{workspace_id}/execution/stop");
+        assert_eq!(backend.requests_to(&stop_path), 0);
+    }
+
+    #[test]
+    fn start_executor_config_serializes_overrides() {
+        let config = start_executor_config(
+            BaseCodingAgent::ClaudeCode,
+            Some("PLAN".to_string()),
+            Some(" claude-haiku-4-5 ".to_string()),
+            Some("low".to_string()),
+            Some("supervised"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "executor": "CLAUDE_CODE",
+                "variant": "PLAN",
+                "model_id": "claude-haiku-4-5",
+                "reasoning_id": "low",
+                "permission_policy": "SUPERVISED",
+            })
+        );
+    }
+
+    #[test]
+    fn start_executor_config_omits_blank_overrides() {
+        let config = start_executor_config(
+            BaseCodingAgent::Codex,
+            None,
+            Some("  ".to_string()),
+            None,
+            Some(""),
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({ "executor": "CODEX" })
+        );
+    }
+
+    #[test]
+    fn unknown_permission_policy_lists_allowed_values() {
+        let error = start_executor_config(BaseCodingAgent::Codex, None, None, None, Some("yolo"))
+            .unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Unknown permission policy 'yolo'. Allowed values: ['AUTO', 'SUPERVISED', 'PLAN']"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_dev_server_returns_a_running_server_instead_of_restarting_it() {
+        let workspace_id = Uuid::new_v4();
+        let mut app = repo_json("app");
+        app["dev_server_script"] = json!("npm run dev");
+        app["target_branch"] = json!("main");
+        let mut docs = repo_json("docs");
+        docs["dev_server_script"] = json!("mkdocs serve");
+        docs["target_branch"] = json!("main");
+        let (app_id, docs_id) = (app["id"].clone(), docs["id"].clone());
+
+        let dev_server = |status: &str, working_dir: &str| {
+            let mut process = execution_process_json("devserver", status);
+            process["executor_action"] = json!({
+                "typ": {
+                    "type": "ScriptRequest",
+                    "script": "npm run dev",
+                    "language": "Bash",
+                    "context": "DevServer",
+                    "working_dir": working_dir,
+                },
+                "next_action": null,
+            });
+            process
+        };
+        let running_docs = dev_server("running", "docs");
+        let processes = json!([dev_server("killed", "app"), running_docs.clone()]);
+        let started_app = dev_server("running", "app");
+        let app_query = format!("repo_id={}", app_id.as_str().unwrap());
+
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/workspaces/{id}/repos",
+                    get(move || async move { envelope(json!([app, docs])) }),
+                )
+                .route(
+                    "/api/workspaces/{id}/execution/processes",
+                    get(move || async move { envelope(processes) }),
+                )
+                .route(
+                    "/api/workspaces/{id}/execution/dev-server/start",
+                    post(move |RawQuery(query): RawQuery| async move {
+                        assert_eq!(query, Some(app_query));
+                        envelope(json!([started_app]))
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+        let start_path = format!("/api/workspaces/{workspace_id}/execution/dev-server/start");
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": docs_id,
+        }))
+        .unwrap();
+        let result = server.run_dev_server(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["already_running"], true);
+        assert_eq!(
+            body["execution_process"]["execution_process_id"],
+            running_docs["id"]
+        );
+        assert_eq!(backend.requests_to(&start_path), 0);
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": app_id,
+        }))
+        .unwrap();
+        let result = server.run_dev_server(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["already_running"], false);
+        assert_eq!(body["execution_process"]["run_reason"], "dev_server");
+        assert_eq!(body["execution_process"]["status"], "running");
+        assert_eq!(backend.requests_to(&start_path), 1);
+    }
+
+    #[tokio::test]
+    async fn run_setup_script_runs_one_repo_and_reports_a_busy_workspace() {
+        let workspace_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let mut app = repo_json("app");
+        app["setup_script"] = json!("npm install");
+        app["target_branch"] = json!("main");
+        let mut docs = repo_json("docs");
+        docs["setup_script"] = json!("pip install -r requirements.txt");
+        docs["target_branch"] = json!("main");
+        let mut web = repo_json("web");
+        web["target_branch"] = json!("main");
+        let (app_id, docs_id, web_id) = (app["id"].clone(), docs["id"].clone(), web["id"].clone());
+        let app_query = format!("repo_id={}", app_id.as_str().unwrap());
+        let setup = execution_process_json("setupscript", "running");
+        let setup_id = setup["id"].clone();
+
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/workspaces/{id}/repos",
+                    get(move || async move { envelope(json!([app, docs, web])) }),
+                )
+                .route(
+                    "/api/sessions",
+                    get(move || async move {
+                        envelope(json!([{
+                            "id": session_id,
+                            "workspace_id": workspace_id,
+                            "name": null,
+                            "executor": "CLAUDE_CODE",
+                            "agent_working_dir": null,
+                            "created_at": "2026-01-01T00:00:00Z",
+                            "updated_at": "2026-01-01T00:00:00Z",
+                        }]))
+                    }),
+                )
+                .route(
+                    "/api/sessions/{id}/setup",
+                    post(move |RawQuery(query): RawQuery| async move {
+                        if query == Some(app_query) {
+                            return envelope(setup);
+                        }
+                        axum::Json(json!({
+                            "success": false,
+                            "data": null,
+                            "message": null,
+                            "error_data": { "type": "process_already_running" },
+                        }))
+                    }),
+                ),
+        )
+        .await;
+        let server = backend.server();
+        let setup_path = format!("/api/sessions/{session_id}/setup");
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": web_id,
+        }))
+        .unwrap();
+        let result = server.run_setup_script(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["error"], "Repository 'web' has no setup script");
+        assert_eq!(backend.requests_to(&setup_path), 0);
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": app_id,
+        }))
+        .unwrap();
+        let result = server.run_setup_script(Parameters(request)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["execution_process"]["execution_process_id"], setup_id);
+        assert_eq!(body["execution_process"]["run_reason"], "setup_script");
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": docs_id,
+        }))
+        .unwrap();
+        let result = server.run_setup_script(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["status"], 409);
+        assert!(
+            body["hint"]
+                .as_str()
+                .unwrap()
+                .contains("stop_workspace_session")
+        );
+        assert_eq!(backend.requests_to(&setup_path), 2);
+    }
+
+    fn repo_json(name: &str) -> serde_json::Value {
+        json!({
+            "id": Uuid::new_v4(),
+            "path": format!("/repos/{name}"),
+            "name": name,
+            "display_name": name,
+            "setup_script": null,
+            "cleanup_script": null,
+            "archive_script": null,
+            "copy_files": null,
+            "parallel_setup_script": false,
+            "dev_server_script": null,
+            "default_target_branch": "main",
+            "default_working_dir": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    #[tokio::test]
+    async fn workspace_repos_default_to_context_repos() {
+        let backend = MockBackend::start(Router::new()).await;
+        let repo_id = Uuid::new_v4();
+        let mut server = backend.server();
+        server.context = ContextCache::new(Some(McpContext {
+            organization_id: None,
+            project_id: None,
+            issue_id: None,
+            orchestrator_session_id: None,
+            workspace_id: Uuid::new_v4(),
+            workspace_branch: "vk/current".to_string(),
+            workspace_repos: vec![McpRepoContext {
+                repo_id,
+                repo_name: "frontend".to_string(),
+                target_branch: "develop".to_string(),
+            }],
+            remote_link_status: RemoteLinkStatus::Unlinked,
+        }));
+
+        let repos = server.resolve_workspace_repos(None).await.unwrap();
+
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].repo_id, repo_id);
+        assert_eq!(repos[0].target_branch, "develop");
+        assert_eq!(backend.requests_to("/api/repos"), 0);
+    }
+
+    #[tokio::test]
+    async fn start_workspace_reports_repos_defaulted_from_context() {
+        let workspace_id = Uuid::new_v4();
+        let process = execution_process_json("codingagent", "running");
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/start",
+            post(move || {
+                let process = process.clone();
+                async move {
+                    envelope(json!({
+                        "workspace": {
+                            "id": workspace_id,
+                            "task_id": null,
+                            "container_ref": null,
+                            "branch": "vk/new",
+                            "setup_completed_at": null,
+                            "created_at": "2026-01-01T00:00:00Z",
+                            "updated_at": "2026-01-01T00:00:00Z",
+                            "archived": false,
+                            "pinned": false,
+                            "name": "New work",
+                            "worktree_deleted": false,
+                        },
+                        "execution_process": process,
+                    }))
+                }
+            }),
+        ))
+        .await;
+        let repo_id = Uuid::new_v4();
+        let mut server = backend.server();
+        server.context = ContextCache::new(Some(McpContext {
+            organization_id: None,
+            project_id: None,
+            issue_id: None,
+            orchestrator_session_id: None,
+            workspace_id: Uuid::new_v4(),
+            workspace_branch: "vk/current".to_string(),
+            workspace_repos: vec![McpRepoContext {
+                repo_id,
+                repo_name: "frontend".to_string(),
+                target_branch: "develop".to_string(),
+            }],
+            remote_link_status: RemoteLinkStatus::Unlinked,
+        }));
+
+        let request = serde_json::from_value(json!({
+            "name": "New work",
+            "executor": "claude-code",
+            "prompt": "Implement the feature",
+        }))
+        .unwrap();
+        let result = server.start_workspace(Parameters(request)).await.unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["workspace_id"], workspace_id.to_string());
+        assert_eq!(body["repositories_from_context"], true);
+        assert!(body.get("start_state").is_none());
+        assert_eq!(
+            body["repositories"],
+            json!([{ "repo_id": repo_id.to_string(), "branch": "develop" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn start_workspace_waits_for_setup_and_reports_failure_output() {
+        let setup = execution_process_json("setupscript", "running");
+        let setup_id = setup["id"].clone();
+        let mut failed = setup.clone();
+        failed["status"] = json!("failed");
+        failed["exit_code"] = json!(1);
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/workspaces/start",
+                    post(move || {
+                        let setup = setup.clone();
+                        async move {
+                            envelope(json!({
+                                "workspace": {
+                                    "id": Uuid::new_v4(),
+                                    "task_id": null,
+                                    "container_ref": null,
+                                    "branch": "vk/new",
+                                    "setup_completed_at": null,
+                                    "created_at": "2026-01-01T00:00:00Z",
+                                    "updated_at": "2026-01-01T00:00:00Z",
+                                    "archived": false,
+                                    "pinned": false,
+                                    "name": "New work",
+                                    "worktree_deleted": false,
+                                },
+                                "execution_process": setup,
+                            }))
+                        }
+                    }),
+                )
+                .route(
+                    "/api/execution-processes/{id}",
+                    get(move || {
+                        let failed = failed.clone();
+                        async move { envelope(failed) }
+                    }),
+                )
+                .route(
+                    "/api/execution-processes/{id}/raw-logs/tail",
+                    get(|| async {
+                        envelope(json!({
+                            "lines": ["npm ERR! missing script: build"],
+                            "truncated": false,
+                            "total_bytes": 31,
+                        }))
+                    }),
+                ),
+        )
+        .await;
+        let repo_id = Uuid::new_v4();
+
+        let request = serde_json::from_value(json!({
+            "name": "New work",
+            "executor": "CODEX",
+            "prompt": "Implement the feature",
+            "repositories": [{ "repo_id": repo_id, "branch": "main" }],
+            "wait_for_start": true,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .start_workspace(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["execution_process_id"], setup_id);
+        assert_eq!(body["start_state"]["status"], "setup_failed");
+        assert_eq!(body["start_state"]["exit_code"], 1);
+        assert_eq!(
+            body["start_state"]["output_tail"],
+            json!(["npm ERR! missing script: build"])
+        );
+    }
+
+    #[tokio::test]
+    async fn start_workspace_points_at_list_repo_branches_for_unknown_branch() {
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/start",
+            post(|| async {
+                (
+                    StatusCode::BAD_REQUEST,
+                    axum::Json(json!({
+                        "success": false,
+                        "message": "Branch 'main' does not exist in repository 'api'",
+                    })),
+                )
+            }),
+        ))
+        .await;
+
+        let request = serde_json::from_value(json!({
+            "name": "New work",
+            "executor": "CODEX",
+            "prompt": "Implement the feature",
+            "repositories": [{ "repo_id": Uuid::new_v4(), "branch": "main" }],
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .start_workspace(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["details"],
+            "Branch 'main' does not exist in repository 'api'"
+        );
+        assert!(
+            body["hint"]
+                .as_str()
+                .unwrap()
+                .contains("list_repo_branches")
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_repo_name_lists_available_repos() {
+        let backend = MockBackend::start(Router::new().route(
+            "/api/repos",
+            get(|| async { envelope(json!([repo_json("frontend"), repo_json("backend")])) }),
+        ))
+        .await;
+
+        let repositories = serde_json::from_value(json!([{ "repo_name": "mobile" }])).unwrap();
+        let error = backend
+            .server()
+            .resolve_workspace_repos(Some(repositories))
+            .await
+            .unwrap_err();
+
+        assert!(error.message.contains("mobile"));
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Available repositories: frontend, backend")
+        );
+    }
+
+    #[tokio::test]
+    async fn repo_name_matches_case_insensitively_and_uses_default_branch() {
+        let frontend = repo_json("Frontend");
+        let frontend_id = frontend["id"].clone();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/repos",
+            get(move || {
+                let frontend = frontend.clone();
+                async move { envelope(json!([frontend])) }
+            }),
+        ))
+        .await;
+
+        let repositories =
+            serde_json::from_value(json!([{ "repo_name": "frontend", "branch": " " }])).unwrap();
+        let repos = backend
+            .server()
+            .resolve_workspace_repos(Some(repositories))
+            .await
+            .unwrap();
+
+        assert_eq!(json!(repos[0].repo_id), frontend_id);
+        assert_eq!(repos[0].target_branch, "main");
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_every_problem_without_starting() {
+        let frontend = repo_json("frontend");
+        let repos = json!([frontend.clone()]);
+        let backend = MockBackend::start(
+            Router::new()
+                .route(
+                    "/api/repos",
+                    get(move || {
+                        let repos = repos.clone();
+                        async move { envelope(repos) }
+                    }),
+                )
+                .route(
+                    "/api/repos/{id}",
+                    get(move || {
+                        let frontend = frontend.clone();
+                        async move { envelope(frontend) }
+                    }),
+                )
+                .route(
+                    "/api/repos/{id}/branches",
+                    get(|| async {
+                        envelope(json!([{
+                            "name": "main",
+                            "is_current": true,
+                            "is_remote": false,
+                            "last_commit_date": "2026-01-01T00:00:00Z",
+                        }]))
+                    }),
+                ),
+        )
+        .await;
+
+        let request = serde_json::from_value(json!({
+            "name": "Try it",
+            "prompt": "Implement the feature",
+            "executor": "not-an-agent",
+            "repositories": [
+                { "repo_name": "frontend", "branch": "feature/missing" },
+                { "repo_name": "mobile" },
+            ],
+            "dry_run": true,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .start_workspace(Parameters(request))
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["would_create"], false);
+        assert_eq!(body["errors"].as_array().unwrap().len(), 3);
+        assert_eq!(body["repositories"][0]["branch_exists"], false);
+        assert_eq!(body["repositories"][1]["requested"], "mobile");
+        assert_eq!(body["prompt_preview"], "Implement the feature");
+        assert_eq!(backend.requests_to("/api/workspaces/start"), 0);
+    }
+}
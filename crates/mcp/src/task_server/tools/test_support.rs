@@ -0,0 +1,196 @@
+//! Helpers for exercising tools against an in-process mock VK backend.
+
+use std::sync::{
+    Arc, Mutex, Once,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use axum::{
+    Json, Router,
+    extract::{Request, State},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+};
+use rmcp::handler::server::tool::ToolRouter;
+use serde::Serialize;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::task_server::{McpMode, McpServer, McpServerConfig};
+
+static RUSTLS_PROVIDER: Once = Once::new();
+
+pub(super) fn install_rustls_provider() {
+    RUSTLS_PROVIDER.call_once(|| {
+        rustls::crypto::aws_lc_rs::default_provider()
+            .install_default()
+            .expect("Failed to install rustls crypto provider");
+    });
+}
+
+/// Wraps `data` in the `ApiResponse` envelope returned by the VK backend.
+pub(super) fn envelope<T: Serialize>(data: T) -> Json<Value> {
+    Json(json!({ "success": true, "data": data }))
+}
+
+/// An issue as the backend returns it, numbered `VK-{number}` with a fresh ID.
+pub(super) fn issue_json(project_id: Uuid, status_id: Uuid, number: i32) -> Value {
+    json!({
+        "id": Uuid::new_v4(),
+        "project_id": project_id,
+        "issue_number": number,
+        "simple_id": format!("VK-{number}"),
+        "status_id": status_id,
+        "title": format!("Issue {number}"),
+        "description": null,
+        "priority": null,
+        "start_date": null,
+        "target_date": null,
+        "completed_at": null,
+        "sort_order": number as f64,
+        "parent_issue_id": null,
+        "parent_issue_sort_order": null,
+        "extension_metadata": {},
+        "creator_user_id": null,
+        "created_at": "2026-01-01T00:00:00Z",
+        "updated_at": "2026-01-01T00:00:00Z",
+    })
+}
+
+/// An organization member whose email is `{username}@example.com`.
+pub(super) fn member_json(user_id: Uuid, username: &str) -> Value {
+    json!({
+        "user_id": user_id,
+        "role": "MEMBER",
+        "joined_at": "2026-01-01T00:00:00Z",
+        "first_name": null,
+        "last_name": null,
+        "username": username,
+        "email": format!("{username}@example.com"),
+        "avatar_url": null,
+    })
+}
+
+/// A started execution process in a fresh session.
+pub(super) fn execution_process_json(run_reason: &str, status: &str) -> Value {
+    json!({
+        "id": Uuid::new_v4(),
+        "session_id": Uuid::new_v4(),
+        "run_reason": run_reason,
+        "executor_action": {},
+        "status": status,
+        "exit_code": null,
+        "dropped": false,
+        "started_at": "2026-01-01T00:00:00Z",
+        "completed_at": null,
+        "created_at": "2026-01-01T00:00:00Z",
+        "updated_at": "2026-01-01T00:00:00Z",
+    })
+}
+
+/// Routes for `project_id` in an organization with `members`, enough for names to resolve to
+/// member user IDs.
+pub(super) fn project_with_members_router(project_id: Uuid, members: Vec<Value>) -> Router {
+    let organization_id = Uuid::new_v4();
+    Router::new()
+        .route(
+            "/api/remote/projects/{project_id}",
+            get(move || async move {
+                envelope(json!({
+                    "id": project_id,
+                    "organization_id": organization_id,
+                    "name": "App",
+                    "color": "217 91% 60%",
+                    "sort_order": 0,
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "updated_at": "2026-01-01T00:00:00Z",
+                }))
+            }),
+        )
+        .route(
+            "/api/organizations/{organization_id}/members",
+            get(move || async move { envelope(json!({ "members": members })) }),
+        )
+}
+
+#[derive(Clone, Default)]
+struct RequestLog {
+    paths: Arc<Mutex<Vec<String>>>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+pub(super) struct MockBackend {
+    base_url: String,
+    log: RequestLog,
+}
+
+impl MockBackend {
+    pub(super) async fn start(router: Router) -> Self {
+        install_rustls_provider();
+
+        let log = RequestLog::default();
+        let app = router.layer(middleware::from_fn_with_state(log.clone(), record_request));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock backend");
+        let addr = listener
+            .local_addr()
+            .expect("Mock backend should have an address");
+        tokio::spawn(async move {
+            axum::serve(listener, app)
+                .await
+                .expect("Mock backend failed");
+        });
+
+        Self {
+            base_url: format!("http://{addr}"),
+            log,
+        }
+    }
+
+    pub(super) fn server(&self) -> McpServer {
+        let config = McpServerConfig::new(self.base_url.clone());
+        McpServer {
+            client: config.build_client(),
+            config,
+            tool_router: ToolRouter::default(),
+            context: Default::default(),
+            mode: McpMode::Global,
+            project_statuses: Default::default(),
+            response_pages: Default::default(),
+            stats: Default::default(),
+        }
+    }
+
+    /// Number of requests received for an exact path (query string excluded).
+    pub(super) fn requests_to(&self, path: &str) -> usize {
+        self.log
+            .paths
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|recorded| recorded.as_str() == path)
+            .count()
+    }
+
+    /// Highest number of requests that were being handled at the same time.
+    pub(super) fn max_in_flight(&self) -> usize {
+        self.log.max_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+async fn record_request(State(log): State<RequestLog>, request: Request, next: Next) -> Response {
+    log.paths
+        .lock()
+        .unwrap()
+        .push(request.uri().path().to_string());
+    let current = log.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+    log.max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+    let response = next.run(request).await;
+
+    log.in_flight.fetch_sub(1, Ordering::SeqCst);
+    response
+}
@@ -1,12 +1,46 @@
-use db::models::{requests::UpdateWorkspace, workspace::Workspace};
+use std::{cmp::Ordering, collections::HashMap};
+
+use api_types::{
+    ListWorkspacesByLocalIdsRequest, ListWorkspacesByLocalIdsResponse, MAX_LOCAL_IDS_PER_LOOKUP,
+    PullRequestStatus, SortDirection,
+};
+use db::models::{
+    execution_process::ExecutionProcess,
+    requests::{FileDiffSummary, UpdateWorkspace, WorkspaceDiffSummary, WorkspaceDiffSummaryQuery},
+    workspace::Workspace,
+    workspace_repo::RepoWithTargetBranch,
+};
+use futures::{StreamExt, stream};
 use rmcp::{
     ErrorData, handler::server::wrapper::Parameters, model::CallToolResult, schemars, tool,
     tool_router,
 };
 use serde::{Deserialize, Serialize};
+use utils::diff::DiffChangeKind;
 use uuid::Uuid;
 
-use super::McpServer;
+use super::{
+    McpServer, ToolError, describe_error, output_schema,
+    paging::{MorePages, PageParams},
+};
+
+// Upper bound on concurrent bulk link lookups when the ids span several chunks.
+const REMOTE_LINK_FETCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WorkspaceSortField {
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    Branch,
+}
+
+const WORKSPACE_SORT_FIELDS: &[(&str, WorkspaceSortField)] = &[
+    ("created_at", WorkspaceSortField::CreatedAt),
+    ("updated_at", WorkspaceSortField::UpdatedAt),
+    ("name", WorkspaceSortField::Name),
+    ("branch", WorkspaceSortField::Branch),
+];
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct McpListWorkspacesRequest {
@@ -18,10 +52,24 @@ struct McpListWorkspacesRequest {
     branch: Option<String>,
     #[schemars(description = "Case-insensitive substring match against workspace name")]
     name_search: Option<String>,
-    #[schemars(description = "Maximum number of workspaces to return (default: 50)")]
+    #[schemars(
+        description = "Maximum number of workspaces to return (default: 50, max: 200; larger values are lowered to 200). Pass -1 for every matching workspace, which fails if more than 200 match. 0 is rejected."
+    )]
     limit: Option<i32>,
-    #[schemars(description = "Number of results to skip before returning rows (default: 0)")]
+    #[schemars(
+        description = "Number of results to skip before returning rows (default: 0). An offset past the end returns no rows."
+    )]
     offset: Option<i32>,
+    #[schemars(description = "Only workspaces linked to this remote issue")]
+    issue_id: Option<Uuid>,
+    #[schemars(description = "Only workspaces linked to this remote project")]
+    project_id: Option<Uuid>,
+    #[schemars(
+        description = "Field to sort by before paginating. Allowed values: 'created_at', 'updated_at', 'name', 'branch'. Default: 'created_at'. Unnamed workspaces come last when sorting by name."
+    )]
+    sort_by: Option<String>,
+    #[schemars(description = "Sort direction. Allowed values: 'asc', 'desc'. Default: 'desc'.")]
+    sort_direction: Option<String>,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -40,6 +88,36 @@ struct WorkspaceSummary {
     created_at: String,
     #[schemars(description = "Last update timestamp")]
     updated_at: String,
+    #[schemars(description = "Remote project the workspace is linked to, if known")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linked_project_id: Option<String>,
+    #[schemars(description = "Remote issue the workspace is linked to, if known")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linked_issue_id: Option<String>,
+}
+
+impl WorkspaceSummary {
+    fn from_workspace(workspace: Workspace) -> Self {
+        Self {
+            id: workspace.id.to_string(),
+            branch: workspace.branch,
+            archived: workspace.archived,
+            pinned: workspace.pinned,
+            name: workspace.name,
+            created_at: workspace.created_at.to_rfc3339(),
+            updated_at: workspace.updated_at.to_rfc3339(),
+            linked_project_id: None,
+            linked_issue_id: None,
+        }
+    }
+
+    fn with_remote_link(mut self, remote: Option<&api_types::Workspace>) -> Self {
+        if let Some(remote) = remote {
+            self.linked_project_id = Some(remote.project_id.to_string());
+            self.linked_issue_id = remote.issue_id.map(|id| id.to_string());
+        }
+        self
+    }
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -49,6 +127,60 @@ struct McpListWorkspacesResponse {
     returned_count: usize,
     limit: usize,
     offset: usize,
+    #[schemars(description = "Whether the requested limit was above the maximum and was lowered")]
+    limit_clamped: bool,
+    #[schemars(description = "The sort field that was applied")]
+    sort_by: String,
+    #[schemars(description = "The sort direction that was applied")]
+    sort_direction: String,
+    #[schemars(
+        description = "Set when some remote links could not be loaded. Those workspaces show no link and are left out by `issue_id` and `project_id` filters."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+    #[serde(flatten)]
+    more_pages: MorePages,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetWorkspaceRequest {
+    #[schemars(
+        description = "Workspace ID to fetch. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct WorkspaceRepoSummary {
+    repo_id: String,
+    repo_name: String,
+    #[schemars(
+        description = "Branch the workspace's changes are compared against and merged into"
+    )]
+    target_branch: String,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct WorkspaceLatestRun {
+    execution_process_id: String,
+    #[schemars(description = "'running', 'completed', 'failed', or 'killed'")]
+    status: String,
+    started_at: String,
+    #[schemars(description = "When the run finished. Null while it is running.")]
+    finished_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpGetWorkspaceResponse {
+    workspace: WorkspaceSummary,
+    #[schemars(description = "Repositories in the workspace, with their target branches")]
+    repos: Vec<WorkspaceRepoSummary>,
+    #[schemars(
+        description = "Simple ID of the linked remote issue (e.g. 'VK-42'). Null when there is no linked issue or the remote API is unreachable."
+    )]
+    linked_issue_simple_id: Option<String>,
+    #[schemars(description = "The latest coding agent run. Null if none has started yet.")]
+    latest_run: Option<WorkspaceLatestRun>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -68,10 +200,55 @@ struct McpUpdateWorkspaceRequest {
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct McpUpdateWorkspaceResponse {
     success: bool,
-    workspace_id: String,
-    archived: bool,
-    pinned: bool,
-    name: Option<String>,
+    workspace: WorkspaceSummary,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpArchiveWorkspaceRequest {
+    #[schemars(
+        description = "Workspace ID to archive or unarchive. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpPinWorkspaceRequest {
+    #[schemars(
+        description = "Workspace ID to pin or unpin. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(description = "Pin the workspace (default: true). Pass false to unpin it.")]
+    pinned: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpWorkspaceStateResponse {
+    workspace: WorkspaceSummary,
+    #[schemars(description = "False if the workspace was already in the requested state")]
+    changed: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpRenameWorkspaceRequest {
+    #[schemars(
+        description = "Workspace ID to rename. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(description = "New workspace display name. Surrounding whitespace is trimmed.")]
+    name: String,
+    #[schemars(
+        description = "Set to true together with an empty `name` to remove the workspace name. Empty names are rejected otherwise."
+    )]
+    clear: Option<bool>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRenameWorkspaceResponse {
+    workspace: WorkspaceSummary,
+    #[schemars(description = "Name before the rename, if the workspace had one")]
+    old_name: Option<String>,
+    #[schemars(description = "Name after the rename, or null if it was cleared")]
+    new_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -96,9 +273,209 @@ struct McpDeleteWorkspaceResponse {
     delete_branches: bool,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpGetWorkspaceDiffSummaryRequest {
+    #[schemars(
+        description = "Workspace ID to summarize. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(description = "Only summarize this repository of the workspace")]
+    repo_id: Option<Uuid>,
+    #[schemars(description = "Also return unified diffs for text files (default: false)")]
+    include_patch: Option<bool>,
+    #[schemars(
+        description = "Byte budget for all patches combined (default: 65536, max: 1048576)"
+    )]
+    max_patch_bytes: Option<usize>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpChangedFile {
+    path: String,
+    #[schemars(description = "Previous path, for renamed and copied files")]
+    old_path: Option<String>,
+    #[schemars(
+        description = "One of 'added', 'deleted', 'modified', 'renamed', 'copied', 'permission_change'"
+    )]
+    change: String,
+    #[schemars(description = "Lines added; omitted for binary files and files too large to diff")]
+    additions: Option<usize>,
+    #[schemars(
+        description = "Lines removed; omitted for binary files and files too large to diff"
+    )]
+    deletions: Option<usize>,
+    binary: bool,
+    #[schemars(description = "Unified diff, when `include_patch` is set and the budget allows")]
+    patch: Option<String>,
+    #[schemars(
+        description = "True when this file's patch was cut short or left out to stay within the byte budget"
+    )]
+    patch_truncated: bool,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRepoDiffSummary {
+    repo_id: String,
+    repo_name: String,
+    #[schemars(description = "Branch the workspace branch is compared against")]
+    target_branch: String,
+    files: Vec<McpChangedFile>,
+    additions: usize,
+    deletions: usize,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpGetWorkspaceDiffSummaryResponse {
+    workspace_id: String,
+    repos: Vec<McpRepoDiffSummary>,
+    files_changed: usize,
+    additions: usize,
+    deletions: usize,
+    #[schemars(
+        description = "True when some patches were cut short or left out to stay within the byte budget"
+    )]
+    truncated: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpCreateWorkspacePrRequest {
+    #[schemars(
+        description = "Workspace whose branch to push. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(
+        description = "Repository of the workspace to open the PR for. Optional when the workspace has a single repository."
+    )]
+    repo_id: Option<Uuid>,
+    #[schemars(description = "Pull request title")]
+    title: String,
+    #[schemars(description = "Pull request description")]
+    body: Option<String>,
+    #[schemars(
+        description = "Branch the PR merges into. Defaults to the workspace repository's target branch."
+    )]
+    target_branch: Option<String>,
+}
+
+// Mirrors the server's `CreatePrApiRequest`.
+#[derive(Debug, Serialize)]
+struct CreateWorkspacePrPayload {
+    title: String,
+    body: Option<String>,
+    target_branch: Option<String>,
+    draft: Option<bool>,
+    repo_id: Uuid,
+}
+
+// Mirrors the server's `PrError`, which comes back as `error_data` on an unsuccessful response.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CreateWorkspacePrFailure {
+    CliNotInstalled { provider: String },
+    CliNotLoggedIn { provider: String },
+    GitCliNotLoggedIn,
+    GitCliNotInstalled,
+    TargetBranchNotFound { branch: String },
+    UnsupportedProvider,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpCreateWorkspacePrResponse {
+    workspace_id: String,
+    repo_id: String,
+    #[schemars(description = "PR number, when it can be read from the URL")]
+    number: Option<i32>,
+    url: String,
+    status: PullRequestStatus,
+    #[schemars(description = "Branch the PR merges into")]
+    target_branch: String,
+    #[schemars(description = "Remote issue the workspace is linked to, if any")]
+    linked_issue_id: Option<String>,
+    #[schemars(
+        description = "Why the PR could not be recorded against the linked issue. The PR itself was still created."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_link_warning: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct McpRebaseWorkspaceRequest {
+    #[schemars(
+        description = "Workspace to rebase. Optional if running inside that workspace context."
+    )]
+    workspace_id: Option<Uuid>,
+    #[schemars(
+        description = "Only rebase this repository of the workspace. Default: all of them."
+    )]
+    repo_id: Option<Uuid>,
+}
+
+// The parts of the server's `RepoBranchStatus` needed to describe a rebase.
+#[derive(Debug, Deserialize)]
+struct RepoBranchState {
+    repo_id: Uuid,
+    repo_name: String,
+    head_oid: Option<String>,
+    commits_ahead: Option<usize>,
+    commits_behind: Option<usize>,
+    target_branch_name: String,
+    #[serde(default)]
+    conflicted_files: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct RebaseWorkspacePayload {
+    repo_id: Uuid,
+}
+
+// Mirrors the server's `GitOperationError`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RebaseFailure {
+    MergeConflicts { conflicted_files: Vec<String> },
+    RebaseInProgress,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRepoRebaseResult {
+    repo_id: String,
+    repo_name: String,
+    #[schemars(description = "Branch the workspace branch was rebased onto")]
+    target_branch: String,
+    #[schemars(
+        description = "One of 'up_to_date', 'fast_forwarded', 'rebased', 'conflict', 'rebase_in_progress', 'failed'"
+    )]
+    result: String,
+    #[schemars(description = "Number of workspace commits replayed, when `result` is 'rebased'")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rebased_commits: Option<usize>,
+    #[schemars(description = "Workspace branch HEAD before the rebase")]
+    before_sha: Option<String>,
+    #[schemars(description = "Workspace branch HEAD after the rebase")]
+    after_sha: Option<String>,
+    #[schemars(
+        description = "Files left with conflicts, for 'conflict' and 'rebase_in_progress'. They are not resolved automatically."
+    )]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    conflicted_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct McpRebaseWorkspaceResponse {
+    workspace_id: String,
+    repos: Vec<McpRepoRebaseResult>,
+    #[schemars(description = "True when any repository stopped with conflicts to resolve")]
+    has_conflicts: bool,
+}
+
 #[tool_router(router = workspaces_tools_router, vis = "pub")]
 impl McpServer {
-    #[tool(description = "List local workspaces with optional filters and pagination.")]
+    #[tool(
+        description = "List local workspaces with optional filters and pagination. Filter by `issue_id` or `project_id` to find workspaces linked to a remote issue or project. Very large results return only their first page plus a `next_cursor` to pass to `fetch_more`.",
+        output_schema = output_schema::<McpListWorkspacesResponse>()
+    )]
     async fn list_workspaces(
         &self,
         Parameters(McpListWorkspacesRequest {
@@ -108,8 +485,26 @@ impl McpServer {
             name_search,
             limit,
             offset,
+            issue_id,
+            project_id,
+            sort_by,
+            sort_direction,
         }): Parameters<McpListWorkspacesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let page_params = match PageParams::parse(limit, offset) {
+            Ok(params) => params,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let sort_field = match Self::parse_workspace_sort_field(sort_by.as_deref()) {
+            Ok(value) => value,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let sort_direction =
+            match Self::parse_sort_direction(sort_direction.as_deref(), SortDirection::Desc) {
+                Ok(value) => value,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
         let url = self.url("/api/workspaces");
         let mut workspaces: Vec<Workspace> = match self.send_json(self.client.get(&url)).await {
             Ok(ws) => ws,
@@ -135,39 +530,135 @@ impl McpServer {
             });
         }
 
-        // Keep ordering deterministic after filtering.
-        workspaces.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        let filter_by_link = issue_id.is_some() || project_id.is_some();
+        let mut remote_links = RemoteWorkspaceLinks::default();
+        if filter_by_link {
+            remote_links = self
+                .fetch_remote_workspace_links(workspaces.iter().map(|w| w.id).collect())
+                .await;
+            // Workspaces without a remote link can't match a link filter.
+            workspaces.retain(|w| {
+                remote_links.links.get(&w.id).is_some_and(|remote| {
+                    issue_id.is_none_or(|id| remote.issue_id == Some(id))
+                        && project_id.is_none_or(|id| remote.project_id == id)
+                })
+            });
+        }
+
+        sort_workspaces(&mut workspaces, sort_field, sort_direction);
 
         let total_count = workspaces.len();
-        let offset = offset.unwrap_or(0).max(0) as usize;
-        let limit = limit.unwrap_or(50).max(0) as usize;
+        let page = match page_params.apply(workspaces) {
+            Ok(page) => page,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if !filter_by_link {
+            remote_links = self
+                .fetch_remote_workspace_links(page.iter().map(|w| w.id).collect())
+                .await;
+        }
 
-        let workspace_summaries = workspaces
+        let workspace_summaries = page
             .into_iter()
-            .skip(offset)
-            .take(limit)
-            .map(|workspace| WorkspaceSummary {
-                id: workspace.id.to_string(),
-                branch: workspace.branch,
-                archived: workspace.archived,
-                pinned: workspace.pinned,
-                name: workspace.name,
-                created_at: workspace.created_at.to_rfc3339(),
-                updated_at: workspace.updated_at.to_rfc3339(),
+            .map(|workspace| {
+                let remote = remote_links.links.get(&workspace.id);
+                WorkspaceSummary::from_workspace(workspace).with_remote_link(remote)
             })
             .collect::<Vec<_>>();
 
-        McpServer::success(&McpListWorkspacesResponse {
-            returned_count: workspace_summaries.len(),
-            total_count,
-            limit,
-            offset,
-            workspaces: workspace_summaries,
+        self.success_paged(
+            &McpListWorkspacesResponse {
+                returned_count: workspace_summaries.len(),
+                total_count,
+                limit: page_params.limit,
+                offset: page_params.offset,
+                limit_clamped: page_params.limit_clamped,
+                workspaces: workspace_summaries,
+                sort_by: sort_field_label(sort_field).to_string(),
+                sort_direction: Self::sort_direction_label(sort_direction).to_string(),
+                warning: (remote_links.failed_count > 0).then(|| {
+                    format!(
+                        "Remote links could not be loaded for {} workspace(s)",
+                        remote_links.failed_count
+                    )
+                }),
+                more_pages: MorePages::default(),
+            },
+            "workspaces",
+        )
+    }
+
+    #[tool(
+        description = "Get a workspace's details: its repositories and target branches, the remote project and issue it is linked to, and the state of its latest coding agent run. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpGetWorkspaceResponse>()
+    )]
+    async fn get_workspace(
+        &self,
+        Parameters(McpGetWorkspaceRequest { workspace_id }): Parameters<McpGetWorkspaceRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let url = self.url(&format!("/api/workspaces/{}", workspace_id));
+        let workspace: Workspace = match self.send_json(self.client.get(&url)).await {
+            Ok(ws) => ws,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        let repos_url = self.url(&format!("/api/workspaces/{}/repos", workspace_id));
+        let latest_url = self.url(&format!(
+            "/api/workspaces/{}/execution/latest-coding-agent",
+            workspace_id
+        ));
+        let (repos, latest, remote) = tokio::join!(
+            self.send_json::<Vec<RepoWithTargetBranch>>(self.client.get(&repos_url)),
+            self.send_optional_json::<ExecutionProcess>(self.client.get(&latest_url)),
+            self.fetch_remote_workspace(workspace_id),
+        );
+        let (repos, latest) = match (repos, latest) {
+            (Ok(repos), Ok(latest)) => (repos, latest),
+            (Err(e), _) | (_, Err(e)) => return Ok(Self::tool_error(e)),
+        };
+
+        // Like the remote link itself, the issue's simple ID is best-effort.
+        let linked_issue_simple_id = match remote.as_ref().and_then(|remote| remote.issue_id) {
+            Some(issue_id) => self
+                .fetch_issue(issue_id)
+                .await
+                .ok()
+                .map(|issue| issue.simple_id),
+            None => None,
+        };
+
+        self.success(&McpGetWorkspaceResponse {
+            workspace: WorkspaceSummary::from_workspace(workspace)
+                .with_remote_link(remote.as_ref()),
+            repos: repos
+                .into_iter()
+                .map(|repo| WorkspaceRepoSummary {
+                    repo_id: repo.repo.id.to_string(),
+                    repo_name: repo.repo.name,
+                    target_branch: repo.target_branch,
+                })
+                .collect(),
+            linked_issue_simple_id,
+            latest_run: latest.map(|process| WorkspaceLatestRun {
+                execution_process_id: process.id.to_string(),
+                status: Self::execution_process_status_label(&process.status).to_string(),
+                started_at: process.started_at.to_rfc3339(),
+                finished_at: process.completed_at.map(|at| at.to_rfc3339()),
+            }),
         })
     }
 
     #[tool(
-        description = "Update a workspace's archived, pinned, or name fields. `workspace_id` is optional if running inside that workspace context."
+        description = "Update a workspace's archived, pinned, or name fields, e.g. to archive it once work is done. At least one field is required. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpUpdateWorkspaceResponse>()
     )]
     async fn update_workspace(
         &self,
@@ -185,6 +676,12 @@ impl McpServer {
         if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
             return Ok(Self::tool_error(error_result));
         }
+        if archived.is_none() && pinned.is_none() && name.is_none() {
+            return Self::err(
+                "Nothing to update",
+                Some("Provide at least one of `name`, `archived`, or `pinned`."),
+            );
+        }
 
         let url = self.url(&format!("/api/workspaces/{}", workspace_id));
         let payload = UpdateWorkspace {
@@ -198,17 +695,390 @@ impl McpServer {
             Err(e) => return Ok(Self::tool_error(e)),
         };
 
-        McpServer::success(&McpUpdateWorkspaceResponse {
+        self.success(&McpUpdateWorkspaceResponse {
             success: true,
-            workspace_id: updated.id.to_string(),
-            archived: updated.archived,
-            pinned: updated.pinned,
-            name: updated.name,
+            workspace: WorkspaceSummary::from_workspace(updated),
         })
     }
 
     #[tool(
-        description = "Delete a local workspace. `workspace_id` is optional if running inside that workspace context."
+        description = "Archive a workspace to hide it from the default workspace list without deleting it. Archiving an archived workspace succeeds without changes. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpWorkspaceStateResponse>()
+    )]
+    async fn archive_workspace(
+        &self,
+        Parameters(McpArchiveWorkspaceRequest { workspace_id }): Parameters<
+            McpArchiveWorkspaceRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.set_workspace_state(workspace_id, Some(true), None)
+            .await
+    }
+
+    #[tool(
+        description = "Restore an archived workspace. Unarchiving a workspace that is not archived succeeds without changes. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpWorkspaceStateResponse>()
+    )]
+    async fn unarchive_workspace(
+        &self,
+        Parameters(McpArchiveWorkspaceRequest { workspace_id }): Parameters<
+            McpArchiveWorkspaceRequest,
+        >,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.set_workspace_state(workspace_id, Some(false), None)
+            .await
+    }
+
+    #[tool(
+        description = "Pin a workspace, or unpin it with `pinned: false`. Already being in the requested state is not an error. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpWorkspaceStateResponse>()
+    )]
+    async fn pin_workspace(
+        &self,
+        Parameters(McpPinWorkspaceRequest {
+            workspace_id,
+            pinned,
+        }): Parameters<McpPinWorkspaceRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.set_workspace_state(workspace_id, None, Some(pinned.unwrap_or(true)))
+            .await
+    }
+
+    #[tool(
+        description = "Rename a workspace, e.g. when its scope changes. Returns the old and new names. An empty name is rejected unless `clear` is true, in which case the name is removed. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpRenameWorkspaceResponse>()
+    )]
+    async fn rename_workspace(
+        &self,
+        Parameters(McpRenameWorkspaceRequest {
+            workspace_id,
+            name,
+            clear,
+        }): Parameters<McpRenameWorkspaceRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let name = name.trim();
+        if name.is_empty() && clear != Some(true) {
+            return Self::err(
+                "Workspace name cannot be empty",
+                Some("Pass `clear: true` to remove the workspace name."),
+            );
+        }
+
+        let url = self.url(&format!("/api/workspaces/{}", workspace_id));
+        let current: Workspace = match self.send_json(self.client.get(&url)).await {
+            Ok(ws) => ws,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        // The update endpoint stores an empty name as NULL.
+        let payload = UpdateWorkspace {
+            archived: None,
+            pinned: None,
+            name: Some(name.to_string()),
+        };
+        let updated: Workspace = match self.send_json(self.client.put(&url).json(&payload)).await {
+            Ok(ws) => ws,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpRenameWorkspaceResponse {
+            old_name: current.name,
+            new_name: updated.name.clone(),
+            workspace: WorkspaceSummary::from_workspace(updated),
+        })
+    }
+
+    #[tool(
+        description = "Summarize what changed in a workspace: per repository, the changed files with line counts, compared against the workspace's target branch. Set `include_patch` to also get unified diffs, capped by `max_patch_bytes`; files whose patch was cut short or left out are marked `patch_truncated`. Binary files are listed with `binary: true` and never get a patch. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpGetWorkspaceDiffSummaryResponse>()
+    )]
+    async fn get_workspace_diff_summary(
+        &self,
+        Parameters(McpGetWorkspaceDiffSummaryRequest {
+            workspace_id,
+            repo_id,
+            include_patch,
+            max_patch_bytes,
+        }): Parameters<McpGetWorkspaceDiffSummaryRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let url = self.url(&format!(
+            "/api/workspaces/{}/git/diff-summary",
+            workspace_id
+        ));
+        let query = WorkspaceDiffSummaryQuery {
+            repo_id,
+            include_patch,
+            max_patch_bytes,
+        };
+        let summary: WorkspaceDiffSummary =
+            match self.send_json(self.client.get(&url).query(&query)).await {
+                Ok(summary) => summary,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+
+        self.success(&McpGetWorkspaceDiffSummaryResponse {
+            workspace_id: workspace_id.to_string(),
+            repos: summary
+                .repos
+                .into_iter()
+                .map(|repo| McpRepoDiffSummary {
+                    repo_id: repo.repo_id.to_string(),
+                    repo_name: repo.repo_name,
+                    target_branch: repo.target_branch,
+                    files: repo.files.into_iter().map(changed_file).collect(),
+                    additions: repo.additions,
+                    deletions: repo.deletions,
+                })
+                .collect(),
+            files_changed: summary.files_changed,
+            additions: summary.additions,
+            deletions: summary.deletions,
+            truncated: summary.truncated,
+        })
+    }
+
+    #[tool(
+        description = "Push a workspace's branch and open a pull request for one of its repositories. `repo_id` is optional when the workspace has a single repository, and `target_branch` defaults to the repository's target branch. Returns the PR number, URL, and status. When the workspace is linked to a remote issue, the PR is also recorded against that issue. Push and provider failures are returned with the underlying git or CLI message. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpCreateWorkspacePrResponse>()
+    )]
+    async fn create_workspace_pr(
+        &self,
+        Parameters(McpCreateWorkspacePrRequest {
+            workspace_id,
+            repo_id,
+            title,
+            body,
+            target_branch,
+        }): Parameters<McpCreateWorkspacePrRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return Self::err("title must not be empty", None);
+        }
+        let target_branch = target_branch
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty());
+
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let repos_url = self.url(&format!("/api/workspaces/{}/repos", workspace_id));
+        let repos: Vec<RepoWithTargetBranch> =
+            match self.send_json(self.client.get(&repos_url)).await {
+                Ok(repos) => repos,
+                Err(e) => return Ok(Self::tool_error(e)),
+            };
+        let repo = match pr_repo(&repos, repo_id) {
+            Ok(repo) => repo,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let base_branch = target_branch
+            .clone()
+            .unwrap_or_else(|| repo.target_branch.clone());
+
+        let url = self.url(&format!("/api/workspaces/{}/pull-requests", workspace_id));
+        let payload = CreateWorkspacePrPayload {
+            title,
+            body,
+            target_branch,
+            draft: None,
+            repo_id: repo.repo.id,
+        };
+        let pr_url = match self
+            .send_json_or_error_data::<String, CreateWorkspacePrFailure>(
+                self.client
+                    .post(&url)
+                    .timeout(self.config.git_request_timeout)
+                    .json(&payload),
+            )
+            .await
+        {
+            Ok(Ok(pr_url)) => pr_url,
+            Ok(Err(failure)) => return Ok(Self::tool_error(pr_failure_error(failure))),
+            Err(e) => return Ok(Self::tool_error(pr_push_error(e))),
+        };
+        let number = pr_number_from_url(&pr_url);
+
+        let linked_issue_id = self
+            .fetch_remote_workspace(workspace_id)
+            .await
+            .and_then(|remote| remote.issue_id);
+        let issue_link_warning = match (linked_issue_id, number) {
+            (Some(issue_id), Some(number)) => self
+                .record_pull_request_on_issue(issue_id, &pr_url, number, &base_branch)
+                .await
+                .err()
+                .map(|e| {
+                    format!(
+                        "Could not record the PR against the linked issue: {}",
+                        describe_error(&e)
+                    )
+                }),
+            (Some(_), None) => Some(format!(
+                "Could not read a PR number from '{pr_url}', so the PR was not recorded against the linked issue"
+            )),
+            (None, _) => None,
+        };
+
+        self.success(&McpCreateWorkspacePrResponse {
+            workspace_id: workspace_id.to_string(),
+            repo_id: repo.repo.id.to_string(),
+            number,
+            url: pr_url,
+            status: PullRequestStatus::Open,
+            target_branch: base_branch,
+            linked_issue_id: linked_issue_id.map(|id| id.to_string()),
+            issue_link_warning,
+        })
+    }
+
+    #[tool(
+        description = "Rebase a workspace's branch onto its target branch, for every repository or just `repo_id`. Reports per repository whether it was already up to date, fast-forwarded, or rebased (with the number of commits replayed), plus the HEAD SHAs before and after. Conflicts are not resolved: the rebase stops with `result: 'conflict'` and the conflicting paths, so a follow-up session can resolve them. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpRebaseWorkspaceResponse>()
+    )]
+    async fn rebase_workspace(
+        &self,
+        Parameters(McpRebaseWorkspaceRequest {
+            workspace_id,
+            repo_id,
+        }): Parameters<McpRebaseWorkspaceRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let status_url = self.url(&format!("/api/workspaces/{}/git/status", workspace_id));
+        let before: Vec<RepoBranchState> = match self.send_json(self.client.get(&status_url)).await
+        {
+            Ok(status) => status,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        let selected: Vec<&RepoBranchState> = match repo_id {
+            Some(repo_id) => match before.iter().find(|repo| repo.repo_id == repo_id) {
+                Some(repo) => vec![repo],
+                None => {
+                    let available = before
+                        .iter()
+                        .map(|repo| format!("{} ({})", repo.repo_name, repo.repo_id))
+                        .collect::<Vec<_>>();
+                    return Self::err(
+                        format!("Repository {repo_id} is not part of this workspace"),
+                        Some(format!("Workspace repositories: {}", available.join(", "))),
+                    );
+                }
+            },
+            None => before.iter().collect(),
+        };
+        if selected.is_empty() {
+            return Self::err("Workspace has no repositories", None);
+        }
+
+        // One repository at a time: each rebase runs git in the shared workspace directory.
+        let rebase_url = self.url(&format!("/api/workspaces/{}/git/rebase", workspace_id));
+        let mut outcomes = Vec::with_capacity(selected.len());
+        for repo in selected {
+            let payload = RebaseWorkspacePayload {
+                repo_id: repo.repo_id,
+            };
+            let outcome = self
+                .send_json_or_error_data::<(), RebaseFailure>(
+                    self.client
+                        .post(&rebase_url)
+                        .timeout(self.config.git_request_timeout)
+                        .json(&payload),
+                )
+                .await;
+            outcomes.push((repo, outcome));
+        }
+
+        // The SHAs afterwards are best-effort; the rebases already happened.
+        let after: HashMap<Uuid, Option<String>> = self
+            .send_json::<Vec<RepoBranchState>>(self.client.get(&status_url))
+            .await
+            .map(|status| {
+                status
+                    .into_iter()
+                    .map(|repo| (repo.repo_id, repo.head_oid))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let repos: Vec<McpRepoRebaseResult> = outcomes
+            .into_iter()
+            .map(|(repo, outcome)| {
+                let after_sha = after.get(&repo.repo_id).cloned().flatten();
+                let mut result = McpRepoRebaseResult {
+                    repo_id: repo.repo_id.to_string(),
+                    repo_name: repo.repo_name.clone(),
+                    target_branch: repo.target_branch_name.clone(),
+                    result: String::new(),
+                    rebased_commits: None,
+                    before_sha: repo.head_oid.clone(),
+                    after_sha: after_sha.clone(),
+                    conflicted_files: Vec::new(),
+                    error: None,
+                };
+                match outcome {
+                    Ok(Ok(())) => {
+                        let (label, rebased_commits) = rebase_result(repo, after_sha.as_deref());
+                        result.result = label.to_string();
+                        result.rebased_commits = rebased_commits;
+                    }
+                    Ok(Err(RebaseFailure::MergeConflicts { conflicted_files })) => {
+                        result.result = "conflict".to_string();
+                        result.conflicted_files = conflicted_files;
+                    }
+                    Ok(Err(RebaseFailure::RebaseInProgress)) => {
+                        result.result = "rebase_in_progress".to_string();
+                        result.conflicted_files = repo.conflicted_files.clone();
+                    }
+                    Err(e) => {
+                        result.result = "failed".to_string();
+                        result.error = Some(describe_error(&e));
+                    }
+                }
+                result
+            })
+            .collect();
+
+        let has_conflicts = repos
+            .iter()
+            .any(|repo| matches!(repo.result.as_str(), "conflict" | "rebase_in_progress"));
+        self.success(&McpRebaseWorkspaceResponse {
+            workspace_id: workspace_id.to_string(),
+            repos,
+            has_conflicts,
+        })
+    }
+
+    #[tool(
+        description = "Delete a local workspace. `workspace_id` is optional if running inside that workspace context.",
+        output_schema = output_schema::<McpDeleteWorkspaceResponse>()
     )]
     async fn delete_workspace(
         &self,
@@ -240,7 +1110,7 @@ impl McpServer {
             return Ok(Self::tool_error(e));
         }
 
-        McpServer::success(&McpDeleteWorkspaceResponse {
+        self.success(&McpDeleteWorkspaceResponse {
             success: true,
             workspace_id: workspace_id.to_string(),
             delete_remote,
@@ -248,3 +1118,1064 @@ impl McpServer {
         })
     }
 }
+
+impl McpServer {
+    // Sets the given flags on a workspace, skipping the update when they already match.
+    async fn set_workspace_state(
+        &self,
+        workspace_id: Option<Uuid>,
+        archived: Option<bool>,
+        pinned: Option<bool>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let workspace_id = match self.resolve_workspace_id(workspace_id) {
+            Ok(id) => id,
+            Err(error_result) => return Ok(Self::tool_error(error_result)),
+        };
+        if let Err(error_result) = self.scope_allows_workspace(workspace_id) {
+            return Ok(Self::tool_error(error_result));
+        }
+
+        let url = self.url(&format!("/api/workspaces/{}", workspace_id));
+        let current: Workspace = match self.send_json(self.client.get(&url)).await {
+            Ok(ws) => ws,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+        if archived.is_none_or(|archived| archived == current.archived)
+            && pinned.is_none_or(|pinned| pinned == current.pinned)
+        {
+            return self.success(&McpWorkspaceStateResponse {
+                workspace: WorkspaceSummary::from_workspace(current),
+                changed: false,
+            });
+        }
+
+        let payload = UpdateWorkspace {
+            archived,
+            pinned,
+            name: None,
+        };
+        let updated: Workspace = match self.send_json(self.client.put(&url).json(&payload)).await {
+            Ok(ws) => ws,
+            Err(e) => return Ok(Self::tool_error(e)),
+        };
+
+        self.success(&McpWorkspaceStateResponse {
+            workspace: WorkspaceSummary::from_workspace(updated),
+            changed: true,
+        })
+    }
+
+    // Maps local workspace IDs to their linked remote workspaces. Workspaces without a link are
+    // absent from the map, as are those whose lookup failed; the latter are counted. IDs are sent
+    // in chunks of at most `MAX_LOCAL_IDS_PER_LOOKUP`, one bulk request per chunk.
+    async fn fetch_remote_workspace_links(
+        &self,
+        local_workspace_ids: Vec<Uuid>,
+    ) -> RemoteWorkspaceLinks {
+        let url = self.url("/api/remote/workspaces/by-local-ids");
+        let responses: Vec<_> = stream::iter(local_workspace_ids.chunks(MAX_LOCAL_IDS_PER_LOOKUP))
+            .map(|chunk| {
+                let request = ListWorkspacesByLocalIdsRequest {
+                    local_workspace_ids: chunk.to_vec(),
+                };
+                let rb = self.client.post(&url).json(&request);
+                async move {
+                    let response = self.send_json::<ListWorkspacesByLocalIdsResponse>(rb).await;
+                    (chunk.len(), response)
+                }
+            })
+            .buffer_unordered(REMOTE_LINK_FETCH_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut remote_links = RemoteWorkspaceLinks::default();
+        for (chunk_len, response) in responses {
+            match response {
+                Ok(response) => remote_links.links.extend(
+                    response
+                        .workspaces
+                        .into_iter()
+                        .filter_map(|remote| remote.local_workspace_id.map(|id| (id, remote))),
+                ),
+                Err(_) => remote_links.failed_count += chunk_len,
+            }
+        }
+        remote_links
+    }
+}
+
+#[derive(Default)]
+struct RemoteWorkspaceLinks {
+    links: HashMap<Uuid, api_types::Workspace>,
+    // Workspaces whose chunk failed to load, so whether they are linked is unknown.
+    failed_count: usize,
+}
+
+// Picks the repository to open a PR for. Without an explicit `repo_id` this only succeeds when
+// the workspace has exactly one repository.
+fn pr_repo(
+    repos: &[RepoWithTargetBranch],
+    repo_id: Option<Uuid>,
+) -> Result<&RepoWithTargetBranch, ToolError> {
+    let available = || {
+        repos
+            .iter()
+            .map(|repo| format!("{} ({})", repo.repo.name, repo.repo.id))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match (repo_id, repos) {
+        (Some(repo_id), _) => repos
+            .iter()
+            .find(|repo| repo.repo.id == repo_id)
+            .ok_or_else(|| {
+                ToolError::new(
+                    format!("Repository {repo_id} is not part of this workspace"),
+                    Some(format!("Workspace repositories: {}", available())),
+                )
+            }),
+        (None, [repo]) => Ok(repo),
+        (None, []) => Err(ToolError::message("Workspace has no repositories")),
+        (None, _) => Err(ToolError::new(
+            format!(
+                "repo_id is required because the workspace has {} repositories",
+                repos.len()
+            ),
+            Some(format!("Workspace repositories: {}", available())),
+        )),
+    }
+}
+
+// Push and provider failures that the server doesn't classify arrive as HTTP errors whose
+// details carry git's own message, e.g. a rejected non-fast-forward push.
+fn pr_push_error(error: ToolError) -> ToolError {
+    if error.status.is_none() || error.hint.is_some() {
+        return error;
+    }
+    ToolError {
+        message: format!("Failed to push the branch or open the PR: {}", error.message),
+        hint: Some(
+            "See `details` for the underlying error. Check that the workspace branch has commits ahead of the target branch and that the remote branch hasn't diverged."
+                .to_string(),
+        ),
+        ..error
+    }
+}
+
+fn pr_failure_error(failure: CreateWorkspacePrFailure) -> ToolError {
+    let provider_cli = |provider: &str| match provider {
+        "git_hub" => "GitHub CLI (`gh`)",
+        "azure_dev_ops" => "Azure CLI (`az`)",
+        _ => "git host CLI",
+    };
+    let (message, hint) = match failure {
+        CreateWorkspacePrFailure::CliNotInstalled { provider } => (
+            format!("The {} is not installed", provider_cli(&provider)),
+            "Install it on the machine running Vibe Kanban, then retry.".to_string(),
+        ),
+        CreateWorkspacePrFailure::CliNotLoggedIn { provider } => (
+            format!("The {} is not logged in", provider_cli(&provider)),
+            "Log in with the CLI on the machine running Vibe Kanban, then retry.".to_string(),
+        ),
+        CreateWorkspacePrFailure::GitCliNotLoggedIn => (
+            "Git could not authenticate with the remote to push the branch".to_string(),
+            "Configure git credentials for the remote, then retry.".to_string(),
+        ),
+        CreateWorkspacePrFailure::GitCliNotInstalled => (
+            "Git is not installed".to_string(),
+            "Install git on the machine running Vibe Kanban, then retry.".to_string(),
+        ),
+        CreateWorkspacePrFailure::TargetBranchNotFound { branch } => (
+            format!("Target branch '{branch}' does not exist on the remote"),
+            "Pass an existing branch as `target_branch`.".to_string(),
+        ),
+        CreateWorkspacePrFailure::UnsupportedProvider => (
+            "The repository's remote is not on a supported git host".to_string(),
+            "Pull requests can be opened for GitHub and Azure DevOps remotes.".to_string(),
+        ),
+    };
+    ToolError {
+        hint: Some(hint),
+        ..ToolError::message(message)
+    }
+}
+
+// Classifies a successful rebase. Without a HEAD to compare against, falls back on whether the
+// branch was behind its target beforehand.
+fn rebase_result(
+    before: &RepoBranchState,
+    after_sha: Option<&str>,
+) -> (&'static str, Option<usize>) {
+    let moved = match (before.head_oid.as_deref(), after_sha) {
+        (Some(before_sha), Some(after_sha)) => before_sha != after_sha,
+        _ => before.commits_behind != Some(0),
+    };
+    match before.commits_ahead {
+        _ if !moved => ("up_to_date", None),
+        Some(0) => ("fast_forwarded", None),
+        commits_ahead => ("rebased", commits_ahead),
+    }
+}
+
+// PR URLs end in the PR number on every supported host, e.g. `.../pull/42`.
+fn pr_number_from_url(url: &str) -> Option<i32> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+fn changed_file(file: FileDiffSummary) -> McpChangedFile {
+    let change = match file.change {
+        DiffChangeKind::Added => "added",
+        DiffChangeKind::Deleted => "deleted",
+        DiffChangeKind::Modified => "modified",
+        DiffChangeKind::Renamed => "renamed",
+        DiffChangeKind::Copied => "copied",
+        DiffChangeKind::PermissionChange => "permission_change",
+    };
+    McpChangedFile {
+        path: file.path,
+        old_path: file.old_path,
+        change: change.to_string(),
+        additions: file.additions,
+        deletions: file.deletions,
+        binary: file.binary,
+        patch: file.patch,
+        patch_truncated: file.patch_truncated,
+    }
+}
+
+impl McpServer {
+    fn parse_workspace_sort_field(sort_by: Option<&str>) -> Result<WorkspaceSortField, ToolError> {
+        let sort_by = sort_by.unwrap_or("created_at").trim().to_ascii_lowercase();
+        WORKSPACE_SORT_FIELDS
+            .iter()
+            .find(|(name, _)| *name == sort_by)
+            .map(|(_, field)| *field)
+            .ok_or_else(|| {
+                let allowed = WORKSPACE_SORT_FIELDS
+                    .iter()
+                    .map(|(name, _)| format!("'{name}'"))
+                    .collect::<Vec<_>>();
+                ToolError::message(format!(
+                    "Unknown sort_by '{}'. Allowed values: [{}]",
+                    sort_by,
+                    allowed.join(", ")
+                ))
+            })
+    }
+}
+
+fn sort_field_label(sort_field: WorkspaceSortField) -> &'static str {
+    WORKSPACE_SORT_FIELDS
+        .iter()
+        .find(|(_, field)| *field == sort_field)
+        .map(|(name, _)| *name)
+        .unwrap_or("created_at")
+}
+
+// Sorts by the requested field, newest first among ties so the order is deterministic.
+// Workspaces without a name sort after named ones in either direction.
+fn sort_workspaces(
+    workspaces: &mut [Workspace],
+    sort_field: WorkspaceSortField,
+    sort_direction: SortDirection,
+) {
+    let directed = |ordering: Ordering| match sort_direction {
+        SortDirection::Asc => ordering,
+        SortDirection::Desc => ordering.reverse(),
+    };
+    workspaces.sort_by(|a, b| {
+        let ordering = match sort_field {
+            WorkspaceSortField::CreatedAt => directed(a.created_at.cmp(&b.created_at)),
+            WorkspaceSortField::UpdatedAt => directed(a.updated_at.cmp(&b.updated_at)),
+            WorkspaceSortField::Branch => {
+                directed(a.branch.to_lowercase().cmp(&b.branch.to_lowercase()))
+            }
+            WorkspaceSortField::Name => {
+                let a_name = a.name.as_deref().map(str::to_lowercase);
+                let b_name = b.name.as_deref().map(str::to_lowercase);
+                a_name
+                    .is_none()
+                    .cmp(&b_name.is_none())
+                    .then_with(|| directed(a_name.cmp(&b_name)))
+            }
+        };
+        ordering
+            .then_with(|| b.created_at.cmp(&a.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{self, AtomicBool},
+    };
+
+    use axum::{
+        Json, Router,
+        http::StatusCode,
+        routing::{get, post},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::task_server::tools::test_support::{MockBackend, envelope, execution_process_json};
+
+    fn local_workspace_json(id: Uuid) -> serde_json::Value {
+        json!({
+            "id": id,
+            "task_id": null,
+            "container_ref": null,
+            "branch": format!("vk/{id}"),
+            "setup_completed_at": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "archived": false,
+            "pinned": false,
+            "name": null,
+            "worktree_deleted": false,
+        })
+    }
+
+    fn remote_workspace_json(
+        local_id: Uuid,
+        project_id: Uuid,
+        issue_id: Option<Uuid>,
+    ) -> serde_json::Value {
+        json!({
+            "id": Uuid::new_v4(),
+            "project_id": project_id,
+            "owner_user_id": Uuid::new_v4(),
+            "issue_id": issue_id,
+            "local_workspace_id": local_id,
+            "name": null,
+            "archived": false,
+            "files_changed": null,
+            "lines_added": null,
+            "lines_removed": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    #[tokio::test]
+    async fn list_workspaces_filters_by_linked_issue_with_one_lookup() {
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let (linked, other_issue, unlinked) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let remote_links = json!({ "workspaces": [
+            remote_workspace_json(linked, project_id, Some(issue_id)),
+            remote_workspace_json(other_issue, project_id, Some(Uuid::new_v4())),
+        ] });
+
+        let router = Router::new()
+            .route(
+                "/api/workspaces",
+                get(move || async move {
+                    envelope(json!([
+                        local_workspace_json(linked),
+                        local_workspace_json(other_issue),
+                        local_workspace_json(unlinked),
+                    ]))
+                }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-ids",
+                post(move || async move { envelope(remote_links) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "issue_id": issue_id })).unwrap();
+        let result = backend
+            .server()
+            .list_workspaces(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_eq!(body["total_count"], 1);
+        assert_eq!(body["workspaces"][0]["id"], linked.to_string());
+        assert_eq!(
+            body["workspaces"][0]["linked_issue_id"],
+            issue_id.to_string()
+        );
+        assert_eq!(
+            backend.requests_to("/api/remote/workspaces/by-local-ids"),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn list_workspaces_chunks_remote_link_lookups() {
+        let project_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let local_ids: Vec<Uuid> = (0..MAX_LOCAL_IDS_PER_LOOKUP + 1)
+            .map(|_| Uuid::new_v4())
+            .collect();
+        let linked = *local_ids.last().unwrap();
+        let local_workspaces: Vec<_> = local_ids
+            .iter()
+            .copied()
+            .map(local_workspace_json)
+            .collect();
+
+        let router = Router::new()
+            .route(
+                "/api/workspaces",
+                get(move || async move { envelope(json!(local_workspaces)) }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-ids",
+                post(
+                    move |Json(request): Json<ListWorkspacesByLocalIdsRequest>| async move {
+                        assert!(request.local_workspace_ids.len() <= MAX_LOCAL_IDS_PER_LOOKUP);
+                        let workspaces: Vec<_> = request
+                            .local_workspace_ids
+                            .into_iter()
+                            .filter(|id| *id == linked)
+                            .map(|id| remote_workspace_json(id, project_id, Some(issue_id)))
+                            .collect();
+                        envelope(json!({ "workspaces": workspaces }))
+                    },
+                ),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "issue_id": issue_id })).unwrap();
+        let result = backend
+            .server()
+            .list_workspaces(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_eq!(body["total_count"], 1);
+        assert_eq!(body["workspaces"][0]["id"], linked.to_string());
+        assert_eq!(
+            backend.requests_to("/api/remote/workspaces/by-local-ids"),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn list_workspaces_warns_when_remote_links_fail_to_load() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let router = Router::new()
+            .route(
+                "/api/workspaces",
+                get(move || async move {
+                    envelope(json!([
+                        local_workspace_json(first),
+                        local_workspace_json(second),
+                    ]))
+                }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-ids",
+                post(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({})).unwrap();
+        let result = backend
+            .server()
+            .list_workspaces(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["total_count"], 2);
+        assert!(body["workspaces"][0].get("linked_issue_id").is_none());
+        assert_eq!(
+            body["warning"],
+            "Remote links could not be loaded for 2 workspace(s)"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_workspace_combines_repos_link_and_latest_run() {
+        let workspace_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let process_id = Uuid::new_v4();
+        let remote_link = remote_workspace_json(workspace_id, Uuid::new_v4(), Some(issue_id));
+
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}",
+                get(move || async move { envelope(local_workspace_json(workspace_id)) }),
+            )
+            .route(
+                "/api/workspaces/{id}/repos",
+                get(|| async {
+                    envelope(json!([{
+                        "id": Uuid::new_v4(),
+                        "path": "/repos/app",
+                        "name": "app",
+                        "display_name": "app",
+                        "setup_script": null,
+                        "cleanup_script": null,
+                        "archive_script": null,
+                        "copy_files": null,
+                        "parallel_setup_script": false,
+                        "dev_server_script": null,
+                        "default_target_branch": "main",
+                        "default_working_dir": null,
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "updated_at": "2026-01-01T00:00:00Z",
+                        "target_branch": "release",
+                    }]))
+                }),
+            )
+            .route(
+                "/api/workspaces/{id}/execution/latest-coding-agent",
+                get(move || async move {
+                    let mut process = execution_process_json("codingagent", "running");
+                    process["id"] = json!(process_id);
+                    envelope(process)
+                }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-id/{id}",
+                get(move || async move { envelope(remote_link) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .get_workspace(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["workspace"]["linked_issue_id"], issue_id.to_string());
+        assert_eq!(body["repos"][0]["repo_name"], "app");
+        assert_eq!(body["repos"][0]["target_branch"], "release");
+        assert_eq!(body["latest_run"]["status"], "running");
+        assert_eq!(body["latest_run"]["finished_at"], serde_json::Value::Null);
+        // The issue lookup failed, so its simple ID degrades to null.
+        assert_eq!(body["linked_issue_simple_id"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn get_workspace_without_a_coding_agent_run_has_no_latest_run() {
+        let workspace_id = Uuid::new_v4();
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}",
+                get(move || async move { envelope(local_workspace_json(workspace_id)) }),
+            )
+            .route(
+                "/api/workspaces/{id}/repos",
+                get(|| async { envelope(json!([])) }),
+            )
+            .route(
+                "/api/workspaces/{id}/execution/latest-coding-agent",
+                get(|| async { envelope(json!(null)) }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .get_workspace(Parameters(request))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["workspace"]["id"], workspace_id.to_string());
+        assert_eq!(body["latest_run"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn archive_workspace_is_a_no_op_when_already_archived() {
+        let workspace_id = Uuid::new_v4();
+        let router = Router::new().route(
+            "/api/workspaces/{id}",
+            get(move || async move {
+                let mut workspace = local_workspace_json(workspace_id);
+                workspace["archived"] = json!(true);
+                envelope(workspace)
+            })
+            .put(move |Json(body): Json<serde_json::Value>| async move {
+                let mut workspace = local_workspace_json(workspace_id);
+                workspace["archived"] = json!(true);
+                workspace["pinned"] = body["pinned"].clone();
+                envelope(workspace)
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let path = format!("/api/workspaces/{}", workspace_id);
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = server.archive_workspace(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["changed"], false);
+        assert_eq!(body["workspace"]["archived"], true);
+        assert_eq!(backend.requests_to(&path), 1);
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = server.pin_workspace(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["changed"], true);
+        assert_eq!(body["workspace"]["pinned"], true);
+        assert_eq!(backend.requests_to(&path), 3);
+    }
+
+    #[tokio::test]
+    async fn rename_workspace_returns_old_and_new_names() {
+        let workspace_id = Uuid::new_v4();
+        let router = Router::new().route(
+            "/api/workspaces/{id}",
+            get(move || async move {
+                let mut workspace = local_workspace_json(workspace_id);
+                workspace["name"] = json!("Old scope");
+                envelope(workspace)
+            })
+            .put(move |Json(body): Json<serde_json::Value>| async move {
+                let mut workspace = local_workspace_json(workspace_id);
+                workspace["name"] = body["name"].clone();
+                envelope(workspace)
+            }),
+        );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "name": "  New scope  ",
+        }))
+        .unwrap();
+        let result = server.rename_workspace(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert_eq!(body["old_name"], "Old scope");
+        assert_eq!(body["new_name"], "New scope");
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "name": "   ",
+        }))
+        .unwrap();
+        let result = server.rename_workspace(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            backend.requests_to(&format!("/api/workspaces/{}", workspace_id)),
+            2
+        );
+    }
+
+    fn workspace_repo_json(repo_id: Uuid, name: &str) -> serde_json::Value {
+        json!({
+            "id": repo_id,
+            "path": format!("/repos/{name}"),
+            "name": name,
+            "display_name": name,
+            "setup_script": null,
+            "cleanup_script": null,
+            "archive_script": null,
+            "copy_files": null,
+            "parallel_setup_script": false,
+            "dev_server_script": null,
+            "default_target_branch": "main",
+            "default_working_dir": null,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "target_branch": "release",
+        })
+    }
+
+    #[tokio::test]
+    async fn create_workspace_pr_picks_the_repo_and_records_the_pr_on_the_linked_issue() {
+        let workspace_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        let (app_id, docs_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let remote_link = remote_workspace_json(workspace_id, Uuid::new_v4(), Some(issue_id));
+
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}/repos",
+                get(move || async move {
+                    envelope(json!([
+                        workspace_repo_json(app_id, "app"),
+                        workspace_repo_json(docs_id, "docs"),
+                    ]))
+                }),
+            )
+            .route(
+                "/api/workspaces/{id}/pull-requests",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["repo_id"], app_id.to_string());
+                    if body["target_branch"] == "missing" {
+                        return Json(json!({
+                            "success": false,
+                            "data": null,
+                            "error_data": { "type": "target_branch_not_found", "branch": "missing" },
+                            "message": null,
+                        }));
+                    }
+                    envelope(json!("https://github.com/acme/app/pull/42"))
+                }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-id/{id}",
+                get(move || async move { envelope(remote_link) }),
+            )
+            .route(
+                "/api/remote/pull-requests",
+                get(|| async { envelope(json!({ "pull_requests": [] })) }),
+            )
+            .route(
+                "/api/remote/pull-request-issues",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["issue_id"], issue_id.to_string());
+                    assert_eq!(body["number"], 42);
+                    assert_eq!(body["target_branch_name"], "release");
+                    envelope(json!({
+                        "data": {
+                            "id": Uuid::new_v4(),
+                            "pull_request_id": Uuid::new_v4(),
+                            "issue_id": issue_id,
+                        },
+                        "txid": 1,
+                    }))
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let create_path = format!("/api/workspaces/{}/pull-requests", workspace_id);
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "title": "Add login",
+        }))
+        .unwrap();
+        let result = server
+            .create_workspace_pr(Parameters(request))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["error"],
+            "repo_id is required because the workspace has 2 repositories"
+        );
+        assert_eq!(backend.requests_to(&create_path), 0);
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": app_id,
+            "title": "Add login",
+            "target_branch": "missing",
+        }))
+        .unwrap();
+        let result = server
+            .create_workspace_pr(Parameters(request))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(
+            body["error"],
+            "Target branch 'missing' does not exist on the remote"
+        );
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "repo_id": app_id,
+            "title": "Add login",
+            "body": "Adds the login form.",
+        }))
+        .unwrap();
+        let result = server
+            .create_workspace_pr(Parameters(request))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["number"], 42);
+        assert_eq!(body["status"], "open");
+        assert_eq!(body["target_branch"], "release");
+        assert_eq!(body["linked_issue_id"], issue_id.to_string());
+        assert!(body.get("issue_link_warning").is_none());
+        assert_eq!(backend.requests_to("/api/remote/pull-request-issues"), 1);
+    }
+
+    #[tokio::test]
+    async fn rebase_workspace_reports_each_repo_and_leaves_conflicts_unresolved() {
+        let workspace_id = Uuid::new_v4();
+        let (app_id, docs_id, site_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let rebased = Arc::new(AtomicBool::new(false));
+        fn branch_status(
+            repo_id: Uuid,
+            name: &str,
+            head: &str,
+            ahead: usize,
+            behind: usize,
+        ) -> serde_json::Value {
+            json!({
+                "repo_id": repo_id,
+                "repo_name": name,
+                "head_oid": head,
+                "commits_ahead": ahead,
+                "commits_behind": behind,
+                "target_branch_name": "main",
+                "is_rebase_in_progress": false,
+                "conflicted_files": [],
+            })
+        }
+
+        let router = Router::new()
+            .route(
+                "/api/workspaces/{id}/git/status",
+                get({
+                    let rebased = rebased.clone();
+                    move || async move {
+                        let app_head = if rebased.load(atomic::Ordering::SeqCst) {
+                            "bbb"
+                        } else {
+                            "aaa"
+                        };
+                        envelope(json!([
+                            branch_status(app_id, "app", app_head, 2, 3),
+                            branch_status(docs_id, "docs", "ccc", 1, 1),
+                            branch_status(site_id, "site", "ddd", 0, 0),
+                        ]))
+                    }
+                }),
+            )
+            .route(
+                "/api/workspaces/{id}/git/rebase",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    if body["repo_id"] == docs_id.to_string() {
+                        return Json(json!({
+                            "success": false,
+                            "data": null,
+                            "error_data": {
+                                "type": "merge_conflicts",
+                                "message": "Rebase stopped with conflicts",
+                                "op": "rebase",
+                                "conflicted_files": ["src/lib.rs"],
+                                "target_branch": "main",
+                            },
+                            "message": null,
+                        }));
+                    }
+                    if body["repo_id"] == app_id.to_string() {
+                        rebased.store(true, atomic::Ordering::SeqCst);
+                    }
+                    envelope(serde_json::Value::Null)
+                }),
+            );
+        let backend = MockBackend::start(router).await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .rebase_workspace(Parameters(request))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+
+        assert_eq!(body["has_conflicts"], true);
+        let app = &body["repos"][0];
+        assert_eq!(app["result"], "rebased");
+        assert_eq!(app["rebased_commits"], 2);
+        assert_eq!(app["before_sha"], "aaa");
+        assert_eq!(app["after_sha"], "bbb");
+        let docs = &body["repos"][1];
+        assert_eq!(docs["result"], "conflict");
+        assert_eq!(docs["conflicted_files"], json!(["src/lib.rs"]));
+        assert_eq!(body["repos"][2]["result"], "up_to_date");
+        assert_eq!(
+            backend.requests_to(&format!("/api/workspaces/{}/git/rebase", workspace_id)),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn diff_summary_marks_binary_files_and_truncated_patches() {
+        let workspace_id = Uuid::new_v4();
+        let repo_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new().route(
+            "/api/workspaces/{id}/git/diff-summary",
+            get(move || async move {
+                envelope(json!({
+                    "repos": [{
+                        "repo_id": repo_id,
+                        "repo_name": "app",
+                        "target_branch": "main",
+                        "files": [
+                            {
+                                "path": "src/lib.rs",
+                                "old_path": null,
+                                "change": "modified",
+                                "additions": 40,
+                                "deletions": 2,
+                                "binary": false,
+                                "patch": "--- a/src/lib.rs\n+++ b/src/lib.rs\n",
+                                "patch_truncated": true,
+                            },
+                            {
+                                "path": "logo.png",
+                                "old_path": null,
+                                "change": "added",
+                                "additions": null,
+                                "deletions": null,
+                                "binary": true,
+                                "patch": null,
+                                "patch_truncated": false,
+                            },
+                        ],
+                        "additions": 40,
+                        "deletions": 2,
+                    }],
+                    "files_changed": 2,
+                    "additions": 40,
+                    "deletions": 2,
+                    "truncated": true,
+                }))
+            }),
+        ))
+        .await;
+
+        let request = serde_json::from_value(json!({
+            "workspace_id": workspace_id,
+            "include_patch": true,
+            "max_patch_bytes": 32,
+        }))
+        .unwrap();
+        let result = backend
+            .server()
+            .get_workspace_diff_summary(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        let files = &body["repos"][0]["files"];
+        assert_eq!(files[0]["patch_truncated"], true);
+        assert_eq!(files[1]["binary"], true);
+        assert_eq!(files[1]["patch"], serde_json::Value::Null);
+        assert_eq!(body["truncated"], true);
+    }
+
+    #[tokio::test]
+    async fn update_workspace_requires_a_field_to_change() {
+        let workspace_id = Uuid::new_v4();
+        let backend = MockBackend::start(Router::new()).await;
+
+        let request = serde_json::from_value(json!({ "workspace_id": workspace_id })).unwrap();
+        let result = backend
+            .server()
+            .update_workspace(Parameters(request))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            backend.requests_to(&format!("/api/workspaces/{}", workspace_id)),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn list_workspaces_sorts_before_paginating_and_echoes_the_sort() {
+        let named = |name: Option<&str>, created_at: &str| {
+            let mut workspace = local_workspace_json(Uuid::new_v4());
+            workspace["name"] = json!(name);
+            workspace["created_at"] = json!(created_at);
+            workspace
+        };
+        let workspaces = json!([
+            named(Some("beta"), "2026-01-03T00:00:00Z"),
+            named(None, "2026-01-04T00:00:00Z"),
+            named(Some("Alpha"), "2026-01-01T00:00:00Z"),
+            named(Some("gamma"), "2026-01-02T00:00:00Z"),
+        ]);
+        let router = Router::new()
+            .route(
+                "/api/workspaces",
+                get(move || async move { envelope(workspaces) }),
+            )
+            .route(
+                "/api/remote/workspaces/by-local-ids",
+                post(|| async { envelope(json!({ "workspaces": [] })) }),
+            );
+        let backend = MockBackend::start(router).await;
+        let server = backend.server();
+        let names = |body: &serde_json::Value| {
+            body["workspaces"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|workspace| workspace["name"].as_str().map(str::to_string))
+                .collect::<Vec<_>>()
+        };
+
+        for (direction, expected) in [
+            ("asc", [Some("Alpha"), Some("beta")]),
+            ("desc", [Some("gamma"), Some("beta")]),
+        ] {
+            let request = serde_json::from_value(json!({
+                "sort_by": "name",
+                "sort_direction": direction,
+                "limit": 2,
+            }))
+            .unwrap();
+            let result = server.list_workspaces(Parameters(request)).await.unwrap();
+            let body: serde_json::Value =
+                serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+            assert_eq!(names(&body), expected.map(|name| name.map(str::to_string)));
+            assert_eq!(body["sort_by"], "name");
+            assert_eq!(body["sort_direction"], direction);
+        }
+
+        let request = serde_json::from_value(json!({ "sort_by": "name", "offset": 3 })).unwrap();
+        let result = server.list_workspaces(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(names(&body), [None]);
+
+        let request = serde_json::from_value(json!({})).unwrap();
+        let result = server.list_workspaces(Parameters(request)).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(names(&body)[0], None);
+        assert_eq!(
+            (&body["sort_by"], &body["sort_direction"]),
+            (&json!("created_at"), &json!("desc"))
+        );
+
+        let request = serde_json::from_value(json!({ "sort_by": "size" })).unwrap();
+        let result = server.list_workspaces(Parameters(request)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            result.content[0]
+                .as_text()
+                .unwrap()
+                .text
+                .contains("'created_at', 'updated_at', 'name', 'branch'")
+        );
+    }
+}
@@ -0,0 +1,10 @@
+-- Support paginated REST fallback lists, which filter on the scope column and
+-- page through rows ordered by updated_at.
+CREATE INDEX IF NOT EXISTS idx_issues_project_id_updated_at
+    ON issues(project_id, updated_at, id);
+CREATE INDEX IF NOT EXISTS idx_issue_comments_issue_id_updated_at
+    ON issue_comments(issue_id, updated_at, id);
+CREATE INDEX IF NOT EXISTS idx_workspaces_project_id_updated_at
+    ON workspaces(project_id, updated_at, id);
+CREATE INDEX IF NOT EXISTS idx_pull_requests_project_id_updated_at
+    ON pull_requests(project_id, updated_at, id);
@@ -0,0 +1,3 @@
+-- Archived issues stay in the database (and in shapes) so they can be restored;
+-- clients hide them unless asked to include archived issues.
+ALTER TABLE issues ADD COLUMN archived_at TIMESTAMPTZ;
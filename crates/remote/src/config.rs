@@ -15,6 +15,7 @@ pub struct RemoteServerConfig {
     pub electric_secret: Option<SecretString>,
     pub electric_role_password: Option<SecretString>,
     pub electric_publication_names: Vec<String>,
+    pub electric_proxy_limits: ElectricProxyLimits,
     pub r2: Option<R2Config>,
     pub azure_blob: Option<AzureBlobConfig>,
     pub review_worker_base_url: Option<String>,
@@ -22,6 +23,39 @@ pub struct RemoteServerConfig {
     pub github_app: Option<GitHubAppConfig>,
 }
 
+/// Concurrency limits for requests proxied to Electric.
+#[derive(Debug, Clone)]
+pub struct ElectricProxyLimits {
+    /// Live (long-poll) shape requests a single user may hold open at once.
+    pub max_live_per_user: usize,
+    /// Non-live (catch-up) shape requests a single user may have in flight at once.
+    pub max_catch_up_per_user: usize,
+    /// Shape requests of any kind in flight across all users.
+    pub max_total: usize,
+}
+
+impl ElectricProxyLimits {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            max_live_per_user: limit_from_env("ELECTRIC_PROXY_MAX_LIVE_PER_USER", 100)?,
+            max_catch_up_per_user: limit_from_env("ELECTRIC_PROXY_MAX_CATCH_UP_PER_USER", 200)?,
+            max_total: limit_from_env("ELECTRIC_PROXY_MAX_TOTAL", 5000)?,
+        })
+    }
+}
+
+fn limit_from_env(name: &'static str, default: usize) -> Result<usize, ConfigError> {
+    match env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|limit| *limit > 0)
+            .ok_or(ConfigError::InvalidVar(name)),
+        Err(_) => Ok(default),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct R2Config {
     pub access_key_id: String,
@@ -245,6 +279,7 @@ impl RemoteServerConfig {
             Ok(value) => parse_publication_names(&value)?,
             Err(_) => Vec::new(),
         };
+        let electric_proxy_limits = ElectricProxyLimits::from_env()?;
 
         let r2 = R2Config::from_env()?;
         let azure_blob = AzureBlobConfig::from_env()?;
@@ -267,6 +302,7 @@ impl RemoteServerConfig {
             electric_secret,
             electric_role_password,
             electric_publication_names,
+            electric_proxy_limits,
             r2,
             azure_blob,
             review_worker_base_url,
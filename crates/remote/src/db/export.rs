@@ -105,6 +105,7 @@ impl ExportRepository {
                 start_date          AS "start_date?: DateTime<Utc>",
                 target_date         AS "target_date?: DateTime<Utc>",
                 completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
                 sort_order          AS "sort_order!",
                 parent_issue_id     AS "parent_issue_id?: Uuid",
                 parent_issue_sort_order AS "parent_issue_sort_order?",
@@ -10,7 +10,7 @@ use super::{
     organization_members::{add_member, assert_admin},
     organizations::{Organization, OrganizationRepository, is_personal_org},
 };
-use crate::db::organization_members::is_member;
+use crate::db::organization_members::{is_member, is_member_by_email};
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Invitation {
@@ -55,6 +55,12 @@ impl<'a> InvitationRepository<'a> {
             ));
         }
 
+        if is_member_by_email(self.pool, organization_id, email).await? {
+            return Err(IdentityError::InvitationError(
+                "User is already a member of this organization".to_string(),
+            ));
+        }
+
         let invitation = sqlx::query_as!(
             Invitation,
             r#"
@@ -83,6 +83,37 @@ impl IssueAssigneeRepository {
         Ok(records)
     }
 
+    /// Lists a user's assignments on issues in any project of an organization.
+    pub async fn list_by_organization_user(
+        pool: &PgPool,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<IssueAssignee>, IssueAssigneeError> {
+        let records = sqlx::query_as!(
+            IssueAssignee,
+            r#"
+            SELECT
+                id          AS "id!: Uuid",
+                issue_id    AS "issue_id!: Uuid",
+                user_id     AS "user_id!: Uuid",
+                assigned_at AS "assigned_at!: DateTime<Utc>"
+            FROM issue_assignees
+            WHERE user_id = $2
+              AND issue_id IN (
+                  SELECT i.id
+                  FROM issues i
+                  JOIN projects p ON p.id = i.project_id
+                  WHERE p.organization_id = $1
+              )
+            "#,
+            organization_id,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(records)
+    }
+
     pub async fn create(
         pool: &PgPool,
         id: Option<Uuid>,
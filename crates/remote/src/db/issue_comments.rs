@@ -4,7 +4,7 @@ use sqlx::PgPool;
 use thiserror::Error;
 use uuid::Uuid;
 
-use super::get_txid;
+use super::{UpdatedPage, get_txid};
 
 #[derive(Debug, Error)]
 pub enum IssueCommentError {
@@ -155,4 +155,53 @@ impl IssueCommentRepository {
 
         Ok(records)
     }
+
+    /// Lists an issue's comments ordered by `updated_at`, with the total number of matches.
+    pub async fn list_page_by_issue(
+        pool: &PgPool,
+        issue_id: Uuid,
+        page: UpdatedPage,
+    ) -> Result<(Vec<IssueComment>, i64), IssueCommentError> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)::BIGINT
+            FROM issue_comments
+            WHERE issue_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            "#,
+            issue_id,
+            page.updated_after
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        let records = sqlx::query_as!(
+            IssueComment,
+            r#"
+            SELECT
+                id          AS "id!: Uuid",
+                issue_id    AS "issue_id!: Uuid",
+                author_id   AS "author_id: Uuid",
+                parent_id   AS "parent_id: Uuid",
+                message     AS "message!",
+                created_at  AS "created_at!: DateTime<Utc>",
+                updated_at  AS "updated_at!: DateTime<Utc>"
+            FROM issue_comments
+            WHERE issue_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $3
+            OFFSET $4
+            "#,
+            issue_id,
+            page.updated_after,
+            page.limit,
+            page.offset
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok((records, total_count))
+    }
 }
@@ -39,6 +39,7 @@ impl IssueRelationshipRepository {
         Ok(record)
     }
 
+    /// Relationships in either direction, so an issue's "blocked by" links are included.
     pub async fn list_by_issue(
         pool: &PgPool,
         issue_id: Uuid,
@@ -53,7 +54,7 @@ impl IssueRelationshipRepository {
                 relationship_type AS "relationship_type!: IssueRelationshipType",
                 created_at        AS "created_at!: DateTime<Utc>"
             FROM issue_relationships
-            WHERE issue_id = $1
+            WHERE issue_id = $1 OR related_issue_id = $1
             "#,
             issue_id
         )
@@ -1,6 +1,6 @@
 use api_types::{
-    DeleteResponse, Issue, IssuePriority, IssueSortField, ListIssuesResponse, MutationResponse,
-    PullRequestStatus, SearchIssuesRequest, SortDirection,
+    DeleteResponse, Issue, IssueCount, IssuePriority, IssueSortField, ListIssuesResponse,
+    MutationResponse, PullRequestStatus, SearchIssuesRequest, SortDirection,
 };
 use chrono::{DateTime, Utc};
 use serde_json::Value;
@@ -9,8 +9,9 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use super::{
-    get_txid, issue_assignees::IssueAssigneeRepository, project_statuses::ProjectStatusRepository,
-    pull_requests::PullRequestRepository, workspaces::WorkspaceRepository,
+    UpdatedPage, get_txid, issue_assignees::IssueAssigneeRepository,
+    project_statuses::ProjectStatusRepository, pull_requests::PullRequestRepository,
+    workspaces::WorkspaceRepository,
 };
 
 #[derive(Debug, Error)]
@@ -42,7 +43,9 @@ impl IssueRepository {
             IssueSortField::Priority => "priority",
             IssueSortField::CreatedAt => "created_at",
             IssueSortField::UpdatedAt => "updated_at",
+            IssueSortField::TargetDate => "target_date",
             IssueSortField::Title => "title",
+            IssueSortField::SimpleId => "simple_id",
         }
     }
 
@@ -72,6 +75,7 @@ impl IssueRepository {
             .map(|search| format!("%{search}%"));
         let simple_id = query.simple_id.as_deref().map(Self::escape_like_pattern);
         let tag_ids = query.tag_ids.as_deref();
+        let exclude_archived = query.include_archived == Some(false);
         let sort_field =
             Self::sort_field_key(query.sort_field.unwrap_or(IssueSortField::SortOrder));
         let sort_direction =
@@ -121,6 +125,11 @@ impl IssueRepository {
                       WHERE it.issue_id = i.id AND it.tag_id = ANY($10)
                   )
               )
+              AND (NOT $11::boolean OR i.archived_at IS NULL)
+              AND ($12::timestamptz IS NULL OR i.created_at > $12)
+              AND ($13::timestamptz IS NULL OR i.updated_at > $13)
+              AND ($14::timestamptz IS NULL OR i.completed_at > $14)
+              AND ($15::timestamptz IS NULL OR i.target_date < $15)
             "#,
             query.project_id,
             query.status_id,
@@ -132,6 +141,11 @@ impl IssueRepository {
             query.assignee_user_id,
             query.tag_id,
             tag_ids,
+            exclude_archived,
+            query.created_after,
+            query.updated_after,
+            query.completed_after,
+            query.target_date_before,
         )
         .fetch_one(pool)
         .await?
@@ -152,6 +166,7 @@ impl IssueRepository {
                 i.start_date          AS "start_date?: DateTime<Utc>",
                 i.target_date         AS "target_date?: DateTime<Utc>",
                 i.completed_at        AS "completed_at?: DateTime<Utc>",
+                i.archived_at         AS "archived_at?: DateTime<Utc>",
                 i.sort_order          AS "sort_order!",
                 i.parent_issue_id     AS "parent_issue_id?: Uuid",
                 i.parent_issue_sort_order AS "parent_issue_sort_order?",
@@ -196,6 +211,11 @@ impl IssueRepository {
                       WHERE it.issue_id = i.id AND it.tag_id = ANY($10)
                   )
               )
+              AND (NOT $15::boolean OR i.archived_at IS NULL)
+              AND ($16::timestamptz IS NULL OR i.created_at > $16)
+              AND ($17::timestamptz IS NULL OR i.updated_at > $17)
+              AND ($18::timestamptz IS NULL OR i.completed_at > $18)
+              AND ($19::timestamptz IS NULL OR i.target_date < $19)
             ORDER BY
                 CASE
                     WHEN $11 = 'sort_order' AND $12 = 'asc' THEN ps.sort_order
@@ -214,7 +234,7 @@ impl IssueRepository {
                 END ASC NULLS LAST,
                 CASE
                     WHEN $11 = 'priority' AND $12 = 'desc' THEN i.priority
-                END DESC NULLS FIRST,
+                END DESC NULLS LAST,
                 CASE
                     WHEN $11 = 'created_at' AND $12 = 'asc' THEN i.created_at
                 END ASC NULLS LAST,
@@ -227,12 +247,24 @@ impl IssueRepository {
                 CASE
                     WHEN $11 = 'updated_at' AND $12 = 'desc' THEN i.updated_at
                 END DESC NULLS LAST,
+                CASE
+                    WHEN $11 = 'target_date' AND $12 = 'asc' THEN i.target_date
+                END ASC NULLS LAST,
+                CASE
+                    WHEN $11 = 'target_date' AND $12 = 'desc' THEN i.target_date
+                END DESC NULLS LAST,
                 CASE
                     WHEN $11 = 'title' AND $12 = 'asc' THEN i.title
                 END ASC NULLS LAST,
                 CASE
                     WHEN $11 = 'title' AND $12 = 'desc' THEN i.title
                 END DESC NULLS LAST,
+                CASE
+                    WHEN $11 = 'simple_id' AND $12 = 'asc' THEN i.issue_number
+                END ASC NULLS LAST,
+                CASE
+                    WHEN $11 = 'simple_id' AND $12 = 'desc' THEN i.issue_number
+                END DESC NULLS LAST,
                 i.issue_number ASC
             LIMIT $13
             OFFSET $14
@@ -251,6 +283,11 @@ impl IssueRepository {
             sort_direction,
             query_limit,
             offset as i64,
+            exclude_archived,
+            query.created_after,
+            query.updated_after,
+            query.completed_after,
+            query.target_date_before,
         )
         .fetch_all(pool)
         .await?;
@@ -265,6 +302,179 @@ impl IssueRepository {
         })
     }
 
+    /// Lists a project's issues ordered by `updated_at`, with the total number of matches.
+    pub async fn list_page_by_project(
+        pool: &PgPool,
+        project_id: Uuid,
+        page: UpdatedPage,
+    ) -> Result<(Vec<Issue>, i64), IssueError> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)::BIGINT
+            FROM issues
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            "#,
+            project_id,
+            page.updated_after
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        let issues = sqlx::query_as!(
+            Issue,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                issue_number        AS "issue_number!",
+                simple_id           AS "simple_id!",
+                status_id           AS "status_id!: Uuid",
+                title               AS "title!",
+                description         AS "description?",
+                priority            AS "priority: IssuePriority",
+                start_date          AS "start_date?: DateTime<Utc>",
+                target_date         AS "target_date?: DateTime<Utc>",
+                completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
+                sort_order          AS "sort_order!",
+                parent_issue_id     AS "parent_issue_id?: Uuid",
+                parent_issue_sort_order AS "parent_issue_sort_order?",
+                extension_metadata  AS "extension_metadata!: Value",
+                creator_user_id     AS "creator_user_id?: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM issues
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $3
+            OFFSET $4
+            "#,
+            project_id,
+            page.updated_after,
+            page.limit,
+            page.offset
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok((issues, total_count))
+    }
+
+    /// Lists issues across every project in an organization, ordered by `updated_at`,
+    /// with the total number of matches.
+    pub async fn list_by_organization(
+        pool: &PgPool,
+        organization_id: Uuid,
+        page: UpdatedPage,
+    ) -> Result<(Vec<Issue>, i64), IssueError> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)::BIGINT
+            FROM issues
+            WHERE project_id IN (SELECT id FROM projects WHERE organization_id = $1)
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            "#,
+            organization_id,
+            page.updated_after
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        let issues = sqlx::query_as!(
+            Issue,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                issue_number        AS "issue_number!",
+                simple_id           AS "simple_id!",
+                status_id           AS "status_id!: Uuid",
+                title               AS "title!",
+                description         AS "description?",
+                priority            AS "priority: IssuePriority",
+                start_date          AS "start_date?: DateTime<Utc>",
+                target_date         AS "target_date?: DateTime<Utc>",
+                completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
+                sort_order          AS "sort_order!",
+                parent_issue_id     AS "parent_issue_id?: Uuid",
+                parent_issue_sort_order AS "parent_issue_sort_order?",
+                extension_metadata  AS "extension_metadata!: Value",
+                creator_user_id     AS "creator_user_id?: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM issues
+            WHERE project_id IN (SELECT id FROM projects WHERE organization_id = $1)
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $3
+            OFFSET $4
+            "#,
+            organization_id,
+            page.updated_after,
+            page.limit,
+            page.offset
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok((issues, total_count))
+    }
+
+    /// Counts a project's unarchived issues per status and priority, along with how many
+    /// of them have an open or merged pull request, without loading the issues.
+    pub async fn count_by_status(
+        pool: &PgPool,
+        project_id: Uuid,
+    ) -> Result<Vec<IssueCount>, IssueError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                i.status_id AS "status_id!: Uuid",
+                i.priority  AS "priority: IssuePriority",
+                COUNT(*)    AS "count!",
+                COUNT(*) FILTER (
+                    WHERE EXISTS (
+                        SELECT 1
+                        FROM pull_request_issues pri
+                        JOIN pull_requests pr ON pr.id = pri.pull_request_id
+                        WHERE pri.issue_id = i.id AND pr.status = 'open'
+                    )
+                )           AS "with_open_pull_requests!",
+                COUNT(*) FILTER (
+                    WHERE EXISTS (
+                        SELECT 1
+                        FROM pull_request_issues pri
+                        JOIN pull_requests pr ON pr.id = pri.pull_request_id
+                        WHERE pri.issue_id = i.id AND pr.status = 'merged'
+                    )
+                )           AS "with_merged_pull_requests!"
+            FROM issues i
+            WHERE i.project_id = $1
+              AND i.archived_at IS NULL
+            GROUP BY i.status_id, i.priority
+            "#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| IssueCount {
+                status_id: row.status_id,
+                priority: row.priority,
+                count: row.count as usize,
+                with_open_pull_requests: row.with_open_pull_requests as usize,
+                with_merged_pull_requests: row.with_merged_pull_requests as usize,
+            })
+            .collect())
+    }
+
     pub async fn find_by_id<'e, E>(executor: E, id: Uuid) -> Result<Option<Issue>, IssueError>
     where
         E: Executor<'e, Database = Postgres>,
@@ -284,6 +494,7 @@ impl IssueRepository {
                 start_date          AS "start_date?: DateTime<Utc>",
                 target_date         AS "target_date?: DateTime<Utc>",
                 completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
                 sort_order          AS "sort_order!",
                 parent_issue_id     AS "parent_issue_id?: Uuid",
                 parent_issue_sort_order AS "parent_issue_sort_order?",
@@ -302,6 +513,51 @@ impl IssueRepository {
         Ok(record)
     }
 
+    /// Looks up an issue by its human-readable simple ID (e.g. "ACME-123") within a project.
+    /// Simple IDs are stored uppercase, so callers should uppercase `simple_id` first.
+    pub async fn find_by_simple_id<'e, E>(
+        executor: E,
+        project_id: Uuid,
+        simple_id: &str,
+    ) -> Result<Option<Issue>, IssueError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let record = sqlx::query_as!(
+            Issue,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                issue_number        AS "issue_number!",
+                simple_id           AS "simple_id!",
+                status_id           AS "status_id!: Uuid",
+                title               AS "title!",
+                description         AS "description?",
+                priority            AS "priority: IssuePriority",
+                start_date          AS "start_date?: DateTime<Utc>",
+                target_date         AS "target_date?: DateTime<Utc>",
+                completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
+                sort_order          AS "sort_order!",
+                parent_issue_id     AS "parent_issue_id?: Uuid",
+                parent_issue_sort_order AS "parent_issue_sort_order?",
+                extension_metadata  AS "extension_metadata!: Value",
+                creator_user_id     AS "creator_user_id?: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM issues
+            WHERE project_id = $1 AND simple_id = $2
+            "#,
+            project_id,
+            simple_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(record)
+    }
+
     pub async fn organization_id(
         pool: &PgPool,
         issue_id: Uuid,
@@ -365,6 +621,7 @@ impl IssueRepository {
                 start_date          AS "start_date?: DateTime<Utc>",
                 target_date         AS "target_date?: DateTime<Utc>",
                 completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
                 sort_order          AS "sort_order!",
                 parent_issue_id     AS "parent_issue_id?: Uuid",
                 parent_issue_sort_order AS "parent_issue_sort_order?",
@@ -470,6 +727,7 @@ impl IssueRepository {
                 start_date          AS "start_date?: DateTime<Utc>",
                 target_date         AS "target_date?: DateTime<Utc>",
                 completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
                 sort_order          AS "sort_order!",
                 parent_issue_id     AS "parent_issue_id?: Uuid",
                 parent_issue_sort_order AS "parent_issue_sort_order?",
@@ -504,6 +762,56 @@ impl IssueRepository {
         Ok(data)
     }
 
+    /// Sets `archived_at` to now when `archived` is true, or clears it otherwise.
+    /// Archiving an already archived issue keeps its original timestamp.
+    pub async fn set_archived(
+        pool: &PgPool,
+        id: Uuid,
+        archived: bool,
+    ) -> Result<MutationResponse<Issue>, IssueError> {
+        let mut tx = super::begin_tx(pool).await?;
+
+        let data = sqlx::query_as!(
+            Issue,
+            r#"
+            UPDATE issues
+            SET
+                archived_at = CASE WHEN $2 THEN COALESCE(archived_at, NOW()) ELSE NULL END,
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                issue_number        AS "issue_number!",
+                simple_id           AS "simple_id!",
+                status_id           AS "status_id!: Uuid",
+                title               AS "title!",
+                description         AS "description?",
+                priority            AS "priority: IssuePriority",
+                start_date          AS "start_date?: DateTime<Utc>",
+                target_date         AS "target_date?: DateTime<Utc>",
+                completed_at        AS "completed_at?: DateTime<Utc>",
+                archived_at         AS "archived_at?: DateTime<Utc>",
+                sort_order          AS "sort_order!",
+                parent_issue_id     AS "parent_issue_id?: Uuid",
+                parent_issue_sort_order AS "parent_issue_sort_order?",
+                extension_metadata  AS "extension_metadata!: Value",
+                creator_user_id     AS "creator_user_id?: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            "#,
+            id,
+            archived
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let txid = get_txid(&mut *tx).await?;
+        tx.commit().await?;
+
+        Ok(MutationResponse { data, txid })
+    }
+
     pub async fn delete(pool: &PgPool, id: Uuid) -> Result<DeleteResponse, IssueError> {
         let mut tx = super::begin_tx(pool).await?;
 
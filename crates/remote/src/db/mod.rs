@@ -32,6 +32,7 @@ pub mod types;
 pub mod users;
 pub mod workspaces;
 
+use chrono::{DateTime, Utc};
 use sqlx::{
     Executor, PgPool, Postgres, Transaction,
     migrate::MigrateError,
@@ -41,6 +42,15 @@ use uuid::Uuid;
 
 pub(crate) type Tx<'a> = Transaction<'a, Postgres>;
 
+/// A window of rows ordered by `updated_at`, optionally limited to rows changed after a
+/// point in time so callers can sync incrementally.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdatedPage {
+    pub updated_after: Option<DateTime<Utc>>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
 /// Per-request context propagated to database transactions via a tokio task-local.
 /// The auth middleware initialises the scope; `begin_tx` reads it.
 #[derive(Clone)]
@@ -75,6 +75,34 @@ where
     Ok(exists)
 }
 
+/// Whether a user with this email address (compared case-insensitively) is already a
+/// member of the organization.
+pub async fn is_member_by_email<'a, E>(
+    executor: E,
+    organization_id: Uuid,
+    email: &str,
+) -> Result<bool, IdentityError>
+where
+    E: Executor<'a, Database = Postgres>,
+{
+    let exists = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1
+            FROM organization_member_metadata omm
+            JOIN users u ON u.id = omm.user_id
+            WHERE omm.organization_id = $1 AND LOWER(u.email) = LOWER($2)
+        ) AS "exists!"
+        "#,
+        organization_id,
+        email
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(exists)
+}
+
 pub(crate) async fn assert_membership(
     pool: &PgPool,
     organization_id: Uuid,
@@ -172,8 +172,29 @@ impl ProjectStatusRepository {
         Ok(MutationResponse { data, txid })
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<DeleteResponse, ProjectStatusError> {
+    /// Deletes a status. With `move_issues_to`, its issues are moved to that status in the same
+    /// transaction; otherwise the delete fails while issues still reference it.
+    pub async fn delete(
+        pool: &PgPool,
+        id: Uuid,
+        move_issues_to: Option<Uuid>,
+    ) -> Result<DeleteResponse, ProjectStatusError> {
         let mut tx = super::begin_tx(pool).await?;
+        if let Some(move_issues_to) = move_issues_to {
+            sqlx::query!(
+                r#"
+                UPDATE issues
+                SET
+                    status_id = $2,
+                    updated_at = NOW()
+                WHERE status_id = $1
+                "#,
+                id,
+                move_issues_to
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
         sqlx::query!("DELETE FROM project_statuses WHERE id = $1", id)
             .execute(&mut *tx)
             .await?;
@@ -1,9 +1,11 @@
 use api_types::{PullRequest, PullRequestStatus};
 use chrono::{DateTime, Utc};
-use sqlx::{Executor, Postgres};
+use sqlx::{Executor, PgPool, Postgres};
 use thiserror::Error;
 use uuid::Uuid;
 
+use super::UpdatedPage;
+
 #[derive(Debug, Error)]
 pub enum PullRequestError {
     #[error("database error: {0}")]
@@ -82,6 +84,59 @@ impl PullRequestRepository {
         Ok(records)
     }
 
+    /// Lists a project's pull requests ordered by `updated_at`, with the total number of matches.
+    pub async fn list_page_by_project(
+        pool: &PgPool,
+        project_id: Uuid,
+        page: UpdatedPage,
+    ) -> Result<(Vec<PullRequest>, i64), PullRequestError> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)::BIGINT
+            FROM pull_requests
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            "#,
+            project_id,
+            page.updated_after
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        let records = sqlx::query_as!(
+            PullRequest,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                url                 AS "url!: String",
+                number              AS "number!: i32",
+                status              AS "status!: PullRequestStatus",
+                merged_at           AS "merged_at: DateTime<Utc>",
+                merge_commit_sha    AS "merge_commit_sha: String",
+                target_branch_name  AS "target_branch_name!: String",
+                project_id          AS "project_id!: Uuid",
+                issue_id            AS "issue_id!: Uuid",
+                workspace_id        AS "workspace_id: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM pull_requests
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $3
+            OFFSET $4
+            "#,
+            project_id,
+            page.updated_after,
+            page.limit,
+            page.offset
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok((records, total_count))
+    }
+
     /// Returns all PR rows matching a URL that belong to projects the user is a member of.
     pub async fn list_by_url_for_user<'e, E>(
         executor: E,
@@ -123,6 +178,40 @@ impl PullRequestRepository {
         Ok(records)
     }
 
+    pub async fn find_by_id<'e, E>(
+        executor: E,
+        id: Uuid,
+    ) -> Result<Option<PullRequest>, PullRequestError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let record = sqlx::query_as!(
+            PullRequest,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                url                 AS "url!: String",
+                number              AS "number!: i32",
+                status              AS "status!: PullRequestStatus",
+                merged_at           AS "merged_at: DateTime<Utc>",
+                merge_commit_sha    AS "merge_commit_sha: String",
+                target_branch_name  AS "target_branch_name!: String",
+                project_id          AS "project_id!: Uuid",
+                issue_id            AS "issue_id!: Uuid",
+                workspace_id        AS "workspace_id: Uuid",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM pull_requests
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(record)
+    }
+
     pub async fn find_by_url_and_project<'e, E>(
         executor: E,
         url: &str,
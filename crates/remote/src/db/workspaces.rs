@@ -4,6 +4,8 @@ use sqlx::PgPool;
 use thiserror::Error;
 use uuid::Uuid;
 
+use super::UpdatedPage;
+
 #[derive(Debug, Error)]
 pub enum WorkspaceError {
     #[error("database error: {0}")]
@@ -85,6 +87,59 @@ impl WorkspaceRepository {
         Ok(records)
     }
 
+    /// Lists a project's workspaces ordered by `updated_at`, with the total number of matches.
+    pub async fn list_page_by_project(
+        pool: &PgPool,
+        project_id: Uuid,
+        page: UpdatedPage,
+    ) -> Result<(Vec<Workspace>, i64), WorkspaceError> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)::BIGINT
+            FROM workspaces
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            "#,
+            project_id,
+            page.updated_after
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        let records = sqlx::query_as!(
+            Workspace,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                owner_user_id       AS "owner_user_id!: Uuid",
+                issue_id            AS "issue_id: Uuid",
+                local_workspace_id  AS "local_workspace_id: Uuid",
+                name                AS "name: String",
+                archived            AS "archived!: bool",
+                files_changed       AS "files_changed: i32",
+                lines_added         AS "lines_added: i32",
+                lines_removed       AS "lines_removed: i32",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM workspaces
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR updated_at > $2)
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $3
+            OFFSET $4
+            "#,
+            project_id,
+            page.updated_after,
+            page.limit,
+            page.offset
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok((records, total_count))
+    }
+
     pub async fn create(
         pool: &PgPool,
         params: CreateWorkspaceParams,
@@ -194,6 +249,37 @@ impl WorkspaceRepository {
         Ok(record)
     }
 
+    pub async fn find_by_local_ids(
+        pool: &PgPool,
+        local_workspace_ids: &[Uuid],
+    ) -> Result<Vec<Workspace>, WorkspaceError> {
+        let records = sqlx::query_as!(
+            Workspace,
+            r#"
+            SELECT
+                id                  AS "id!: Uuid",
+                project_id          AS "project_id!: Uuid",
+                owner_user_id       AS "owner_user_id!: Uuid",
+                issue_id            AS "issue_id: Uuid",
+                local_workspace_id  AS "local_workspace_id: Uuid",
+                name                AS "name: String",
+                archived            AS "archived!: bool",
+                files_changed       AS "files_changed: i32",
+                lines_added         AS "lines_added: i32",
+                lines_removed       AS "lines_removed: i32",
+                created_at          AS "created_at!: DateTime<Utc>",
+                updated_at          AS "updated_at!: DateTime<Utc>"
+            FROM workspaces
+            WHERE local_workspace_id = ANY($1)
+            "#,
+            local_workspace_ids
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
     pub async fn exists_by_local_id(
         pool: &PgPool,
         local_workspace_id: Uuid,
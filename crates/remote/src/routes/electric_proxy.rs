@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use axum::{
     Router,
@@ -9,10 +12,11 @@ use axum::{
 use futures::TryStreamExt;
 use secrecy::ExposeSecret;
 use serde::Deserialize;
-use tracing::error;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{error, warn};
 use uuid::Uuid;
 
-use crate::{AppState, shape_definition::ShapeExport};
+use crate::{AppState, config::ElectricProxyLimits, shape_definition::ShapeExport};
 
 #[derive(Deserialize)]
 pub(crate) struct OrgShapeQuery {
@@ -29,6 +33,8 @@ pub(crate) struct ShapeQuery {
 
 const ELECTRIC_PARAMS: &[&str] = &["offset", "handle", "live", "cursor", "columns"];
 const ELECTRIC_STICKY_HEADER: &str = "x-vk-electric-sticky";
+/// Seconds a client should wait before retrying a shape request rejected by the limiter.
+const RETRY_AFTER_SECS: u64 = 5;
 
 pub(crate) fn router() -> Router<AppState> {
     let mut router = Router::new();
@@ -38,6 +44,97 @@ pub(crate) fn router() -> Router<AppState> {
     router
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ProxyRequestKind {
+    /// Long-poll requests (`live=true`) that stay open until Electric has new data.
+    Live,
+    /// Initial sync and catch-up requests that return as soon as the log is read.
+    CatchUp,
+}
+
+impl ProxyRequestKind {
+    fn from_params(params: &HashMap<String, String>) -> Self {
+        match params.get("live").map(String::as_str) {
+            Some("true") => Self::Live,
+            _ => Self::CatchUp,
+        }
+    }
+}
+
+/// Caps concurrent Electric requests per user and overall, so a misbehaving client
+/// cannot exhaust the proxy's connections and starve the REST API.
+///
+/// Requests over the limit are rejected rather than queued.
+pub struct ElectricProxyLimiter {
+    limits: ElectricProxyLimits,
+    total: Arc<Semaphore>,
+    in_flight: Arc<Mutex<HashMap<(Uuid, ProxyRequestKind), usize>>>,
+}
+
+impl ElectricProxyLimiter {
+    pub fn new(limits: ElectricProxyLimits) -> Self {
+        Self {
+            total: Arc::new(Semaphore::new(limits.max_total)),
+            limits,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn try_acquire(
+        &self,
+        user_id: Uuid,
+        kind: ProxyRequestKind,
+    ) -> Result<ProxyPermit, ProxyError> {
+        let max_per_user = match kind {
+            ProxyRequestKind::Live => self.limits.max_live_per_user,
+            ProxyRequestKind::CatchUp => self.limits.max_catch_up_per_user,
+        };
+        let key = (user_id, kind);
+
+        // Dropped again if the per-user check below fails.
+        let total = Arc::clone(&self.total).try_acquire_owned().map_err(|_| {
+            warn!(
+                max_total = self.limits.max_total,
+                "Electric proxy global limit reached"
+            );
+            ProxyError::TooManyRequests
+        })?;
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        let count = in_flight.entry(key).or_default();
+        if *count >= max_per_user {
+            warn!(%user_id, ?kind, max_per_user, "Electric proxy per-user limit reached");
+            return Err(ProxyError::TooManyRequests);
+        }
+        *count += 1;
+
+        Ok(ProxyPermit {
+            key,
+            in_flight: Arc::clone(&self.in_flight),
+            _total: total,
+        })
+    }
+}
+
+/// A slot in the [`ElectricProxyLimiter`], released when dropped.
+struct ProxyPermit {
+    key: (Uuid, ProxyRequestKind),
+    in_flight: Arc<Mutex<HashMap<(Uuid, ProxyRequestKind), usize>>>,
+    _total: OwnedSemaphorePermit,
+}
+
+impl Drop for ProxyPermit {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if let Some(count) = in_flight.get_mut(&self.key) {
+            *count -= 1;
+            if *count == 0 {
+                in_flight.remove(&self.key);
+            }
+        }
+    }
+}
+
 /// Proxy a Shape request to Electric for a specific table.
 ///
 /// The table and where clause are set server-side (not from client params)
@@ -47,8 +144,13 @@ pub(crate) async fn proxy_table(
     shape: &dyn ShapeExport,
     client_params: &HashMap<String, String>,
     electric_params: &[String],
+    user_id: Uuid,
     session_id: Uuid,
 ) -> Result<Response, ProxyError> {
+    let permit = state
+        .electric_limiter()
+        .try_acquire(user_id, ProxyRequestKind::from_params(client_params))?;
+
     // Build the Electric URL
     let mut origin_url = url::Url::parse(&state.config.electric_url)
         .map_err(|e| ProxyError::InvalidConfig(format!("invalid electric_url: {e}")))?;
@@ -108,8 +210,15 @@ pub(crate) async fn proxy_table(
     // Add Vary header for proper caching with auth
     headers.insert(header::VARY, HeaderValue::from_static("Authorization"));
 
-    // Stream the response body directly without buffering
-    let body_stream = response.bytes_stream().map_err(std::io::Error::other);
+    // Stream the response body directly without buffering, holding the limiter
+    // permit until the stream finishes or the client goes away.
+    let body_stream = response
+        .bytes_stream()
+        .map_err(std::io::Error::other)
+        .map_ok(move |chunk| {
+            let _permit = &permit;
+            chunk
+        });
     let body = Body::from_stream(body_stream);
 
     Ok((status, headers, body).into_response())
@@ -120,6 +229,7 @@ pub(crate) enum ProxyError {
     Connection(reqwest::Error),
     InvalidConfig(String),
     Authorization(String),
+    TooManyRequests,
 }
 
 impl IntoResponse for ProxyError {
@@ -141,6 +251,76 @@ impl IntoResponse for ProxyError {
                 error!(%msg, "authorization failed for Electric proxy");
                 (StatusCode::FORBIDDEN, "forbidden").into_response()
             }
+            ProxyError::TooManyRequests => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, RETRY_AFTER_SECS.to_string())],
+                "too many concurrent shape requests",
+            )
+                .into_response(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(max_live_per_user: usize, max_total: usize) -> ElectricProxyLimiter {
+        ElectricProxyLimiter::new(ElectricProxyLimits {
+            max_live_per_user,
+            max_catch_up_per_user: 2,
+            max_total,
+        })
+    }
+
+    #[test]
+    fn live_requests_are_limited_per_user() {
+        let limiter = limiter(1, 10);
+        let user = Uuid::new_v4();
+
+        let live = limiter.try_acquire(user, ProxyRequestKind::Live).unwrap();
+        assert!(matches!(
+            limiter.try_acquire(user, ProxyRequestKind::Live),
+            Err(ProxyError::TooManyRequests)
+        ));
+
+        // Catch-up requests and other users have their own allowance.
+        assert!(limiter.try_acquire(user, ProxyRequestKind::CatchUp).is_ok());
+        assert!(
+            limiter
+                .try_acquire(Uuid::new_v4(), ProxyRequestKind::Live)
+                .is_ok()
+        );
+
+        drop(live);
+        assert!(limiter.try_acquire(user, ProxyRequestKind::Live).is_ok());
+    }
+
+    #[test]
+    fn global_limit_applies_across_users() {
+        let limiter = limiter(5, 1);
+
+        let held = limiter
+            .try_acquire(Uuid::new_v4(), ProxyRequestKind::Live)
+            .unwrap();
+        let user = Uuid::new_v4();
+        assert!(matches!(
+            limiter.try_acquire(user, ProxyRequestKind::CatchUp),
+            Err(ProxyError::TooManyRequests)
+        ));
+
+        drop(held);
+        assert!(limiter.try_acquire(user, ProxyRequestKind::CatchUp).is_ok());
+        assert!(limiter.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn too_many_requests_sets_retry_after() {
+        let response = ProxyError::TooManyRequests.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(header::RETRY_AFTER).unwrap(),
+            &RETRY_AFTER_SECS.to_string()
+        );
+    }
+}
@@ -5,7 +5,7 @@ use axum::{
 };
 use serde_json::json;
 
-use crate::db::identity_errors::IdentityError;
+use crate::db::{TX_CONTEXT, identity_errors::IdentityError};
 
 #[derive(Debug)]
 pub struct ErrorResponse {
@@ -24,7 +24,17 @@ impl ErrorResponse {
 
 impl IntoResponse for ErrorResponse {
     fn into_response(self) -> Response {
-        (self.status, Json(json!({ "error": self.message }))).into_response()
+        let mut body = json!({ "error": self.message });
+        // Lets clients quote the ID in bug reports so the failure can be found in the logs.
+        if let Some(request_id) = TX_CONTEXT
+            .try_with(|ctx| ctx.as_ref().map(|ctx| ctx.request_id.clone()))
+            .ok()
+            .flatten()
+            .filter(|id| !id.is_empty())
+        {
+            body["request_id"] = json!(request_id);
+        }
+        (self.status, Json(body)).into_response()
     }
 }
 
@@ -12,7 +12,7 @@ use uuid::Uuid;
 
 use super::{
     error::{ErrorResponse, db_error},
-    organization_members::ensure_issue_access,
+    organization_members::{ensure_issue_access, ensure_project_access},
 };
 use crate::{
     AppState,
@@ -38,24 +38,36 @@ pub fn router() -> axum::Router<AppState> {
 #[instrument(
     name = "issue_assignees.list_issue_assignees",
     skip(state, ctx),
-    fields(issue_id = %query.issue_id, user_id = %ctx.user.id)
+    fields(issue_id = ?query.issue_id, project_id = ?query.project_id, user_id = %ctx.user.id)
 )]
 async fn list_issue_assignees(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ListIssueAssigneesQuery>,
 ) -> Result<Json<ListIssueAssigneesResponse>, ErrorResponse> {
-    ensure_issue_access(state.pool(), ctx.user.id, query.issue_id).await?;
-
-    let issue_assignees = IssueAssigneeRepository::list_by_issue(state.pool(), query.issue_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, issue_id = %query.issue_id, "failed to list issue assignees");
-            ErrorResponse::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list issue assignees",
-            )
-        })?;
+    let issue_assignees = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => {
+            ensure_issue_access(state.pool(), ctx.user.id, issue_id).await?;
+            IssueAssigneeRepository::list_by_issue(state.pool(), issue_id).await
+        }
+        (None, Some(project_id)) => {
+            ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
+            IssueAssigneeRepository::list_by_project(state.pool(), project_id).await
+        }
+        (None, None) => {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "issue_id or project_id is required",
+            ));
+        }
+    }
+    .map_err(|error| {
+        tracing::error!(?error, "failed to list issue assignees");
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to list issue assignees",
+        )
+    })?;
 
     Ok(Json(ListIssueAssigneesResponse { issue_assignees }))
 }
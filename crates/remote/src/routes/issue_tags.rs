@@ -12,7 +12,7 @@ use uuid::Uuid;
 
 use super::{
     error::{ErrorResponse, db_error},
-    organization_members::ensure_issue_access,
+    organization_members::{ensure_issue_access, ensure_project_access},
 };
 use crate::{
     AppState,
@@ -37,24 +37,36 @@ pub fn router() -> axum::Router<AppState> {
 #[instrument(
     name = "issue_tags.list_issue_tags",
     skip(state, ctx),
-    fields(issue_id = %query.issue_id, user_id = %ctx.user.id)
+    fields(issue_id = ?query.issue_id, project_id = ?query.project_id, user_id = %ctx.user.id)
 )]
 async fn list_issue_tags(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ListIssueTagsQuery>,
 ) -> Result<Json<ListIssueTagsResponse>, ErrorResponse> {
-    ensure_issue_access(state.pool(), ctx.user.id, query.issue_id).await?;
-
-    let issue_tags = IssueTagRepository::list_by_issue(state.pool(), query.issue_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, issue_id = %query.issue_id, "failed to list issue tags");
-            ErrorResponse::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list issue tags",
-            )
-        })?;
+    let issue_tags = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => {
+            ensure_issue_access(state.pool(), ctx.user.id, issue_id).await?;
+            IssueTagRepository::list_by_issue(state.pool(), issue_id).await
+        }
+        (None, Some(project_id)) => {
+            ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
+            IssueTagRepository::list_by_project(state.pool(), project_id).await
+        }
+        (None, None) => {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "issue_id or project_id is required",
+            ));
+        }
+    }
+    .map_err(|error| {
+        tracing::error!(?error, "failed to list issue tags");
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to list issue tags",
+        )
+    })?;
 
     Ok(Json(ListIssueTagsResponse { issue_tags }))
 }
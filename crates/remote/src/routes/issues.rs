@@ -1,13 +1,13 @@
 use api_types::{
-    CreateIssueRequest, DeleteResponse, Issue, ListIssuesQuery, ListIssuesResponse,
-    MutationResponse, NotificationPayload, NotificationType, SearchIssuesRequest,
-    UpdateIssueRequest,
+    CountIssuesQuery, CreateIssueRequest, DeleteResponse, Issue, IssueCountsResponse,
+    ListIssuesQuery, ListIssuesResponse, MutationResponse, NotificationPayload, NotificationType,
+    SearchIssuesRequest, UpdateIssueRequest,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
     http::StatusCode,
-    routing::post,
+    routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
@@ -45,7 +45,14 @@ pub fn router() -> axum::Router<AppState> {
     mutation()
         .router()
         .route("/issues/search", post(search_issues))
+        .route("/issues/counts", get(count_issues))
+        .route(
+            "/issues/by-simple-id/{project_id}/{simple_id}",
+            get(get_issue_by_simple_id),
+        )
         .route("/issues/bulk", post(bulk_update_issues))
+        .route("/issues/{issue_id}/archive", post(archive_issue))
+        .route("/issues/{issue_id}/unarchive", post(unarchive_issue))
 }
 
 async fn notify_issue_update_changes(
@@ -184,22 +191,7 @@ async fn list_issues(
 ) -> Result<Json<ListIssuesResponse>, ErrorResponse> {
     let project_id = query.project_id;
     ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
-    let request = SearchIssuesRequest {
-        project_id,
-        status_id: None,
-        status_ids: None,
-        priority: None,
-        parent_issue_id: None,
-        search: None,
-        simple_id: None,
-        assignee_user_id: None,
-        tag_id: None,
-        tag_ids: None,
-        sort_field: None,
-        sort_direction: None,
-        limit: None,
-        offset: None,
-    };
+    let request = SearchIssuesRequest::from(query);
 
     let response = IssueRepository::search(state.pool(), &request)
         .await
@@ -233,6 +225,28 @@ async fn search_issues(
     Ok(Json(response))
 }
 
+#[instrument(
+    name = "issues.count_issues",
+    skip(state, ctx),
+    fields(project_id = %query.project_id, user_id = %ctx.user.id)
+)]
+async fn count_issues(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(query): Query<CountIssuesQuery>,
+) -> Result<Json<IssueCountsResponse>, ErrorResponse> {
+    ensure_project_access(state.pool(), ctx.user.id, query.project_id).await?;
+
+    let counts = IssueRepository::count_by_status(state.pool(), query.project_id)
+        .await
+        .map_err(|error| {
+            tracing::error!(?error, project_id = %query.project_id, "failed to count issues");
+            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to count issues")
+        })?;
+
+    Ok(Json(IssueCountsResponse { counts }))
+}
+
 #[instrument(
     name = "issues.get_issue",
     skip(state, ctx),
@@ -256,6 +270,30 @@ async fn get_issue(
     Ok(Json(issue))
 }
 
+#[instrument(
+    name = "issues.get_issue_by_simple_id",
+    skip(state, ctx),
+    fields(project_id = %project_id, simple_id = %simple_id, user_id = %ctx.user.id)
+)]
+async fn get_issue_by_simple_id(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((project_id, simple_id)): Path<(Uuid, String)>,
+) -> Result<Json<Issue>, ErrorResponse> {
+    ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
+
+    let simple_id = simple_id.to_ascii_uppercase();
+    let issue = IssueRepository::find_by_simple_id(state.pool(), project_id, &simple_id)
+        .await
+        .map_err(|error| {
+            tracing::error!(?error, %project_id, %simple_id, "failed to load issue");
+            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to load issue")
+        })?
+        .ok_or_else(|| ErrorResponse::new(StatusCode::NOT_FOUND, "issue not found"))?;
+
+    Ok(Json(issue))
+}
+
 #[instrument(
     name = "issues.create_issue",
     skip(state, ctx, payload),
@@ -461,6 +499,58 @@ async fn delete_issue(
     Ok(Json(response))
 }
 
+#[instrument(
+    name = "issues.archive_issue",
+    skip(state, ctx),
+    fields(issue_id = %issue_id, user_id = %ctx.user.id)
+)]
+async fn archive_issue(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(issue_id): Path<Uuid>,
+) -> Result<Json<MutationResponse<Issue>>, ErrorResponse> {
+    set_issue_archived(&state, &ctx, issue_id, true).await
+}
+
+#[instrument(
+    name = "issues.unarchive_issue",
+    skip(state, ctx),
+    fields(issue_id = %issue_id, user_id = %ctx.user.id)
+)]
+async fn unarchive_issue(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(issue_id): Path<Uuid>,
+) -> Result<Json<MutationResponse<Issue>>, ErrorResponse> {
+    set_issue_archived(&state, &ctx, issue_id, false).await
+}
+
+async fn set_issue_archived(
+    state: &AppState,
+    ctx: &RequestContext,
+    issue_id: Uuid,
+    archived: bool,
+) -> Result<Json<MutationResponse<Issue>>, ErrorResponse> {
+    let issue = IssueRepository::find_by_id(state.pool(), issue_id)
+        .await
+        .map_err(|error| {
+            tracing::error!(?error, %issue_id, "failed to load issue");
+            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to load issue")
+        })?
+        .ok_or_else(|| ErrorResponse::new(StatusCode::NOT_FOUND, "issue not found"))?;
+
+    ensure_project_access(state.pool(), ctx.user.id, issue.project_id).await?;
+
+    let response = IssueRepository::set_archived(state.pool(), issue_id, archived)
+        .await
+        .map_err(|error| {
+            tracing::error!(?error, archived, "failed to update issue archive state");
+            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "internal server error")
+        })?;
+
+    Ok(Json(response))
+}
+
 // =============================================================================
 // Bulk Update
 // =============================================================================
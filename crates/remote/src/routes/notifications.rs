@@ -1,11 +1,14 @@
-use api_types::{DeleteResponse, MutationResponse, Notification, UpdateNotificationRequest};
+use api_types::{
+    BulkUpdateNotificationsRequest, BulkUpdateNotificationsResponse, DeleteResponse,
+    ListNotificationsResponse, MutationResponse, Notification, UpdateNotificationRequest,
+};
 use axum::{
     Json, Router,
     extract::{Extension, Path, Query, State},
     http::StatusCode,
     routing::post,
 };
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -17,35 +20,12 @@ use crate::{
     mutation_definition::{MutationBuilder, NoCreate},
 };
 
-#[derive(Debug, Serialize)]
-pub struct ListNotificationsResponse {
-    pub notifications: Vec<Notification>,
-}
-
 #[derive(Debug, Deserialize)]
 pub struct ListNotificationsQuery {
     #[serde(default)]
     pub include_dismissed: bool,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct BulkUpdateNotificationItem {
-    pub id: Uuid,
-    #[serde(flatten)]
-    pub changes: UpdateNotificationRequest,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct BulkUpdateNotificationsRequest {
-    pub updates: Vec<BulkUpdateNotificationItem>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BulkUpdateNotificationsResponse {
-    pub data: Vec<Notification>,
-    pub txid: i64,
-}
-
 pub fn mutation() -> MutationBuilder<Notification, NoCreate, UpdateNotificationRequest> {
     MutationBuilder::new("notifications")
         .list(list_notifications)
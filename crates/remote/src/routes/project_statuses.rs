@@ -1,5 +1,6 @@
 use api_types::{
-    CreateProjectStatusRequest, DeleteResponse, ListProjectStatusesQuery,
+    BulkUpdateProjectStatusesRequest, BulkUpdateProjectStatusesResponse,
+    CreateProjectStatusRequest, DeleteProjectStatusQuery, DeleteResponse, ListProjectStatusesQuery,
     ListProjectStatusesResponse, MutationResponse, ProjectStatus, UpdateProjectStatusRequest,
 };
 use axum::{
@@ -9,7 +10,6 @@ use axum::{
     routing::post,
 };
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -20,7 +20,11 @@ use super::{
 use crate::{
     AppState,
     auth::RequestContext,
-    db::{get_txid, project_statuses::ProjectStatusRepository, types::is_valid_hsl_color},
+    db::{
+        get_txid,
+        project_statuses::{ProjectStatusError, ProjectStatusRepository},
+        types::is_valid_hsl_color,
+    },
     mutation_definition::MutationBuilder,
 };
 
@@ -189,6 +193,7 @@ async fn delete_project_status(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Path(project_status_id): Path<Uuid>,
+    Query(query): Query<DeleteProjectStatusQuery>,
 ) -> Result<Json<DeleteResponse>, ErrorResponse> {
     let status = ProjectStatusRepository::find_by_id(state.pool(), project_status_id)
         .await
@@ -203,32 +208,50 @@ async fn delete_project_status(
 
     ensure_project_access(state.pool(), ctx.user.id, status.project_id).await?;
 
-    let response = ProjectStatusRepository::delete(state.pool(), project_status_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, "failed to delete project status");
-            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "internal server error")
-        })?;
-
-    Ok(Json(response))
-}
-
-#[derive(Debug, Deserialize)]
-pub struct BulkUpdateProjectStatusItem {
-    pub id: Uuid,
-    #[serde(flatten)]
-    pub changes: UpdateProjectStatusRequest,
-}
+    if let Some(target_id) = query.move_issues_to_status_id {
+        if target_id == project_status_id {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "cannot move issues to the status being deleted",
+            ));
+        }
+        let target = ProjectStatusRepository::find_by_id(state.pool(), target_id)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, %target_id, "failed to load target project status");
+                ErrorResponse::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to load project status",
+                )
+            })?;
+        if target.is_none_or(|target| target.project_id != status.project_id) {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "move_issues_to_status_id must be another status of the same project",
+            ));
+        }
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct BulkUpdateProjectStatusesRequest {
-    pub updates: Vec<BulkUpdateProjectStatusItem>,
-}
+    let response = ProjectStatusRepository::delete(
+        state.pool(),
+        project_status_id,
+        query.move_issues_to_status_id,
+    )
+    .await
+    .map_err(|error| {
+        if let ProjectStatusError::Database(sqlx::Error::Database(db_err)) = &error
+            && db_err.is_foreign_key_violation()
+        {
+            return ErrorResponse::new(
+                StatusCode::CONFLICT,
+                "project status still has issues; pass move_issues_to_status_id to move them",
+            );
+        }
+        tracing::error!(?error, "failed to delete project status");
+        ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "internal server error")
+    })?;
 
-#[derive(Debug, Serialize)]
-pub struct BulkUpdateProjectStatusesResponse {
-    pub data: Vec<ProjectStatus>,
-    pub txid: i64,
+    Ok(Json(response))
 }
 
 #[instrument(
@@ -1,6 +1,6 @@
 use api_types::{
-    CreatePullRequestIssueRequest, DeleteResponse, ListPullRequestIssuesResponse, MutationResponse,
-    PullRequestIssue,
+    CreatePullRequestIssueRequest, DeleteResponse, ListPullRequestIssuesQuery,
+    ListPullRequestIssuesResponse, MutationResponse, PullRequestIssue,
 };
 use axum::{
     Json,
@@ -12,7 +12,7 @@ use uuid::Uuid;
 
 use super::{
     error::{ErrorResponse, db_error},
-    organization_members::ensure_issue_access,
+    organization_members::{ensure_issue_access, ensure_project_access},
 };
 use crate::{
     AppState,
@@ -24,11 +24,6 @@ use crate::{
     mutation_definition::{MutationBuilder, NoUpdate},
 };
 
-#[derive(Debug, serde::Deserialize)]
-pub struct ListPullRequestIssuesQuery {
-    pub issue_id: Uuid,
-}
-
 pub fn mutation() -> MutationBuilder<PullRequestIssue, CreatePullRequestIssueRequest, NoUpdate> {
     MutationBuilder::new("pull_request_issues")
         .list(list_pull_request_issues)
@@ -44,25 +39,36 @@ pub fn router() -> axum::Router<AppState> {
 #[instrument(
     name = "pull_request_issues.list",
     skip(state, ctx),
-    fields(issue_id = %query.issue_id, user_id = %ctx.user.id)
+    fields(issue_id = ?query.issue_id, project_id = ?query.project_id, user_id = %ctx.user.id)
 )]
 async fn list_pull_request_issues(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ListPullRequestIssuesQuery>,
 ) -> Result<Json<ListPullRequestIssuesResponse>, ErrorResponse> {
-    ensure_issue_access(state.pool(), ctx.user.id, query.issue_id).await?;
-
-    let pull_request_issues =
-        PullRequestIssueRepository::list_by_issue(state.pool(), query.issue_id)
-            .await
-            .map_err(|error| {
-                tracing::error!(?error, "failed to list pull request issues");
-                ErrorResponse::new(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "failed to list pull request issues",
-                )
-            })?;
+    let pull_request_issues = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => {
+            ensure_issue_access(state.pool(), ctx.user.id, issue_id).await?;
+            PullRequestIssueRepository::list_by_issue(state.pool(), issue_id).await
+        }
+        (None, Some(project_id)) => {
+            ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
+            PullRequestIssueRepository::list_by_project(state.pool(), project_id).await
+        }
+        (None, None) => {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "issue_id or project_id is required",
+            ));
+        }
+    }
+    .map_err(|error| {
+        tracing::error!(?error, "failed to list pull request issues");
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to list pull request issues",
+        )
+    })?;
 
     Ok(Json(ListPullRequestIssuesResponse {
         pull_request_issues,
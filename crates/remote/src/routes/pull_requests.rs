@@ -4,7 +4,7 @@ use api_types::{
 };
 use axum::{
     Json, Router,
-    extract::{Extension, Query, State},
+    extract::{Extension, Path, Query, State},
     http::StatusCode,
     routing::get,
 };
@@ -15,7 +15,7 @@ use uuid::Uuid;
 
 use super::{
     error::{ErrorResponse, db_error},
-    organization_members::ensure_issue_access,
+    organization_members::{ensure_issue_access, ensure_project_access},
 };
 use crate::{
     AppState,
@@ -51,38 +51,78 @@ struct UpdatePullRequestRequest {
 }
 
 pub(super) fn router() -> Router<AppState> {
-    Router::new().route(
-        "/pull_requests",
-        get(list_pull_requests)
-            .post(create_pull_request)
-            .patch(update_pull_request)
-            .put(upsert_pull_request),
-    )
+    Router::new()
+        .route(
+            "/pull_requests",
+            get(list_pull_requests)
+                .post(create_pull_request)
+                .patch(update_pull_request)
+                .put(upsert_pull_request),
+        )
+        .route("/pull_requests/{pull_request_id}", get(get_pull_request))
 }
 
 #[instrument(
     name = "pull_requests.list_pull_requests",
     skip(state, ctx),
-    fields(issue_id = %query.issue_id, user_id = %ctx.user.id)
+    fields(issue_id = ?query.issue_id, project_id = ?query.project_id, user_id = %ctx.user.id)
 )]
 async fn list_pull_requests(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ListPullRequestsQuery>,
 ) -> Result<Json<ListPullRequestsResponse>, ErrorResponse> {
-    ensure_issue_access(state.pool(), ctx.user.id, query.issue_id).await?;
+    let pull_requests = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => {
+            ensure_issue_access(state.pool(), ctx.user.id, issue_id).await?;
+            PullRequestRepository::list_by_issue(state.pool(), issue_id).await
+        }
+        (None, Some(project_id)) => {
+            ensure_project_access(state.pool(), ctx.user.id, project_id).await?;
+            PullRequestRepository::list_by_project(state.pool(), project_id).await
+        }
+        (None, None) => {
+            return Err(ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "issue_id or project_id is required",
+            ));
+        }
+    }
+    .map_err(|error| {
+        tracing::error!(?error, "failed to list pull requests");
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to list pull requests",
+        )
+    })?;
 
-    let pull_requests = PullRequestRepository::list_by_issue(state.pool(), query.issue_id)
+    Ok(Json(ListPullRequestsResponse { pull_requests }))
+}
+
+#[instrument(
+    name = "pull_requests.get_pull_request",
+    skip(state, ctx),
+    fields(pull_request_id = %pull_request_id, user_id = %ctx.user.id)
+)]
+async fn get_pull_request(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(pull_request_id): Path<Uuid>,
+) -> Result<Json<PullRequest>, ErrorResponse> {
+    let pull_request = PullRequestRepository::find_by_id(state.pool(), pull_request_id)
         .await
         .map_err(|error| {
-            tracing::error!(?error, "failed to list pull requests");
+            tracing::error!(?error, %pull_request_id, "failed to load pull request");
             ErrorResponse::new(
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list pull requests",
+                "failed to load pull request",
             )
-        })?;
+        })?
+        .ok_or_else(|| ErrorResponse::new(StatusCode::NOT_FOUND, "pull request not found"))?;
 
-    Ok(Json(ListPullRequestsResponse { pull_requests }))
+    ensure_project_access(state.pool(), ctx.user.id, pull_request.project_id).await?;
+
+    Ok(Json(pull_request))
 }
 
 /// Deprecated: use `POST /v1/pull_request_issues` instead.
@@ -1,4 +1,9 @@
-use api_types::{DeleteWorkspaceRequest, UpdateWorkspaceRequest, Workspace};
+use std::collections::HashSet;
+
+use api_types::{
+    DeleteWorkspaceRequest, ListWorkspacesByLocalIdsRequest, ListWorkspacesByLocalIdsResponse,
+    MAX_LOCAL_IDS_PER_LOOKUP, UpdateWorkspaceRequest, Workspace,
+};
 use axum::{
     Json, Router,
     extract::{Extension, Path, State},
@@ -51,6 +56,10 @@ pub(super) fn router() -> Router<AppState> {
             "/workspaces/by-local-id/{local_workspace_id}",
             get(get_workspace_by_local_id),
         )
+        .route(
+            "/workspaces/by-local-ids",
+            post(list_workspaces_by_local_ids),
+        )
         .route(
             "/workspaces/exists/{local_workspace_id}",
             head(workspace_exists),
@@ -291,6 +300,57 @@ async fn get_workspace_by_local_id(
     Ok(Json(workspace))
 }
 
+#[instrument(
+    name = "workspaces.list_workspaces_by_local_ids",
+    skip(state, ctx, payload),
+    fields(count = payload.local_workspace_ids.len(), user_id = %ctx.user.id)
+)]
+async fn list_workspaces_by_local_ids(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(payload): Json<ListWorkspacesByLocalIdsRequest>,
+) -> Result<Json<ListWorkspacesByLocalIdsResponse>, ErrorResponse> {
+    if payload.local_workspace_ids.len() > MAX_LOCAL_IDS_PER_LOOKUP {
+        return Err(ErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            format!("at most {MAX_LOCAL_IDS_PER_LOOKUP} local workspace ids per request"),
+        ));
+    }
+
+    let workspaces =
+        WorkspaceRepository::find_by_local_ids(state.pool(), &payload.local_workspace_ids)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, "failed to find workspaces");
+                ErrorResponse::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to find workspaces",
+                )
+            })?;
+
+    // Drop workspaces in projects the caller cannot see instead of failing the whole lookup.
+    let mut accessible_projects = HashSet::new();
+    for project_id in workspaces
+        .iter()
+        .map(|workspace| workspace.project_id)
+        .collect::<HashSet<_>>()
+    {
+        if ensure_project_access(state.pool(), ctx.user.id, project_id)
+            .await
+            .is_ok()
+        {
+            accessible_projects.insert(project_id);
+        }
+    }
+
+    Ok(Json(ListWorkspacesByLocalIdsResponse {
+        workspaces: workspaces
+            .into_iter()
+            .filter(|workspace| accessible_projects.contains(&workspace.project_id))
+            .collect(),
+    }))
+}
+
 #[instrument(
     name = "workspaces.workspace_exists",
     skip(state, _ctx),
@@ -18,19 +18,31 @@
 //! );
 //! ```
 
+use std::collections::HashMap;
+
 use axum::{
-    extract::{Extension, Path, Query, State},
+    body::Body,
+    extract::{Extension, Path, Query, Request, State},
     handler::Handler,
+    http::{
+        HeaderValue, StatusCode, Uri,
+        header::{ETAG, IF_NONE_MATCH},
+    },
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
     routing::{MethodRouter, get},
 };
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
 use ts_rs::TS;
 use uuid::Uuid;
 
 use crate::{
     AppState,
     auth::RequestContext,
-    db::organization_members,
+    db::{UpdatedPage, organization_members},
     routes::electric_proxy::{OrgShapeQuery, ProxyError, ShapeQuery, proxy_table},
     shape_definition::{ShapeDefinition, ShapeExport},
 };
@@ -57,21 +69,77 @@ impl<A, B, C, D, Q> HasQueryParams<Q> for (A, B, C, D, Query<Q>) {}
 // =============================================================================
 
 /// Query params for org-scoped fallback handlers (Org, OrgWithUser).
+///
+/// `limit`, `offset` and `updated_after` are only honoured by the paginated handlers
+/// (org issues).
 #[derive(Debug, Deserialize)]
 pub struct OrgFallbackQuery {
     pub organization_id: Uuid,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub updated_after: Option<DateTime<Utc>>,
 }
 
+impl OrgFallbackQuery {
+    pub fn page(&self) -> UpdatedPage {
+        fallback_page(self.limit, self.offset, self.updated_after)
+    }
+}
+
+/// Rows returned by a paginated fallback handler when the client sends no `limit`.
+/// Generous so clients written before pagination still see everything in most projects.
+pub const DEFAULT_FALLBACK_LIMIT: i64 = 1000;
+/// Upper bound on `limit` for paginated fallback handlers.
+pub const MAX_FALLBACK_LIMIT: i64 = 5000;
+
 /// Query params for project-scoped fallback handlers.
+///
+/// `limit`, `offset` and `updated_after` are only honoured by the paginated handlers
+/// (issues, workspaces, pull requests).
 #[derive(Debug, Deserialize)]
 pub struct ProjectFallbackQuery {
     pub project_id: Uuid,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+impl ProjectFallbackQuery {
+    pub fn page(&self) -> UpdatedPage {
+        fallback_page(self.limit, self.offset, self.updated_after)
+    }
 }
 
 /// Query params for issue-scoped fallback handlers.
+///
+/// `limit`, `offset` and `updated_after` are only honoured by the paginated handlers
+/// (issue comments).
 #[derive(Debug, Deserialize)]
 pub struct IssueFallbackQuery {
     pub issue_id: Uuid,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+impl IssueFallbackQuery {
+    pub fn page(&self) -> UpdatedPage {
+        fallback_page(self.limit, self.offset, self.updated_after)
+    }
+}
+
+fn fallback_page(
+    limit: Option<i64>,
+    offset: Option<i64>,
+    updated_after: Option<DateTime<Utc>>,
+) -> UpdatedPage {
+    UpdatedPage {
+        updated_after,
+        limit: limit
+            .unwrap_or(DEFAULT_FALLBACK_LIMIT)
+            .clamp(1, MAX_FALLBACK_LIMIT),
+        offset: offset.unwrap_or(0).max(0),
+    }
 }
 
 /// Marker for fallback handlers that require no query parameters.
@@ -136,6 +204,11 @@ impl ShapeRoute {
     /// `HasQueryParams`), ensuring the handler accepts the correct scope
     /// parameters. Use `Query<NoQueryParams>` for handlers that don't need
     /// query parameters (e.g. User-scoped shapes).
+    ///
+    /// Fallback responses carry an `ETag` and honour `If-None-Match`, so polling
+    /// clients get `304 Not Modified` while the data is unchanged. For tables in
+    /// [`VERSIONED_TABLES`] the tag comes from a `count(*)` + `max(updated_at)` query
+    /// and a match skips building the body; other tables hash the serialized body.
     pub fn new<T, H, HT, Q>(
         shape: &'static ShapeDefinition<T>,
         scope: ShapeScope,
@@ -144,13 +217,20 @@ impl ShapeRoute {
     ) -> Self
     where
         T: TS + Sync + Send + 'static,
-        H: Handler<HT, AppState> + Clone + Send + 'static,
+        H: Handler<HT, AppState> + Clone + Send + Sync + 'static,
         HT: HasQueryParams<Q> + 'static,
     {
         let proxy_handler = build_proxy_handler(shape, scope);
+        let fallback = if VERSIONED_TABLES.contains(&shape.table()) {
+            get(move |State(state): State<AppState>, request: Request| {
+                versioned_fallback(state, shape, scope, fallback_handler, request)
+            })
+        } else {
+            get(fallback_handler).layer(middleware::from_fn(conditional_fallback))
+        };
         let router = axum::Router::new()
             .route(shape.url(), proxy_handler)
-            .route(fallback_url, get(fallback_handler));
+            .route(fallback_url, fallback);
 
         Self {
             router,
@@ -160,6 +240,224 @@ impl ShapeRoute {
     }
 }
 
+// =============================================================================
+// Conditional requests for fallback routes
+// =============================================================================
+
+/// Tables whose `updated_at` moves on every insert and update, so `count(*)` plus
+/// `max(updated_at)` over a shape's rows changes whenever its fallback payload does.
+/// Deletes lower the count and archiving bumps `updated_at`.
+const VERSIONED_TABLES: &[&str] = &[
+    "projects",
+    "issues",
+    "workspaces",
+    "pull_requests",
+    "issue_comments",
+];
+
+/// Row count and newest `updated_at` of the rows a shape covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ShapeVersion {
+    count: i64,
+    max_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Answers `If-None-Match` from the shape's version before running the fallback
+/// handler, so unchanged polls never load or serialize the rows.
+///
+/// The version is read before the body, so a write that lands in between is tagged
+/// with the older version and the next poll simply refetches. When the version can't
+/// be computed (unauthorized, bad query, database error) the handler runs as usual
+/// and the response is tagged from its body.
+async fn versioned_fallback<H, HT>(
+    state: AppState,
+    shape: &'static dyn ShapeExport,
+    scope: ShapeScope,
+    handler: H,
+    request: Request,
+) -> Response
+where
+    H: Handler<HT, AppState>,
+{
+    let if_none_match = request.headers().get(IF_NONE_MATCH).cloned();
+    let ctx = request.extensions().get::<RequestContext>().cloned();
+    let uri = request.uri().clone();
+
+    let version = match ctx {
+        Some(ctx) => fallback_version(&state, shape, scope, &ctx, &uri).await,
+        None => None,
+    };
+    let Some(version) = version else {
+        let response = handler.call(request, state).await;
+        return with_etag(if_none_match.as_ref(), response).await;
+    };
+
+    let etag = version_etag(uri.query(), version);
+    if if_none_match
+        .as_ref()
+        .is_some_and(|value| etag_matches(value, &etag))
+    {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, etag)]).into_response();
+    }
+
+    let mut response = handler.call(request, state).await;
+    if response.status() == StatusCode::OK {
+        response.headers_mut().insert(ETAG, etag);
+    }
+    response
+}
+
+/// Resolves the shape's params from the fallback query (user params come from the
+/// session), checks the caller may read the scope, then reads the shape version.
+async fn fallback_version(
+    state: &AppState,
+    shape: &'static dyn ShapeExport,
+    scope: ShapeScope,
+    ctx: &RequestContext,
+    uri: &Uri,
+) -> Option<ShapeVersion> {
+    let Query(query) = Query::<HashMap<String, String>>::try_from_uri(uri).ok()?;
+
+    let mut params = Vec::with_capacity(shape.params().len());
+    for name in shape.params() {
+        let value = if name.ends_with("user_id") {
+            ctx.user.id
+        } else {
+            query.get(*name)?.parse().ok()?
+        };
+        params.push(value);
+    }
+
+    // The scope id is always the shape's first param.
+    let pool = state.pool();
+    let authorized = match scope {
+        ShapeScope::Org | ShapeScope::OrgWithUser => {
+            organization_members::assert_membership(pool, params[0], ctx.user.id)
+                .await
+                .is_ok()
+        }
+        ShapeScope::Project => {
+            organization_members::assert_project_access(pool, params[0], ctx.user.id)
+                .await
+                .is_ok()
+        }
+        ShapeScope::Issue => {
+            organization_members::assert_issue_access(pool, params[0], ctx.user.id)
+                .await
+                .is_ok()
+        }
+        ShapeScope::User => true,
+    };
+    if !authorized {
+        return None;
+    }
+
+    shape_version(pool, shape, &params)
+        .await
+        .inspect_err(|error| {
+            tracing::warn!(
+                ?error,
+                table = shape.table(),
+                "failed to read fallback version"
+            );
+        })
+        .ok()
+}
+
+/// `count(*)` and `max(updated_at)` over the rows matched by the shape's where clause.
+/// Table and clause are static shape definitions; only the params are bound.
+async fn shape_version(
+    pool: &PgPool,
+    shape: &dyn ShapeExport,
+    params: &[Uuid],
+) -> Result<ShapeVersion, sqlx::Error> {
+    let sql = format!(
+        r#"SELECT COUNT(*)::BIGINT, MAX("updated_at") FROM "{}" WHERE {}"#,
+        shape.table(),
+        shape.where_clause()
+    );
+    let mut query = sqlx::query_as::<_, (i64, Option<DateTime<Utc>>)>(&sql);
+    for param in params {
+        query = query.bind(*param);
+    }
+    let (count, max_updated_at) = query.fetch_one(pool).await?;
+
+    Ok(ShapeVersion {
+        count,
+        max_updated_at,
+    })
+}
+
+/// Weak validator for a version; the request query is included because paging and
+/// `updated_after` select different slices of the same rows.
+fn version_etag(query: Option<&str>, version: ShapeVersion) -> HeaderValue {
+    let key = format!(
+        "{}\n{}\n{}",
+        query.unwrap_or_default(),
+        version.count,
+        version
+            .max_updated_at
+            .map(|updated_at| updated_at.timestamp_micros())
+            .unwrap_or_default()
+    );
+    weak_etag(key.as_bytes())
+}
+
+/// Tags successful fallback responses with an `ETag` and answers `304 Not Modified`
+/// when the client already has the same payload.
+async fn conditional_fallback(request: Request, next: Next) -> Response {
+    let if_none_match = request.headers().get(IF_NONE_MATCH).cloned();
+    let response = next.run(request).await;
+    with_etag(if_none_match.as_ref(), response).await
+}
+
+async fn with_etag(if_none_match: Option<&HeaderValue>, response: Response) -> Response {
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            tracing::error!(?error, "failed to buffer fallback response");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = weak_etag(&bytes);
+    if if_none_match.is_some_and(|value| etag_matches(value, &etag)) {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, etag)]).into_response();
+    }
+
+    parts.headers.insert(ETAG, etag);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Weak validator derived from the given bytes (a serialized payload or a version
+/// key), so any change to the rows (including deletions) changes the tag.
+fn weak_etag(bytes: &[u8]) -> HeaderValue {
+    let digest = hex::encode(Sha256::digest(bytes));
+    HeaderValue::from_str(&format!("W/\"{}\"", &digest[..32]))
+        .expect("hex digest is a valid header value")
+}
+
+/// `If-None-Match` uses weak comparison: `W/` prefixes are ignored and `*` matches anything.
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(if_none_match) = if_none_match.to_str() else {
+        return false;
+    };
+    let Ok(etag) = etag.to_str() else {
+        return false;
+    };
+    let etag = etag.trim_start_matches("W/");
+
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
 // =============================================================================
 // Handler construction
 // =============================================================================
@@ -187,6 +485,7 @@ fn build_proxy_handler(
                     shape,
                     &query.params,
                     &[query.organization_id.to_string()],
+                    ctx.user.id,
                     ctx.session_id,
                 )
                 .await
@@ -210,6 +509,7 @@ fn build_proxy_handler(
                     shape,
                     &query.params,
                     &[query.organization_id.to_string(), ctx.user.id.to_string()],
+                    ctx.user.id,
                     ctx.session_id,
                 )
                 .await
@@ -230,6 +530,7 @@ fn build_proxy_handler(
                     shape,
                     &query.params,
                     &[project_id.to_string()],
+                    ctx.user.id,
                     ctx.session_id,
                 )
                 .await
@@ -250,6 +551,7 @@ fn build_proxy_handler(
                     shape,
                     &query.params,
                     &[issue_id.to_string()],
+                    ctx.user.id,
                     ctx.session_id,
                 )
                 .await
@@ -265,6 +567,7 @@ fn build_proxy_handler(
                     shape,
                     &query.params,
                     &[ctx.user.id.to_string()],
+                    ctx.user.id,
                     ctx.session_id,
                 )
                 .await
@@ -272,3 +575,187 @@ fn build_proxy_handler(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::Json;
+    use serde_json::json;
+
+    use super::*;
+
+    async fn fallback_response(
+        if_none_match: Option<&HeaderValue>,
+        payload: serde_json::Value,
+    ) -> Response {
+        with_etag(if_none_match, Json(payload).into_response()).await
+    }
+
+    fn issue_payload(title: &str, updated_at: &str) -> serde_json::Value {
+        json!({
+            "issues": [{
+                "id": "7f4c6b0e-2f4b-4c43-9d0a-8a2b1c3d4e5f",
+                "title": title,
+                "updated_at": updated_at,
+            }],
+            "total_count": 1,
+        })
+    }
+
+    #[tokio::test]
+    async fn fallback_etag_changes_when_an_issue_is_updated() {
+        let original = issue_payload("Fix login", "2026-01-01T00:00:00Z");
+        let first = fallback_response(None, original.clone()).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers().get(ETAG).cloned().unwrap();
+        assert!(etag.to_str().unwrap().starts_with("W/\""));
+
+        let unchanged = fallback_response(Some(&etag), original).await;
+        assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+        let body = axum::body::to_bytes(unchanged.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(body.is_empty());
+
+        let updated = issue_payload("Fix login redirect", "2026-01-02T00:00:00Z");
+        let changed = fallback_response(Some(&etag), updated).await;
+        assert_eq!(changed.status(), StatusCode::OK);
+        assert_ne!(changed.headers().get(ETAG), Some(&etag));
+    }
+
+    #[test]
+    fn version_etag_tracks_count_updated_at_and_query() {
+        let query = Some("project_id=7f4c6b0e-2f4b-4c43-9d0a-8a2b1c3d4e5f");
+        let version = ShapeVersion {
+            count: 3,
+            max_updated_at: Some("2026-01-01T00:00:00Z".parse().unwrap()),
+        };
+        let etag = version_etag(query, version);
+        assert!(etag.to_str().unwrap().starts_with("W/\""));
+        assert_eq!(version_etag(query, version), etag);
+
+        let deleted = ShapeVersion {
+            count: 2,
+            ..version
+        };
+        assert_ne!(version_etag(query, deleted), etag);
+        let updated = ShapeVersion {
+            max_updated_at: Some("2026-01-01T00:00:00.000001Z".parse().unwrap()),
+            ..version
+        };
+        assert_ne!(version_etag(query, updated), etag);
+        assert_ne!(
+            version_etag(
+                Some("project_id=7f4c6b0e-2f4b-4c43-9d0a-8a2b1c3d4e5f&offset=100"),
+                version
+            ),
+            etag
+        );
+    }
+
+    #[test]
+    fn version_etag_changes_when_an_issue_is_updated() {
+        let shape: &dyn ShapeExport = &crate::shapes::PROJECT_ISSUES_SHAPE;
+        assert!(VERSIONED_TABLES.contains(&shape.table()));
+
+        let query = Some("project_id=7f4c6b0e-2f4b-4c43-9d0a-8a2b1c3d4e5f");
+        let version = |updated_at: &[DateTime<Utc>]| ShapeVersion {
+            count: updated_at.len() as i64,
+            max_updated_at: updated_at.iter().max().copied(),
+        };
+        let mut updated_at: Vec<DateTime<Utc>> = vec![
+            "2026-01-01T00:00:00Z".parse().unwrap(),
+            "2026-01-02T00:00:00Z".parse().unwrap(),
+        ];
+        let before = version_etag(query, version(&updated_at));
+        assert_eq!(version_etag(query, version(&updated_at)), before);
+
+        // Updating an issue sets its `updated_at` to now, past every other row in the shape.
+        updated_at[0] = "2026-01-03T00:00:00Z".parse().unwrap();
+        let after = version_etag(query, version(&updated_at));
+        assert_ne!(after, before);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "needs a Postgres database in DATABASE_URL"]
+    async fn version_etag_changes_when_projects_change(pool: PgPool) {
+        let organization_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO organizations (name, slug) VALUES ('Acme', 'acme') RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let query = format!("organization_id={organization_id}");
+        let etag = |version| version_etag(Some(&query), version);
+        let shape = &crate::shapes::PROJECTS_SHAPE;
+
+        let empty = shape_version(&pool, shape, &[organization_id])
+            .await
+            .unwrap();
+        assert_eq!(empty.count, 0);
+        assert_eq!(empty.max_updated_at, None);
+
+        let project_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO projects (organization_id, name) VALUES ($1, 'Web') RETURNING id",
+        )
+        .bind(organization_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let created = shape_version(&pool, shape, &[organization_id])
+            .await
+            .unwrap();
+        assert_eq!(created.count, 1);
+        assert_ne!(etag(created), etag(empty));
+        let unchanged = shape_version(&pool, shape, &[organization_id])
+            .await
+            .unwrap();
+        assert_eq!(etag(unchanged), etag(created));
+
+        sqlx::query("UPDATE projects SET name = 'Web app' WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let renamed = shape_version(&pool, shape, &[organization_id])
+            .await
+            .unwrap();
+        assert_eq!(renamed.count, 1);
+        assert_ne!(etag(renamed), etag(created));
+
+        sqlx::query("DELETE FROM projects WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let deleted = shape_version(&pool, shape, &[organization_id])
+            .await
+            .unwrap();
+        assert_eq!(deleted.count, 0);
+        assert_ne!(etag(deleted), etag(renamed));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let etag = HeaderValue::from_static("W/\"abc\"");
+        assert!(etag_matches(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(etag_matches(
+            &HeaderValue::from_static("W/\"xyz\", W/\"abc\""),
+            &etag
+        ));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("W/\"xyz\""), &etag));
+    }
+
+    #[test]
+    fn fallback_page_defaults_and_clamps() {
+        let page = fallback_page(None, None, None);
+        assert_eq!(page.limit, DEFAULT_FALLBACK_LIMIT);
+        assert_eq!(page.offset, 0);
+
+        let page = fallback_page(Some(1_000_000), Some(-5), None);
+        assert_eq!(page.limit, MAX_FALLBACK_LIMIT);
+        assert_eq!(page.offset, 0);
+
+        assert_eq!(fallback_page(Some(0), None, None).limit, 1);
+    }
+}
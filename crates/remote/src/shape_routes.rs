@@ -1,11 +1,11 @@
 //! All shape route declarations with authorization scope and REST fallback.
 
 use api_types::{
-    ListIssueAssigneesResponse, ListIssueCommentReactionsResponse, ListIssueCommentsResponse,
-    ListIssueFollowersResponse, ListIssueRelationshipsResponse, ListIssueTagsResponse,
-    ListIssuesResponse, ListProjectStatusesResponse, ListProjectsResponse,
-    ListPullRequestIssuesResponse, ListPullRequestsResponse, ListTagsResponse, Notification,
-    OrganizationMember, SearchIssuesRequest, User, Workspace,
+    Issue, ListIssueAssigneesResponse, ListIssueCommentReactionsResponse,
+    ListIssueCommentsResponse, ListIssueFollowersResponse, ListIssueRelationshipsResponse,
+    ListIssueTagsResponse, ListNotificationsResponse, ListProjectStatusesResponse,
+    ListProjectsResponse, ListPullRequestIssuesResponse, ListPullRequestsResponse,
+    ListTagsResponse, OrganizationMember, User, Workspace,
 };
 use axum::{
     Json,
@@ -18,7 +18,7 @@ use crate::{
     AppState,
     auth::RequestContext,
     db::{
-        issue_assignees::IssueAssigneeRepository,
+        UpdatedPage, issue_assignees::IssueAssigneeRepository,
         issue_comment_reactions::IssueCommentReactionRepository,
         issue_comments::IssueCommentRepository, issue_followers::IssueFollowerRepository,
         issue_relationships::IssueRelationshipRepository, issue_tags::IssueTagRepository,
@@ -42,11 +42,6 @@ use crate::{
 // Response types not defined in api-types (field name must match shape table)
 // =============================================================================
 
-#[derive(Debug, Serialize)]
-struct ListNotificationsResponse {
-    notifications: Vec<Notification>,
-}
-
 #[derive(Debug, Serialize)]
 struct ListOrganizationMembersResponse {
     organization_member_metadata: Vec<OrganizationMember>,
@@ -62,6 +57,34 @@ struct ListWorkspacesResponse {
     workspaces: Vec<Workspace>,
 }
 
+#[derive(Debug, Serialize)]
+struct ListFallbackIssuesResponse {
+    issues: Vec<Issue>,
+}
+
+/// A page of fallback rows plus what the client needs to fetch the next one.
+#[derive(Debug, Serialize)]
+struct PagedFallbackResponse<T> {
+    #[serde(flatten)]
+    rows: T,
+    total_count: i64,
+    limit: i64,
+    offset: i64,
+    has_more: bool,
+}
+
+impl<T> PagedFallbackResponse<T> {
+    fn new(rows: T, row_count: usize, total_count: i64, page: UpdatedPage) -> Self {
+        Self {
+            rows,
+            total_count,
+            limit: page.limit,
+            offset: page.offset,
+            has_more: page.offset + (row_count as i64) < total_count,
+        }
+    }
+}
+
 // =============================================================================
 // Shape route registration
 // =============================================================================
@@ -96,6 +119,18 @@ pub fn all_shape_routes() -> Vec<ShapeRoute> {
             "/fallback/users",
             fallback_list_users,
         ),
+        ShapeRoute::new(
+            &shapes::ORG_ISSUES_SHAPE,
+            ShapeScope::Org,
+            "/fallback/org_issues",
+            fallback_list_org_issues,
+        ),
+        ShapeRoute::new(
+            &shapes::ORG_ISSUE_ASSIGNEES_SHAPE,
+            ShapeScope::OrgWithUser,
+            "/fallback/org_issue_assignees",
+            fallback_list_org_issue_assignees,
+        ),
         // Project-scoped
         ShapeRoute::new(
             &shapes::PROJECT_TAGS_SHAPE,
@@ -264,6 +299,55 @@ async fn fallback_list_users(
     Ok(Json(ListUsersResponse { users }))
 }
 
+async fn fallback_list_org_issues(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(query): Query<OrgFallbackQuery>,
+) -> Result<Json<PagedFallbackResponse<ListFallbackIssuesResponse>>, ErrorResponse> {
+    ensure_member_access(state.pool(), query.organization_id, ctx.user.id).await?;
+
+    let page = query.page();
+    let (issues, total_count) =
+        IssueRepository::list_by_organization(state.pool(), query.organization_id, page)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, organization_id = %query.organization_id, "failed to list org issues (fallback)");
+                ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to list issues")
+            })?;
+
+    let row_count = issues.len();
+    Ok(Json(PagedFallbackResponse::new(
+        ListFallbackIssuesResponse { issues },
+        row_count,
+        total_count,
+        page,
+    )))
+}
+
+async fn fallback_list_org_issue_assignees(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(query): Query<OrgFallbackQuery>,
+) -> Result<Json<ListIssueAssigneesResponse>, ErrorResponse> {
+    ensure_member_access(state.pool(), query.organization_id, ctx.user.id).await?;
+
+    let issue_assignees = IssueAssigneeRepository::list_by_organization_user(
+        state.pool(),
+        query.organization_id,
+        ctx.user.id,
+    )
+    .await
+    .map_err(|error| {
+        tracing::error!(?error, organization_id = %query.organization_id, "failed to list org issue assignees (fallback)");
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to list issue assignees",
+        )
+    })?;
+
+    Ok(Json(ListIssueAssigneesResponse { issue_assignees }))
+}
+
 // =============================================================================
 // Project-scoped fallback handlers
 // =============================================================================
@@ -310,27 +394,14 @@ async fn fallback_list_issues(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ProjectFallbackQuery>,
-) -> Result<Json<ListIssuesResponse>, ErrorResponse> {
+) -> Result<Json<PagedFallbackResponse<ListFallbackIssuesResponse>>, ErrorResponse> {
     ensure_project_access(state.pool(), ctx.user.id, query.project_id).await?;
 
-    let response = IssueRepository::search(
+    let page = query.page();
+    let (issues, total_count) = IssueRepository::list_page_by_project(
         state.pool(),
-        &SearchIssuesRequest {
-            project_id: query.project_id,
-            status_id: None,
-            status_ids: None,
-            priority: None,
-            parent_issue_id: None,
-            search: None,
-            simple_id: None,
-            assignee_user_id: None,
-            tag_id: None,
-            tag_ids: None,
-            sort_field: None,
-            sort_direction: None,
-            limit: None,
-            offset: None,
-        },
+        query.project_id,
+        page,
     )
     .await
     .map_err(|error| {
@@ -338,24 +409,38 @@ async fn fallback_list_issues(
         ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to list issues")
     })?;
 
-    Ok(Json(response))
+    let row_count = issues.len();
+    Ok(Json(PagedFallbackResponse::new(
+        ListFallbackIssuesResponse { issues },
+        row_count,
+        total_count,
+        page,
+    )))
 }
 
 async fn fallback_list_project_workspaces(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ProjectFallbackQuery>,
-) -> Result<Json<ListWorkspacesResponse>, ErrorResponse> {
+) -> Result<Json<PagedFallbackResponse<ListWorkspacesResponse>>, ErrorResponse> {
     ensure_project_access(state.pool(), ctx.user.id, query.project_id).await?;
 
-    let workspaces = WorkspaceRepository::list_by_project(state.pool(), query.project_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, project_id = %query.project_id, "failed to list workspaces (fallback)");
-            ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to list workspaces")
-        })?;
+    let page = query.page();
+    let (workspaces, total_count) =
+        WorkspaceRepository::list_page_by_project(state.pool(), query.project_id, page)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, project_id = %query.project_id, "failed to list workspaces (fallback)");
+                ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, "failed to list workspaces")
+            })?;
 
-    Ok(Json(ListWorkspacesResponse { workspaces }))
+    let row_count = workspaces.len();
+    Ok(Json(PagedFallbackResponse::new(
+        ListWorkspacesResponse { workspaces },
+        row_count,
+        total_count,
+        page,
+    )))
 }
 
 async fn fallback_list_issue_assignees(
@@ -447,20 +532,28 @@ async fn fallback_list_pull_requests(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<ProjectFallbackQuery>,
-) -> Result<Json<ListPullRequestsResponse>, ErrorResponse> {
+) -> Result<Json<PagedFallbackResponse<ListPullRequestsResponse>>, ErrorResponse> {
     ensure_project_access(state.pool(), ctx.user.id, query.project_id).await?;
 
-    let pull_requests = PullRequestRepository::list_by_project(state.pool(), query.project_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, project_id = %query.project_id, "failed to list pull requests (fallback)");
-            ErrorResponse::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list pull requests",
-            )
-        })?;
+    let page = query.page();
+    let (pull_requests, total_count) =
+        PullRequestRepository::list_page_by_project(state.pool(), query.project_id, page)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, project_id = %query.project_id, "failed to list pull requests (fallback)");
+                ErrorResponse::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to list pull requests",
+                )
+            })?;
 
-    Ok(Json(ListPullRequestsResponse { pull_requests }))
+    let row_count = pull_requests.len();
+    Ok(Json(PagedFallbackResponse::new(
+        ListPullRequestsResponse { pull_requests },
+        row_count,
+        total_count,
+        page,
+    )))
 }
 
 async fn fallback_list_pull_request_issues(
@@ -516,20 +609,28 @@ async fn fallback_list_issue_comments(
     State(state): State<AppState>,
     Extension(ctx): Extension<RequestContext>,
     Query(query): Query<IssueFallbackQuery>,
-) -> Result<Json<ListIssueCommentsResponse>, ErrorResponse> {
+) -> Result<Json<PagedFallbackResponse<ListIssueCommentsResponse>>, ErrorResponse> {
     ensure_issue_access(state.pool(), ctx.user.id, query.issue_id).await?;
 
-    let issue_comments = IssueCommentRepository::list_by_issue(state.pool(), query.issue_id)
-        .await
-        .map_err(|error| {
-            tracing::error!(?error, issue_id = %query.issue_id, "failed to list issue comments (fallback)");
-            ErrorResponse::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list issue comments",
-            )
-        })?;
+    let page = query.page();
+    let (issue_comments, total_count) =
+        IssueCommentRepository::list_page_by_issue(state.pool(), query.issue_id, page)
+            .await
+            .map_err(|error| {
+                tracing::error!(?error, issue_id = %query.issue_id, "failed to list issue comments (fallback)");
+                ErrorResponse::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to list issue comments",
+                )
+            })?;
 
-    Ok(Json(ListIssueCommentsResponse { issue_comments }))
+    let row_count = issue_comments.len();
+    Ok(Json(PagedFallbackResponse::new(
+        ListIssueCommentsResponse { issue_comments },
+        row_count,
+        total_count,
+        page,
+    )))
 }
 
 async fn fallback_list_issue_comment_reactions(
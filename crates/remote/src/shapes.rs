@@ -44,6 +44,23 @@ pub const USERS_SHAPE: ShapeDefinition<User> = crate::define_shape!(
     params: ["organization_id"],
 );
 
+pub const ORG_ISSUES_SHAPE: ShapeDefinition<Issue> = crate::define_shape!(
+    name: "ORG_ISSUES_SHAPE",
+    table: "issues",
+    where_clause: r#""project_id" IN (SELECT id FROM projects WHERE "organization_id" = $1)"#,
+    url: "/shape/org_issues",
+    params: ["organization_id"],
+);
+
+/// The current user's assignments across the organization, for "assigned to me" views.
+pub const ORG_ISSUE_ASSIGNEES_SHAPE: ShapeDefinition<IssueAssignee> = crate::define_shape!(
+    name: "ORG_ISSUE_ASSIGNEES_SHAPE",
+    table: "issue_assignees",
+    where_clause: r#""user_id" = $2 AND "issue_id" IN (SELECT id FROM issues WHERE "project_id" IN (SELECT id FROM projects WHERE "organization_id" = $1))"#,
+    url: "/shape/org_issue_assignees",
+    params: ["organization_id", "user_id"],
+);
+
 // =============================================================================
 // Project-scoped shapes
 // =============================================================================
@@ -11,6 +11,7 @@ use crate::{
     github_app::GitHubAppService,
     mail::Mailer,
     r2::R2Service,
+    routes::electric_proxy::ElectricProxyLimiter,
 };
 
 #[derive(Clone)]
@@ -21,6 +22,7 @@ pub struct AppState {
     pub mailer: Arc<dyn Mailer>,
     pub server_public_base_url: String,
     pub http_client: reqwest::Client,
+    electric_limiter: Arc<ElectricProxyLimiter>,
     handoff: Arc<OAuthHandoffService>,
     oauth_token_validator: Arc<OAuthTokenValidator>,
     r2: Option<R2Service>,
@@ -47,6 +49,10 @@ impl AppState {
         billing: BillingService,
         analytics: Option<AnalyticsService>,
     ) -> Self {
+        let electric_limiter = Arc::new(ElectricProxyLimiter::new(
+            config.electric_proxy_limits.clone(),
+        ));
+
         Self {
             pool,
             config,
@@ -54,6 +60,7 @@ impl AppState {
             mailer,
             server_public_base_url,
             http_client,
+            electric_limiter,
             handoff,
             oauth_token_validator,
             r2,
@@ -76,6 +83,10 @@ impl AppState {
         Arc::clone(&self.jwt)
     }
 
+    pub(crate) fn electric_limiter(&self) -> &ElectricProxyLimiter {
+        &self.electric_limiter
+    }
+
     pub fn handoff(&self) -> Arc<OAuthHandoffService> {
         Arc::clone(&self.handoff)
     }
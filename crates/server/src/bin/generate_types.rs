@@ -112,7 +112,7 @@ fn generate_types_content() -> String {
         relay_types::RemoveRelayPairedClientResponse::decl(),
         relay_types::RefreshRelaySigningSessionRequest::decl(),
         relay_types::RefreshRelaySigningSessionResponse::decl(),
-        server::routes::sessions::CreateFollowUpAttempt::decl(),
+        db::models::requests::CreateFollowUpAttempt::decl(),
         server::routes::sessions::ResetProcessRequest::decl(),
         server::routes::workspaces::git::ChangeTargetBranchRequest::decl(),
         server::routes::workspaces::git::ChangeTargetBranchResponse::decl(),
@@ -3,6 +3,7 @@ use axum::{
     middleware::Next,
     response::Response,
 };
+use utils::http_headers::current_request_id;
 
 pub async fn log_server_errors(request: Request, next: Next) -> Response {
     let method = request.method().clone();
@@ -24,6 +25,7 @@ pub async fn log_server_errors(request: Request, next: Next) -> Response {
             uri = %uri,
             matched_path = matched_path.as_deref().unwrap_or("<unmatched>"),
             status = %response.status(),
+            request_id = current_request_id().as_deref().unwrap_or("<none>"),
             "API request returned server error"
         );
     }
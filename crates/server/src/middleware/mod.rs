@@ -2,9 +2,11 @@ pub mod error_logging;
 pub mod model_loaders;
 pub mod origin;
 pub mod relay_request_signature;
+pub mod request_id;
 pub mod signed_ws;
 
 pub use error_logging::*;
 pub use model_loaders::*;
 pub use origin::*;
 pub use relay_request_signature::*;
+pub use request_id::*;
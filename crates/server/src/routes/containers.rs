@@ -5,8 +5,8 @@ use axum::{
     routing::get,
 };
 use db::models::{
-    requests::ContainerQuery,
-    workspace::{Workspace, WorkspaceContext},
+    requests::{AttemptContextQuery, ContainerQuery},
+    workspace::{Workspace, WorkspaceContext, WorkspaceError},
 };
 use deployment::Deployment;
 use serde::Serialize;
@@ -36,14 +36,30 @@ async fn get_container_info(
 
 async fn get_context(
     State(deployment): State<DeploymentImpl>,
-    Query(payload): Query<ContainerQuery>,
+    Query(payload): Query<AttemptContextQuery>,
 ) -> Result<ResponseJson<ApiResponse<WorkspaceContext>>, ApiError> {
-    let info =
-        Workspace::resolve_container_ref_by_prefix(&deployment.db().pool, &payload.container_ref)
-            .await
-            .map_err(ApiError::Database)?;
+    let workspace_id = match (payload.workspace_id, payload.container_ref) {
+        (Some(workspace_id), _) => workspace_id,
+        // A path outside every workspace is a 404, so callers can tell it from a failure.
+        (None, Some(container_ref)) => {
+            Workspace::resolve_container_ref_by_prefix(&deployment.db().pool, &container_ref)
+                .await
+                .map_err(|error| match error {
+                    sqlx::Error::RowNotFound => {
+                        ApiError::Workspace(WorkspaceError::WorkspaceNotFound)
+                    }
+                    error => ApiError::Database(error),
+                })?
+                .workspace_id
+        }
+        (None, None) => {
+            return Err(ApiError::BadRequest(
+                "Either ref or workspace_id is required".to_string(),
+            ));
+        }
+    };
 
-    let ctx = Workspace::load_context(&deployment.db().pool, info.workspace_id).await?;
+    let ctx = Workspace::load_context(&deployment.db().pool, workspace_id).await?;
     Ok(ResponseJson(ApiResponse::success(ctx)))
 }
 
@@ -9,11 +9,12 @@ use axum::{
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessStatus},
     execution_process_repo_state::ExecutionProcessRepoState,
+    requests::{ExecutionProcessLogTail, ExecutionProcessLogTailQuery, LogStream},
 };
 use deployment::Deployment;
 use futures_util::{StreamExt, TryStreamExt};
 use serde::Deserialize;
-use services::services::container::ContainerService;
+use services::services::{container::ContainerService, execution_process::load_raw_log_messages};
 use utils::{log_msg::LogMsg, response::ApiResponse};
 use uuid::Uuid;
 
@@ -26,6 +27,10 @@ use crate::{
     },
 };
 
+const DEFAULT_LOG_TAIL_LINES: usize = 50;
+const MAX_LOG_TAIL_LINES: usize = 1000;
+const MAX_LOG_TAIL_BYTES: usize = 64 * 1024;
+
 #[derive(Debug, Deserialize)]
 struct SessionExecutionProcessQuery {
     pub session_id: Uuid,
@@ -212,6 +217,80 @@ async fn stop_execution_process(
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
+/// The last lines of a process's raw output, for callers that can't hold a log stream open.
+async fn get_execution_process_log_tail(
+    Extension(execution_process): Extension<ExecutionProcess>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ExecutionProcessLogTailQuery>,
+) -> Result<ResponseJson<ApiResponse<ExecutionProcessLogTail>>, ApiError> {
+    let messages = match deployment
+        .container()
+        .get_msg_store_by_id(&execution_process.id)
+        .await
+    {
+        Some(store) => store.get_history(),
+        None => load_raw_log_messages(&deployment.db().pool, execution_process.id)
+            .await
+            .unwrap_or_default(),
+    };
+    let stream = query.stream.unwrap_or_default();
+    let output = messages
+        .into_iter()
+        .filter_map(|msg| match (msg, stream) {
+            (LogMsg::Stdout(content), LogStream::Stdout | LogStream::Combined)
+            | (LogMsg::Stderr(content), LogStream::Stderr | LogStream::Combined) => Some(content),
+            _ => None,
+        })
+        .collect::<String>();
+
+    let max_lines = query
+        .lines
+        .unwrap_or(DEFAULT_LOG_TAIL_LINES)
+        .min(MAX_LOG_TAIL_LINES);
+    let max_bytes = query
+        .max_bytes
+        .unwrap_or(MAX_LOG_TAIL_BYTES)
+        .min(MAX_LOG_TAIL_BYTES);
+    let (lines, truncated) = tail_lines(&output, max_lines, max_bytes);
+
+    Ok(ResponseJson(ApiResponse::success(
+        ExecutionProcessLogTail {
+            lines,
+            truncated,
+            total_bytes: output.len(),
+        },
+    )))
+}
+
+// The last `max_lines` lines of `output` that fit in `max_bytes`, and whether anything was left
+// out. A final line longer than the budget keeps its end.
+pub(crate) fn tail_lines(output: &str, max_lines: usize, max_bytes: usize) -> (Vec<String>, bool) {
+    let all_lines = output.lines().collect::<Vec<_>>();
+    let mut lines = Vec::new();
+    let mut bytes = 0;
+    let mut cut = false;
+    for line in all_lines.iter().rev().take(max_lines) {
+        // Count the newline that separated each line, so the budget matches the joined output.
+        let line_bytes = line.len() + usize::from(!lines.is_empty());
+        if bytes + line_bytes > max_bytes {
+            if lines.is_empty() {
+                let mut start = line.len() - max_bytes;
+                while !line.is_char_boundary(start) {
+                    start += 1;
+                }
+                lines.push(line[start..].to_string());
+                cut = true;
+            }
+            break;
+        }
+        bytes += line_bytes;
+        lines.push(line.to_string());
+    }
+    let truncated = cut || lines.len() < all_lines.len();
+    lines.reverse();
+    (lines, truncated)
+}
+
 async fn stream_execution_processes_by_session_ws(
     ws: SignedWsUpgrade,
     State(deployment): State<DeploymentImpl>,
@@ -288,6 +367,7 @@ pub(super) fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/", get(get_execution_process_by_id))
         .route("/stop", post(stop_execution_process))
         .route("/repo-states", get(get_execution_process_repo_states))
+        .route("/raw-logs/tail", get(get_execution_process_log_tail))
         .route("/raw-logs/ws", get(stream_raw_logs_ws))
         .route("/normalized-logs/ws", get(stream_normalized_logs_ws))
         .layer(from_fn_with_state(
@@ -304,3 +384,25 @@ pub(super) fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
 
     Router::new().nest("/execution-processes", workspaces_router)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::tail_lines;
+
+    #[test]
+    fn tail_lines_respects_both_the_line_and_byte_budgets() {
+        let output = "one\ntwo\nthree\n";
+
+        assert_eq!(
+            tail_lines(output, 10, 1024),
+            (vec!["one".into(), "two".into(), "three".into()], false)
+        );
+        assert_eq!(
+            tail_lines(output, 2, 1024),
+            (vec!["two".into(), "three".into()], true)
+        );
+        // "two\nthree" is 9 bytes, so only "three" fits in 8.
+        assert_eq!(tail_lines(output, 10, 8), (vec!["three".into()], true));
+        assert_eq!(tail_lines(output, 10, 3), (vec!["ree".into()], true));
+    }
+}
@@ -75,6 +75,7 @@ pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
             middleware::validate_origin,
         ))
         .layer(axum::middleware::from_fn(middleware::log_server_errors))
+        .layer(axum::middleware::from_fn(middleware::propagate_request_id))
         .with_state(deployment);
 
     Router::new()
@@ -15,7 +15,8 @@ use crate::{DeploymentImpl, error::ApiError};
 
 #[derive(Debug, Deserialize)]
 pub(super) struct ListIssueAssigneesQuery {
-    pub issue_id: Uuid,
+    pub issue_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
 }
 
 pub(super) fn router() -> Router<DeploymentImpl> {
@@ -35,7 +36,15 @@ async fn list_issue_assignees(
     Query(query): Query<ListIssueAssigneesQuery>,
 ) -> Result<ResponseJson<ApiResponse<ListIssueAssigneesResponse>>, ApiError> {
     let client = deployment.remote_client()?;
-    let response = client.list_issue_assignees(query.issue_id).await?;
+    let response = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => client.list_issue_assignees(issue_id).await?,
+        (None, Some(project_id)) => client.list_project_issue_assignees(project_id).await?,
+        (None, None) => {
+            return Err(ApiError::BadRequest(
+                "issue_id or project_id is required".to_string(),
+            ));
+        }
+    };
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
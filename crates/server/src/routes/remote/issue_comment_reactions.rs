@@ -0,0 +1,69 @@
+use api_types::{
+    CreateIssueCommentReactionRequest, IssueCommentReaction, ListIssueCommentReactionsResponse,
+    MutationResponse,
+};
+use axum::{
+    Router,
+    extract::{Json, Path, Query, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use serde::Deserialize;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Lists the reactions on one comment, or on every comment of an issue.
+#[derive(Debug, Deserialize)]
+pub(super) struct ListIssueCommentReactionsQuery {
+    pub comment_id: Option<Uuid>,
+    pub issue_id: Option<Uuid>,
+}
+
+pub(super) fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/issue-comment-reactions",
+            get(list_issue_comment_reactions).post(create_issue_comment_reaction),
+        )
+        .route(
+            "/issue-comment-reactions/{reaction_id}",
+            delete(delete_issue_comment_reaction),
+        )
+}
+
+async fn list_issue_comment_reactions(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ListIssueCommentReactionsQuery>,
+) -> Result<ResponseJson<ApiResponse<ListIssueCommentReactionsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = match (query.comment_id, query.issue_id) {
+        (Some(comment_id), _) => client.list_comment_reactions(comment_id).await?,
+        (None, Some(issue_id)) => client.list_issue_comment_reactions(issue_id).await?,
+        (None, None) => {
+            return Err(ApiError::BadRequest(
+                "Either comment_id or issue_id is required".to_string(),
+            ));
+        }
+    };
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn create_issue_comment_reaction(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateIssueCommentReactionRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<IssueCommentReaction>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_issue_comment_reaction(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn delete_issue_comment_reaction(
+    State(deployment): State<DeploymentImpl>,
+    Path(reaction_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let client = deployment.remote_client()?;
+    client.delete_issue_comment_reaction(reaction_id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
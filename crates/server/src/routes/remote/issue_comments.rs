@@ -0,0 +1,63 @@
+use api_types::{
+    CreateIssueCommentRequest, IssueComment, ListIssueCommentsQuery, ListIssueCommentsResponse,
+    MutationResponse, UpdateIssueCommentRequest,
+};
+use axum::{
+    Router,
+    extract::{Json, Path, Query, State},
+    response::Json as ResponseJson,
+    routing::{get, patch},
+};
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+pub(super) fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/issue-comments",
+            get(list_issue_comments).post(create_issue_comment),
+        )
+        .route(
+            "/issue-comments/{comment_id}",
+            patch(update_issue_comment).delete(delete_issue_comment),
+        )
+}
+
+async fn list_issue_comments(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ListIssueCommentsQuery>,
+) -> Result<ResponseJson<ApiResponse<ListIssueCommentsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.list_issue_comments(query.issue_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn create_issue_comment(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateIssueCommentRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<IssueComment>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_issue_comment(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn update_issue_comment(
+    State(deployment): State<DeploymentImpl>,
+    Path(comment_id): Path<Uuid>,
+    Json(request): Json<UpdateIssueCommentRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<IssueComment>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.update_issue_comment(comment_id, &request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn delete_issue_comment(
+    State(deployment): State<DeploymentImpl>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let client = deployment.remote_client()?;
+    client.delete_issue_comment(comment_id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
@@ -0,0 +1,53 @@
+use api_types::{
+    CreateIssueFollowerRequest, IssueFollower, ListIssueFollowersQuery, ListIssueFollowersResponse,
+    MutationResponse,
+};
+use axum::{
+    Router,
+    extract::{Json, Path, Query, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+pub(super) fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/issue-followers",
+            get(list_issue_followers).post(create_issue_follower),
+        )
+        .route(
+            "/issue-followers/{issue_follower_id}",
+            delete(delete_issue_follower),
+        )
+}
+
+async fn list_issue_followers(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ListIssueFollowersQuery>,
+) -> Result<ResponseJson<ApiResponse<ListIssueFollowersResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.list_issue_followers(query.issue_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn create_issue_follower(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateIssueFollowerRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<IssueFollower>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_issue_follower(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn delete_issue_follower(
+    State(deployment): State<DeploymentImpl>,
+    Path(issue_follower_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let client = deployment.remote_client()?;
+    client.delete_issue_follower(issue_follower_id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
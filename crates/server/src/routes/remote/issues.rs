@@ -1,6 +1,6 @@
 use api_types::{
-    CreateIssueRequest, Issue, ListIssuesQuery, ListIssuesResponse, MutationResponse,
-    SearchIssuesRequest, UpdateIssueRequest,
+    CountIssuesQuery, CreateIssueRequest, Issue, IssueCountsResponse, ListIssuesQuery,
+    ListIssuesResponse, MutationResponse, SearchIssuesRequest, UpdateIssueRequest,
 };
 use axum::{
     Router,
@@ -17,10 +17,17 @@ pub(super) fn router() -> Router<DeploymentImpl> {
     Router::new()
         .route("/issues", get(list_issues).post(create_issue))
         .route("/issues/search", post(search_issues))
+        .route("/issues/counts", get(count_issues))
+        .route(
+            "/issues/by-simple-id/{project_id}/{simple_id}",
+            get(get_issue_by_simple_id),
+        )
         .route(
             "/issues/{issue_id}",
             get(get_issue).patch(update_issue).delete(delete_issue),
         )
+        .route("/issues/{issue_id}/archive", post(archive_issue))
+        .route("/issues/{issue_id}/unarchive", post(unarchive_issue))
 }
 
 async fn list_issues(
@@ -28,7 +35,9 @@ async fn list_issues(
     Query(query): Query<ListIssuesQuery>,
 ) -> Result<ResponseJson<ApiResponse<ListIssuesResponse>>, ApiError> {
     let client = deployment.remote_client()?;
-    let response = client.list_issues(query.project_id).await?;
+    let response = client
+        .search_issues(&SearchIssuesRequest::from(query))
+        .await?;
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
@@ -41,6 +50,15 @@ async fn search_issues(
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
+async fn count_issues(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<CountIssuesQuery>,
+) -> Result<ResponseJson<ApiResponse<IssueCountsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.count_issues(query.project_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
 async fn get_issue(
     State(deployment): State<DeploymentImpl>,
     Path(issue_id): Path<Uuid>,
@@ -50,6 +68,17 @@ async fn get_issue(
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
+async fn get_issue_by_simple_id(
+    State(deployment): State<DeploymentImpl>,
+    Path((project_id, simple_id)): Path<(Uuid, String)>,
+) -> Result<ResponseJson<ApiResponse<Issue>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client
+        .get_issue_by_simple_id(project_id, &simple_id)
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
 async fn create_issue(
     State(deployment): State<DeploymentImpl>,
     Json(request): Json<CreateIssueRequest>,
@@ -69,6 +98,24 @@ async fn update_issue(
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
+async fn archive_issue(
+    State(deployment): State<DeploymentImpl>,
+    Path(issue_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<Issue>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.archive_issue(issue_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn unarchive_issue(
+    State(deployment): State<DeploymentImpl>,
+    Path(issue_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<Issue>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.unarchive_issue(issue_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
 async fn delete_issue(
     State(deployment): State<DeploymentImpl>,
     Path(issue_id): Path<Uuid>,
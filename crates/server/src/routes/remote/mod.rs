@@ -3,9 +3,13 @@ use axum::Router;
 use crate::DeploymentImpl;
 
 mod issue_assignees;
+mod issue_comment_reactions;
+mod issue_comments;
+mod issue_followers;
 mod issue_relationships;
 mod issue_tags;
 mod issues;
+mod notifications;
 mod project_statuses;
 mod projects;
 pub mod pull_requests;
@@ -15,9 +19,13 @@ mod workspaces;
 pub fn router() -> Router<DeploymentImpl> {
     Router::new()
         .merge(issue_assignees::router())
+        .merge(issue_comment_reactions::router())
+        .merge(issue_comments::router())
+        .merge(issue_followers::router())
         .merge(issue_relationships::router())
         .merge(issue_tags::router())
         .merge(issues::router())
+        .merge(notifications::router())
         .merge(projects::router())
         .merge(project_statuses::router())
         .merge(pull_requests::router())
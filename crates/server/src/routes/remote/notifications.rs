@@ -0,0 +1,61 @@
+use api_types::{
+    BulkUpdateNotificationsRequest, BulkUpdateNotificationsResponse, ListNotificationsResponse,
+    MutationResponse, Notification, UpdateNotificationRequest,
+};
+use axum::{
+    Router,
+    extract::{Json, Path, Query, State},
+    response::Json as ResponseJson,
+    routing::{get, patch, post},
+};
+use serde::Deserialize;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ListNotificationsQuery {
+    #[serde(default)]
+    pub include_dismissed: bool,
+}
+
+pub(super) fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/notifications", get(list_notifications))
+        .route("/notifications/bulk", post(bulk_update_notifications))
+        .route(
+            "/notifications/{notification_id}",
+            patch(update_notification),
+        )
+}
+
+async fn list_notifications(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ListNotificationsQuery>,
+) -> Result<ResponseJson<ApiResponse<ListNotificationsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.list_notifications(query.include_dismissed).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn update_notification(
+    State(deployment): State<DeploymentImpl>,
+    Path(notification_id): Path<Uuid>,
+    Json(request): Json<UpdateNotificationRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<Notification>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client
+        .update_notification(notification_id, &request)
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn bulk_update_notifications(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<BulkUpdateNotificationsRequest>,
+) -> Result<ResponseJson<ApiResponse<BulkUpdateNotificationsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.bulk_update_notifications(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
@@ -1,9 +1,13 @@
-use api_types::ListProjectStatusesResponse;
+use api_types::{
+    BulkUpdateProjectStatusesRequest, BulkUpdateProjectStatusesResponse,
+    CreateProjectStatusRequest, DeleteProjectStatusQuery, ListProjectStatusesResponse,
+    MutationResponse, ProjectStatus, UpdateProjectStatusRequest,
+};
 use axum::{
     Router,
-    extract::{Query, State},
+    extract::{Json, Path, Query, State},
     response::Json as ResponseJson,
-    routing::get,
+    routing::{get, patch, post},
 };
 use serde::Deserialize;
 use utils::response::ApiResponse;
@@ -17,7 +21,16 @@ pub(super) struct ListProjectStatusesQuery {
 }
 
 pub(super) fn router() -> Router<DeploymentImpl> {
-    Router::new().route("/project-statuses", get(list_project_statuses))
+    Router::new()
+        .route(
+            "/project-statuses",
+            get(list_project_statuses).post(create_project_status),
+        )
+        .route("/project-statuses/bulk", post(bulk_update_project_statuses))
+        .route(
+            "/project-statuses/{status_id}",
+            patch(update_project_status).delete(delete_project_status),
+        )
 }
 
 async fn list_project_statuses(
@@ -28,3 +41,43 @@ async fn list_project_statuses(
     let response = client.list_project_statuses(query.project_id).await?;
     Ok(ResponseJson(ApiResponse::success(response)))
 }
+
+async fn create_project_status(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateProjectStatusRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<ProjectStatus>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_project_status(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn update_project_status(
+    State(deployment): State<DeploymentImpl>,
+    Path(status_id): Path<Uuid>,
+    Json(request): Json<UpdateProjectStatusRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<ProjectStatus>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.update_project_status(status_id, &request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn delete_project_status(
+    State(deployment): State<DeploymentImpl>,
+    Path(status_id): Path<Uuid>,
+    Query(query): Query<DeleteProjectStatusQuery>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let client = deployment.remote_client()?;
+    client
+        .delete_project_status(status_id, query.move_issues_to_status_id)
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+async fn bulk_update_project_statuses(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<BulkUpdateProjectStatusesRequest>,
+) -> Result<ResponseJson<ApiResponse<BulkUpdateProjectStatusesResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.bulk_update_project_statuses(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
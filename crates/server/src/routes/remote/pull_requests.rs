@@ -1,7 +1,10 @@
-use api_types::{ListPullRequestsQuery, ListPullRequestsResponse};
+use api_types::{
+    CreatePullRequestIssueRequest, ListPullRequestIssuesResponse, ListPullRequestsQuery,
+    ListPullRequestsResponse, MutationResponse, PullRequestIssue,
+};
 use axum::{
     Json, Router,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     response::Json as ResponseJson,
     routing::{get, post},
 };
@@ -10,12 +13,18 @@ use deployment::Deployment;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utils::response::ApiResponse;
+use uuid::Uuid;
 
 use crate::{DeploymentImpl, error::ApiError};
 
 pub fn router() -> Router<DeploymentImpl> {
     Router::new()
         .route("/pull-requests", get(list_pull_requests))
+        .route("/pull-requests/{pull_request_id}", get(get_pull_request))
+        .route(
+            "/pull-request-issues",
+            get(list_pull_request_issues).post(create_pull_request_issue),
+        )
         .route("/pull-requests/link", post(link_pr_to_issue))
 }
 
@@ -24,7 +33,49 @@ async fn list_pull_requests(
     Query(query): Query<ListPullRequestsQuery>,
 ) -> Result<ResponseJson<ApiResponse<ListPullRequestsResponse>>, ApiError> {
     let client = deployment.remote_client()?;
-    let response = client.list_pull_requests(query.issue_id).await?;
+    let response = match (query.issue_id, query.project_id) {
+        (Some(issue_id), _) => client.list_pull_requests(issue_id).await?,
+        (None, Some(project_id)) => client.list_project_pull_requests(project_id).await?,
+        (None, None) => {
+            return Err(ApiError::BadRequest(
+                "issue_id or project_id is required".to_string(),
+            ));
+        }
+    };
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn get_pull_request(
+    State(deployment): State<DeploymentImpl>,
+    Path(pull_request_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<api_types::PullRequest>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.get_pull_request(pull_request_id).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+#[derive(Debug, Deserialize)]
+struct ListPullRequestIssuesQuery {
+    project_id: Uuid,
+}
+
+async fn list_pull_request_issues(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ListPullRequestIssuesQuery>,
+) -> Result<ResponseJson<ApiResponse<ListPullRequestIssuesResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client
+        .list_project_pull_request_issues(query.project_id)
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn create_pull_request_issue(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreatePullRequestIssueRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<PullRequestIssue>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_pull_request_issue(&request).await?;
     Ok(ResponseJson(ApiResponse::success(response)))
 }
 
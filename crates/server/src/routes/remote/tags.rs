@@ -1,7 +1,7 @@
-use api_types::{ListTagsResponse, Tag};
+use api_types::{CreateTagRequest, ListTagsResponse, MutationResponse, Tag, UpdateTagRequest};
 use axum::{
     Router,
-    extract::{Path, Query, State},
+    extract::{Json, Path, Query, State},
     response::Json as ResponseJson,
     routing::get,
 };
@@ -18,8 +18,11 @@ pub(super) struct ListTagsQuery {
 
 pub(super) fn router() -> Router<DeploymentImpl> {
     Router::new()
-        .route("/tags", get(list_tags))
-        .route("/tags/{tag_id}", get(get_tag))
+        .route("/tags", get(list_tags).post(create_tag))
+        .route(
+            "/tags/{tag_id}",
+            get(get_tag).patch(update_tag).delete(delete_tag),
+        )
 }
 
 async fn list_tags(
@@ -39,3 +42,31 @@ async fn get_tag(
     let response = client.get_tag(tag_id).await?;
     Ok(ResponseJson(ApiResponse::success(response)))
 }
+
+async fn create_tag(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateTagRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<Tag>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.create_tag(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn update_tag(
+    State(deployment): State<DeploymentImpl>,
+    Path(tag_id): Path<Uuid>,
+    Json(request): Json<UpdateTagRequest>,
+) -> Result<ResponseJson<ApiResponse<MutationResponse<Tag>>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.update_tag(tag_id, &request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+async fn delete_tag(
+    State(deployment): State<DeploymentImpl>,
+    Path(tag_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let client = deployment.remote_client()?;
+    client.delete_tag(tag_id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
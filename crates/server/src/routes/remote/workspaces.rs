@@ -1,9 +1,9 @@
-use api_types::Workspace;
+use api_types::{ListWorkspacesByLocalIdsRequest, ListWorkspacesByLocalIdsResponse, Workspace};
 use axum::{
     Router,
-    extract::{Path, State},
+    extract::{Json, Path, State},
     response::Json as ResponseJson,
-    routing::get,
+    routing::{get, post},
 };
 use utils::response::ApiResponse;
 use uuid::Uuid;
@@ -11,10 +11,15 @@ use uuid::Uuid;
 use crate::{DeploymentImpl, error::ApiError};
 
 pub(super) fn router() -> Router<DeploymentImpl> {
-    Router::new().route(
-        "/workspaces/by-local-id/{local_workspace_id}",
-        get(get_workspace_by_local_id),
-    )
+    Router::new()
+        .route(
+            "/workspaces/by-local-id/{local_workspace_id}",
+            get(get_workspace_by_local_id),
+        )
+        .route(
+            "/workspaces/by-local-ids",
+            post(list_workspaces_by_local_ids),
+        )
 }
 
 async fn get_workspace_by_local_id(
@@ -25,3 +30,12 @@ async fn get_workspace_by_local_id(
     let workspace = client.get_workspace_by_local_id(local_workspace_id).await?;
     Ok(ResponseJson(ApiResponse::success(workspace)))
 }
+
+async fn list_workspaces_by_local_ids(
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<ListWorkspacesByLocalIdsRequest>,
+) -> Result<ResponseJson<ApiResponse<ListWorkspacesByLocalIdsResponse>>, ApiError> {
+    let client = deployment.remote_client()?;
+    let response = client.list_workspaces_by_local_ids(&request).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, process::Stdio, sync::OnceLock, time::Duration};
 
 use axum::{
     Router,
@@ -7,17 +7,40 @@ use axum::{
     response::Json as ResponseJson,
     routing::{get, post},
 };
-use db::models::repo::{Repo, SearchResult, UpdateRepo};
+use db::models::{
+    execution_process::ExecutionProcess,
+    repo::{Repo, SearchResult, UpdateRepo},
+    requests::{
+        ListRepoBranchesResponse, RepoBranchDetails, RepoScriptRun, RepoScriptRunsQuery,
+        ValidateRepoScriptRequest, ValidateRepoScriptResponse,
+    },
+};
 use deployment::Deployment;
 use git::{GitBranch, GitRemote};
 use git_host::{GitHostError, GitHostProvider, GitHostService, ProviderKind, PullRequestDetail};
 use serde::{Deserialize, Serialize};
-use services::services::file_search::SearchQuery;
+use services::services::{
+    container::ContainerService, execution_process::load_raw_log_tail_messages,
+    file_search::SearchQuery,
+};
+use tokio::{io::AsyncWriteExt, process::Command};
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::{log_msg::LogMsg, response::ApiResponse, shell::get_shell_command};
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{DeploymentImpl, error::ApiError, routes::execution_processes::tail_lines};
+
+const DEFAULT_SCRIPT_RUNS_LIMIT: i64 = 5;
+const MAX_SCRIPT_RUNS_LIMIT: i64 = 50;
+const SCRIPT_RUN_OUTPUT_MAX_LINES: usize = 100;
+const SCRIPT_RUN_OUTPUT_MAX_BYTES: usize = 4096;
+// Log files also hold JSON patches and escaping, so read well past the output budget.
+const SCRIPT_RUN_LOG_READ_BYTES: u64 = 64 * 1024;
+const DEFAULT_MAX_SCRIPT_BYTES: usize = 64 * 1024;
+const SCRIPT_SYNTAX_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+// Ahead/behind counts walk the commit graph, so only the most recently
+// committed branches get them.
+const MAX_AHEAD_BEHIND_BRANCHES: usize = 50;
 
 #[derive(serde::Deserialize)]
 pub struct OpenEditorRequest {
@@ -93,6 +116,76 @@ pub async fn get_repo_branches(
     Ok(ResponseJson(ApiResponse::success(branches)))
 }
 
+pub async fn get_repo_branch_details(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<ListRepoBranchesResponse>>, ApiError> {
+    let repo = deployment
+        .repo()
+        .get_by_id(&deployment.db().pool, repo_id)
+        .await?;
+    if !deployment.git().is_repo_openable(&repo.path) {
+        return Err(ApiError::Conflict(format!(
+            "Repository '{}' is missing or is not a git repository: {}",
+            repo.display_name,
+            repo.path.display()
+        )));
+    }
+
+    let branches = deployment.git().get_all_branches(&repo.path)?;
+    // A detached HEAD has no branch to fall back on.
+    let default_branch = repo
+        .default_target_branch
+        .filter(|branch| !branch.is_empty())
+        .or_else(|| {
+            branches
+                .iter()
+                .find(|branch| branch.is_current && branch.name != "HEAD")
+                .map(|branch| branch.name.clone())
+        });
+
+    let counts = match &default_branch {
+        Some(default_branch) => {
+            let names = branches
+                .iter()
+                .take(MAX_AHEAD_BEHIND_BRANCHES)
+                .map(|branch| branch.name.as_str())
+                .collect::<Vec<_>>();
+            deployment
+                .git()
+                .get_branches_ahead_behind(&repo.path, &names, default_branch)
+                .unwrap_or_else(|e| {
+                    tracing::debug!("Failed to compare branches with {}: {}", default_branch, e);
+                    Default::default()
+                })
+        }
+        None => Default::default(),
+    };
+
+    let branches = branches
+        .into_iter()
+        .map(|branch| {
+            let counts = counts.get(&branch.name);
+            RepoBranchDetails {
+                is_default: default_branch.as_deref() == Some(branch.name.as_str()),
+                ahead_of_default: counts.map(|(ahead, _)| *ahead),
+                behind_default: counts.map(|(_, behind)| *behind),
+                name: branch.name,
+                is_current: branch.is_current,
+                is_remote: branch.is_remote,
+                last_commit_date: branch.last_commit_date,
+            }
+        })
+        .collect();
+
+    Ok(ResponseJson(ApiResponse::success(
+        ListRepoBranchesResponse {
+            default_branch,
+            branches,
+        },
+    )))
+}
+
 pub async fn get_repo_remotes(
     State(deployment): State<DeploymentImpl>,
     Path(repo_id): Path<Uuid>,
@@ -148,6 +241,197 @@ pub async fn update_repo(
     Ok(ResponseJson(ApiResponse::success(repo)))
 }
 
+pub async fn get_repo_script_runs(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+    Query(query): Query<RepoScriptRunsQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<RepoScriptRun>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let repo = deployment.repo().get_by_id(pool, repo_id).await?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SCRIPT_RUNS_LIMIT)
+        .clamp(1, MAX_SCRIPT_RUNS_LIMIT);
+
+    let processes = ExecutionProcess::find_recent_by_repo_and_run_reason(
+        pool,
+        &repo,
+        &query.script_type.run_reason(),
+        limit,
+    )
+    .await?;
+
+    let mut runs = Vec::with_capacity(processes.len());
+    for process in processes {
+        let (output, output_truncated) = script_run_output(&deployment, process.id).await;
+        runs.push(RepoScriptRun {
+            execution_process_id: process.id,
+            session_id: process.session_id,
+            status: process.status,
+            exit_code: process.exit_code,
+            started_at: process.started_at,
+            completed_at: process.completed_at,
+            output,
+            output_truncated,
+        });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(runs)))
+}
+
+// Running processes still have their logs in memory; finished ones have only the end of their
+// log file read back from disk.
+async fn script_run_output(deployment: &DeploymentImpl, execution_id: Uuid) -> (String, bool) {
+    let (messages, skipped) = match deployment
+        .container()
+        .get_msg_store_by_id(&execution_id)
+        .await
+    {
+        Some(store) => (store.get_history(), false),
+        None => load_raw_log_tail_messages(
+            &deployment.db().pool,
+            execution_id,
+            SCRIPT_RUN_LOG_READ_BYTES,
+        )
+        .await
+        .unwrap_or_default(),
+    };
+
+    let output = messages
+        .into_iter()
+        .filter_map(|msg| match msg {
+            LogMsg::Stdout(content) | LogMsg::Stderr(content) => Some(content),
+            _ => None,
+        })
+        .collect::<String>();
+    let (lines, truncated) = tail_lines(
+        &output,
+        SCRIPT_RUN_OUTPUT_MAX_LINES,
+        SCRIPT_RUN_OUTPUT_MAX_BYTES,
+    );
+    (lines.join("\n"), skipped || truncated)
+}
+
+pub async fn validate_repo_script(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+    ResponseJson(payload): ResponseJson<ValidateRepoScriptRequest>,
+) -> Result<ResponseJson<ApiResponse<ValidateRepoScriptResponse>>, ApiError> {
+    deployment
+        .repo()
+        .get_by_id(&deployment.db().pool, repo_id)
+        .await?;
+
+    let script = payload.script.replace("\r\n", "\n");
+    let mut errors = Vec::new();
+    let max_bytes = max_script_bytes();
+    if script.len() > max_bytes {
+        errors.push(format!(
+            "Script is {} bytes, which exceeds the {} byte limit",
+            script.len(),
+            max_bytes
+        ));
+    } else {
+        errors.extend(check_script_syntax(&script).await);
+    }
+    let warnings = script_warnings(&script);
+
+    Ok(ResponseJson(ApiResponse::success(
+        ValidateRepoScriptResponse {
+            script,
+            errors,
+            warnings,
+        },
+    )))
+}
+
+fn max_script_bytes() -> usize {
+    static MAX: OnceLock<usize> = OnceLock::new();
+    *MAX.get_or_init(|| {
+        std::env::var("VK_MAX_SCRIPT_BYTES")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_MAX_SCRIPT_BYTES)
+    })
+}
+
+// Parses the script with the same shell that runs it, without executing anything.
+// cmd.exe has no equivalent, so nothing is checked on Windows.
+async fn check_script_syntax(script: &str) -> Vec<String> {
+    if cfg!(windows) {
+        return Vec::new();
+    }
+
+    let (shell, _) = get_shell_command();
+    let mut child = match Command::new(&shell)
+        .arg("-n")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            tracing::warn!("Failed to start {} for script syntax check: {}", shell, e);
+            return Vec::new();
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(script.as_bytes()).await;
+    }
+
+    let output =
+        match tokio::time::timeout(SCRIPT_SYNTAX_CHECK_TIMEOUT, child.wait_with_output()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                tracing::warn!("Script syntax check failed to run: {}", e);
+                return Vec::new();
+            }
+            Err(_) => {
+                tracing::warn!("Script syntax check timed out");
+                return Vec::new();
+            }
+        };
+    if output.status.success() {
+        return Vec::new();
+    }
+
+    let errors = String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        vec!["Script has a syntax error".to_string()]
+    } else {
+        errors
+    }
+}
+
+// Scripts run as `$SHELL -c <script>`, so a shebang line is only a comment and isn't checked.
+fn script_warnings(script: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let exits_on_error = script
+        .lines()
+        .any(|line| line.trim_start().starts_with("set -e"));
+    if !exits_on_error {
+        for (index, line) in script.lines().enumerate() {
+            let command = line.trim();
+            if (command == "cd" || command.starts_with("cd ")) && !command.contains("&&") {
+                warnings.push(format!(
+                    "Line {}: `{}` is not followed by `&&`, so later commands run in the wrong directory if it fails",
+                    index + 1,
+                    command
+                ));
+            }
+        }
+    }
+    warnings
+}
+
 pub async fn open_repo_in_editor(
     State(deployment): State<DeploymentImpl>,
     Path(repo_id): Path<Uuid>,
@@ -340,9 +624,17 @@ pub struct DeleteRepoConflict {
     pub workspaces: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DeleteRepoQuery {
+    /// Delete the repo even if active workspaces still use it.
+    #[serde(default)]
+    pub force: bool,
+}
+
 pub async fn delete_repo(
     State(deployment): State<DeploymentImpl>,
     Path(repo_id): Path<Uuid>,
+    Query(query): Query<DeleteRepoQuery>,
 ) -> Result<
     (
         StatusCode,
@@ -351,7 +643,7 @@ pub async fn delete_repo(
     ApiError,
 > {
     let active = Repo::active_workspace_names(&deployment.db().pool, repo_id).await?;
-    if !active.is_empty() {
+    if !active.is_empty() && !query.force {
         return Ok((
             StatusCode::CONFLICT,
             ResponseJson(ApiResponse::error_with_data(DeleteRepoConflict {
@@ -376,9 +668,40 @@ pub fn router() -> Router<DeploymentImpl> {
             get(get_repo).put(update_repo).delete(delete_repo),
         )
         .route("/repos/{repo_id}/branches", get(get_repo_branches))
+        .route(
+            "/repos/{repo_id}/branches/details",
+            get(get_repo_branch_details),
+        )
         .route("/repos/{repo_id}/remotes", get(get_repo_remotes))
         .route("/repos/{repo_id}/prs", get(list_open_prs))
+        .route("/repos/{repo_id}/script-runs", get(get_repo_script_runs))
+        .route(
+            "/repos/{repo_id}/validate-script",
+            post(validate_repo_script),
+        )
         .route("/repos/pr-info", get(get_pr_info))
         .route("/repos/{repo_id}/search", get(search_repo))
         .route("/repos/{repo_id}/open-editor", post(open_repo_in_editor))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::script_warnings;
+
+    #[test]
+    fn warns_about_cd_without_and() {
+        let warnings = script_warnings("#!/bin/bash\ncd frontend\nnpm install\ncd api && make\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Line 2:"));
+    }
+
+    #[test]
+    fn does_not_require_a_shebang() {
+        assert!(script_warnings("npm install\n").is_empty());
+    }
+
+    #[test]
+    fn set_e_silences_cd_warning() {
+        assert!(script_warnings("#!/bin/bash\nset -euo pipefail\ncd frontend\n").is_empty());
+    }
+}
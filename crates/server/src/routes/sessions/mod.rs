@@ -11,18 +11,15 @@ use axum::{
 use db::models::{
     coding_agent_turn::CodingAgentTurn,
     execution_process::{ExecutionProcess, ExecutionProcessRunReason},
-    requests::UpdateSession,
+    requests::{CreateFollowUpAttempt, RunRepoScriptQuery, UpdateSession},
     scratch::{Scratch, ScratchType},
     session::{CreateSession, Session, SessionError},
     workspace::{Workspace, WorkspaceError},
     workspace_repo::WorkspaceRepo,
 };
 use deployment::Deployment;
-use executors::{
-    actions::{
-        ExecutorAction, ExecutorActionType, coding_agent_follow_up::CodingAgentFollowUpRequest,
-    },
-    profile::ExecutorConfig,
+use executors::actions::{
+    ExecutorAction, ExecutorActionType, coding_agent_follow_up::CodingAgentFollowUpRequest,
 };
 use serde::Deserialize;
 use services::services::container::ContainerService;
@@ -31,8 +28,10 @@ use utils::response::ApiResponse;
 use uuid::Uuid;
 
 use crate::{
-    DeploymentImpl, error::ApiError, middleware::load_session_middleware,
-    routes::workspaces::execution::RunScriptError,
+    DeploymentImpl,
+    error::ApiError,
+    middleware::load_session_middleware,
+    routes::workspaces::execution::{RunScriptError, select_repos},
 };
 
 #[derive(Debug, Deserialize)]
@@ -105,15 +104,6 @@ pub async fn update_session(
     Ok(ResponseJson(ApiResponse::success(updated)))
 }
 
-#[derive(Debug, Deserialize, TS)]
-pub struct CreateFollowUpAttempt {
-    pub prompt: String,
-    pub executor_config: ExecutorConfig,
-    pub retry_process_id: Option<Uuid>,
-    pub force_when_dirty: Option<bool>,
-    pub perform_git_reset: Option<bool>,
-}
-
 #[derive(Debug, Deserialize, TS)]
 pub struct ResetProcessRequest {
     pub process_id: Uuid,
@@ -257,6 +247,7 @@ pub async fn reset_process(
 pub async fn run_setup_script(
     Extension(session): Extension<Session>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<RunRepoScriptQuery>,
 ) -> Result<ResponseJson<ApiResponse<ExecutionProcess, RunScriptError>>, ApiError> {
     let pool = &deployment.db().pool;
 
@@ -279,7 +270,10 @@ pub async fn run_setup_script(
         .ensure_container_exists(&workspace)
         .await?;
 
-    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let repos = select_repos(
+        WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?,
+        query.repo_id,
+    )?;
     let executor_action = match deployment.container().setup_actions_for_repos(&repos) {
         Some(action) => action,
         None => {
@@ -1,6 +1,13 @@
-use axum::{Extension, Router, extract::State, response::Json as ResponseJson, routing::post};
+use axum::{
+    Extension, Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+    repo::{Repo, RepoError},
+    requests::RunRepoScriptQuery,
     session::{CreateSession, Session},
     workspace::Workspace,
     workspace_repo::WorkspaceRepo,
@@ -32,15 +39,23 @@ pub fn router() -> Router<DeploymentImpl> {
         .route("/cleanup", post(run_cleanup_script))
         .route("/archive", post(run_archive_script))
         .route("/stop", post(stop_workspace_execution))
+        .route("/latest-coding-agent", get(get_latest_coding_agent_process))
+        .route("/processes", get(list_workspace_execution_processes))
 }
 
 #[axum::debug_handler]
 pub async fn start_dev_server(
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<RunRepoScriptQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<ExecutionProcess>>>, ApiError> {
     let pool = &deployment.db().pool;
 
+    let repos = select_repos(
+        WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?,
+        query.repo_id,
+    )?;
+
     let existing_dev_servers =
         match ExecutionProcess::find_running_dev_servers_by_workspace(pool, workspace.id).await {
             Ok(servers) => servers,
@@ -56,7 +71,7 @@ pub async fn start_dev_server(
             }
         };
 
-    for dev_server in existing_dev_servers {
+    for dev_server in dev_servers_to_restart(existing_dev_servers, &repos, query.repo_id) {
         tracing::info!(
             "Stopping existing dev server {} for workspace {}",
             dev_server.id,
@@ -72,7 +87,6 @@ pub async fn start_dev_server(
         }
     }
 
-    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
     let repos_with_dev_script: Vec<_> = repos
         .iter()
         .filter(|r| r.dev_server_script.as_ref().is_some_and(|s| !s.is_empty()))
@@ -136,6 +150,64 @@ pub async fn start_dev_server(
     Ok(ResponseJson(ApiResponse::success(execution_processes)))
 }
 
+// Narrows the workspace's repositories to the one `repo_id` names, or keeps them all.
+pub(crate) fn select_repos(
+    mut repos: Vec<Repo>,
+    repo_id: Option<Uuid>,
+) -> Result<Vec<Repo>, RepoError> {
+    if let Some(repo_id) = repo_id {
+        repos.retain(|repo| repo.id == repo_id);
+        if repos.is_empty() {
+            return Err(RepoError::NotFound);
+        }
+    }
+    Ok(repos)
+}
+
+// Restarting one repository's dev server leaves the others running.
+fn dev_servers_to_restart(
+    running: Vec<ExecutionProcess>,
+    repos: &[Repo],
+    repo_id: Option<Uuid>,
+) -> Vec<ExecutionProcess> {
+    running
+        .into_iter()
+        .filter(|dev_server| {
+            repo_id.is_none()
+                || dev_server
+                    .script_working_dir()
+                    .is_none_or(|dir| repos.iter().any(|repo| repo.name == dir))
+        })
+        .collect()
+}
+
+/// Latest coding agent execution across all of the workspace's sessions, if any.
+pub async fn get_latest_coding_agent_process(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<ExecutionProcess>>>, ApiError> {
+    let execution_process = ExecutionProcess::find_latest_by_workspace_and_run_reason(
+        &deployment.db().pool,
+        workspace.id,
+        &ExecutionProcessRunReason::CodingAgent,
+    )
+    .await?;
+
+    Ok(ResponseJson(ApiResponse::success(execution_process)))
+}
+
+/// Execution processes across all of the workspace's sessions, oldest first. Processes
+/// dropped from the history view are left out unless they are still running.
+pub async fn list_workspace_execution_processes(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ExecutionProcess>>>, ApiError> {
+    let execution_processes =
+        ExecutionProcess::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(execution_processes)))
+}
+
 pub async fn stop_workspace_execution(
     Extension(workspace): Extension<Workspace>,
     State(deployment): State<DeploymentImpl>,
@@ -286,3 +358,98 @@ pub async fn run_archive_script(
 
     Ok(ResponseJson(ApiResponse::success(execution_process)))
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use db::models::{
+        execution_process::{
+            ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus,
+            ExecutorActionField,
+        },
+        repo::{Repo, RepoError},
+    };
+    use executors::actions::{
+        ExecutorAction, ExecutorActionType,
+        script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
+    };
+    use uuid::Uuid;
+
+    use super::{dev_servers_to_restart, select_repos};
+
+    fn repo(name: &str) -> Repo {
+        Repo {
+            id: Uuid::new_v4(),
+            path: format!("/repos/{name}").into(),
+            name: name.to_string(),
+            display_name: name.to_string(),
+            setup_script: None,
+            cleanup_script: None,
+            archive_script: None,
+            copy_files: None,
+            parallel_setup_script: false,
+            dev_server_script: Some("npm run dev".to_string()),
+            default_target_branch: None,
+            default_working_dir: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn dev_server(working_dir: Option<&str>) -> ExecutionProcess {
+        let action = ExecutorAction::new(
+            ExecutorActionType::ScriptRequest(ScriptRequest {
+                script: "npm run dev".to_string(),
+                language: ScriptRequestLanguage::Bash,
+                context: ScriptContext::DevServer,
+                working_dir: working_dir.map(str::to_string),
+            }),
+            None,
+        );
+        ExecutionProcess {
+            id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            run_reason: ExecutionProcessRunReason::DevServer,
+            executor_action: sqlx::types::Json(ExecutorActionField::ExecutorAction(action)),
+            status: ExecutionProcessStatus::Running,
+            exit_code: None,
+            dropped: false,
+            started_at: Utc::now(),
+            completed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn select_repos_keeps_only_the_requested_repo() {
+        let (app, docs) = (repo("app"), repo("docs"));
+        let repos = vec![app.clone(), docs.clone()];
+
+        let selected = select_repos(repos.clone(), Some(docs.id)).unwrap();
+        assert_eq!(selected.iter().map(|r| r.id).collect::<Vec<_>>(), [docs.id]);
+        assert_eq!(select_repos(repos.clone(), None).unwrap().len(), 2);
+        assert!(matches!(
+            select_repos(repos, Some(Uuid::new_v4())),
+            Err(RepoError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn restarting_one_repo_leaves_other_dev_servers_running() {
+        let app = repo("app");
+        let running = vec![
+            dev_server(Some("app")),
+            dev_server(Some("docs")),
+            dev_server(None),
+        ];
+        let ids = running.iter().map(|p| p.id).collect::<Vec<_>>();
+
+        let stopped = dev_servers_to_restart(running.clone(), &[app.clone()], Some(app.id));
+        assert_eq!(
+            stopped.iter().map(|p| p.id).collect::<Vec<_>>(),
+            [ids[0], ids[2]]
+        );
+        assert_eq!(dev_servers_to_restart(running, &[app], None).len(), 3);
+    }
+}
@@ -5,27 +5,34 @@ use std::{
 
 use axum::{
     Extension, Json, Router,
-    extract::State,
+    extract::{Query, State},
     response::{IntoResponse, Json as ResponseJson},
     routing::{get, post},
 };
 use db::models::{
     merge::{Merge, MergeStatus, PrMerge, PullRequestInfo},
     repo::{Repo, RepoError},
+    requests::{FileDiffSummary, RepoDiffSummary, WorkspaceDiffSummary, WorkspaceDiffSummaryQuery},
     workspace::Workspace,
-    workspace_repo::WorkspaceRepo,
+    workspace_repo::{RepoWithTargetBranch, WorkspaceRepo},
 };
 use deployment::Deployment;
-use git::{ConflictOp, GitCliError, GitServiceError};
+use git::{ConflictOp, GitCliError, GitService, GitServiceError};
 use serde::{Deserialize, Serialize};
 use services::services::{container::ContainerService, diff_stream, remote_sync};
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::{
+    diff::{Diff, DiffChangeKind, create_unified_diff},
+    response::ApiResponse,
+};
 use uuid::Uuid;
 
 use super::streams::{DiffStreamQuery, stream_workspace_diff_ws};
 use crate::{DeploymentImpl, error::ApiError, middleware::signed_ws::SignedWsUpgrade};
 
+const DEFAULT_DIFF_SUMMARY_PATCH_BYTES: usize = 64 * 1024;
+const MAX_DIFF_SUMMARY_PATCH_BYTES: usize = 1024 * 1024;
+
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct RebaseWorkspaceRequest {
     pub repo_id: Uuid,
@@ -138,6 +145,7 @@ pub fn router() -> Router<DeploymentImpl> {
     Router::new()
         .route("/status", get(get_workspace_branch_status))
         .route("/diff/ws", get(stream_diff_ws))
+        .route("/diff-summary", get(get_workspace_diff_summary))
         .route("/merge", post(merge_workspace))
         .route("/push", post(push_workspace_branch))
         .route("/push/force", post(force_push_workspace_branch))
@@ -501,6 +509,142 @@ pub async fn get_workspace_branch_status(
     Ok(ResponseJson(ApiResponse::success(results)))
 }
 
+pub async fn get_workspace_diff_summary(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<WorkspaceDiffSummaryQuery>,
+) -> Result<ResponseJson<ApiResponse<WorkspaceDiffSummary>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let repos =
+        WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace.id).await?;
+    if let Some(repo_id) = query.repo_id
+        && !repos.iter().any(|r| r.repo.id == repo_id)
+    {
+        return Err(ApiError::BadRequest(format!(
+            "Repository {} is not part of this workspace",
+            repo_id
+        )));
+    }
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let workspace_dir = PathBuf::from(&container_ref);
+
+    let mut patch_budget = query.include_patch.unwrap_or(false).then(|| {
+        query
+            .max_patch_bytes
+            .unwrap_or(DEFAULT_DIFF_SUMMARY_PATCH_BYTES)
+            .min(MAX_DIFF_SUMMARY_PATCH_BYTES)
+    });
+    let mut summary = WorkspaceDiffSummary {
+        repos: Vec::new(),
+        files_changed: 0,
+        additions: 0,
+        deletions: 0,
+        truncated: false,
+    };
+
+    for RepoWithTargetBranch {
+        repo,
+        target_branch,
+    } in repos
+    {
+        if query.repo_id.is_some_and(|id| id != repo.id) {
+            continue;
+        }
+
+        let base_commit =
+            deployment
+                .git()
+                .get_base_commit(&repo.path, &workspace.branch, &target_branch)?;
+        let diffs =
+            deployment
+                .git()
+                .get_diffs(&workspace_dir.join(&repo.name), &base_commit, None)?;
+
+        let files = diffs
+            .into_iter()
+            .map(|diff| file_diff_summary(diff, &mut patch_budget, &mut summary.truncated))
+            .collect::<Vec<_>>();
+        let additions = files.iter().filter_map(|f| f.additions).sum::<usize>();
+        let deletions = files.iter().filter_map(|f| f.deletions).sum::<usize>();
+
+        summary.files_changed += files.len();
+        summary.additions += additions;
+        summary.deletions += deletions;
+        summary.repos.push(RepoDiffSummary {
+            repo_id: repo.id,
+            repo_name: repo.name,
+            target_branch,
+            files,
+            additions,
+            deletions,
+        });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(summary)))
+}
+
+// Binary files are the ones git gave no text content for, unless it was left out for size.
+fn file_diff_summary(
+    diff: Diff,
+    patch_budget: &mut Option<usize>,
+    truncated: &mut bool,
+) -> FileDiffSummary {
+    let path = GitService::diff_path(&diff);
+    let binary = !diff.content_omitted && diff.old_content.is_none() && diff.new_content.is_none();
+
+    let (patch, patch_truncated) = match patch_budget {
+        Some(remaining) if !binary => {
+            if diff.content_omitted || *remaining == 0 {
+                (None, true)
+            } else {
+                let patch = create_unified_diff(
+                    &path,
+                    diff.old_content.as_deref().unwrap_or(""),
+                    diff.new_content.as_deref().unwrap_or(""),
+                );
+                let (patch, cut) = truncate_patch(patch, *remaining);
+                *remaining -= patch.len();
+                (Some(patch), cut)
+            }
+        }
+        _ => (None, false),
+    };
+    *truncated |= patch_truncated;
+
+    FileDiffSummary {
+        old_path: matches!(
+            diff.change,
+            DiffChangeKind::Renamed | DiffChangeKind::Copied
+        )
+        .then_some(diff.old_path)
+        .flatten(),
+        path,
+        change: diff.change,
+        additions: diff.additions,
+        deletions: diff.deletions,
+        binary,
+        patch,
+        patch_truncated,
+    }
+}
+
+/// Cuts a patch down to at most `max_bytes`, at the last whole line that fits.
+fn truncate_patch(patch: String, max_bytes: usize) -> (String, bool) {
+    if patch.len() <= max_bytes {
+        return (patch, false);
+    }
+    let mut end = max_bytes;
+    while !patch.is_char_boundary(end) {
+        end -= 1;
+    }
+    let end = patch[..end].rfind('\n').map_or(0, |i| i + 1);
+    (patch[..end].to_string(), true)
+}
+
 #[axum::debug_handler]
 pub async fn change_target_branch(
     Extension(workspace): Extension<Workspace>,
@@ -838,3 +982,19 @@ pub async fn continue_workspace_rebase(
 
     Ok(ResponseJson(ApiResponse::success(())))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_patch;
+
+    #[test]
+    fn truncate_patch_cuts_at_the_last_whole_line() {
+        let patch = "--- a/f\n+++ b/f\n@@ -1 +1 @@\n-old\n+new\n".to_string();
+
+        assert_eq!(truncate_patch(patch.clone(), 1024), (patch.clone(), false));
+        assert_eq!(
+            truncate_patch(patch, 20),
+            ("--- a/f\n+++ b/f\n".to_string(), true)
+        );
+    }
+}
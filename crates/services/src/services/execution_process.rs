@@ -1,6 +1,7 @@
 use std::{
     collections::HashMap,
     io::{IsTerminal, Write},
+    path::Path,
     sync::Arc,
 };
 
@@ -20,7 +21,7 @@ use utils::{
     assets::prod_asset_dir_path,
     execution_logs::{
         ExecutionLogWriter, process_log_file_path, process_log_file_path_in_root,
-        read_execution_log_file,
+        read_execution_log_file, read_execution_log_file_tail,
     },
     log_msg::LogMsg,
     msg_store::MsgStore,
@@ -193,21 +194,43 @@ pub async fn remove_session_process_logs(session_id: Uuid) -> Result<()> {
 }
 
 pub async fn load_raw_log_messages(pool: &SqlitePool, execution_id: Uuid) -> Option<Vec<LogMsg>> {
-    if let Some(jsonl) = read_execution_logs_for_execution(pool, execution_id)
+    load_raw_log_messages_from(pool, execution_id, None)
         .await
-        .inspect_err(|e| {
-            tracing::warn!(
-                "Failed to read execution log file for execution {}: {:#}",
-                execution_id,
-                e
-            );
-        })
-        .ok()
-        .flatten()
+        .map(|(messages, _)| messages)
+}
+
+/// Like [`load_raw_log_messages`], but reads at most the last `max_file_bytes` of the log file,
+/// and reports whether earlier messages were skipped. Logs still stored in the database are
+/// loaded in full.
+pub async fn load_raw_log_tail_messages(
+    pool: &SqlitePool,
+    execution_id: Uuid,
+    max_file_bytes: u64,
+) -> Option<(Vec<LogMsg>, bool)> {
+    load_raw_log_messages_from(pool, execution_id, Some(max_file_bytes)).await
+}
+
+async fn load_raw_log_messages_from(
+    pool: &SqlitePool,
+    execution_id: Uuid,
+    tail_bytes: Option<u64>,
+) -> Option<(Vec<LogMsg>, bool)> {
+    if let Some((jsonl, skipped)) =
+        read_execution_logs_for_execution(pool, execution_id, tail_bytes)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!(
+                    "Failed to read execution log file for execution {}: {:#}",
+                    execution_id,
+                    e
+                );
+            })
+            .ok()
+            .flatten()
     {
         let messages = utils::execution_logs::parse_log_jsonl_lossy(execution_id, &jsonl);
         if !messages.is_empty() {
-            return Some(messages);
+            return Some((messages, skipped));
         }
     }
 
@@ -226,7 +249,7 @@ pub async fn load_raw_log_messages(pool: &SqlitePool, execution_id: Uuid) -> Opt
     };
 
     match ExecutionProcessLogs::parse_logs(&db_log_records) {
-        Ok(msgs) => Some(msgs),
+        Ok(msgs) => Some((msgs, false)),
         Err(e) => {
             tracing::error!(
                 "Failed to parse DB logs for execution {}: {}",
@@ -351,7 +374,8 @@ pub fn spawn_stream_raw_logs_to_storage(
 async fn read_execution_logs_for_execution(
     pool: &SqlitePool,
     execution_id: Uuid,
-) -> Result<Option<String>> {
+    tail_bytes: Option<u64>,
+) -> Result<Option<(String, bool)>> {
     let session_id = if let Some(process) = ExecutionProcess::find_by_id(pool, execution_id).await?
     {
         process.session_id
@@ -361,7 +385,7 @@ async fn read_execution_logs_for_execution(
     let path = process_log_file_path(session_id, execution_id);
 
     match tokio::fs::metadata(&path).await {
-        Ok(_) => Ok(Some(read_execution_log_file(&path).await.with_context(
+        Ok(_) => Ok(Some(read_log_file(&path, tail_bytes).await.with_context(
             || format!("read execution log file for execution {execution_id}"),
         )?)),
         Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
@@ -369,7 +393,7 @@ async fn read_execution_logs_for_execution(
                 // Convenience for local development with a clone of a prod db. Read only access to prod logs.
                 let prod_path =
                     process_log_file_path_in_root(&prod_asset_dir_path(), session_id, execution_id);
-                match read_execution_log_file(&prod_path).await {
+                match read_log_file(&prod_path, tail_bytes).await {
                     Ok(contents) => return Ok(Some(contents)),
                     Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
                     Err(err) => {
@@ -393,6 +417,13 @@ async fn read_execution_logs_for_execution(
     }
 }
 
+async fn read_log_file(path: &Path, tail_bytes: Option<u64>) -> std::io::Result<(String, bool)> {
+    match tail_bytes {
+        Some(max_bytes) => read_execution_log_file_tail(path, max_bytes).await,
+        None => Ok((read_execution_log_file(path).await?, false)),
+    }
+}
+
 fn new_spinner(message: &'static str) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
     pb.set_style(
@@ -3,20 +3,30 @@
 use std::time::Duration;
 
 use api_types::{
-    AcceptInvitationResponse, AuthMethodsResponse, CreateInvitationRequest,
-    CreateInvitationResponse, CreateIssueAssigneeRequest, CreateIssueRelationshipRequest,
-    CreateIssueRequest, CreateIssueTagRequest, CreateOrganizationRequest,
-    CreateOrganizationResponse, CreateWorkspaceRequest, DeleteResponse, DeleteWorkspaceRequest,
+    AcceptInvitationResponse, AuthMethodsResponse, BulkUpdateNotificationsRequest,
+    BulkUpdateNotificationsResponse, BulkUpdateProjectStatusesRequest,
+    BulkUpdateProjectStatusesResponse, CreateInvitationRequest, CreateInvitationResponse,
+    CreateIssueAssigneeRequest, CreateIssueCommentReactionRequest, CreateIssueCommentRequest,
+    CreateIssueFollowerRequest, CreateIssueRelationshipRequest, CreateIssueRequest,
+    CreateIssueTagRequest, CreateOrganizationRequest, CreateOrganizationResponse,
+    CreateProjectRequest, CreateProjectStatusRequest, CreatePullRequestIssueRequest,
+    CreateTagRequest, CreateWorkspaceRequest, DeleteResponse, DeleteWorkspaceRequest,
     GetInvitationResponse, GetOrganizationResponse, HandoffInitRequest, HandoffInitResponse,
-    HandoffRedeemRequest, HandoffRedeemResponse, Issue, IssueAssignee, IssueRelationship, IssueTag,
+    HandoffRedeemRequest, HandoffRedeemResponse, Issue, IssueAssignee, IssueComment,
+    IssueCommentReaction, IssueCountsResponse, IssueFollower, IssueRelationship, IssueTag,
     ListAttachmentsResponse, ListInvitationsResponse, ListIssueAssigneesResponse,
+    ListIssueCommentReactionsResponse, ListIssueCommentsResponse, ListIssueFollowersResponse,
     ListIssueRelationshipsResponse, ListIssueTagsResponse, ListIssuesResponse, ListMembersResponse,
-    ListOrganizationsResponse, ListProjectStatusesResponse, ListProjectsResponse,
-    ListPullRequestsResponse, ListTagsResponse, LocalLoginRequest, LocalLoginResponse,
-    MutationResponse, Organization, ProfileResponse, PullRequest, RevokeInvitationRequest,
-    SearchIssuesRequest, Tag, TokenRefreshRequest, TokenRefreshResponse, UpdateIssueRequest,
-    UpdateMemberRoleRequest, UpdateMemberRoleResponse, UpdateOrganizationRequest,
-    UpdatePullRequestApiRequest, UpdateWorkspaceRequest, UpsertPullRequestRequest, Workspace,
+    ListNotificationsResponse, ListOrganizationsResponse, ListProjectStatusesResponse,
+    ListProjectsResponse, ListPullRequestIssuesResponse, ListPullRequestsResponse,
+    ListTagsResponse, ListWorkspacesByLocalIdsRequest, ListWorkspacesByLocalIdsResponse,
+    LocalLoginRequest, LocalLoginResponse, MutationResponse, Notification, Organization,
+    ProfileResponse, ProjectStatus, PullRequest, PullRequestIssue, RevokeInvitationRequest,
+    SearchIssuesRequest, Tag, TokenRefreshRequest, TokenRefreshResponse, UpdateIssueCommentRequest,
+    UpdateIssueRequest, UpdateMemberRoleRequest, UpdateMemberRoleResponse,
+    UpdateNotificationRequest, UpdateOrganizationRequest, UpdateProjectStatusRequest,
+    UpdatePullRequestApiRequest, UpdateTagRequest, UpdateWorkspaceRequest,
+    UpsertPullRequestRequest, Workspace,
 };
 use backon::{ExponentialBuilder, Retryable};
 use chrono::Duration as ChronoDuration;
@@ -26,7 +36,10 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::warn;
 use url::Url;
-use utils::jwt::extract_expiration;
+use utils::{
+    http_headers::{REQUEST_ID_HEADER, current_request_id},
+    jwt::extract_expiration,
+};
 use uuid::Uuid;
 
 use super::{auth::AuthContext, oauth_credentials::Credentials};
@@ -384,6 +397,10 @@ impl RemoteClient {
                 .header("X-Client-Version", env!("CARGO_PKG_VERSION"))
                 .header("X-Client-Type", "local-backend");
 
+            if let Some(request_id) = current_request_id() {
+                req = req.header(REQUEST_ID_HEADER, request_id);
+            }
+
             if requires_auth {
                 let token = self.require_token().await?;
                 req = req.bearer_auth(token);
@@ -674,6 +691,15 @@ impl RemoteClient {
             .await
     }
 
+    /// Gets the remote workspaces linked to any of the given local workspace IDs.
+    pub async fn list_workspaces_by_local_ids(
+        &self,
+        request: &ListWorkspacesByLocalIdsRequest,
+    ) -> Result<ListWorkspacesByLocalIdsResponse, RemoteClientError> {
+        self.post_authed("/v1/workspaces/by-local-ids", Some(request))
+            .await
+    }
+
     /// Checks if a workspace exists on the remote server.
     pub async fn workspace_exists(
         &self,
@@ -753,15 +779,6 @@ impl RemoteClient {
 
     // ── Issues ──────────────────────────────────────────────────────────
 
-    /// Lists issues for a project.
-    pub async fn list_issues(
-        &self,
-        project_id: Uuid,
-    ) -> Result<ListIssuesResponse, RemoteClientError> {
-        self.get_authed(&format!("/v1/issues?project_id={project_id}"))
-            .await
-    }
-
     /// Searches issues for a project using the canonical JSON request shape.
     pub async fn search_issues(
         &self,
@@ -770,11 +787,30 @@ impl RemoteClient {
         self.post_authed("/v1/issues/search", Some(request)).await
     }
 
+    /// Counts a project's unarchived issues per status and priority.
+    pub async fn count_issues(
+        &self,
+        project_id: Uuid,
+    ) -> Result<IssueCountsResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/issues/counts?project_id={project_id}"))
+            .await
+    }
+
     /// Gets a single issue by ID.
     pub async fn get_issue(&self, issue_id: Uuid) -> Result<Issue, RemoteClientError> {
         self.get_authed(&format!("/v1/issues/{issue_id}")).await
     }
 
+    /// Gets a single issue by its simple ID (e.g. "ACME-123") within a project.
+    pub async fn get_issue_by_simple_id(
+        &self,
+        project_id: Uuid,
+        simple_id: &str,
+    ) -> Result<Issue, RemoteClientError> {
+        self.get_authed(&format!("/v1/issues/by-simple-id/{project_id}/{simple_id}"))
+            .await
+    }
+
     /// Creates a new issue.
     pub async fn create_issue(
         &self,
@@ -793,6 +829,24 @@ impl RemoteClient {
             .await
     }
 
+    /// Archives an issue, hiding it from default listings without deleting it.
+    pub async fn archive_issue(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<MutationResponse<Issue>, RemoteClientError> {
+        self.post_authed(&format!("/v1/issues/{issue_id}/archive"), None::<&()>)
+            .await
+    }
+
+    /// Restores an archived issue.
+    pub async fn unarchive_issue(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<MutationResponse<Issue>, RemoteClientError> {
+        self.post_authed(&format!("/v1/issues/{issue_id}/unarchive"), None::<&()>)
+            .await
+    }
+
     /// Deletes an issue.
     pub async fn delete_issue(&self, issue_id: Uuid) -> Result<DeleteResponse, RemoteClientError> {
         let res = self
@@ -819,6 +873,15 @@ impl RemoteClient {
             .await
     }
 
+    /// Lists the assignees of every issue in a project.
+    pub async fn list_project_issue_assignees(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ListIssueAssigneesResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/issue_assignees?project_id={project_id}"))
+            .await
+    }
+
     /// Gets a single issue assignee by ID.
     pub async fn get_issue_assignee(
         &self,
@@ -854,6 +917,43 @@ impl RemoteClient {
             .map_err(|e| RemoteClientError::Serde(e.to_string()))
     }
 
+    // ── Issue Followers ────────────────────────────────────────────────
+
+    /// Lists followers for an issue.
+    pub async fn list_issue_followers(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<ListIssueFollowersResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/issue_followers?issue_id={issue_id}"))
+            .await
+    }
+
+    /// Creates a new issue follower.
+    pub async fn create_issue_follower(
+        &self,
+        request: &CreateIssueFollowerRequest,
+    ) -> Result<MutationResponse<IssueFollower>, RemoteClientError> {
+        self.post_authed("/v1/issue_followers", Some(request)).await
+    }
+
+    /// Deletes an issue follower.
+    pub async fn delete_issue_follower(
+        &self,
+        issue_follower_id: Uuid,
+    ) -> Result<DeleteResponse, RemoteClientError> {
+        let res = self
+            .send(
+                reqwest::Method::DELETE,
+                &format!("/v1/issue_followers/{issue_follower_id}"),
+                true,
+                None::<&()>,
+            )
+            .await?;
+        res.json::<DeleteResponse>()
+            .await
+            .map_err(|e| RemoteClientError::Serde(e.to_string()))
+    }
+
     // ── Tags ───────────────────────────────────────────────────────────
 
     /// Lists tags for a project.
@@ -867,6 +967,39 @@ impl RemoteClient {
         self.get_authed(&format!("/v1/tags/{tag_id}")).await
     }
 
+    /// Creates a tag in a project.
+    pub async fn create_tag(
+        &self,
+        request: &CreateTagRequest,
+    ) -> Result<MutationResponse<Tag>, RemoteClientError> {
+        self.post_authed("/v1/tags", Some(request)).await
+    }
+
+    /// Renames or recolors a tag.
+    pub async fn update_tag(
+        &self,
+        tag_id: Uuid,
+        request: &UpdateTagRequest,
+    ) -> Result<MutationResponse<Tag>, RemoteClientError> {
+        self.patch_authed(&format!("/v1/tags/{tag_id}"), request)
+            .await
+    }
+
+    /// Deletes a tag, detaching it from every issue.
+    pub async fn delete_tag(&self, tag_id: Uuid) -> Result<DeleteResponse, RemoteClientError> {
+        let res = self
+            .send(
+                reqwest::Method::DELETE,
+                &format!("/v1/tags/{tag_id}"),
+                true,
+                None::<&()>,
+            )
+            .await?;
+        res.json::<DeleteResponse>()
+            .await
+            .map_err(|e| RemoteClientError::Serde(e.to_string()))
+    }
+
     // ── Issue Tags ─────────────────────────────────────────────────────
 
     /// Lists tags attached to an issue.
@@ -878,6 +1011,15 @@ impl RemoteClient {
             .await
     }
 
+    /// Lists the tags attached to every issue in a project.
+    pub async fn list_project_issue_tags(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ListIssueTagsResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/issue_tags?project_id={project_id}"))
+            .await
+    }
+
     /// Gets a single issue-tag relation by ID.
     pub async fn get_issue_tag(&self, issue_tag_id: Uuid) -> Result<IssueTag, RemoteClientError> {
         self.get_authed(&format!("/v1/issue_tags/{issue_tag_id}"))
@@ -939,6 +1081,83 @@ impl RemoteClient {
             .await
     }
 
+    // ── Issue Comments ─────────────────────────────────────────────────
+
+    /// Lists comments for an issue.
+    pub async fn list_issue_comments(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<ListIssueCommentsResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/issue_comments?issue_id={issue_id}"))
+            .await
+    }
+
+    /// Creates a new comment on an issue.
+    pub async fn create_issue_comment(
+        &self,
+        request: &CreateIssueCommentRequest,
+    ) -> Result<MutationResponse<IssueComment>, RemoteClientError> {
+        self.post_authed("/v1/issue_comments", Some(request)).await
+    }
+
+    /// Edits a comment. Only its author or an organization admin may do so.
+    pub async fn update_issue_comment(
+        &self,
+        comment_id: Uuid,
+        request: &UpdateIssueCommentRequest,
+    ) -> Result<MutationResponse<IssueComment>, RemoteClientError> {
+        self.patch_authed(&format!("/v1/issue_comments/{comment_id}"), request)
+            .await
+    }
+
+    /// Deletes a comment. Only its author or an organization admin may do so.
+    pub async fn delete_issue_comment(&self, comment_id: Uuid) -> Result<(), RemoteClientError> {
+        self.delete_authed(&format!("/v1/issue_comments/{comment_id}"))
+            .await
+    }
+
+    // ── Issue Comment Reactions ────────────────────────────────────────
+
+    /// Lists reactions on a single comment.
+    pub async fn list_comment_reactions(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<ListIssueCommentReactionsResponse, RemoteClientError> {
+        self.get_authed(&format!(
+            "/v1/issue_comment_reactions?comment_id={comment_id}"
+        ))
+        .await
+    }
+
+    /// Lists reactions on all comments of an issue in one request.
+    pub async fn list_issue_comment_reactions(
+        &self,
+        issue_id: Uuid,
+    ) -> Result<ListIssueCommentReactionsResponse, RemoteClientError> {
+        self.get_authed(&format!(
+            "/v1/fallback/issue_comment_reactions?issue_id={issue_id}"
+        ))
+        .await
+    }
+
+    /// Adds a reaction to a comment as the current user.
+    pub async fn create_issue_comment_reaction(
+        &self,
+        request: &CreateIssueCommentReactionRequest,
+    ) -> Result<MutationResponse<IssueCommentReaction>, RemoteClientError> {
+        self.post_authed("/v1/issue_comment_reactions", Some(request))
+            .await
+    }
+
+    /// Removes one of the current user's reactions.
+    pub async fn delete_issue_comment_reaction(
+        &self,
+        reaction_id: Uuid,
+    ) -> Result<(), RemoteClientError> {
+        self.delete_authed(&format!("/v1/issue_comment_reactions/{reaction_id}"))
+            .await
+    }
+
     // ── Remote Projects ─────────────────────────────────────────────────
 
     /// Gets a single remote project by ID.
@@ -949,6 +1168,24 @@ impl RemoteClient {
         self.get_authed(&format!("/v1/projects/{project_id}")).await
     }
 
+    /// Creates a project along with its default statuses.
+    pub async fn create_remote_project(
+        &self,
+        request: &CreateProjectRequest,
+    ) -> Result<MutationResponse<api_types::Project>, RemoteClientError> {
+        self.post_authed("/v1/projects", Some(request)).await
+    }
+
+    /// Renames or recolors a project.
+    pub async fn update_remote_project(
+        &self,
+        project_id: Uuid,
+        request: &UpdateProjectRequest,
+    ) -> Result<MutationResponse<api_types::Project>, RemoteClientError> {
+        self.patch_authed(&format!("/v1/projects/{project_id}"), request)
+            .await
+    }
+
     /// Lists projects for an organization.
     pub async fn list_remote_projects(
         &self,
@@ -969,6 +1206,85 @@ impl RemoteClient {
             .await
     }
 
+    /// Creates a status column in a project.
+    pub async fn create_project_status(
+        &self,
+        request: &CreateProjectStatusRequest,
+    ) -> Result<MutationResponse<ProjectStatus>, RemoteClientError> {
+        self.post_authed("/v1/project_statuses", Some(request))
+            .await
+    }
+
+    /// Updates a project status (name, color, sort order or visibility).
+    pub async fn update_project_status(
+        &self,
+        status_id: Uuid,
+        request: &UpdateProjectStatusRequest,
+    ) -> Result<MutationResponse<ProjectStatus>, RemoteClientError> {
+        self.patch_authed(&format!("/v1/project_statuses/{status_id}"), request)
+            .await
+    }
+
+    /// Deletes a project status, first moving its issues to `move_issues_to_status_id` if given.
+    pub async fn delete_project_status(
+        &self,
+        status_id: Uuid,
+        move_issues_to_status_id: Option<Uuid>,
+    ) -> Result<DeleteResponse, RemoteClientError> {
+        let mut path = format!("/v1/project_statuses/{status_id}");
+        if let Some(target_id) = move_issues_to_status_id {
+            path.push_str(&format!("?move_issues_to_status_id={target_id}"));
+        }
+        let res = self
+            .send(reqwest::Method::DELETE, &path, true, None::<&()>)
+            .await?;
+        res.json::<DeleteResponse>()
+            .await
+            .map_err(|e| RemoteClientError::Serde(e.to_string()))
+    }
+
+    /// Updates several statuses of one project in one transaction.
+    pub async fn bulk_update_project_statuses(
+        &self,
+        request: &BulkUpdateProjectStatusesRequest,
+    ) -> Result<BulkUpdateProjectStatusesResponse, RemoteClientError> {
+        self.post_authed("/v1/project_statuses/bulk", Some(request))
+            .await
+    }
+
+    // ── Notifications ───────────────────────────────────────────────────
+
+    /// Lists notifications for the current user across all organizations.
+    /// Read notifications are dismissed, so they are only included with `include_dismissed`.
+    pub async fn list_notifications(
+        &self,
+        include_dismissed: bool,
+    ) -> Result<ListNotificationsResponse, RemoteClientError> {
+        self.get_authed(&format!(
+            "/v1/notifications?include_dismissed={include_dismissed}"
+        ))
+        .await
+    }
+
+    /// Updates a single notification (e.g. marks it as read).
+    pub async fn update_notification(
+        &self,
+        notification_id: Uuid,
+        request: &UpdateNotificationRequest,
+    ) -> Result<MutationResponse<Notification>, RemoteClientError> {
+        self.patch_authed(&format!("/v1/notifications/{notification_id}"), request)
+            .await
+    }
+
+    /// Updates several notifications in one transaction.
+    pub async fn bulk_update_notifications(
+        &self,
+        request: &BulkUpdateNotificationsRequest,
+    ) -> Result<BulkUpdateNotificationsResponse, RemoteClientError> {
+        self.post_authed("/v1/notifications/bulk", Some(request))
+            .await
+    }
+
     // ── Pull Requests ───────────────────────────────────────────────────
 
     /// Upserts a pull request on the remote server.
@@ -997,6 +1313,15 @@ impl RemoteClient {
         Ok(response.data)
     }
 
+    /// Gets a single pull request by ID.
+    pub async fn get_pull_request(
+        &self,
+        pull_request_id: Uuid,
+    ) -> Result<PullRequest, RemoteClientError> {
+        self.get_authed(&format!("/v1/pull_requests/{pull_request_id}"))
+            .await
+    }
+
     /// Lists pull requests linked to an issue.
     pub async fn list_pull_requests(
         &self,
@@ -1006,6 +1331,34 @@ impl RemoteClient {
             .await
     }
 
+    /// Lists pull requests across all issues in a project.
+    pub async fn list_project_pull_requests(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ListPullRequestsResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/pull_requests?project_id={project_id}"))
+            .await
+    }
+
+    /// Lists the links between pull requests and issues across a project.
+    pub async fn list_project_pull_request_issues(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ListPullRequestIssuesResponse, RemoteClientError> {
+        self.get_authed(&format!("/v1/pull_request_issues?project_id={project_id}"))
+            .await
+    }
+
+    /// Links a pull request to an issue, creating the pull request if the project doesn't
+    /// have one with the same URL yet.
+    pub async fn create_pull_request_issue(
+        &self,
+        request: &CreatePullRequestIssueRequest,
+    ) -> Result<MutationResponse<PullRequestIssue>, RemoteClientError> {
+        self.post_authed("/v1/pull_request_issues", Some(request))
+            .await
+    }
+
     /// Lists attachments for an issue on the remote server.
     pub async fn list_issue_attachments(
         &self,
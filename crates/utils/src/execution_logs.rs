@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use uuid::Uuid;
 
 use crate::{assets::asset_dir, log_msg::LogMsg};
@@ -56,6 +56,30 @@ pub async fn read_execution_log_file(path: &Path) -> std::io::Result<String> {
     tokio::fs::read_to_string(path).await
 }
 
+/// Reads at most the last `max_bytes` of a log file, starting at a line boundary, and whether
+/// earlier lines were skipped.
+pub async fn read_execution_log_file_tail(
+    path: &Path,
+    max_bytes: u64,
+) -> std::io::Result<(String, bool)> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let len = file.metadata().await?.len();
+    let start = len.saturating_sub(max_bytes);
+    file.seek(std::io::SeekFrom::Start(start)).await?;
+
+    let mut bytes = Vec::with_capacity((len - start) as usize);
+    file.read_to_end(&mut bytes).await?;
+    if start > 0 {
+        // The read most likely began mid-line; drop that partial JSONL record.
+        let skip = bytes
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(bytes.len(), |newline| newline + 1);
+        bytes.drain(..skip);
+    }
+    Ok((String::from_utf8_lossy(&bytes).into_owned(), start > 0))
+}
+
 pub fn parse_log_jsonl_lossy(execution_id: Uuid, jsonl: &str) -> Vec<LogMsg> {
     let mut messages = Vec::new();
     let mut bad_lines = 0usize;
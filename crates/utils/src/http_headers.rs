@@ -8,3 +8,17 @@ pub fn is_hop_by_hop_header(name: &str) -> bool {
         || name.eq_ignore_ascii_case("transfer-encoding")
         || name.eq_ignore_ascii_case("upgrade")
 }
+
+/// Header carrying a correlation ID from the MCP server through the local server to the
+/// remote API.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    /// Correlation ID of the request currently being handled.
+    pub static REQUEST_ID: String;
+}
+
+/// Returns the correlation ID of the current request, if one is in scope.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
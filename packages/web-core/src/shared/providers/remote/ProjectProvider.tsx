@@ -122,6 +122,13 @@ export function ProjectProvider({ projectId, children }: ProjectProviderProps) {
     workspacesResult,
   ]);
 
+  // Archived issues stay in the shape so links to them keep resolving, but are
+  // left off the board and lists.
+  const activeIssues = useMemo(
+    () => issuesResult.data.filter((issue) => !issue.archived_at),
+    [issuesResult.data]
+  );
+
   // Computed Maps for O(1) lookup
   const issuesById = useMemo(() => {
     const map = new Map<string, Issue>();
@@ -155,8 +162,8 @@ export function ProjectProvider({ projectId, children }: ProjectProviderProps) {
 
   const getIssuesForStatus = useCallback(
     (statusId: string) =>
-      issuesResult.data.filter((i) => i.status_id === statusId),
-    [issuesResult.data]
+      activeIssues.filter((i) => i.status_id === statusId),
+    [activeIssues]
   );
 
   const getAssigneesForIssue = useCallback(
@@ -229,7 +236,7 @@ export function ProjectProvider({ projectId, children }: ProjectProviderProps) {
       projectId,
 
       // Data
-      issues: issuesResult.data,
+      issues: activeIssues,
       statuses: statusesResult.data,
       tags: tagsResult.data,
       issueAssignees: issueAssigneesResult.data,
@@ -300,6 +307,7 @@ export function ProjectProvider({ projectId, children }: ProjectProviderProps) {
     }),
     [
       projectId,
+      activeIssues,
       issuesResult,
       statusesResult,
       tagsResult,
@@ -20,7 +20,11 @@ export type ProjectStatus = { id: string, project_id: string, name: string, colo
 
 export type Tag = { id: string, project_id: string, name: string, color: string, };
 
-export type Issue = { id: string, project_id: string, issue_number: number, simple_id: string, status_id: string, title: string, description: string | null, priority: IssuePriority | null, start_date: string | null, target_date: string | null, completed_at: string | null, sort_order: number, parent_issue_id: string | null, parent_issue_sort_order: number | null, extension_metadata: JsonValue, creator_user_id: string | null, created_at: string, updated_at: string, };
+export type Issue = { id: string, project_id: string, issue_number: number, simple_id: string, status_id: string, title: string, description: string | null, priority: IssuePriority | null, start_date: string | null, target_date: string | null, completed_at: string | null, 
+/**
+ * Set while the issue is archived. Archived issues are kept so they can be restored.
+ */
+archived_at: string | null, sort_order: number, parent_issue_id: string | null, parent_issue_sort_order: number | null, extension_metadata: JsonValue, creator_user_id: string | null, created_at: string, updated_at: string, };
 
 export type IssueAssignee = { id: string, issue_id: string, user_id: string, assigned_at: string, };
 
@@ -44,11 +48,31 @@ export type IssueCommentReaction = { id: string, comment_id: string, user_id: st
 
 export type IssuePriority = "urgent" | "high" | "medium" | "low";
 
-export type IssueSortField = "sort_order" | "priority" | "created_at" | "updated_at" | "title";
+export type IssueSortField = "sort_order" | "priority" | "created_at" | "updated_at" | "target_date" | "title" | "simple_id";
 
-export type ListIssuesQuery = { project_id: string, };
+export type ListIssuesQuery = { project_id: string, status_id?: string, priority?: IssuePriority, parent_issue_id?: string, search?: string, assignee_user_id?: string, tag_id?: string, limit?: number, offset?: number, };
 
-export type SearchIssuesRequest = { project_id: string, status_id?: string, status_ids?: Array<string>, priority?: IssuePriority, parent_issue_id?: string, search?: string, simple_id?: string, assignee_user_id?: string, tag_id?: string, tag_ids?: Array<string>, sort_field?: IssueSortField, sort_direction?: SortDirection, limit?: number, offset?: number, };
+export type SearchIssuesRequest = { project_id: string, status_id?: string, status_ids?: Array<string>, priority?: IssuePriority, parent_issue_id?: string, search?: string, simple_id?: string, assignee_user_id?: string, tag_id?: string, tag_ids?: Array<string>, sort_field?: IssueSortField, sort_direction?: SortDirection, limit?: number, offset?: number, 
+/**
+ * Archived issues are included unless this is `false`.
+ */
+include_archived?: boolean, 
+/**
+ * Only issues created after this time.
+ */
+created_after?: string, 
+/**
+ * Only issues updated after this time.
+ */
+updated_after?: string, 
+/**
+ * Only issues completed after this time.
+ */
+completed_after?: string, 
+/**
+ * Only issues due before this time. Issues without a target date never match.
+ */
+target_date_before?: string, };
 
 export type ListIssuesResponse = { issues: Array<Issue>, total_count: number, limit: number, offset: number, };
 
@@ -88,9 +112,9 @@ export type CreateProjectRequest = {
  */
 id?: string, organization_id: string, name: string, color: string, };
 
-export type UpdateProjectRequest = { name: string | null, color: string | null, sort_order: number | null, };
+export type UpdateProjectRequest = { name?: string | null, color?: string | null, sort_order?: number | null, };
 
-export type UpdateNotificationRequest = { seen: boolean | null, };
+export type UpdateNotificationRequest = { seen?: boolean | null, };
 
 export type CreateTagRequest = { 
 /**
@@ -99,7 +123,7 @@ export type CreateTagRequest = {
  */
 id?: string, project_id: string, name: string, color: string, };
 
-export type UpdateTagRequest = { name: string | null, color: string | null, };
+export type UpdateTagRequest = { name?: string | null, color?: string | null, };
 
 export type CreateProjectStatusRequest = { 
 /**
@@ -108,7 +132,7 @@ export type CreateProjectStatusRequest = {
  */
 id?: string, project_id: string, name: string, color: string, sort_order: number, hidden: boolean, };
 
-export type UpdateProjectStatusRequest = { name: string | null, color: string | null, sort_order: number | null, hidden: boolean | null, };
+export type UpdateProjectStatusRequest = { name?: string | null, color?: string | null, sort_order?: number | null, hidden?: boolean | null, };
 
 export type CreateIssueRequest = { 
 /**
@@ -154,7 +178,7 @@ export type CreateIssueCommentRequest = {
  */
 id?: string, issue_id: string, message: string, parent_id: string | null, };
 
-export type UpdateIssueCommentRequest = { message: string | null, parent_id: string | null | null, };
+export type UpdateIssueCommentRequest = { message?: string | null, parent_id?: string | null | null, };
 
 export type CreateIssueCommentReactionRequest = { 
 /**
@@ -231,6 +255,20 @@ export const USERS_SHAPE = defineShape<User>(
   '/v1/fallback/users'
 );
 
+export const ORG_ISSUES_SHAPE = defineShape<Issue>(
+  'issues',
+  ['organization_id'] as const,
+  '/v1/shape/org_issues',
+  '/v1/fallback/org_issues'
+);
+
+export const ORG_ISSUE_ASSIGNEES_SHAPE = defineShape<IssueAssignee>(
+  'issue_assignees',
+  ['organization_id', 'user_id'] as const,
+  '/v1/shape/org_issue_assignees',
+  '/v1/fallback/org_issue_assignees'
+);
+
 export const PROJECT_TAGS_SHAPE = defineShape<Tag>(
   'tags',
   ['project_id'] as const,